@@ -21,6 +21,9 @@ abi StorageMapExample {
 
     #[storage(read, write)]
     fn access_nested_map();
+
+    #[storage(read, write)]
+    fn access_with_index_syntax();
 }
 
 impl StorageMapExample for Contract {
@@ -59,4 +62,18 @@ impl StorageMapExample for Contract {
         assert(storage.nested_map.get(2).get(2).try_read().is_none()); // Nothing inserted here
     }
     // ANCHOR_END: storage_map_nested_access
+
+    // ANCHOR: storage_map_index_syntax
+    #[storage(read, write)]
+    fn access_with_index_syntax() {
+        let addr1 = Address::from(0x0101010101010101010101010101010101010101010101010101010101010101);
+
+        storage.map[addr1] = 42;
+        storage.map[addr1] += 1;
+        storage.nested_map[0][1] = 24;
+
+        assert(storage.map[addr1].read() == 43);
+        assert(storage.nested_map[0][1].read() == 24);
+    }
+    // ANCHOR_END: storage_map_index_syntax
 }
@@ -22,10 +22,21 @@ fn foo_shorter(array: MyArray) -> MyArray {
 }
 // ANCHOR_END: long_type_use_shorter
 
+// ANCHOR: generic_type_alias
+type Pair<T> = (T, T);
+
+fn swap<T>(pair: Pair<T>) -> Pair<T> {
+    (pair.1, pair.0)
+}
+// ANCHOR_END: generic_type_alias
+
 fn main() {
     // ANCHOR: addition 
     let x: u64 = 5;
     let y: Kilometers = 5;
     assert(x + y == 10);
     // ANCHOR_END: addition 
+
+    let distances: Pair<Kilometers> = (3, 7);
+    assert(swap(distances).0 == 7);
 }
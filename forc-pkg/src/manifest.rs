@@ -212,6 +212,8 @@ pub struct BuildProfile {
     #[serde(default)]
     pub print_ir: bool,
     #[serde(default)]
+    pub verify_ir: bool,
+    #[serde(default)]
     pub print_finalized_asm: bool,
     #[serde(default)]
     pub print_intermediate_asm: bool,
@@ -228,6 +230,16 @@ pub struct BuildProfile {
     #[serde(default)]
     pub error_on_warnings: bool,
     pub reverse_results: bool,
+    #[serde(default)]
+    pub json_diagnostics: bool,
+    #[serde(default)]
+    pub stack_frame_budget: Option<u64>,
+    #[serde(default)]
+    pub inline_threshold: Option<usize>,
+    #[serde(default)]
+    pub monomorphization_depth_limit: Option<usize>,
+    #[serde(default)]
+    pub cfg: Vec<String>,
 }
 
 impl DependencyDetails {
@@ -694,6 +706,7 @@ impl BuildProfile {
             print_dca_graph: None,
             print_dca_graph_url_format: None,
             print_ir: false,
+            verify_ir: false,
             print_finalized_asm: false,
             print_intermediate_asm: false,
             terse: false,
@@ -703,6 +716,11 @@ impl BuildProfile {
             json_abi_with_callpaths: false,
             error_on_warnings: false,
             reverse_results: false,
+            json_diagnostics: false,
+            stack_frame_budget: None,
+            inline_threshold: None,
+            monomorphization_depth_limit: None,
+            cfg: vec![],
         }
     }
 
@@ -712,6 +730,7 @@ impl BuildProfile {
             print_dca_graph: None,
             print_dca_graph_url_format: None,
             print_ir: false,
+            verify_ir: false,
             print_finalized_asm: false,
             print_intermediate_asm: false,
             terse: false,
@@ -721,6 +740,11 @@ impl BuildProfile {
             json_abi_with_callpaths: false,
             error_on_warnings: false,
             reverse_results: false,
+            json_diagnostics: false,
+            stack_frame_budget: None,
+            inline_threshold: None,
+            monomorphization_depth_limit: None,
+            cfg: vec![],
         }
     }
 }
@@ -17,7 +17,7 @@ use petgraph::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{hash_map, BTreeSet, HashMap, HashSet},
+    collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
     fs::{self, File},
     hash::{Hash, Hasher},
@@ -26,7 +26,7 @@ use std::{
     str::FromStr,
     sync::Arc,
 };
-use sway_core::fuel_prelude::fuel_types::ChainId;
+use sway_core::fuel_prelude::fuel_types::{Bytes32, ChainId};
 pub use sway_core::Programs;
 use sway_core::{
     abi_generation::{
@@ -43,10 +43,14 @@ use sway_core::{
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
-    BuildTarget, Engines, FinalizedEntry,
+    BuildTarget, Engines, FinalizedEntry, TypeInfo,
 };
-use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
-use sway_types::{Ident, Span, Spanned};
+use sway_error::{
+    error::CompileError,
+    handler::{Duplicates, Handler},
+    warning::CompileWarning,
+};
+use sway_types::{constants::TEST_SHOULD_REVERT_ARG_NAME, Ident, Span, Spanned};
 use sway_utils::{constants, time_expr, PerformanceData, PerformanceMetric};
 use tracing::{info, warn};
 
@@ -101,6 +105,8 @@ pub struct BuiltPackage {
     pub descriptor: PackageDescriptor,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    /// The path of the storage field holding the first word of each of the `storage_slots`.
+    pub storage_slot_paths: BTreeMap<Bytes32, String>,
     pub warnings: Vec<CompileWarning>,
     source_map: SourceMap,
     pub tree_type: TreeType,
@@ -157,6 +163,21 @@ pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
+    /// Whether the test function returns a `Result`, in which case returning `Err` fails the test.
+    pub returns_result: bool,
+}
+
+/// An entry of the test manifest written alongside the bytecode of a package built with tests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestManifestEntry {
+    /// The name of the test function.
+    pub name: String,
+    /// The file that contains the test function.
+    pub file_path: PathBuf,
+    /// The line number of the test declaration, starting from 1.
+    pub line_number: usize,
+    /// The offset of the test entry point in the bytecode.
+    pub offset: u64,
 }
 
 /// The result of successfully compiling a workspace.
@@ -176,12 +197,23 @@ pub struct CompiledPackage {
     pub tree_type: TreeType,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    pub storage_slot_paths: BTreeMap<Bytes32, String>,
     pub bytecode: BuiltPackageBytecode,
     pub namespace: namespace::Root,
     pub warnings: Vec<CompileWarning>,
     pub metrics: PerformanceData,
 }
 
+/// An initialized storage slot, as written to the storage slots JSON file.
+#[derive(Debug, Serialize)]
+pub struct StorageSlotEntry<'a> {
+    #[serde(flatten)]
+    pub slot: &'a StorageSlot,
+    /// The path of the storage field holding the first word of the slot, e.g. `config.limits`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<&'a str>,
+}
+
 /// Compiled contract dependency parts relevant to calculating a contract's ID.
 pub struct CompiledContractDependency {
     pub bytecode: Vec<u8>,
@@ -265,6 +297,8 @@ pub struct PrintOpts {
     pub ir: bool,
     /// Output build errors and warnings in reverse order.
     pub reverse_order: bool,
+    /// Output build errors and warnings as JSON, one object per line.
+    pub json_diagnostics: bool,
 }
 
 #[derive(Default, Clone)]
@@ -305,6 +339,8 @@ pub struct BuildOpts {
     pub metrics_outfile: Option<String>,
     /// Warnings must be treated as compiler errors.
     pub error_on_warnings: bool,
+    /// If set, overrides the monomorphization depth limit of the build profile.
+    pub monomorphization_depth_limit: Option<usize>,
     /// Include all test functions within the build.
     pub tests: bool,
     /// The set of options to filter by member project kind.
@@ -415,6 +451,17 @@ impl Edge {
 }
 
 impl BuiltPackage {
+    /// The initialized storage slots, each together with the path of the storage field it holds.
+    pub fn storage_slot_entries(&self) -> Vec<StorageSlotEntry> {
+        self.storage_slots
+            .iter()
+            .map(|slot| StorageSlotEntry {
+                slot,
+                path: self.storage_slot_paths.get(slot.key()).map(String::as_str),
+            })
+            .collect()
+    }
+
     /// Writes bytecode of the BuiltPackage to the given `path`.
     pub fn write_bytecode(&self, path: &Path) -> Result<()> {
         fs::write(path, &self.bytecode.bytes)?;
@@ -469,6 +516,31 @@ impl BuiltPackage {
         Ok(())
     }
 
+    /// Returns an entry for each test entry point of the BuiltPackage, so that tooling can run
+    /// them without recompiling the package.
+    pub fn test_manifest(&self) -> Vec<TestManifestEntry> {
+        self.bytecode
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                entry.kind.test().map(|test| TestManifestEntry {
+                    name: entry.finalized.fn_name.clone(),
+                    file_path: (*test.file_path).clone(),
+                    line_number: test.span.start_pos().line_col().0,
+                    offset: entry.finalized.imm,
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the test manifest of the BuiltPackage in JSON format to the given `path`.
+    pub fn write_test_manifest(&self, path: &Path) -> Result<()> {
+        let test_manifest_json =
+            serde_json::to_vec_pretty(&self.test_manifest()).expect("JSON serialization failed");
+        fs::write(path, test_manifest_json)?;
+        Ok(())
+    }
+
     /// Writes BuiltPackage to `output_dir`.
     pub fn write_output(
         &self,
@@ -488,6 +560,12 @@ impl BuiltPackage {
         let json_abi_path = output_dir.join(program_abi_stem).with_extension("json");
         self.write_json_abi(&json_abi_path, minify.clone())?;
 
+        if self.bytecode.entries.iter().any(PkgEntry::is_test) {
+            let test_manifest_stem = format!("{pkg_name}-tests");
+            let test_manifest_path = output_dir.join(test_manifest_stem).with_extension("json");
+            self.write_test_manifest(&test_manifest_path)?;
+        }
+
         info!("      Bytecode size: {} bytes", self.bytecode.bytes.len());
         // Additional ops required depending on the program type
         match self.tree_type {
@@ -496,10 +574,11 @@ impl BuiltPackage {
                 let storage_slots_stem = format!("{pkg_name}-storage_slots");
                 let storage_slots_path = output_dir.join(storage_slots_stem).with_extension("json");
                 let storage_slots_file = File::create(storage_slots_path)?;
+                let storage_slot_entries = self.storage_slot_entries();
                 let res = if minify.json_storage_slots {
-                    serde_json::to_writer(&storage_slots_file, &self.storage_slots)
+                    serde_json::to_writer(&storage_slots_file, &storage_slot_entries)
                 } else {
-                    serde_json::to_writer_pretty(&storage_slots_file, &self.storage_slots)
+                    serde_json::to_writer_pretty(&storage_slots_file, &storage_slot_entries)
                 };
 
                 res?;
@@ -1578,7 +1657,12 @@ pub fn sway_build_config(
     .print_finalized_asm(build_profile.print_finalized_asm)
     .print_intermediate_asm(build_profile.print_intermediate_asm)
     .print_ir(build_profile.print_ir)
+    .verify_ir(build_profile.verify_ir)
     .include_tests(build_profile.include_tests)
+    .stack_frame_budget(build_profile.stack_frame_budget)
+    .inline_threshold(build_profile.inline_threshold)
+    .monomorphization_depth_limit(build_profile.monomorphization_depth_limit)
+    .cfg_flags(build_profile.cfg.clone())
     .time_phases(build_profile.time_phases)
     .metrics(build_profile.metrics_outfile.clone());
     Ok(build_config)
@@ -1772,14 +1856,18 @@ pub fn compile(
         sway_build_config(pkg.manifest_file.dir(), &entry_path, pkg.target, profile)?;
     let terse_mode = profile.terse;
     let reverse_results = profile.reverse_results;
+    let json_diagnostics = profile.json_diagnostics;
     let fail = |handler: Handler| {
+        let duplicates = handler.duplicates();
         let (errors, warnings) = handler.consume();
         print_on_failure(
             engines.se(),
             terse_mode,
             &warnings,
             &errors,
+            &duplicates,
             reverse_results,
+            json_diagnostics,
         );
         bail!("Failed to compile {}", pkg.name);
     };
@@ -1818,6 +1906,7 @@ pub fn compile(
     }
 
     let storage_slots = typed_program.storage_slots.clone();
+    let storage_slot_paths = typed_program.storage_slot_paths.clone();
     let tree_type = typed_program.kind.tree_type();
 
     let namespace = typed_program.root.namespace.clone().into();
@@ -1909,9 +1998,18 @@ pub fn compile(
         _ => return fail(handler),
     };
 
+    let duplicates = handler.duplicates();
     let (_, warnings) = handler.consume();
 
-    print_warnings(engines.se(), terse_mode, &pkg.name, &warnings, &tree_type);
+    print_warnings(
+        engines.se(),
+        terse_mode,
+        &pkg.name,
+        &warnings,
+        &duplicates,
+        &tree_type,
+        json_diagnostics,
+    );
 
     // TODO: This should probably be in `fuel_abi_json::generate_json_abi_program`?
     // If ABI requires knowing config offsets, they should be inputs to ABI gen.
@@ -1937,6 +2035,7 @@ pub fn compile(
         source_map: source_map.clone(),
         program_abi,
         storage_slots,
+        storage_slot_paths,
         tree_type,
         bytecode,
         namespace,
@@ -1983,8 +2082,6 @@ impl PkgTestEntry {
         let span = decl_ref.span();
         let test_function_decl = engines.de().get_function(&decl_ref);
 
-        const FAILING_TEST_KEYWORD: &str = "should_revert";
-
         let test_args: HashMap<String, Option<String>> = test_function_decl
             .attributes
             .get(&AttributeKind::Test)
@@ -2003,7 +2100,7 @@ impl PkgTestEntry {
 
         let pass_condition = if test_args.is_empty() {
             anyhow::Ok(TestPassCondition::ShouldNotRevert)
-        } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
+        } else if let Some(args) = test_args.get(TEST_SHOULD_REVERT_ARG_NAME) {
             let expected_revert_code = args
                 .as_ref()
                 .map(|arg| {
@@ -2023,10 +2120,18 @@ impl PkgTestEntry {
                     .ok_or_else(|| anyhow::anyhow!("Missing span for test function"))?,
             ),
         );
+        let returns_result = match engines
+            .te()
+            .get_unaliased(test_function_decl.return_type.type_id)
+        {
+            TypeInfo::Enum(decl_ref) => engines.de().get_enum(&decl_ref).is_result_like(),
+            _ => false,
+        };
         Ok(Self {
             pass_condition,
             span,
             file_path,
+            returns_result,
         })
     }
 }
@@ -2053,6 +2158,7 @@ fn build_profile_from_opts(
         metrics_outfile,
         tests,
         error_on_warnings,
+        monomorphization_depth_limit,
         ..
     } = build_options;
     let mut selected_build_profile = BuildProfile::DEBUG;
@@ -2099,6 +2205,7 @@ fn build_profile_from_opts(
     profile.print_finalized_asm |= print.finalized_asm;
     profile.print_intermediate_asm |= print.intermediate_asm;
     profile.terse |= pkg.terse;
+    profile.json_diagnostics |= print.json_diagnostics;
     profile.time_phases |= time_phases;
     if profile.metrics_outfile.is_none() {
         profile.metrics_outfile = metrics_outfile.clone();
@@ -2106,6 +2213,9 @@ fn build_profile_from_opts(
     profile.include_tests |= tests;
     profile.json_abi_with_callpaths |= pkg.json_abi_with_callpaths;
     profile.error_on_warnings |= error_on_warnings;
+    if monomorphization_depth_limit.is_some() {
+        profile.monomorphization_depth_limit = *monomorphization_depth_limit;
+    }
 
     Ok((selected_build_profile.to_string(), profile))
 }
@@ -2310,7 +2420,9 @@ pub fn build(
                 profile.terse,
                 warnings,
                 errors,
+                &Duplicates::default(),
                 profile.reverse_results,
+                profile.json_diagnostics,
             );
             bail!("Failed to compile {}", pkg.name);
         };
@@ -2413,7 +2525,9 @@ pub fn build(
                     profile.terse,
                     &[],
                     &errs,
+                    &Duplicates::default(),
                     profile.reverse_results,
+                    profile.json_diagnostics,
                 );
                 bail!("Failed to compile {}", pkg.name);
             }
@@ -2450,6 +2564,7 @@ pub fn build(
             descriptor,
             program_abi: compiled.program_abi,
             storage_slots: compiled.storage_slots,
+            storage_slot_paths: compiled.storage_slot_paths,
             source_map: compiled.source_map,
             tree_type: compiled.tree_type,
             bytecode: compiled.bytecode,
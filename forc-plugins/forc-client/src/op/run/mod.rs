@@ -192,6 +192,7 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
             intermediate_asm: cmd.print.intermediate_asm,
             ir: cmd.print.ir,
             reverse_order: cmd.print.reverse_order,
+            json_diagnostics: cmd.print.json_diagnostics,
         },
         minify: pkg::MinifyOpts {
             json_abi: cmd.minify.json_abi,
@@ -201,6 +202,7 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
         build_profile: cmd.build_profile.build_profile.clone(),
         release: cmd.build_profile.release,
         error_on_warnings: cmd.build_profile.error_on_warnings,
+        monomorphization_depth_limit: cmd.build_profile.monomorphization_depth_limit,
         time_phases: cmd.print.time_phases,
         metrics_outfile: cmd.print.metrics_outfile.clone(),
         binary_outfile: cmd.build_output.bin_file.clone(),
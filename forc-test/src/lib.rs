@@ -59,6 +59,8 @@ pub struct TestResult {
     pub state: vm::state::ProgramState,
     /// The required state of the VM for this test to pass.
     pub condition: pkg::TestPassCondition,
+    /// Whether the test function returned the `Err` variant of a `Result`.
+    pub returned_err: bool,
     /// Emitted `Recipt`s during the execution of the test.
     pub logs: Vec<fuel_tx::Receipt>,
     /// Gas used while executing this test.
@@ -160,6 +162,8 @@ pub struct Opts {
     pub release: bool,
     /// Should warnings be treated as errors?
     pub error_on_warnings: bool,
+    /// If set, overrides the monomorphization depth limit of the build profile.
+    pub monomorphization_depth_limit: Option<usize>,
     /// Output the time elapsed over each part of the compilation process.
     pub time_phases: bool,
     /// Output compilation metrics into file.
@@ -434,6 +438,17 @@ impl<'a> PackageTests {
                             anyhow::anyhow!("missing used gas information from test execution")
                         })?;
 
+                    // A `Result` is returned by reference and its first word is the variant tag,
+                    // which is `0` for `Ok` and `1` for `Err`.
+                    let returned_err = test_entry.returns_result
+                        && receipts.iter().any(|receipt| match receipt {
+                            tx::Receipt::ReturnData { data, .. } => data
+                                .get(..8)
+                                .and_then(|tag| <[u8; 8]>::try_from(tag).ok())
+                                .map_or(false, |tag| u64::from_be_bytes(tag) != 0),
+                            _ => false,
+                        });
+
                     // Only retain `Log` and `LogData` receipts.
                     let logs = receipts
                         .into_iter()
@@ -453,6 +468,7 @@ impl<'a> PackageTests {
                         span,
                         state,
                         condition,
+                        returned_err,
                         logs,
                         gas_used,
                     })
@@ -501,6 +517,7 @@ impl Opts {
             build_profile: self.build_profile,
             release: self.release,
             error_on_warnings: self.error_on_warnings,
+            monomorphization_depth_limit: self.monomorphization_depth_limit,
             time_phases: self.time_phases,
             metrics_outfile: self.metrics_outfile,
             tests: true,
@@ -515,10 +532,12 @@ impl TestResult {
         match &self.condition {
             TestPassCondition::ShouldRevert(revert_code) => match revert_code {
                 Some(revert_code) => self.state == vm::state::ProgramState::Revert(*revert_code),
-                None => matches!(self.state, vm::state::ProgramState::Revert(_)),
+                None => {
+                    matches!(self.state, vm::state::ProgramState::Revert(_)) || self.returned_err
+                }
             },
             TestPassCondition::ShouldNotRevert => {
-                !matches!(self.state, vm::state::ProgramState::Revert(_))
+                !matches!(self.state, vm::state::ProgramState::Revert(_)) && !self.returned_err
             }
         }
     }
@@ -829,7 +848,11 @@ fn exec_test(
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{path::PathBuf, sync::Arc};
+
+    use forc_pkg::TestPassCondition;
+    use fuel_vm::state::ProgramState;
+    use sway_types::Span;
 
     use crate::{build, BuiltTests, Opts, TestFilter, TestResult};
 
@@ -949,4 +972,48 @@ mod tests {
 
         assert_eq!(tested_package_test_count, 2)
     }
+
+    fn test_result(
+        state: ProgramState,
+        condition: TestPassCondition,
+        returned_err: bool,
+    ) -> TestResult {
+        TestResult {
+            name: "test".to_string(),
+            duration: std::time::Duration::default(),
+            span: Span::dummy(),
+            file_path: Arc::new(PathBuf::new()),
+            state,
+            condition,
+            returned_err,
+            logs: vec![],
+            gas_used: 0,
+        }
+    }
+
+    #[test]
+    fn test_returned_err_fails_should_not_revert() {
+        let condition = TestPassCondition::ShouldNotRevert;
+
+        assert!(test_result(ProgramState::Return(0), condition.clone(), false).passed());
+        assert!(!test_result(ProgramState::Return(0), condition.clone(), true).passed());
+        assert!(!test_result(ProgramState::Revert(0), condition, false).passed());
+    }
+
+    #[test]
+    fn test_returned_err_passes_should_revert() {
+        let condition = TestPassCondition::ShouldRevert(None);
+
+        assert!(test_result(ProgramState::Return(0), condition.clone(), true).passed());
+        assert!(test_result(ProgramState::Revert(0), condition.clone(), false).passed());
+        assert!(!test_result(ProgramState::Return(0), condition, false).passed());
+    }
+
+    #[test]
+    fn test_returned_err_fails_should_revert_with_code() {
+        let condition = TestPassCondition::ShouldRevert(Some(42));
+
+        assert!(test_result(ProgramState::Revert(42), condition.clone(), false).passed());
+        assert!(!test_result(ProgramState::Return(0), condition, true).passed());
+    }
 }
@@ -0,0 +1,187 @@
+//! Machine-readable representation of compile errors and warnings.
+//!
+//! A [JsonDiagnostic] is created from the very same [Diagnostic] that is used
+//! to render the human-readable output, so both outputs always report the same
+//! reason, code, labels and help.
+
+use serde::{Deserialize, Serialize};
+use sway_error::diagnostic::{Diagnostic, Label, LabelType, Level, SourcePath, Suggestion};
+use sway_types::Span;
+
+/// A single compile error or warning, emitted as one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonDiagnostic {
+    pub severity: JsonSeverity,
+    /// The code of the diagnostic, e.g., `E4019`, if it has one.
+    pub code: Option<String>,
+    /// The same message that is shown in the title of the human-readable output.
+    pub message: String,
+    /// The place in source code where the issue occurs, if the issue is related to source code.
+    pub primary_span: Option<JsonSpan>,
+    /// All the other places in source code that are related to the issue.
+    pub secondary_spans: Vec<JsonSpan>,
+    pub help: Vec<String>,
+    /// The replacement of a piece of source code that would resolve the issue, if known.
+    pub suggested_replacement: Option<JsonSuggestion>,
+    /// The number of identical diagnostics that were removed during deduplication.
+    pub duplicates: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonLabelType {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A span of source code. Lines and columns are one-based, byte offsets are zero-based
+/// and the end offset is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSpan {
+    pub file: Option<String>,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub column_start: usize,
+    pub line_end: usize,
+    pub column_end: usize,
+    /// The label attached to the span. Empty for a [JsonSuggestion] span.
+    pub label: String,
+    pub label_type: Option<JsonLabelType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSuggestion {
+    pub span: JsonSpan,
+    pub replacement: String,
+}
+
+impl JsonDiagnostic {
+    /// Creates the [JsonDiagnostic] out of the `diagnostic`, noting the number
+    /// of its `duplicates` removed by [sway_error::handler::Handler::dedup].
+    pub fn new(diagnostic: &Diagnostic, duplicates: usize) -> Self {
+        let issue = diagnostic.issue();
+        Self {
+            severity: match diagnostic.level() {
+                Level::Error => JsonSeverity::Error,
+                Level::Warning => JsonSeverity::Warning,
+            },
+            code: diagnostic.code().map(str::to_string),
+            message: message(diagnostic),
+            primary_span: issue.is_in_source().then(|| JsonSpan::from_label(issue)),
+            secondary_spans: diagnostic
+                .secondary_labels()
+                .into_iter()
+                .map(JsonSpan::from_label)
+                .collect(),
+            help: diagnostic.help().cloned().collect(),
+            suggested_replacement: diagnostic.suggestion().map(JsonSuggestion::new),
+            duplicates,
+        }
+    }
+
+    /// Serializes the diagnostic into a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("a diagnostic is always serializable to JSON")
+    }
+}
+
+impl JsonSpan {
+    fn from_label(label: &Label) -> Self {
+        Self {
+            label: label.text().to_string(),
+            label_type: Some(match label.label_type() {
+                LabelType::Info => JsonLabelType::Info,
+                LabelType::Warning => JsonLabelType::Warning,
+                LabelType::Error => JsonLabelType::Error,
+            }),
+            ..Self::from_span(label.span(), label.source_path())
+        }
+    }
+
+    fn from_span(span: &Span, source_path: Option<&SourcePath>) -> Self {
+        let (start, end) = span.line_col();
+        Self {
+            file: source_path.map(|path| path.as_str().to_string()),
+            byte_start: span.start(),
+            byte_end: span.end(),
+            line_start: start.line,
+            column_start: start.col,
+            line_end: end.line,
+            column_end: end.col,
+            label: String::new(),
+            label_type: None,
+        }
+    }
+}
+
+impl JsonSuggestion {
+    fn new(suggestion: &Suggestion) -> Self {
+        Self {
+            span: JsonSpan::from_span(suggestion.span(), suggestion.source_path()),
+            replacement: suggestion.replacement().to_string(),
+        }
+    }
+}
+
+/// The title of the diagnostic, built the same way as in the human-readable output.
+pub(crate) fn message(diagnostic: &Diagnostic) -> String {
+    let mut message = String::new();
+    if let Some(reason) = diagnostic.reason() {
+        message.push_str(reason.description());
+        message.push_str(". ");
+    }
+    message.push_str(diagnostic.issue().friendly_text());
+    if !diagnostic.is_old_style() && !message.ends_with('.') {
+        message.push('.');
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use sway_error::{diagnostic::ToDiagnostic, error::CompileError};
+    use sway_types::{Ident, SourceEngine};
+
+    #[test]
+    fn suggestion_replaces_the_unknown_name() {
+        let src: Arc<str> = Arc::from("fn f(v: Vex<u64>) {}");
+        let span = |start, end| Span::new(src.clone(), start, end, None).unwrap();
+        let error = CompileError::UnknownParameterType {
+            param_name: Ident::new(span(5, 6)),
+            ty: "Vex".to_string(),
+            suggestion: Some("Vec".to_string()),
+            type_span: span(8, 16),
+            span: span(8, 11),
+        };
+
+        let diagnostic = error.to_diagnostic(&SourceEngine::default());
+        let json = JsonDiagnostic::new(&diagnostic, 0);
+
+        let suggestion = json.suggested_replacement.as_ref().unwrap();
+        assert_eq!(suggestion.replacement, "Vec");
+        assert_eq!(
+            (suggestion.span.byte_start, suggestion.span.byte_end),
+            (8, 11)
+        );
+        assert_eq!(
+            (suggestion.span.column_start, suggestion.span.column_end),
+            (9, 12)
+        );
+
+        let parsed: JsonDiagnostic = serde_json::from_str(&json.to_json_line()).unwrap();
+        assert_eq!(parsed, json);
+        assert_eq!(parsed.severity, JsonSeverity::Error);
+        assert_eq!(parsed.code.as_deref(), Some(error.code().as_str()));
+    }
+}
@@ -17,12 +17,16 @@ use sway_core::language::parsed::TreeType;
 use sway_error::{
     diagnostic::{Diagnostic, Issue, Label, LabelType, Level, ToDiagnostic},
     error::CompileError,
+    handler::Duplicates,
     warning::CompileWarning,
 };
 use sway_types::{LineCol, SourceEngine, Span};
 use sway_utils::constants;
 use tracing::error;
 
+use crate::json_diagnostics::JsonDiagnostic;
+
+pub mod json_diagnostics;
 pub mod restricted;
 
 pub const DEFAULT_OUTPUT_DIRECTORY: &str = "out";
@@ -367,17 +371,23 @@ pub fn print_warnings(
     terse_mode: bool,
     proj_name: &str,
     warnings: &[CompileWarning],
+    duplicates: &Duplicates,
     tree_type: &TreeType,
+    json_diagnostics: bool,
 ) {
     if warnings.is_empty() {
         return;
     }
     let type_str = program_type_str(tree_type);
 
-    if !terse_mode {
-        warnings
-            .iter()
-            .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
+    if json_diagnostics || !terse_mode {
+        warnings.iter().for_each(|w| {
+            print_diagnostic(
+                &w.to_diagnostic(source_engine),
+                duplicates.of_warning(w),
+                json_diagnostics,
+            )
+        });
     }
 
     println_yellow_err(&format!(
@@ -398,28 +408,34 @@ pub fn print_on_failure(
     terse_mode: bool,
     warnings: &[CompileWarning],
     errors: &[CompileError],
+    duplicates: &Duplicates,
     reverse_results: bool,
+    json_diagnostics: bool,
 ) {
     let e_len = errors.len();
     let w_len = warnings.len();
 
-    if !terse_mode {
+    if json_diagnostics || !terse_mode {
+        let print_warning = |w: &CompileWarning| {
+            print_diagnostic(
+                &w.to_diagnostic(source_engine),
+                duplicates.of_warning(w),
+                json_diagnostics,
+            )
+        };
+        let print_error = |e: &CompileError| {
+            print_diagnostic(
+                &e.to_diagnostic(source_engine),
+                duplicates.of_error(e),
+                json_diagnostics,
+            )
+        };
         if reverse_results {
-            warnings
-                .iter()
-                .rev()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
-            errors
-                .iter()
-                .rev()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+            warnings.iter().rev().for_each(print_warning);
+            errors.iter().rev().for_each(print_error);
         } else {
-            warnings
-                .iter()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
-            errors
-                .iter()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+            warnings.iter().for_each(print_warning);
+            errors.iter().for_each(print_error);
         }
     }
 
@@ -437,26 +453,48 @@ pub fn print_on_failure(
     }
 }
 
-fn format_diagnostic(diagnostic: &Diagnostic) {
-    /// Temporary switch for testing the feature.
-    /// Keep it false until we decide to fully support the diagnostic codes.
-    const SHOW_DIAGNOSTIC_CODE: bool = false;
+/// Prints the `diagnostic` either in the human-readable format or, if `json_diagnostics`
+/// is set, as a single line of JSON to stdout.
+fn print_diagnostic(diagnostic: &Diagnostic, duplicates: usize, json_diagnostics: bool) {
+    if json_diagnostics {
+        println!(
+            "{}",
+            JsonDiagnostic::new(diagnostic, duplicates).to_json_line()
+        );
+    } else {
+        format_diagnostic(diagnostic, duplicates);
+    }
+}
+
+/// Formats the `diagnostic`, noting the number of its `duplicates` removed by
+/// [sway_error::handler::Handler::dedup], if any.
+fn format_diagnostic(diagnostic: &Diagnostic, duplicates: usize) {
+    let duplicates_note = (duplicates > 0).then(|| {
+        format!(
+            "...and {duplicates} more duplicate {}.",
+            if duplicates > 1 {
+                "instances"
+            } else {
+                "instance"
+            }
+        )
+    });
+    let duplicates_note = duplicates_note.as_deref().map(|note| Annotation {
+        id: None,
+        label: Some(note),
+        annotation_type: AnnotationType::Note,
+    });
 
     if diagnostic.is_old_style() {
-        format_old_style_diagnostic(diagnostic.issue());
+        format_old_style_diagnostic(diagnostic.issue(), diagnostic.code(), duplicates_note);
         return;
     }
 
-    let mut label = String::new();
-    get_title_label(diagnostic, &mut label);
+    let label = json_diagnostics::message(diagnostic);
 
     let snippet_title = Some(Annotation {
         label: Some(label.as_str()),
-        id: if SHOW_DIAGNOSTIC_CODE {
-            diagnostic.reason().map(|reason| reason.code())
-        } else {
-            None
-        },
+        id: diagnostic.code(),
         annotation_type: diagnostic_level_to_annotation_type(diagnostic.level()),
     });
 
@@ -480,6 +518,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
             annotation_type: AnnotationType::Help,
         });
     }
+    snippet_footer.extend(duplicates_note);
 
     let snippet = Snippet {
         title: snippet_title,
@@ -496,7 +535,11 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
         Level::Error => tracing::error!("{}\n____\n", DisplayList::from(snippet)),
     }
 
-    fn format_old_style_diagnostic(issue: &Issue) {
+    fn format_old_style_diagnostic(
+        issue: &Issue,
+        code: Option<&str>,
+        duplicates_note: Option<Annotation>,
+    ) {
         let annotation_type = label_type_to_annotation_type(issue.label_type());
 
         let snippet_title = Some(Annotation {
@@ -505,7 +548,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
             } else {
                 Some(issue.friendly_text())
             },
-            id: None,
+            id: code,
             annotation_type,
         });
 
@@ -536,7 +579,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
 
         let snippet = Snippet {
             title: snippet_title,
-            footer: vec![],
+            footer: duplicates_note.into_iter().collect(),
             slices: snippet_slices,
             opt: FormatOptions {
                 color: true,
@@ -547,16 +590,6 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
         tracing::error!("{}\n____\n", DisplayList::from(snippet));
     }
 
-    fn get_title_label(diagnostics: &Diagnostic, label: &mut String) {
-        label.clear();
-        if diagnostics.reason().is_some() {
-            label.push_str(diagnostics.reason().unwrap().description());
-            label.push_str(". ");
-        }
-        label.push_str(diagnostics.issue().friendly_text());
-        label.push('.');
-    }
-
     fn diagnostic_level_to_annotation_type(level: Level) -> AnnotationType {
         match level {
             Level::Warning => AnnotationType::Warning,
@@ -0,0 +1,24 @@
+use clap::Parser;
+use forc_util::{forc_result_bail, ForcResult};
+use sway_error::error_codes;
+
+/// Print the extended explanation of a compiler error, given its code, e.g. `E4019`.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The code of the error, as shown in the header of the diagnostic.
+    pub code: String,
+}
+
+pub fn exec(command: Command) -> ForcResult<()> {
+    let code = command.code.trim().to_uppercase();
+    match error_codes::explain(&code) {
+        Some(explanation) => println!("{explanation}"),
+        None if error_codes::is_error_code(&code) => {
+            println!(
+                "{code} does not have an extended explanation. Its message describes it fully."
+            )
+        }
+        None => forc_result_bail!("{} is not a valid error code.", code),
+    }
+    Ok(())
+}
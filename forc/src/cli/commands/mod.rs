@@ -4,6 +4,7 @@ pub mod check;
 pub mod clean;
 pub mod completions;
 pub mod contract_id;
+pub mod explain;
 pub mod init;
 pub mod new;
 pub mod parse_bytecode;
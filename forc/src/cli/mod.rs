@@ -1,8 +1,8 @@
 use std::str::FromStr;
 
 use self::commands::{
-    addr2line, build, check, clean, completions, contract_id, init, new, parse_bytecode, plugins,
-    predicate_root, template, test, update,
+    addr2line, build, check, clean, completions, contract_id, explain, init, new, parse_bytecode,
+    plugins, predicate_root, template, test, update,
 };
 use addr2line::Command as Addr2LineCommand;
 use anyhow::anyhow;
@@ -12,6 +12,7 @@ use clap::{Parser, Subcommand};
 pub use clean::Command as CleanCommand;
 pub use completions::Command as CompletionsCommand;
 pub(crate) use contract_id::Command as ContractIdCommand;
+pub use explain::Command as ExplainCommand;
 use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions};
 use forc_util::ForcResult;
 pub use init::Command as InitCommand;
@@ -57,6 +58,7 @@ enum Forc {
     Check(CheckCommand),
     Clean(CleanCommand),
     Completions(CompletionsCommand),
+    Explain(ExplainCommand),
     New(NewCommand),
     Init(InitCommand),
     ParseBytecode(ParseBytecodeCommand),
@@ -96,6 +98,7 @@ pub async fn run_cli() -> ForcResult<()> {
         Forc::Check(command) => check::exec(command),
         Forc::Clean(command) => clean::exec(command),
         Forc::Completions(command) => completions::exec(command),
+        Forc::Explain(command) => explain::exec(command),
         Forc::Init(command) => init::exec(command),
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
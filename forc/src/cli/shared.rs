@@ -50,6 +50,10 @@ pub struct BuildProfile {
     /// Treat warnings as errors.
     #[clap(long)]
     pub error_on_warnings: bool,
+    /// How deeply the type arguments of the types a generic function is instantiated with may be
+    /// nested. Overrides the limit of the build profile.
+    #[clap(long)]
+    pub monomorphization_depth_limit: Option<usize>,
 }
 
 /// Options related to printing stages of compiler output.
@@ -89,6 +93,10 @@ pub struct Print {
     /// Output build errors and warnings in reverse order.
     #[clap(long)]
     pub reverse_order: bool,
+    /// Output build errors and warnings as JSON, one object per line, instead of the
+    /// human-readable format.
+    #[clap(long)]
+    pub json_diagnostics: bool,
     /// Output compilation metrics into file.
     #[clap(long)]
     pub metrics_outfile: Option<String>,
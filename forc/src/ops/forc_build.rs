@@ -27,6 +27,7 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
             intermediate_asm: cmd.build.print.intermediate_asm,
             ir: cmd.build.print.ir,
             reverse_order: cmd.build.print.reverse_order,
+            json_diagnostics: cmd.build.print.json_diagnostics,
         },
         time_phases: cmd.build.print.time_phases,
         metrics_outfile: cmd.build.print.metrics_outfile,
@@ -37,6 +38,7 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
         build_profile: cmd.build.profile.build_profile,
         release: cmd.build.profile.release,
         error_on_warnings: cmd.build.profile.error_on_warnings,
+        monomorphization_depth_limit: cmd.build.profile.monomorphization_depth_limit,
         binary_outfile: cmd.build.output.bin_file,
         debug_outfile: cmd.build.output.debug_file,
         build_target: cmd.build.build_target,
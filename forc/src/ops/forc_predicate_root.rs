@@ -31,6 +31,7 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
             intermediate_asm: cmd.print.intermediate_asm,
             ir: cmd.print.ir,
             reverse_order: cmd.print.reverse_order,
+            json_diagnostics: cmd.print.json_diagnostics,
         },
         time_phases: cmd.print.time_phases,
         metrics_outfile: cmd.print.metrics_outfile,
@@ -41,6 +42,7 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
         build_profile: cmd.build_profile.build_profile.clone(),
         release: cmd.build_profile.release,
         error_on_warnings: cmd.build_profile.error_on_warnings,
+        monomorphization_depth_limit: cmd.build_profile.monomorphization_depth_limit,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file,
         build_target: BuildTarget::default(),
@@ -1,4 +1,5 @@
 use crate::priv_prelude::*;
+use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct Annotated<T> {
@@ -12,6 +13,11 @@ pub struct Annotated<T> {
 //    #[attribute()]
 //    #[attribute(value)]
 //    #[attribute(value0, value1, value2)]
+//
+// Arguments can have a value or arguments of their own:
+//
+//    #[attribute(name = "value")]
+//    #[attribute(name(value0, value1))]
 
 #[derive(Clone, Debug, Serialize)]
 pub struct AttributeDecl {
@@ -19,6 +25,16 @@ pub struct AttributeDecl {
     pub attribute: SquareBrackets<Punctuated<Attribute, CommaToken>>,
 }
 
+impl AttributeDecl {
+    /// Returns `true` if this attribute declaration stems from a doc comment, i.e., `///` or `//!`.
+    pub fn is_doc_comment(&self) -> bool {
+        self.attribute
+            .get()
+            .into_iter()
+            .any(|attr| attr.name.as_str() == DOC_COMMENT_ATTRIBUTE_NAME)
+    }
+}
+
 impl Spanned for AttributeDecl {
     fn span(&self) -> Span {
         let hash_span = match &self.hash_kind {
@@ -56,12 +72,15 @@ pub enum AttributeHashKind {
 pub struct AttributeArg {
     pub name: Ident,
     pub value: Option<Literal>,
+    pub args: Option<Parens<Punctuated<AttributeArg, CommaToken>>>,
 }
 
 impl Spanned for AttributeArg {
     fn span(&self) -> Span {
         if let Some(value) = &self.value {
             Span::join(self.name.span(), value.span())
+        } else if let Some(args) = &self.args {
+            Span::join(self.name.span(), args.span())
         } else {
             self.name.span()
         }
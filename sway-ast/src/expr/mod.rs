@@ -79,6 +79,11 @@ pub enum Expr {
         bang_token: BangToken,
         expr: Box<Expr>,
     },
+    /// Forwards the arguments collected by a rest parameter, as in `f(a, ..rest)`.
+    Spread {
+        double_dot_token: DoubleDotToken,
+        name: Ident,
+    },
     Mul {
         lhs: Box<Expr>,
         star_token: StarToken,
@@ -230,6 +235,10 @@ impl Spanned for Expr {
             Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
             Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
+            Expr::Spread {
+                double_dot_token,
+                name,
+            } => Span::join(double_dot_token.span(), name.span()),
             Expr::Pow { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Div { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
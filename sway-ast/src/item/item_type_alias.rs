@@ -4,6 +4,7 @@ use crate::priv_prelude::*;
 pub struct ItemTypeAlias {
     pub visibility: Option<PubToken>,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub type_token: TypeToken,
     pub eq_token: EqToken,
     pub ty: Ty,
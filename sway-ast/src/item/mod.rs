@@ -74,18 +74,28 @@ impl Spanned for TypeField {
 
 #[derive(Clone, Debug, Serialize)]
 pub enum FnArgs {
-    Static(Punctuated<FnArg, CommaToken>),
+    Static {
+        args: Punctuated<FnArg, CommaToken>,
+        /// The rest parameter that collects the remaining arguments, as in `fn f(a: u64, ..rest)`.
+        rest_opt: Option<FnArgRest>,
+    },
     NonStatic {
         self_token: SelfToken,
         ref_self: Option<RefToken>,
         mutable_self: Option<MutToken>,
+        /// The explicit type of `self`, as in `self: Ptr<Self>`.
+        self_ty_opt: Option<(ColonToken, Box<Ty>)>,
         args_opt: Option<(CommaToken, Punctuated<FnArg, CommaToken>)>,
     },
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct FnArg {
+    /// The attributes of the parameter, as in `#[cfg(testnet)] x: u64`.
+    pub attribute_list: Vec<AttributeDecl>,
     pub pattern: Pattern,
+    /// Marks an optional parameter, as in `x?: u64`.
+    pub question_mark_token: Option<QuestionMarkToken>,
     pub colon_token: ColonToken,
     pub ty: Ty,
 }
@@ -96,6 +106,18 @@ impl Spanned for FnArg {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct FnArgRest {
+    pub double_dot_token: DoubleDotToken,
+    pub name: Ident,
+}
+
+impl Spanned for FnArgRest {
+    fn span(&self) -> Span {
+        Span::join(self.double_dot_token.span(), self.name.span())
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct FnSignature {
     pub visibility: Option<PubToken>,
@@ -42,13 +42,13 @@ pub use crate::{
         item_trait::{ItemTrait, ItemTraitItem, Traits},
         item_type_alias::ItemTypeAlias,
         item_use::{ItemUse, UseTree},
-        FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
+        FnArg, FnArgRest, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
     keywords::{CommaToken, DoubleColonToken, PubToken},
     literal::{LitInt, LitIntType, Literal},
     module::{Module, ModuleKind},
     path::{PathExpr, PathExprSegment, PathType, PathTypeSegment, QualifiedPathRoot},
-    pattern::{Pattern, PatternStructField},
+    pattern::{Pattern, PatternArrayElement, PatternStructField},
     punctuated::Punctuated,
     statement::{Statement, StatementLet},
     submodule::Submodule,
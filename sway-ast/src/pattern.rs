@@ -30,6 +30,15 @@ pub enum Pattern {
         fields: Braces<Punctuated<PatternStructField, CommaToken>>,
     },
     Tuple(Parens<Punctuated<Pattern, CommaToken>>),
+    /// A pattern that destructures an array, as in `[first, second, ..rest]`.
+    Array(SquareBrackets<Punctuated<PatternArrayElement, CommaToken>>),
+    /// A pattern that binds the whole value to `name`, as well as matching it against `pattern`,
+    /// as in `whole @ Point { x, .. }`.
+    At {
+        name: Ident,
+        at_token: AtToken,
+        pattern: Box<Pattern>,
+    },
     // to handle parser recovery: Error represents an incomplete Constructor
     Error(Box<[Span]>, #[serde(skip_serializing)] ErrorEmitted),
 }
@@ -61,6 +70,8 @@ impl Spanned for Pattern {
             Pattern::Constructor { path, args } => Span::join(path.span(), args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), fields.span()),
             Pattern::Tuple(pat_tuple) => pat_tuple.span(),
+            Pattern::Array(pat_array) => pat_array.span(),
+            Pattern::At { name, pattern, .. } => Span::join(name.span(), pattern.span()),
             Pattern::Error(spans, _) => spans.iter().cloned().reduce(Span::join).unwrap(),
         }
     }
@@ -92,3 +103,25 @@ impl Spanned for PatternStructField {
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize)]
+pub enum PatternArrayElement {
+    /// The remaining elements of the array, optionally bound to a name, as in `..rest`.
+    Rest {
+        token: DoubleDotToken,
+        name_opt: Option<Ident>,
+    },
+    Pattern(Box<Pattern>),
+}
+
+impl Spanned for PatternArrayElement {
+    fn span(&self) -> Span {
+        match self {
+            PatternArrayElement::Rest { token, name_opt } => match name_opt {
+                Some(name) => Span::join(token.span(), name.span()),
+                None => token.span(),
+            },
+            PatternArrayElement::Pattern(pattern) => pattern.span(),
+        }
+    }
+}
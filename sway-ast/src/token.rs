@@ -254,6 +254,8 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '@' => Some(PunctKind::At),
+            '?' => Some(PunctKind::QuestionMark),
             _ => None,
         }
     }
@@ -21,6 +21,10 @@ pub enum Ty {
         slice_token: SliceToken,
         ty: SquareBrackets<Box<Ty>>,
     },
+    TypeOf {
+        typeof_token: TypeofToken,
+        expr: Parens<Box<Expr>>,
+    },
 }
 
 impl Spanned for Ty {
@@ -33,6 +37,7 @@ impl Spanned for Ty {
             Ty::Infer { underscore_token } => underscore_token.span(),
             Ty::Ptr { ptr_token, ty } => Span::join(ptr_token.span(), ty.span()),
             Ty::Slice { slice_token, ty } => Span::join(slice_token.span(), ty.span()),
+            Ty::TypeOf { typeof_token, expr } => Span::join(typeof_token.span(), expr.span()),
         }
     }
 }
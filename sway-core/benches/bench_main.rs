@@ -0,0 +1,144 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use sway_core::{compile_to_ast, namespace, Engines};
+use sway_error::handler::Handler;
+use sway_utils::PerformanceData;
+
+/// The number of generic functions in the benchmarked library.
+const NUM_GENERIC_FNS: usize = 48;
+
+/// Generates a library with many generic functions, each with several generic parameters, and a
+/// function calling all of them, so that type-checking it resolves the same parameter types over
+/// and over again.
+fn generic_fns_library() -> String {
+    let mut src = String::from(
+        "library;
+
+pub struct Pair<A, B> {
+    a: A,
+    b: B,
+}
+
+pub enum Either<A, B> {
+    Left: A,
+    Right: B,
+}
+",
+    );
+    for i in 0..NUM_GENERIC_FNS {
+        src.push_str(&format!(
+            "
+pub fn generic_{i}<A, B, C>(a: A, b: B, c: C, pair: Pair<A, B>, either: Either<B, C>) -> (Pair<A, B>, Either<B, C>, [C; 2]) {{
+    let first = Pair {{ a: a, b: b }};
+    let second = Pair {{ a: pair.a, b: first.b }};
+    let flag = true;
+    let count = if flag {{ {i}u64 }} else {{ 0u64 }};
+    let _ = (first, count, [count, count, count]);
+    (second, either, [c, c])
+}}
+"
+        ));
+    }
+    src.push_str("\npub fn call_all() {\n");
+    for i in 0..NUM_GENERIC_FNS {
+        src.push_str(&format!(
+            "    let _ = generic_{i}(1u64, true, 0u8, Pair {{ a: 2u64, b: false }}, Either::Left::<bool, u8>(true));\n"
+        ));
+    }
+    src.push_str("}\n");
+    src
+}
+
+/// The number of calls to generic functions in the benchmarked script, each of which is
+/// monomorphized separately.
+const NUM_GENERIC_INSTANTIATIONS: usize = 300;
+
+/// Generates a script calling generic functions, whose bodies mention the same generic types
+/// many times, a few hundred times with different type arguments, so that monomorphizing them
+/// substitutes the same types over and over again.
+fn generic_instantiations_script() -> String {
+    let mut src = String::from(
+        "script;
+
+enum Option<T> {
+    None: (),
+    Some: T,
+}
+
+struct Wrapper<T> {
+    value: T,
+    next: Option<T>,
+}
+
+fn wrap<T>(value: T) -> Option<Wrapper<T>> {
+    let first: Option<T> = Option::Some(value);
+    let second: Option<T> = Option::None;
+    let third: Option<Option<T>> = Option::Some(second);
+    let wrapper: Wrapper<T> = Wrapper { value: value, next: first };
+    let wrappers: [Option<Wrapper<T>>; 2] = [Option::Some(wrapper), Option::None];
+    let _ = (first, third, wrappers);
+    Option::Some(Wrapper { value: value, next: second })
+}
+
+fn pair<A, B>(a: A, b: B) -> (Option<Wrapper<A>>, Option<Wrapper<B>>) {
+    let left: Option<Wrapper<A>> = wrap(a);
+    let right: Option<Wrapper<B>> = wrap(b);
+    (left, right)
+}
+",
+    );
+    let values = [
+        "1u8",
+        "2u16",
+        "3u32",
+        "4u64",
+        "true",
+        "(5u64, false)",
+        "[6u8, 7u8]",
+        "Wrapper { value: 8u64, next: Option::None }",
+    ];
+    src.push_str("\nfn main() {\n");
+    for i in 0..NUM_GENERIC_INSTANTIATIONS {
+        let a = values[i % values.len()];
+        let b = values[(i / values.len()) % values.len()];
+        src.push_str(&format!("    let _ = pair({a}, {b});\n"));
+    }
+    src.push_str("}\n");
+    src
+}
+
+fn compile(src: &Arc<str>, package_name: &str) {
+    let handler = Handler::default();
+    let engines = Engines::default();
+    let programs = compile_to_ast(
+        &handler,
+        &engines,
+        src.clone(),
+        namespace::Module::default(),
+        None,
+        package_name,
+        &mut PerformanceData::default(),
+    );
+    assert!(!handler.has_errors());
+    black_box(programs.expect("the benchmark program compiles"));
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let src: Arc<str> = generic_fns_library().into();
+    c.bench_function("type_check_generic_fns", |b| {
+        b.iter(|| compile(&src, "generic_fns"))
+    });
+
+    let src: Arc<str> = generic_instantiations_script().into();
+    c.bench_function("monomorphize_generic_instantiations", |b| {
+        b.iter(|| compile(&src, "generic_instantiations"))
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(std::time::Duration::from_secs(10));
+    targets = benchmarks
+}
+
+criterion_main!(benches);
@@ -781,6 +781,12 @@ fn four_regs(
     };
 
     impl ConstantRegister {
+        /// The names under which the reserved registers can be referenced in `asm` blocks.
+        pub(crate) const RESERVED_NAMES: [&'static str; 17] = [
+            "zero", "one", "of", "pc", "ssp", "sp", "fp", "hp", "err", "ggas", "cgas", "bal", "is",
+            "flag", "retl", "ret", "ds",
+        ];
+
         pub(crate) fn parse_register_name(raw: &str) -> Option<ConstantRegister> {
             use ConstantRegister::*;
             Some(match raw {
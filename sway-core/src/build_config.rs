@@ -1,4 +1,4 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
@@ -32,6 +32,9 @@ pub enum BuildTarget {
     MidenVM,
 }
 
+/// The default of [BuildConfig::monomorphization_depth_limit].
+pub const DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT: usize = 32;
+
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
 pub struct BuildConfig {
@@ -45,7 +48,12 @@ pub struct BuildConfig {
     pub(crate) print_intermediate_asm: bool,
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
+    pub(crate) verify_ir: bool,
     pub(crate) include_tests: bool,
+    pub(crate) stack_frame_budget: Option<u64>,
+    pub(crate) inline_threshold: Option<usize>,
+    pub(crate) monomorphization_depth_limit: usize,
+    pub(crate) cfg_flags: HashSet<String>,
     pub time_phases: bool,
     pub metrics_outfile: Option<String>,
 }
@@ -89,7 +97,12 @@ impl BuildConfig {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            verify_ir: false,
             include_tests: false,
+            stack_frame_budget: None,
+            inline_threshold: None,
+            monomorphization_depth_limit: DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT,
+            cfg_flags: HashSet::new(),
             time_phases: false,
             metrics_outfile: None,
         }
@@ -130,6 +143,17 @@ impl BuildConfig {
         }
     }
 
+    /// Whether or not to verify the IR after each optimization pass which modifies it. An invalid
+    /// IR is reported as an error naming the pass, instead of failing later in codegen.
+    ///
+    /// Default: `false`.
+    pub fn verify_ir(self, a: bool) -> Self {
+        Self {
+            verify_ir: a,
+            ..self
+        }
+    }
+
     pub fn time_phases(self, a: bool) -> Self {
         Self {
             time_phases: a,
@@ -156,6 +180,52 @@ impl BuildConfig {
         }
     }
 
+    /// The number of bytes of stack that the parameters and locals of a function may take before
+    /// a warning is emitted for it.
+    ///
+    /// Default: `None`, which disables the check.
+    pub fn stack_frame_budget(self, stack_frame_budget: Option<u64>) -> Self {
+        Self {
+            stack_frame_budget,
+            ..self
+        }
+    }
+
+    /// The estimated size, in instructions, above which a function called from more than one
+    /// place is not inlined, unless it is attributed with `#[inline(always)]`.
+    ///
+    /// Default: `None`, which keeps the default threshold of the inliner.
+    pub fn inline_threshold(self, inline_threshold: Option<usize>) -> Self {
+        Self {
+            inline_threshold,
+            ..self
+        }
+    }
+
+    /// How deeply the type arguments of the types a generic function is instantiated with may be
+    /// nested, e.g., `Vec<Vec<u64>>` is nested two levels deep. Instantiating a function beyond the
+    /// limit is an error.
+    ///
+    /// Default: [DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT], used if `None` is given.
+    pub fn monomorphization_depth_limit(self, monomorphization_depth_limit: Option<usize>) -> Self {
+        Self {
+            monomorphization_depth_limit: monomorphization_depth_limit
+                .unwrap_or(DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT),
+            ..self
+        }
+    }
+
+    /// The flags that are set for conditional compilation. An item annotated with
+    /// `#[cfg(flag)]` is only compiled if `flag` is among them.
+    ///
+    /// Default: no flags are set.
+    pub fn cfg_flags(self, cfg_flags: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            cfg_flags: cfg_flags.into_iter().collect(),
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
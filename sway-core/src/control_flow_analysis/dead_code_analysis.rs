@@ -17,7 +17,11 @@ use sway_error::{
     handler::Handler,
     warning::{CompileWarning, Warning},
 };
-use sway_types::{constants::ALLOW_DEAD_CODE_NAME, span::Span, Ident, Named, Spanned};
+use sway_types::{
+    constants::{ALLOW_DEAD_CODE_NAME, ALLOW_UNUSED_NAME},
+    span::Span,
+    Ident, Named, Spanned,
+};
 
 impl<'cfg> ControlFlowGraph<'cfg> {
     pub(crate) fn find_dead_code(&self, decl_engine: &DeclEngine) -> Vec<CompileWarning> {
@@ -98,9 +102,9 @@ impl<'cfg> ControlFlowGraph<'cfg> {
                 ControlFlowGraphNode::FunctionParameter {
                     param_name,
                     is_self,
-                    ..
+                    allow_unused,
                 } => {
-                    if *is_self || param_name.as_str().starts_with('_') {
+                    if *is_self || *allow_unused || param_name.as_str().starts_with('_') {
                         // self type parameter is always alive
                         true
                     } else {
@@ -228,7 +232,7 @@ impl<'cfg> ControlFlowGraph<'cfg> {
                         ControlFlowGraphNode::FunctionParameter { param_name, .. } => {
                             Some(CompileWarning {
                                 span: param_name.span(),
-                                warning_content: Warning::DeadDeclaration,
+                                warning_content: Warning::UnusedVariable,
                             })
                         }
                     }
@@ -922,6 +926,7 @@ fn connect_typed_fn_decl<'eng: 'cfg, 'cfg>(
                 type_engine.get(fn_param.type_argument.initial_type_id),
                 TypeInfo::SelfType
             ),
+            allow_unused: fn_param.allow_unused,
         });
         graph.add_edge(entry_node, fn_param_node, "".into());
 
@@ -1707,6 +1712,17 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             exp.span.clone(),
             options,
         ),
+        ArrayToSlice { array } => connect_expression(
+            engines,
+            &array.expression,
+            graph,
+            leaves,
+            exit_node,
+            "array to slice exp",
+            tree_type,
+            array.span.clone(),
+            options,
+        ),
         UnsafeDowncast {
             exp,
             call_path_decl,
@@ -2042,12 +2058,12 @@ fn construct_dead_code_warning_from_node(
             if decl.name.span().source_id().is_some() {
                 CompileWarning {
                     span: decl.name.span(),
-                    warning_content: Warning::DeadDeclaration,
+                    warning_content: Warning::UnusedVariable,
                 }
             } else if span.source_id().is_some() {
                 CompileWarning {
                     span: span.clone(),
-                    warning_content: Warning::DeadDeclaration,
+                    warning_content: Warning::UnusedVariable,
                 }
             } else {
                 return None;
@@ -2196,23 +2212,18 @@ fn connect_call_path_decl<'eng: 'cfg, 'cfg>(
     Ok(())
 }
 
-/// Checks [AttributesMap] for `#[allow(dead_code)]` usage, if so returns true
-/// otherwise returns false.
+/// Checks [AttributesMap] for `#[allow(dead_code)]`, or `#[allow(unused)]`, usage, if so returns
+/// true otherwise returns false.
 fn allow_dead_code(attributes: AttributesMap) -> bool {
-    fn allow_dead_code_helper(attributes: AttributesMap) -> Option<bool> {
-        Some(
-            attributes
-                .get(&transform::AttributeKind::Allow)?
-                .last()?
-                .args
-                .first()?
-                .name
-                .as_str()
-                == ALLOW_DEAD_CODE_NAME,
-        )
-    }
-
-    allow_dead_code_helper(attributes).unwrap_or_default()
+    attributes
+        .get(&transform::AttributeKind::Allow)
+        .map(|attrs| {
+            attrs
+                .iter()
+                .flat_map(|attr| attr.args.iter())
+                .any(|arg| matches!(arg.name.as_str(), ALLOW_DEAD_CODE_NAME | ALLOW_UNUSED_NAME))
+        })
+        .unwrap_or_default()
 }
 
 /// Returns true when the given `node` contains the attribute `#[allow(dead_code)]`
@@ -84,6 +84,8 @@ pub enum ControlFlowGraphNode<'cfg> {
     FunctionParameter {
         param_name: Ident,
         is_self: bool,
+        /// Whether the parameter is marked `#[allow(unused_variables)]`, or `#[allow(unused)]`.
+        allow_unused: bool,
     },
 }
 
@@ -214,6 +214,19 @@ impl DeclEngine {
         self.get(index)
     }
 
+    /// Runs `f` on the function declaration at `index` without cloning it.
+    ///
+    /// The declarations are locked while `f` runs, so `f` must not insert into or replace
+    /// declarations in the [DeclEngine].
+    pub fn with_function<R>(
+        &self,
+        index: &DeclId<ty::TyFunctionDecl>,
+        f: impl FnOnce(&ty::TyFunctionDecl) -> R,
+    ) -> R {
+        self.function_slab
+            .with_slice(|functions| f(&functions[index.inner()]))
+    }
+
     /// Friendly helper method for calling the `get` method from the
     /// implementation of [DeclEngineGet] for [DeclEngine]
     ///
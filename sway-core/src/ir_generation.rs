@@ -2,8 +2,12 @@ pub(crate) mod compile;
 pub mod const_eval;
 mod convert;
 mod function;
+mod inline;
 mod lexical_map;
+mod overflow;
+pub mod parameter_layout;
 mod purity;
+mod stack_frame;
 pub mod storage;
 mod types;
 
@@ -11,7 +15,10 @@ use sway_error::error::CompileError;
 use sway_ir::{Context, Kind};
 use sway_types::span::Span;
 
+pub(crate) use inline::check_inline_attributes;
+pub(crate) use overflow::check_constant_overflows;
 pub(crate) use purity::{check_function_purity, PurityEnv};
+pub(crate) use stack_frame::check_stack_frame_budget;
 
 use crate::{language::ty, Engines};
 
@@ -403,6 +403,7 @@ fn compile_fn(
     let decl_engine = engines.de();
 
     let inline_opt = ast_fn_decl.inline();
+    let extern_abi_opt = ast_fn_decl.extern_abi();
     let ty::TyFunctionDecl {
         name,
         body,
@@ -429,9 +430,9 @@ fn compile_fn(
                 (
                     // Convert the name.
                     param.name.as_str().into(),
-                    // Convert the type further to a pointer if it's a reference.
-                    param
-                        .is_reference
+                    // Convert the type further to a pointer if it's a mutable reference.
+                    // Arguments to `ref` parameters that are not `mut` are passed by value.
+                    (param.is_reference && param.is_mutable)
                         .then(|| Type::new_ptr(context, ty))
                         .unwrap_or(ty),
                     // Convert the span to a metadata index.
@@ -450,7 +451,11 @@ fn compile_fn(
     )?;
 
     let span_md_idx = md_mgr.span_to_md(context, span);
-    let storage_md_idx = md_mgr.purity_to_md(context, *purity);
+    let storage_md_idx = if ast_fn_decl.has_inferred_purity {
+        md_mgr.inferred_purity_to_md(context)
+    } else {
+        md_mgr.purity_to_md(context, *purity)
+    };
     let mut metadata = md_combine(context, &span_md_idx, &storage_md_idx);
 
     let decl_index = test_decl_ref.map(|decl_ref| *decl_ref.id());
@@ -462,6 +467,10 @@ fn compile_fn(
         let inline_md_idx = md_mgr.inline_to_md(context, inline);
         metadata = md_combine(context, &metadata, &inline_md_idx);
     }
+    if let Some(abi) = extern_abi_opt {
+        let extern_abi_md_idx = md_mgr.extern_abi_to_md(context, abi);
+        metadata = md_combine(context, &metadata, &extern_abi_md_idx);
+    }
 
     let func = Function::new(
         context,
@@ -249,6 +249,9 @@ pub(crate) fn compile_constant_expression_to_constant(
 
     match const_eval_typed_expr(lookup, &mut known_consts, const_expr) {
         Ok(Some(constant)) => Ok(constant),
+        Err(ConstEvalError::CompileError(
+            err @ CompileError::ConfigurableInConstantContext { .. },
+        )) => Err(err),
         _ => err,
     }
 }
@@ -299,6 +302,18 @@ fn const_eval_typed_expr(
 
             res?
         }
+        ty::TyExpressionVariant::ConstantExpression { const_decl, .. }
+            if const_decl.is_configurable =>
+        {
+            // Configurables are loaded from the binary at runtime, since their values can be
+            // changed after compilation.
+            return Err(ConstEvalError::CompileError(
+                CompileError::ConfigurableInConstantContext {
+                    name: const_decl.call_path.suffix.clone(),
+                    span: expr.span.clone(),
+                },
+            ));
+        }
         ty::TyExpressionVariant::ConstantExpression { const_decl, .. } => {
             let call_path = &const_decl.call_path;
             let name = &call_path.suffix;
@@ -618,6 +633,7 @@ fn const_eval_typed_expr(
         | ty::TyExpressionVariant::StorageAccess(_)
         | ty::TyExpressionVariant::AbiName(_)
         | ty::TyExpressionVariant::EnumTag { .. }
+        | ty::TyExpressionVariant::ArrayToSlice { .. }
         | ty::TyExpressionVariant::UnsafeDowncast { .. }
         | ty::TyExpressionVariant::Break
         | ty::TyExpressionVariant::Continue
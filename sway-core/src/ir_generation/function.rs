@@ -25,6 +25,7 @@ use sway_ir::{Context, *};
 use sway_types::{
     constants,
     ident::Ident,
+    integer_bits::IntegerBits,
     span::{Span, Spanned},
     state::StateIndex,
     Named,
@@ -407,6 +408,9 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::EnumTag { exp } => {
                 self.compile_enum_tag(context, md_mgr, exp.to_owned())
             }
+            ty::TyExpressionVariant::ArrayToSlice { array } => {
+                self.compile_array_to_slice(context, md_mgr, array, span_md_idx)
+            }
             ty::TyExpressionVariant::WhileLoop { body, condition } => {
                 self.compile_while_loop(context, md_mgr, body, condition, span_md_idx)
             }
@@ -797,10 +801,25 @@ impl<'eng> FnCompiler<'eng> {
                 let rhs = &arguments[1];
                 let lhs_value = self.compile_expression_to_value(context, md_mgr, lhs)?;
                 let rhs_value = self.compile_expression_to_value(context, md_mgr, rhs)?;
+
+                // `u8`, `u16` and `u32` are compiled to `u64`, so note their width on the
+                // arithmetic that can overflow it, which would then revert.
+                let uint_width_md_idx = match (op, engines.te().get(lhs.return_type)) {
+                    (
+                        BinaryOpKind::Add | BinaryOpKind::Sub | BinaryOpKind::Mul,
+                        TypeInfo::UnsignedInteger(
+                            bits @ (IntegerBits::Eight
+                            | IntegerBits::Sixteen
+                            | IntegerBits::ThirtyTwo),
+                        ),
+                    ) => md_mgr.uint_width_to_md(context, bits),
+                    _ => None,
+                };
                 Ok(self
                     .current_block
                     .ins(context)
-                    .binary_op(op, lhs_value, rhs_value))
+                    .binary_op(op, lhs_value, rhs_value)
+                    .add_metadatum(context, uint_width_md_idx))
             }
             Intrinsic::Revert => {
                 let revert_code_val =
@@ -1550,6 +1569,68 @@ impl<'eng> FnCompiler<'eng> {
             .add_metadatum(context, tag_span_md_idx))
     }
 
+    fn compile_array_to_slice(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        array: &ty::TyExpression,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let length = match self.engines.te().get_unaliased(array.return_type) {
+            TypeInfo::Array(_, length) => length.val() as u64,
+            _ => {
+                return Err(CompileError::Internal(
+                    "Coercion to a slice from a non-array type.",
+                    array.span.clone(),
+                ))
+            }
+        };
+        let array_ptr = self.compile_expression_to_ptr(context, md_mgr, array)?;
+        if array_ptr.is_diverging(context) {
+            return Ok(array_ptr);
+        }
+
+        // A slice is the address of its first element followed by its length.
+        let u64_ty = Type::get_uint64(context);
+        let parts_type = Type::new_struct(context, vec![u64_ty, u64_ty]);
+        let temp_name = self.lexical_map.insert_anon();
+        let parts_var = self
+            .function
+            .new_local_var(context, temp_name, parts_type, None, false)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let parts_ptr = self
+            .current_block
+            .ins(context)
+            .get_local(parts_var)
+            .add_metadatum(context, span_md_idx);
+
+        let addr_val = self
+            .current_block
+            .ins(context)
+            .ptr_to_int(array_ptr, u64_ty)
+            .add_metadatum(context, span_md_idx);
+        let length_val = Constant::get_uint(context, 64, length);
+        for (idx, part_val) in [addr_val, length_val].into_iter().enumerate() {
+            let gep_val = self
+                .current_block
+                .ins(context)
+                .get_elem_ptr_with_idx(parts_ptr, u64_ty, idx as u64)
+                .add_metadatum(context, span_md_idx);
+            self.current_block
+                .ins(context)
+                .store(gep_val, part_val)
+                .add_metadatum(context, span_md_idx);
+        }
+
+        let slice_ty = Type::get_slice(context);
+        let slice_ptr_ty = Type::new_ptr(context, slice_ty);
+        Ok(self
+            .current_block
+            .ins(context)
+            .cast_ptr(parts_ptr, slice_ptr_ty)
+            .add_metadatum(context, span_md_idx))
+    }
+
     fn compile_while_loop(
         &mut self,
         context: &mut Context,
@@ -2258,6 +2339,44 @@ impl<'eng> FnCompiler<'eng> {
         // all the variants must have unit types, hence the absence of the union. Therefore, there
         // is no need for another `store` instruction here.
         let field_tys = enum_type.get_field_types(context);
+
+        // If the variant does not cover the whole union, clear the padding in front of it, so
+        // that no stale data is left there. Otherwise it would, e.g., end up in storage when the
+        // enum is written there. Variants are left padded, so the padding starts at the beginning
+        // of the union.
+        if field_tys.len() != 1 {
+            let union_ty = field_tys[1];
+            let union_size = union_ty.size_in_bytes(context);
+            let variant_size = match contents {
+                Some(_) => union_ty.get_field_types(context)[tag].size_in_bytes(context),
+                None => 0,
+            };
+            let padding_size = union_size - variant_size;
+            if padding_size > 0 {
+                let u8_ty = Type::get_uint8(context);
+                let padding_ty = Type::new_array(context, u8_ty, padding_size);
+                let padding_ptr_ty = Type::new_ptr(context, padding_ty);
+                let zero_byte = Constant::new_uint(context, 8, 0);
+                let zero_padding =
+                    Constant::new_array(context, u8_ty, vec![zero_byte; padding_size as usize]);
+                let zero_value = Value::new_constant(context, zero_padding);
+                let union_ptr = self
+                    .current_block
+                    .ins(context)
+                    .get_elem_ptr_with_idx(enum_ptr, union_ty, 1)
+                    .add_metadatum(context, span_md_idx);
+                let padding_ptr = self
+                    .current_block
+                    .ins(context)
+                    .cast_ptr(union_ptr, padding_ptr_ty)
+                    .add_metadatum(context, span_md_idx);
+                self.current_block
+                    .ins(context)
+                    .store(padding_ptr, zero_value)
+                    .add_metadatum(context, span_md_idx);
+            }
+        }
+
         if field_tys.len() != 1 && contents.is_some() {
             // Insert the value too.
             let contents_value =
@@ -0,0 +1,45 @@
+use crate::{language::Inline, metadata::MetadataManager};
+
+use sway_error::{
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_ir::{Context, Instruction};
+use sway_types::span::Span;
+
+/// Warns about the functions attributed with `#[inline(always)]` that the inliner does not
+/// always inline, because they contain `asm` blocks.
+///
+/// Designed to be called _prior_ to inlining, so that the `asm` blocks of the called functions
+/// are not attributed to their callers.
+pub(crate) fn check_inline_attributes(
+    handler: &Handler,
+    context: &Context,
+    md_mgr: &mut MetadataManager,
+) {
+    for function in context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+    {
+        if !matches!(
+            md_mgr.md_to_inline(context, function.get_metadata(context)),
+            Some(Inline::Always)
+        ) {
+            continue;
+        }
+        let asm_block = function.instruction_iter(context).find(|(_block, ins)| {
+            matches!(
+                ins.get_instruction(context),
+                Some(Instruction::AsmBlock(..))
+            )
+        });
+        if let Some((_block, asm_block)) = asm_block {
+            handler.emit_warn(CompileWarning {
+                span: md_mgr
+                    .md_to_span(context, asm_block.get_metadata(context))
+                    .unwrap_or_else(Span::dummy),
+                warning_content: Warning::InlineAlwaysWithAsmBlock,
+            });
+        }
+    }
+}
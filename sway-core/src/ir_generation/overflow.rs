@@ -0,0 +1,64 @@
+use crate::metadata::MetadataManager;
+
+use sway_error::{error::CompileError, handler::Handler};
+use sway_ir::{BinaryOpKind, ConstantValue, Context, Instruction, Value};
+use sway_types::span::Span;
+
+/// Reports the additions, subtractions and multiplications of two constants which are left in the
+/// IR because they overflow, and would always revert when executed. The operands of `u8`, `u16`
+/// and `u32` arithmetic are compiled to `u64`, so their real width is taken from the metadata.
+///
+/// Designed to be called _after_ the optimizations, once constants are propagated into the
+/// arithmetic and the operations that do not overflow are folded away. Inlining combines the
+/// metadata of the call site with the one of the inlined instruction, so the reported span is
+/// the one of the expression in the calling code, e.g., `a + b`, rather than the `__add` in the
+/// core library.
+pub(crate) fn check_constant_overflows(
+    handler: &Handler,
+    context: &Context,
+    md_mgr: &mut MetadataManager,
+) {
+    for function in context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+    {
+        for (_block, ins) in function.instruction_iter(context) {
+            let Some(Instruction::BinaryOp { op, arg1, arg2 }) = ins.get_instruction(context)
+            else {
+                continue;
+            };
+            let (Some(lhs), Some(rhs)) =
+                (uint_constant(context, arg1), uint_constant(context, arg2))
+            else {
+                continue;
+            };
+            let (op, result) = match op {
+                BinaryOpKind::Add => ("+", lhs.checked_add(rhs)),
+                BinaryOpKind::Sub => ("-", lhs.checked_sub(rhs)),
+                BinaryOpKind::Mul => ("*", lhs.checked_mul(rhs)),
+                _ => continue,
+            };
+            let bits = md_mgr
+                .md_to_uint_width(context, ins.get_metadata(context))
+                .unwrap_or(64);
+            if result.map_or(true, |result| result > u64::MAX >> (64 - bits)) {
+                handler.emit_err(CompileError::ConstantArithmeticOverflow {
+                    op: op.to_string(),
+                    lhs,
+                    rhs,
+                    bits,
+                    span: md_mgr
+                        .md_to_span(context, ins.get_metadata(context))
+                        .unwrap_or_else(Span::dummy),
+                });
+            }
+        }
+    }
+}
+
+fn uint_constant(context: &Context, value: &Value) -> Option<u64> {
+    match value.get_constant(context)?.value {
+        ConstantValue::Uint(value) => Some(value),
+        _ => None,
+    }
+}
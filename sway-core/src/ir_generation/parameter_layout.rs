@@ -0,0 +1,216 @@
+//! The layout of the parameters of a function, for tools which check that the ABI of a contract
+//! stays the same across its versions, e.g., when it is upgraded.
+
+use sway_ir::{size_bytes_round_up_to_word_alignment, Context};
+use sway_types::Ident;
+
+use crate::{asm_generation::from_ir::ir_type_size_in_bytes, language::ty, Engines, TypeInfo};
+
+use super::convert::convert_resolved_typeid_no_span;
+
+/// The size of a parameter and its place among the parameters of the function.
+///
+/// The parameters are laid out one after another, in the order they are declared, each padded to
+/// a whole number of words. Arguments to `ref mut` parameters are passed as pointers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterLayout {
+    pub name: Ident,
+    /// The type of the parameter once resolved, e.g., with type aliases replaced by the types
+    /// they stand for.
+    pub type_name: String,
+    pub size: ParameterSize,
+    /// The offset of the parameter from the first one, in bytes. `None` if the size of this
+    /// parameter, or of any parameter before it, is not known.
+    pub offset: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterSize {
+    /// The size in bytes, padded to a whole number of words.
+    Bytes(u64),
+    /// The type of the parameter is, or contains, a type parameter of a generic function, so the
+    /// size is known only once the function is instantiated. Also used for the types which have
+    /// no size in the calling convention.
+    UnsizedOrGeneric,
+}
+
+/// Computes the layout of the parameters of the function `decl`, in the order they are declared.
+///
+/// Comparing the layouts of two versions of a function tells whether the arguments of the calls
+/// made against the old version are still read correctly by the new one.
+pub fn compute_parameter_layout(
+    engines: &Engines,
+    decl: &ty::TyFunctionDecl,
+) -> Vec<ParameterLayout> {
+    let type_engine = engines.te();
+    let mut context = Context::new(engines.se());
+
+    let mut next_offset = Some(0);
+    decl.parameters
+        .iter()
+        .map(|param| {
+            let type_id = param.type_argument.type_id;
+            let is_generic = !type_id
+                .extract_any_including_self(
+                    engines,
+                    &|type_info| {
+                        matches!(
+                            type_info,
+                            TypeInfo::UnknownGeneric { .. } | TypeInfo::Placeholder(_)
+                        )
+                    },
+                    vec![],
+                )
+                .is_empty();
+            let size = if param.is_reference && param.is_mutable {
+                // A pointer is a single word.
+                Some(8)
+            } else if is_generic || param.resolution_failed {
+                None
+            } else {
+                convert_resolved_typeid_no_span(type_engine, engines.de(), &mut context, &type_id)
+                    .ok()
+                    .map(|ty| {
+                        let size_in_bytes = ir_type_size_in_bytes(&context, &ty);
+                        size_bytes_round_up_to_word_alignment!(size_in_bytes)
+                    })
+            };
+
+            let offset = next_offset;
+            next_offset = next_offset.zip(size).map(|(offset, size)| offset + size);
+            ParameterLayout {
+                name: param.name.clone(),
+                type_name: engines.help_out(type_id).to_string(),
+                size: size.map_or(ParameterSize::UnsizedOrGeneric, ParameterSize::Bytes),
+                offset: offset.filter(|_| size.is_some()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        language::{Purity, Visibility},
+        transform,
+        type_system::{TypeArgument, VecSet},
+    };
+    use sway_types::{integer_bits::IntegerBits, Span};
+
+    fn parameter(
+        engines: &Engines,
+        name: &str,
+        type_info: TypeInfo,
+        is_ref_mut: bool,
+    ) -> ty::TyFunctionParameter {
+        let type_id = engines.te().insert(engines, type_info);
+        ty::TyFunctionParameter {
+            name: Ident::new_no_span(name.into()),
+            is_reference: is_ref_mut,
+            is_mutable: is_ref_mut,
+            mutability_span: Span::dummy(),
+            type_argument: TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: Span::dummy(),
+                call_path_tree: None,
+            },
+            is_rest: false,
+            is_optional: false,
+            resolution_failed: false,
+            allow_unused: false,
+        }
+    }
+
+    fn function(engines: &Engines, parameters: Vec<ty::TyFunctionParameter>) -> ty::TyFunctionDecl {
+        let return_type = engines.te().insert(engines, TypeInfo::Tuple(vec![]));
+        ty::TyFunctionDecl {
+            name: Ident::new_no_span("f".into()),
+            body: ty::TyCodeBlock::default(),
+            parameters,
+            implementing_type: None,
+            span: Span::dummy(),
+            attributes: transform::AttributesMap::default(),
+            type_parameters: vec![],
+            return_type: TypeArgument {
+                type_id: return_type,
+                initial_type_id: return_type,
+                span: Span::dummy(),
+                call_path_tree: None,
+            },
+            visibility: Visibility::Public,
+            is_contract_call: false,
+            purity: Purity::Pure,
+            has_inferred_purity: false,
+            where_clause: vec![],
+        }
+    }
+
+    #[test]
+    fn parameters_are_laid_out_in_words() {
+        let engines = Engines::default();
+        let u64_type = || TypeInfo::UnsignedInteger(IntegerBits::SixtyFour);
+        let u64_id = engines.te().insert(&engines, u64_type());
+        let generic = TypeInfo::UnknownGeneric {
+            name: Ident::new_no_span("T".into()),
+            trait_constraints: VecSet(vec![]),
+        };
+        let decl = function(
+            &engines,
+            vec![
+                parameter(&engines, "flag", TypeInfo::Boolean, false),
+                parameter(
+                    &engines,
+                    "pair",
+                    TypeInfo::Tuple(vec![u64_id.into(), u64_id.into()]),
+                    false,
+                ),
+                parameter(&engines, "hash", TypeInfo::B256, true),
+                parameter(&engines, "value", generic, false),
+                parameter(&engines, "amount", u64_type(), false),
+            ],
+        );
+        let layout = compute_parameter_layout(&engines, &decl);
+
+        let sizes_and_offsets = layout
+            .iter()
+            .map(|param| (param.name.as_str(), param.size, param.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sizes_and_offsets,
+            vec![
+                ("flag", ParameterSize::Bytes(8), Some(0)),
+                ("pair", ParameterSize::Bytes(16), Some(8)),
+                // A `ref mut` parameter is passed as a pointer.
+                ("hash", ParameterSize::Bytes(8), Some(24)),
+                ("value", ParameterSize::UnsizedOrGeneric, None),
+                // The offset depends on the size of the generic parameter before it.
+                ("amount", ParameterSize::Bytes(8), None),
+            ]
+        );
+        assert_eq!(layout[1].type_name, "(u64, u64)");
+        assert_eq!(layout[2].type_name, "b256");
+    }
+
+    #[test]
+    fn parameters_whose_types_failed_to_resolve_are_unsized() {
+        let engines = Engines::default();
+        let decl = function(
+            &engines,
+            vec![
+                ty::TyFunctionParameter {
+                    resolution_failed: true,
+                    ..parameter(&engines, "unknown", TypeInfo::Boolean, false)
+                },
+                parameter(&engines, "flag", TypeInfo::Boolean, false),
+            ],
+        );
+        let layout = compute_parameter_layout(&engines, &decl);
+
+        assert_eq!(layout[0].size, ParameterSize::UnsizedOrGeneric);
+        assert_eq!(layout[0].offset, None);
+        assert_eq!(layout[1].size, ParameterSize::Bytes(8));
+        assert_eq!(layout[1].offset, None);
+    }
+}
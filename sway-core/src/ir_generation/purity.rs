@@ -15,7 +15,46 @@ use std::collections::HashMap;
 
 #[derive(Default)]
 pub(crate) struct PurityEnv {
-    memos: HashMap<Function, (bool, bool)>,
+    memos: HashMap<Function, StorageEffects>,
+}
+
+/// The storage accesses a function performs, either directly or through the functions it calls.
+///
+/// Each access is witnessed by the chain of spans leading to it from the function: the spans of
+/// the calls, followed by the span of the storage access itself.
+#[derive(Clone, Default)]
+pub(crate) struct StorageEffects {
+    reads: Option<Vec<Span>>,
+    writes: Option<Vec<Span>>,
+}
+
+impl StorageEffects {
+    /// Records a direct storage access, keeping the first access found for each operation.
+    fn access(mut self, reads: bool, writes: bool, span: &Span) -> Self {
+        if reads && self.reads.is_none() {
+            self.reads = Some(vec![span.clone()]);
+        }
+        if writes && self.writes.is_none() {
+            self.writes = Some(vec![span.clone()]);
+        }
+        self
+    }
+
+    /// Records the storage accesses of a function called at `span`.
+    fn call(mut self, callee: &StorageEffects, span: &Span) -> Self {
+        let through_call = |chain: &Vec<Span>| {
+            std::iter::once(span.clone())
+                .chain(chain.iter().cloned())
+                .collect()
+        };
+        if self.reads.is_none() {
+            self.reads = callee.reads.as_ref().map(through_call);
+        }
+        if self.writes.is_none() {
+            self.writes = callee.writes.as_ref().map(through_call);
+        }
+        self
+    }
 }
 
 /// Analyses purity annotations on functions.
@@ -23,69 +62,69 @@ pub(crate) struct PurityEnv {
 /// Designed to be called for each entry point, _prior_ to inlining or other optimizations.
 /// The checker will check this function and any that it calls.
 ///
-/// Returns bools for whether it (reads, writes).
+/// The storage accesses of functions whose purity is inferred are computed bottom-up over the
+/// call graph but never reported for the functions themselves. They are only checked against the
+/// attributes of the annotated functions and entry points that call them.
+///
+/// Returns the storage accesses of the function.
 pub(crate) fn check_function_purity(
     handler: &Handler,
     env: &mut PurityEnv,
     context: &Context,
     md_mgr: &mut MetadataManager,
     function: &Function,
-) -> (bool, bool) {
+) -> StorageEffects {
     // Iterate for each instruction in the function and gather whether we have read and/or
     // write storage operations:
     // - via the storage IR instructions,
     // - via ASM blocks with storage VM instructions or
     // - via calls into functions with the above.
-    let (reads, writes) = function.instruction_iter(context).fold(
-        (false, false),
-        |(reads, writes), (_block, ins_value)| {
-            ins_value
-                .get_instruction(context)
-                .map(|instruction| {
-                    match instruction {
-                        Instruction::FuelVm(FuelVmInstruction::StateLoadQuadWord { .. })
-                        | Instruction::FuelVm(FuelVmInstruction::StateLoadWord(_)) => {
-                            (true, writes)
-                        }
-
-                        Instruction::FuelVm(FuelVmInstruction::StateClear { .. })
-                        | Instruction::FuelVm(FuelVmInstruction::StateStoreQuadWord { .. })
-                        | Instruction::FuelVm(FuelVmInstruction::StateStoreWord { .. }) => {
-                            (reads, true)
-                        }
-
-                        // Iterate for and check each instruction in the ASM block.
-                        Instruction::AsmBlock(asm_block, _args) => {
-                            asm_block.get_content(context).body.iter().fold(
-                                (reads, writes),
-                                |(reads, writes), asm_op| match asm_op.name.as_str() {
-                                    "scwq" | "srw" | "srwq" => (true, writes),
-                                    "sww" | "swwq" => (reads, true),
-                                    _ => (reads, writes),
-                                },
-                            )
-                        }
-
-                        // Recurse to find the called function purity.  Use memoisation to
-                        // avoid redoing work.
-                        Instruction::Call(callee, _args) => {
-                            let (called_fn_reads, called_fn_writes) =
-                                env.memos.get(callee).copied().unwrap_or_else(|| {
-                                    let r_w = check_function_purity(
-                                        handler, env, context, md_mgr, callee,
-                                    );
-                                    env.memos.insert(*callee, r_w);
-                                    r_w
-                                });
-                            (reads || called_fn_reads, writes || called_fn_writes)
-                        }
-
-                        _otherwise => (reads, writes),
-                    }
-                })
-                .unwrap_or_else(|| (reads, writes))
+    let effects = function.instruction_iter(context).fold(
+        StorageEffects::default(),
+        |effects, (_block, ins_value)| {
+            let span = md_mgr
+                .md_to_span(context, ins_value.get_metadata(context))
+                .unwrap_or_else(Span::dummy);
+            match ins_value.get_instruction(context) {
+                Some(
+                    Instruction::FuelVm(FuelVmInstruction::StateLoadQuadWord { .. })
+                    | Instruction::FuelVm(FuelVmInstruction::StateLoadWord(_)),
+                ) => effects.access(true, false, &span),
+
+                Some(
+                    Instruction::FuelVm(FuelVmInstruction::StateClear { .. })
+                    | Instruction::FuelVm(FuelVmInstruction::StateStoreQuadWord { .. })
+                    | Instruction::FuelVm(FuelVmInstruction::StateStoreWord { .. }),
+                ) => effects.access(false, true, &span),
+
+                // Iterate for and check each instruction in the ASM block.
+                Some(Instruction::AsmBlock(asm_block, _args)) => asm_block
+                    .get_content(context)
+                    .body
+                    .iter()
+                    .fold(effects, |effects, asm_op| match asm_op.name.as_str() {
+                        "scwq" | "srw" | "srwq" => effects.access(true, false, &span),
+                        "sww" | "swwq" => effects.access(false, true, &span),
+                        _ => effects,
+                    }),
+
+                // Recurse to find the called function purity.  Use memoisation to
+                // avoid redoing work.
+                Some(Instruction::Call(callee, _args)) => {
+                    let called_fn_effects = env.memos.get(callee).cloned().unwrap_or_else(|| {
+                        let effects = check_function_purity(handler, env, context, md_mgr, callee);
+                        env.memos.insert(*callee, effects.clone());
+                        effects
+                    });
+                    effects.call(&called_fn_effects, &span)
+                }
+
+                _otherwise => effects,
+            }
         },
     );
+    let reads = effects.reads.is_some();
+    let writes = effects.writes.is_some();
 
     let attributed_purity = md_mgr.md_to_storage_op(context, function.get_metadata(context));
     let span = md_mgr
@@ -97,6 +136,14 @@ pub(crate) fn check_function_purity(
         handler.emit_err(CompileError::ImpureInPureContext {
             storage_op,
             attrs: promote_purity(existing, needed).to_attribute_syntax(),
+            read_chain: match needed {
+                Reads | ReadsWrites => effects.reads.clone().unwrap_or_default(),
+                Pure | Writes => vec![],
+            },
+            write_chain: match needed {
+                Writes | ReadsWrites => effects.writes.clone().unwrap_or_default(),
+                Pure | Reads => vec![],
+            },
             span,
         });
     };
@@ -110,6 +157,9 @@ pub(crate) fn check_function_purity(
     };
 
     match (attributed_purity, reads, writes) {
+        // The storage accesses are inferred, so there is nothing to check.
+        (Some(StorageOperation::Inferred), _, _) => (),
+
         // Has no attributes but needs some.
         (None, true, false) => error(span, "read", Pure, Reads),
         (None, false, true) => error(span, "write", Pure, Writes),
@@ -132,5 +182,5 @@ pub(crate) fn check_function_purity(
         | (Some(StorageOperation::ReadsWrites), true, true) => (),
     };
 
-    (reads, writes)
+    effects
 }
@@ -0,0 +1,58 @@
+use crate::metadata::MetadataManager;
+
+use sway_error::{
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_ir::Context;
+use sway_types::span::Span;
+
+/// The number of largest parameters listed in the warning for a function over the budget.
+const LARGEST_PARAMETERS_COUNT: usize = 3;
+
+/// Warns about the functions whose parameters and locals take more than `budget` bytes of stack.
+///
+/// Designed to be called _prior_ to any optimizations, so that the parameters still have the
+/// types they were declared with, before large ones are demoted to references.
+pub(crate) fn check_stack_frame_budget(
+    handler: &Handler,
+    context: &Context,
+    md_mgr: &mut MetadataManager,
+    budget: u64,
+) {
+    for function in context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+    {
+        let mut parameters = function
+            .args_iter(context)
+            .filter_map(|(name, arg)| {
+                arg.get_type(context)
+                    .filter(|ty| !ty.is_ptr(context))
+                    .map(|ty| (name.clone(), ty.size_in_bytes(context)))
+            })
+            .collect::<Vec<_>>();
+        let locals_size: u64 = function
+            .locals_iter(context)
+            .map(|(_name, local)| local.get_inner_type(context).size_in_bytes(context))
+            .sum();
+        let frame_size = parameters.iter().map(|(_name, size)| size).sum::<u64>() + locals_size;
+        if frame_size <= budget {
+            continue;
+        }
+
+        // The stable sort keeps the parameters of the same size in declaration order.
+        parameters.sort_by(|(_, a), (_, b)| b.cmp(a));
+        parameters.truncate(LARGEST_PARAMETERS_COUNT);
+        handler.emit_warn(CompileWarning {
+            span: md_mgr
+                .md_to_span(context, function.get_metadata(context))
+                .unwrap_or_else(Span::dummy),
+            warning_content: Warning::StackFrameExceedsBudget {
+                frame_size,
+                budget,
+                largest_parameters: parameters,
+            },
+        });
+    }
+}
@@ -50,8 +50,9 @@ pub(super) fn add_to_b256(x: Bytes32, y: u64) -> Bytes32 {
 /// Given a constant value `constant`, a type `ty`, a state index, and a vector of subfield
 /// indices, serialize the constant into a vector of storage slots. The keys (slots) are
 /// generated using the state index and the subfield indices which are recursively built. The
-/// values are generated such that each subfield gets its own storage slot except for enums and
-/// strings which are spread over successive storage slots (use `serialize_to_words` in this case).
+/// values are generated such that each subfield gets its own storage slot except for enums,
+/// strings, arrays and structs which are spread over successive storage slots (use
+/// `serialize_to_words` in this case).
 ///
 /// This behavior matches the behavior of how storage slots are assigned for storage reads and
 /// writes (i.e. how `state_read_*` and `state_write_*` instructions are generated).
@@ -104,10 +105,11 @@ pub fn serialize_to_storage_slots(
                 Bytes32::new(*b),
             )]
         }
-        ConstantValue::Array(_a) if ty.is_array(context) => {
-            unimplemented!("Arrays in storage have not been implemented yet.")
-        }
-        _ if ty.is_string(context) || ty.is_struct(context) || ty.is_union(context) => {
+        _ if ty.is_string(context)
+            || ty.is_array(context)
+            || ty.is_struct(context)
+            || ty.is_union(context) =>
+        {
             // Serialize the constant data in words and add zero words until the number of words
             // is a multiple of 4. This is useful because each storage slot is 4 words.
             let mut packed = serialize_to_words(constant, context, ty);
@@ -176,8 +178,12 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
                 )
             }))
         }
-        ConstantValue::Array(_) if ty.is_array(context) => {
-            unimplemented!("Arrays in storage have not been implemented yet.")
+        ConstantValue::Array(elems) if ty.is_array(context) => {
+            let elem_ty = ty.get_array_elem_type(context).unwrap();
+            elems
+                .iter()
+                .flat_map(|elem| serialize_to_words(elem, context, &elem_ty))
+                .collect()
         }
         ConstantValue::Struct(vec) if ty.is_struct(context) => {
             let field_tys = ty.get_field_types(context);
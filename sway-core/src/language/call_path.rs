@@ -3,6 +3,7 @@ use std::{fmt, sync::Arc};
 use crate::{Ident, Namespace};
 
 use sway_types::{span::Span, Spanned};
+use sway_utils::iter_prefixes;
 
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct CallPathTree {
@@ -135,6 +136,32 @@ impl CallPath {
 
             prefixes.extend(synonym_prefixes);
 
+            CallPath {
+                prefixes,
+                suffix: self.suffix.clone(),
+                is_absolute: true,
+            }
+        } else if let Some(module_path) = namespace.use_module_synonyms.get(&self.prefixes[0]) {
+            // If the path starts with an imported module, replace it with the path to that
+            // module. The path to an imported module is relative to the package root, unless it
+            // goes through an external module, in which case it starts at that external module.
+            let external_module_index = iter_prefixes(module_path).position(
+                |path| matches!(namespace.root().submodule(path), Some(m) if m.is_external),
+            );
+
+            let mut prefixes: Vec<Ident> = vec![];
+            match external_module_index {
+                Some(index) => prefixes.extend(module_path[index..].iter().cloned()),
+                None => {
+                    if let Some(pkg_name) = &namespace.root().module.name {
+                        prefixes.push(pkg_name.clone());
+                    }
+                    prefixes.extend(module_path.iter().cloned());
+                }
+            }
+
+            prefixes.extend(self.prefixes[1..].iter().cloned());
+
             CallPath {
                 prefixes,
                 suffix: self.suffix.clone(),
@@ -0,0 +1,28 @@
+use sway_types::constants::{EXTERN_ABI_MEMORY_NAME, EXTERN_ABI_REGISTER_NAME};
+
+/// The ABI a function attributed with `#[extern_abi(..)]` passes its arguments with.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum ExternAbi {
+    /// Every argument is passed by value, in a register.
+    Register,
+    /// Every argument is passed by reference, in memory.
+    Memory,
+}
+
+impl ExternAbi {
+    /// Returns the ABI named `name` in the attribute, if there is one.
+    pub fn from_name(name: &str) -> Option<ExternAbi> {
+        match name {
+            EXTERN_ABI_REGISTER_NAME => Some(ExternAbi::Register),
+            EXTERN_ABI_MEMORY_NAME => Some(ExternAbi::Memory),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExternAbi::Register => EXTERN_ABI_REGISTER_NAME,
+            ExternAbi::Memory => EXTERN_ABI_MEMORY_NAME,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod asm;
 mod call_path;
+mod extern_abi;
 mod inline;
 mod lazy_op;
 pub mod lexed;
@@ -13,6 +14,7 @@ mod visibility;
 
 pub use asm::*;
 pub use call_path::*;
+pub use extern_abi::*;
 pub use inline::*;
 pub use lazy_op::*;
 pub use literal::*;
@@ -4,7 +4,7 @@ use crate::{
     transform::{self, AttributeKind},
     type_system::*,
 };
-use sway_types::{ident::Ident, span::Span};
+use sway_types::{ident::Ident, span::Span, Spanned};
 
 #[derive(Debug, Clone)]
 pub struct FunctionDeclaration {
@@ -27,6 +27,47 @@ pub struct FunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    /// Whether this is a rest parameter, like `..rest`, whose type is inferred from the
+    /// function it is forwarded to.
+    pub is_rest: bool,
+    /// Whether this is an optional parameter, like `x?: u64`, which stands for `x: Option<u64>`
+    /// that is `None` if the argument is omitted.
+    pub is_optional: bool,
+    /// The pattern of a parameter that destructures an array, like `[a, b]: [u64; 2]`. Such a
+    /// parameter gets a generated name, and its elements are bound at the start of the body.
+    pub array_pattern: Option<ArrayParameterPattern>,
+    /// The span of the `#[fields]` attribute of a parameter of a struct type. The fields of such
+    /// a parameter that the body refers to, like `fee` for `config.fee`, are bound at the start
+    /// of the body.
+    pub bind_fields: Option<Span>,
+    /// The expression of a parameter typed by `typeof(expr)`. The parameter gets the type of the
+    /// expression, which may refer to the parameters declared before it.
+    pub type_of: Option<Expression>,
+    /// The attributes of the parameter, like `#[allow(unused_variables)]`.
+    pub attributes: transform::AttributesMap,
+}
+
+/// The pattern of a parameter that destructures an array, like `[first, _, ..rest]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayParameterPattern {
+    /// The names bound to the leading elements of the array, `None` for `_`.
+    pub elements: Vec<Option<Ident>>,
+    /// The rest that the pattern ends in, with the name bound to the array of the remaining
+    /// elements, `Some(None)` for a bare `..`.
+    pub rest: Option<Option<Ident>>,
+    pub span: Span,
+}
+
+impl ArrayParameterPattern {
+    /// Returns the names bound by the pattern.
+    pub fn bound_names(&self) -> Vec<Ident> {
+        self.elements
+            .iter()
+            .chain(self.rest.iter())
+            .flatten()
+            .cloned()
+            .collect()
+    }
 }
 
 impl EqWithEngines for FunctionParameter {}
@@ -37,6 +78,13 @@ impl PartialEqWithEngines for FunctionParameter {
             && self.is_mutable == other.is_mutable
             && self.mutability_span == other.mutability_span
             && self.type_argument.eq(&other.type_argument, engines)
+            && self.is_rest == other.is_rest
+            && self.is_optional == other.is_optional
+            && self.array_pattern == other.array_pattern
+            && self.bind_fields == other.bind_fields
+            && self.type_of.as_ref().map(|expr| expr.span())
+                == other.type_of.as_ref().map(|expr| expr.span())
+            && self.attributes == other.attributes
     }
 }
 
@@ -6,6 +6,7 @@ use sway_types::{ident::Ident, span::Span};
 pub struct TypeAliasDeclaration {
     pub name: Ident,
     pub attributes: transform::AttributesMap,
+    pub type_parameters: Vec<TypeParameter>,
     pub ty: TypeArgument,
     pub visibility: Visibility,
     pub span: Span,
@@ -47,6 +47,9 @@ pub struct TupleIndexExpression {
 pub struct ArrayExpression {
     pub contents: Vec<Expression>,
     pub length_span: Option<Span>,
+    /// The constant giving the length of a repeat expression like `[value; N]`. In that case
+    /// `contents` only holds `value`, which is repeated once the value of the constant is known.
+    pub length_constant: Option<CallPath>,
 }
 
 #[derive(Debug, Clone)]
@@ -232,6 +235,9 @@ pub enum ExpressionKind {
     Continue,
     Reassignment(ReassignmentExpression),
     Return(Box<Expression>),
+    /// A spread of a rest parameter into the arguments of a function call, like `..rest` in
+    /// `f(x, ..rest)`.
+    Spread(Ident),
 }
 
 #[derive(Debug, Clone)]
@@ -171,6 +171,20 @@ impl TyAstNode {
         }
     }
 
+    /// recurse into `self` and get the numeric literals, see
+    /// [TyExpression::gather_numeric_literals].
+    pub(crate) fn gather_numeric_literals(&self) -> Vec<&TyExpression> {
+        match &self.content {
+            TyAstNodeContent::ImplicitReturnExpression(exp) | TyAstNodeContent::Expression(exp) => {
+                exp.gather_numeric_literals()
+            }
+            TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => {
+                decl.body.gather_numeric_literals()
+            }
+            TyAstNodeContent::SideEffect(_) | TyAstNodeContent::Declaration(_) => vec![],
+        }
+    }
+
     /// Returns `true` if this AST node will be exported in a library, i.e. it is a public declaration.
     pub(crate) fn is_public(&self, decl_engine: &DeclEngine) -> bool {
         match &self.content {
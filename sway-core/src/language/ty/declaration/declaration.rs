@@ -384,6 +384,25 @@ impl TyDecl {
             None
         }
     }
+
+    /// Runs `f` on the function declaration in the `decl_engine`, if `self` is the
+    /// [TyDecl::FunctionDecl] variant. The declaration is borrowed, not cloned, so `f` must not
+    /// insert into or replace declarations in the `decl_engine`.
+    ///
+    /// The parameters of the function can then be queried through
+    /// [TyFunctionDecl::parameters] and [TyFunctionDecl::parameter_by_name].
+    pub fn with_fun_decl<R>(
+        &self,
+        decl_engine: &DeclEngine,
+        f: impl FnOnce(&TyFunctionDecl) -> R,
+    ) -> Option<R> {
+        match self {
+            TyDecl::FunctionDecl(FunctionDecl { decl_id, .. }) => {
+                Some(decl_engine.with_function(decl_id, f))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Spanned for TyDecl {
@@ -7,7 +7,7 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{Ident, Named, Span, Spanned};
+use sway_types::{style::find_similar_name, Ident, Named, Span, Spanned};
 
 use crate::{
     engine_threading::*,
@@ -114,10 +114,28 @@ impl TyEnumDecl {
             None => Err(handler.emit_err(CompileError::UnknownEnumVariant {
                 enum_name: self.call_path.suffix.clone(),
                 variant_name: variant_name.clone(),
+                suggestion: find_similar_name(
+                    variant_name.as_str(),
+                    self.variants.iter().map(|variant| variant.name.as_str()),
+                )
+                .map(str::to_string),
                 span: variant_name.span(),
             })),
         }
     }
+
+    /// Returns true if the enum has exactly the variants `Ok` and `Err`, in that order, like the
+    /// `Result` of the standard library.
+    pub fn is_result_like(&self) -> bool {
+        matches!(
+            self.variants
+                .iter()
+                .map(|variant| variant.name.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            ["Ok", "Err"]
+        )
+    }
 }
 
 impl Spanned for TyEnumVariant {
@@ -9,7 +9,7 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use crate::{
     decl_engine::*,
     engine_threading::*,
-    language::{parsed, ty::*, Inline, Purity, Visibility},
+    language::{parsed, ty::*, ExternAbi, Inline, Purity, Visibility},
     semantic_analysis::TypeCheckContext,
     transform,
     type_system::*,
@@ -35,6 +35,9 @@ pub struct TyFunctionDecl {
     /// whether this function exists in another contract and requires a call to it or not
     pub is_contract_call: bool,
     pub purity: Purity,
+    /// Whether the storage access of this function is inferred from its body instead of being
+    /// declared by a `#[storage(...)]` attribute. See [TyFunctionDecl::type_check_signature].
+    pub has_inferred_purity: bool,
     pub where_clause: Vec<(Ident, Vec<TraitConstraint>)>,
 }
 
@@ -85,6 +88,7 @@ impl HashWithEngines for TyFunctionDecl {
             attributes: _,
             implementing_type: _,
             where_clause: _,
+            has_inferred_purity: _,
         } = self;
         name.hash(state);
         body.hash(state, engines);
@@ -244,6 +248,7 @@ impl TyFunctionDecl {
             visibility,
             return_type,
             type_parameters: Default::default(),
+            has_inferred_purity: false,
             where_clause,
         }
     }
@@ -343,10 +348,40 @@ impl TyFunctionDecl {
         }
     }
 
+    /// The ABI the arguments are passed with, if the function is attributed with a valid
+    /// `#[extern_abi(..)]`.
+    pub fn extern_abi(&self) -> Option<ExternAbi> {
+        ExternAbi::from_name(
+            self.attributes
+                .get(&transform::AttributeKind::ExternAbi)?
+                .last()?
+                .args
+                .first()?
+                .name
+                .as_str(),
+        )
+    }
+
     /// Whether or not this function describes a program entry point.
     pub fn is_entry(&self) -> bool {
         self.is_main_entry() || self.is_test()
     }
+
+    /// All the parameters of this function, including the `self` receiver, if any.
+    pub fn parameters(&self) -> &[TyFunctionParameter] {
+        &self.parameters
+    }
+
+    /// Retrieves the parameter named `name`.
+    ///
+    /// The `self` receiver is never returned. Use [TyFunctionParameter::is_self]
+    /// on the [TyFunctionDecl::parameters] to access it.
+    pub fn parameter_by_name(&self, name: &Ident) -> Option<&TyFunctionParameter> {
+        self.parameters
+            .iter()
+            .filter(|param| !param.is_self())
+            .find(|param| param.name == *name)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -356,6 +391,21 @@ pub struct TyFunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    /// Whether this is a rest parameter. Its type is the tuple of the parameters it is forwarded
+    /// to, and the trailing arguments of a call are packed into that tuple.
+    pub is_rest: bool,
+    /// Whether this is an optional parameter. Its type is wrapped in `Option`, the arguments
+    /// passed to it are wrapped in `Some`, and it is `None` if the argument is omitted.
+    pub is_optional: bool,
+    /// Whether resolving the declared type of this parameter failed. The errors are already
+    /// reported, and the type is the one recovered from them, so the checks which depend on the
+    /// type of the parameter skip it. Not compared or hashed, as it is not a part of the
+    /// signature.
+    pub resolution_failed: bool,
+    /// Whether the parameter is marked `#[allow(unused_variables)]`, or `#[allow(unused)]`, so
+    /// that it is not reported if the body does not use it. Not compared or hashed, for the same
+    /// reason as `resolution_failed`.
+    pub allow_unused: bool,
 }
 
 impl EqWithEngines for TyFunctionParameter {}
@@ -365,6 +415,8 @@ impl PartialEqWithEngines for TyFunctionParameter {
             && self.type_argument.eq(&other.type_argument, engines)
             && self.is_reference == other.is_reference
             && self.is_mutable == other.is_mutable
+            && self.is_rest == other.is_rest
+            && self.is_optional == other.is_optional
     }
 }
 
@@ -375,14 +427,20 @@ impl HashWithEngines for TyFunctionParameter {
             is_reference,
             is_mutable,
             type_argument,
+            is_rest,
+            is_optional,
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
             mutability_span: _,
+            resolution_failed: _,
+            allow_unused: _,
         } = self;
         name.hash(state);
         type_argument.hash(state, engines);
         is_reference.hash(state);
         is_mutable.hash(state);
+        is_rest.hash(state);
+        is_optional.hash(state);
     }
 }
 
@@ -404,6 +462,47 @@ impl TyFunctionParameter {
     pub fn is_self(&self) -> bool {
         self.name.as_str() == "self"
     }
+
+    /// Returns `true` if the parameter is declared as `_`. Such a parameter is not inserted into
+    /// the namespace, so it cannot be referenced, unlike the ones with an `_`-prefixed name, like
+    /// `_count`, which are only exempt from the unused parameter warning.
+    pub fn is_wildcard(&self) -> bool {
+        self.name.as_str() == "_"
+    }
+
+    /// Returns `true` if `self` and `other` are declared the same way, including the types they
+    /// were written with before resolution.
+    ///
+    /// Use `==` (i.e., [PartialEqWithEngines]) to check whether two parameters accept the same
+    /// arguments. It compares only the resolved types, so `x: MyU64` and `x: u64` are equal if
+    /// `MyU64` resolves to `u64`. Use `strict_eq` where the way the parameter is written matters
+    /// as well, like when checking that a method implementation repeats its declaration. Spans
+    /// are compared by neither of them.
+    pub fn strict_eq(&self, other: &Self, engines: &Engines) -> bool {
+        let TyFunctionParameter {
+            name,
+            is_reference,
+            is_mutable,
+            type_argument,
+            is_rest,
+            is_optional,
+            mutability_span: _,
+            resolution_failed: _,
+            allow_unused: _,
+        } = self;
+        let type_engine = engines.te();
+        *name == other.name
+            && *is_reference == other.is_reference
+            && *is_mutable == other.is_mutable
+            && *is_rest == other.is_rest
+            && *is_optional == other.is_optional
+            && type_argument.eq(&other.type_argument, engines)
+            && (type_argument.initial_type_id == other.type_argument.initial_type_id
+                || type_engine.get(type_argument.initial_type_id).eq(
+                    &type_engine.get(other.type_argument.initial_type_id),
+                    engines,
+                ))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -424,3 +523,109 @@ impl TyFunctionSig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_types::integer_bits::IntegerBits;
+
+    fn parameter(
+        engines: &Engines,
+        initial_type: TypeInfo,
+        is_reference: bool,
+    ) -> TyFunctionParameter {
+        let type_engine = engines.te();
+        TyFunctionParameter {
+            name: Ident::new_no_span("x".into()),
+            is_reference,
+            is_mutable: is_reference,
+            mutability_span: Span::dummy(),
+            type_argument: TypeArgument {
+                type_id: type_engine
+                    .insert(engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                initial_type_id: type_engine.insert(engines, initial_type),
+                span: Span::dummy(),
+                call_path_tree: None,
+            },
+            is_rest: false,
+            is_optional: false,
+            resolution_failed: false,
+            allow_unused: false,
+        }
+    }
+
+    #[test]
+    fn strict_eq_compares_initial_types() {
+        let engines = Engines::default();
+        let u64_type = || TypeInfo::UnsignedInteger(IntegerBits::SixtyFour);
+        let alias = TypeInfo::Custom {
+            call_path: Ident::new_no_span("MyU64".into()).into(),
+            type_arguments: None,
+        };
+
+        let declared = parameter(&engines, u64_type(), false);
+        let same = parameter(&engines, u64_type(), false);
+        assert!(declared.eq(&same, &engines));
+        assert!(declared.strict_eq(&same, &engines));
+
+        let aliased = parameter(&engines, alias, false);
+        assert!(declared.eq(&aliased, &engines));
+        assert!(!declared.strict_eq(&aliased, &engines));
+
+        let reference = parameter(&engines, u64_type(), true);
+        assert!(!declared.eq(&reference, &engines));
+        assert!(!declared.strict_eq(&reference, &engines));
+    }
+
+    #[test]
+    fn parameter_by_name_skips_self() {
+        let engines = Engines::default();
+        let named = |name: &str| TyFunctionParameter {
+            name: Ident::new_no_span(name.into()),
+            ..parameter(
+                &engines,
+                TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+                false,
+            )
+        };
+        let return_type = engines.te().insert(&engines, TypeInfo::Tuple(vec![]));
+        let fn_decl = TyFunctionDecl {
+            name: Ident::new_no_span("f".into()),
+            body: TyCodeBlock::default(),
+            parameters: vec![named("self"), named("x")],
+            implementing_type: None,
+            span: Span::dummy(),
+            attributes: transform::AttributesMap::default(),
+            type_parameters: vec![],
+            return_type: TypeArgument {
+                type_id: return_type,
+                initial_type_id: return_type,
+                span: Span::dummy(),
+                call_path_tree: None,
+            },
+            visibility: Visibility::Public,
+            is_contract_call: false,
+            purity: Purity::Pure,
+            has_inferred_purity: false,
+            where_clause: vec![],
+        };
+        let decl = TyDecl::from(engines.de().insert(fn_decl));
+
+        let (parameters, self_param, x_param) = decl
+            .with_fun_decl(engines.de(), |fn_decl| {
+                (
+                    fn_decl.parameters().len(),
+                    fn_decl
+                        .parameter_by_name(&Ident::new_no_span("self".into()))
+                        .is_some(),
+                    fn_decl
+                        .parameter_by_name(&Ident::new_no_span("x".into()))
+                        .map(|param| param.name.as_str().to_string()),
+                )
+            })
+            .unwrap();
+        assert_eq!(parameters, 2);
+        assert!(!self_param);
+        assert_eq!(x_param.as_deref(), Some("x"));
+    }
+}
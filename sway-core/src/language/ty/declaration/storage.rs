@@ -4,7 +4,7 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{state::StateIndex, Ident, Named, Span, Spanned};
+use sway_types::{state::StateIndex, style::find_similar_name, Ident, Named, Span, Spanned};
 
 use crate::{
     decl_engine::DeclEngine, engine_threading::*, language::ty::*, transform, type_system::*,
@@ -123,6 +123,8 @@ impl TyStorageDecl {
                         field_name: field.clone(),
                         available_fields: available_fields.join(", "),
                         struct_name: type_checked_buf.last().unwrap().name.clone(),
+                        suggestion: find_similar_name(field.as_str(), available_fields)
+                            .map(str::to_string),
                         span: field.span(),
                     }));
                 }
@@ -7,7 +7,7 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{Ident, Named, Span, Spanned};
+use sway_types::{style::find_similar_name, Ident, Named, Span, Spanned};
 
 use crate::{
     engine_threading::*,
@@ -121,6 +121,11 @@ impl TyStructDecl {
                         .join("\n"),
                     field_name: field_to_access.clone(),
                     struct_name: self.call_path.suffix.clone(),
+                    suggestion: find_similar_name(
+                        field_to_access.as_str(),
+                        self.fields.iter().map(|field| field.name.as_str()),
+                    )
+                    .map(str::to_string),
                     span: field_to_access.span(),
                 }));
             }
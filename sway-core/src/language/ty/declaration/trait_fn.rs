@@ -19,6 +19,22 @@ pub struct TyTraitFn {
     pub attributes: transform::AttributesMap,
 }
 
+impl TyTraitFn {
+    /// The span covering all the parameters, or the name if there are none.
+    pub(crate) fn parameters_span(&self) -> Span {
+        if !self.parameters.is_empty() {
+            self.parameters.iter().fold(
+                self.parameters[0].name.span(),
+                |acc, TyFunctionParameter { type_argument, .. }| {
+                    Span::join(acc, type_argument.span.clone())
+                },
+            )
+        } else {
+            self.name.span()
+        }
+    }
+}
+
 impl Named for TyTraitFn {
     fn name(&self) -> &Ident {
         &self.name
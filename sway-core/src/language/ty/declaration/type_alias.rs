@@ -8,6 +8,7 @@ use crate::{engine_threading::*, language::Visibility, transform, type_system::*
 pub struct TyTypeAliasDecl {
     pub name: Ident,
     pub attributes: transform::AttributesMap,
+    pub type_parameters: Vec<TypeParameter>,
     pub ty: TypeArgument,
     pub visibility: Visibility,
     pub span: Span,
@@ -23,6 +24,7 @@ impl EqWithEngines for TyTypeAliasDecl {}
 impl PartialEqWithEngines for TyTypeAliasDecl {
     fn eq(&self, other: &Self, engines: &Engines) -> bool {
         self.name == other.name
+            && self.type_parameters.eq(&other.type_parameters, engines)
             && self.ty.eq(&other.ty, engines)
             && self.visibility == other.visibility
     }
@@ -32,6 +34,7 @@ impl HashWithEngines for TyTypeAliasDecl {
     fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
         let TyTypeAliasDecl {
             name,
+            type_parameters,
             ty,
             visibility,
             // these fields are not hashed because they aren't relevant/a
@@ -40,6 +43,7 @@ impl HashWithEngines for TyTypeAliasDecl {
             attributes: _,
         } = self;
         name.hash(state);
+        type_parameters.hash(state, engines);
         ty.hash(state, engines);
         visibility.hash(state);
     }
@@ -48,12 +52,28 @@ impl HashWithEngines for TyTypeAliasDecl {
 impl SubstTypes for TyTypeAliasDecl {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: &Engines) {
         self.ty.subst(type_mapping, engines);
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.subst(type_mapping, engines));
     }
 }
 
 impl ReplaceSelfType for TyTypeAliasDecl {
     fn replace_self_type(&mut self, engines: &Engines, self_type: TypeId) {
         self.ty.replace_self_type(engines, self_type);
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
+    }
+}
+
+impl MonomorphizeHelper for TyTypeAliasDecl {
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.name
     }
 }
 
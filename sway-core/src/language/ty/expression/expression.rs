@@ -1,7 +1,7 @@
 use std::{fmt, hash::Hasher};
 
 use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::{Span, Spanned};
+use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     decl_engine::*,
@@ -247,6 +247,9 @@ impl CollectTypesMetadata for TyExpression {
             EnumTag { exp } => {
                 res.append(&mut exp.collect_types_metadata(handler, ctx)?);
             }
+            ArrayToSlice { array } => {
+                res.append(&mut array.collect_types_metadata(handler, ctx)?);
+            }
             UnsafeDowncast {
                 exp,
                 variant,
@@ -359,6 +362,7 @@ impl DeterministicallyAborts for TyExpression {
             }
             AbiName(_) => false,
             EnumTag { exp } => exp.deterministically_aborts(decl_engine, check_call_body),
+            ArrayToSlice { array } => array.deterministically_aborts(decl_engine, check_call_body),
             UnsafeDowncast { exp, .. } => {
                 exp.deterministically_aborts(decl_engine, check_call_body)
             }
@@ -403,15 +407,132 @@ impl TyExpression {
     }
 
     /// gathers the mutability of the expressions within
+    ///
+    /// Fields of structs and tuples, and elements of arrays, are as mutable as the variable they
+    /// belong to.
     pub(crate) fn gather_mutability(&self) -> VariableMutability {
         match &self.expression {
             TyExpressionVariant::VariableExpression { mutability, .. } => *mutability,
+            TyExpressionVariant::StructFieldAccess { prefix, .. }
+            | TyExpressionVariant::TupleElemAccess { prefix, .. }
+            | TyExpressionVariant::ArrayIndex { prefix, .. } => prefix.gather_mutability(),
             _ => VariableMutability::Immutable,
         }
     }
 
+    /// Returns the name of the variable that `self` is, or that `self` is a field or an element
+    /// of, if any.
+    pub(crate) fn gather_variable_name(&self) -> Option<&Ident> {
+        match &self.expression {
+            TyExpressionVariant::VariableExpression { name, .. } => Some(name),
+            TyExpressionVariant::StructFieldAccess { prefix, .. }
+            | TyExpressionVariant::TupleElemAccess { prefix, .. }
+            | TyExpressionVariant::ArrayIndex { prefix, .. } => prefix.gather_variable_name(),
+            _ => None,
+        }
+    }
+
     /// Returns `self` as a literal, if possible.
     pub(crate) fn extract_literal_value(&self) -> Option<Literal> {
         self.expression.extract_literal_value()
     }
+
+    /// recurse into `self` and get the numeric literals, i.e. the integer literals without a
+    /// type suffix, like `1` -- used to default their types and check that their values fit
+    /// once the enclosing function is type checked
+    /// This does _not_ recurse into the bodies of called functions.
+    pub(crate) fn gather_numeric_literals(&self) -> Vec<&TyExpression> {
+        use TyExpressionVariant::*;
+        match &self.expression {
+            Literal(crate::language::Literal::Numeric(_)) => vec![self],
+            FunctionApplication {
+                contract_call_params,
+                arguments,
+                selector,
+                ..
+            } => contract_call_params
+                .values()
+                .chain(arguments.iter().map(|(_name, expr)| expr))
+                .chain(
+                    selector
+                        .iter()
+                        .map(|contract_call_params| &*contract_call_params.contract_address),
+                )
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            LazyOperator { lhs, rhs, .. }
+            | ArrayIndex {
+                prefix: lhs,
+                index: rhs,
+            } => [lhs, rhs]
+                .into_iter()
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            Tuple { fields: contents } | Array { contents, .. } => contents
+                .iter()
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            StructExpression { fields, .. } => fields
+                .iter()
+                .flat_map(|field| field.value.gather_numeric_literals())
+                .collect(),
+            CodeBlock(block) => block
+                .contents
+                .iter()
+                .flat_map(|node| node.gather_numeric_literals())
+                .collect(),
+            MatchExp { desugared, .. } => desugared.gather_numeric_literals(),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                let mut buf = condition.gather_numeric_literals();
+                buf.append(&mut then.gather_numeric_literals());
+                if let Some(r#else) = r#else {
+                    buf.append(&mut r#else.gather_numeric_literals());
+                }
+                buf
+            }
+            AsmExpression { registers, .. } => registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref())
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            StructFieldAccess { prefix, .. }
+            | TupleElemAccess { prefix, .. }
+            | AbiCast {
+                address: prefix, ..
+            }
+            | EnumTag { exp: prefix }
+            | ArrayToSlice { array: prefix }
+            | UnsafeDowncast { exp: prefix, .. }
+            | Return(prefix) => prefix.gather_numeric_literals(),
+            EnumInstantiation { contents, .. } => contents
+                .iter()
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            IntrinsicFunction(kind) => kind
+                .arguments
+                .iter()
+                .flat_map(|expr| expr.gather_numeric_literals())
+                .collect(),
+            WhileLoop { condition, body } => {
+                let mut buf = condition.gather_numeric_literals();
+                for node in &body.contents {
+                    buf.append(&mut node.gather_numeric_literals());
+                }
+                buf
+            }
+            Reassignment(reassignment) => reassignment.rhs.gather_numeric_literals(),
+            Literal(_)
+            | ConstantExpression { .. }
+            | VariableExpression { .. }
+            | FunctionParameter
+            | StorageAccess(_)
+            | AbiName(_)
+            | Break
+            | Continue => vec![],
+        }
+    }
 }
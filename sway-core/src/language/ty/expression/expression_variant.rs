@@ -5,7 +5,7 @@ use std::{
 };
 
 use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::{Ident, Named, Span, Spanned};
+use sway_types::{integer_bits::IntegerBits, Ident, Named, Span, Spanned};
 
 use crate::{
     decl_engine::*,
@@ -123,6 +123,11 @@ pub enum TyExpressionVariant {
     EnumTag {
         exp: Box<TyExpression>,
     },
+    /// views the fixed-size `array` as a slice of its elements, whose length is the length of
+    /// the array
+    ArrayToSlice {
+        array: Box<TyExpression>,
+    },
     /// performs an unsafe cast from the `exp` to the type of the given enum `variant`
     UnsafeDowncast {
         exp: Box<TyExpression>,
@@ -385,6 +390,9 @@ impl PartialEqWithEngines for TyExpressionVariant {
             (Self::EnumTag { exp: l_exp }, Self::EnumTag { exp: r_exp }) => {
                 l_exp.eq(&**r_exp, engines)
             }
+            (Self::ArrayToSlice { array: l_array }, Self::ArrayToSlice { array: r_array }) => {
+                l_array.eq(&**r_array, engines)
+            }
             (Self::StorageAccess(l_exp), Self::StorageAccess(r_exp)) => l_exp.eq(r_exp, engines),
             (
                 Self::WhileLoop {
@@ -576,6 +584,9 @@ impl HashWithEngines for TyExpressionVariant {
             Self::EnumTag { exp } => {
                 exp.hash(state, engines);
             }
+            Self::ArrayToSlice { array } => {
+                array.hash(state, engines);
+            }
             Self::UnsafeDowncast {
                 exp,
                 variant,
@@ -719,6 +730,9 @@ impl SubstTypes for TyExpressionVariant {
             EnumTag { exp } => {
                 exp.subst(type_mapping, engines);
             }
+            ArrayToSlice { array } => {
+                array.subst(type_mapping, engines);
+            }
             UnsafeDowncast {
                 exp,
                 variant,
@@ -857,6 +871,9 @@ impl ReplaceSelfType for TyExpressionVariant {
             EnumTag { exp } => {
                 exp.replace_self_type(engines, self_type);
             }
+            ArrayToSlice { array } => {
+                array.replace_self_type(engines, self_type);
+            }
             UnsafeDowncast {
                 exp,
                 variant,
@@ -1016,6 +1033,7 @@ impl ReplaceDecls for TyExpressionVariant {
                 StorageAccess { .. } => (),
                 IntrinsicFunction(_) => {}
                 EnumTag { exp } => exp.replace_decls(decl_mapping, handler, ctx)?,
+                ArrayToSlice { array } => array.replace_decls(decl_mapping, handler, ctx)?,
                 UnsafeDowncast { exp, .. } => exp.replace_decls(decl_mapping, handler, ctx)?,
                 AbiName(_) => (),
                 WhileLoop {
@@ -1117,6 +1135,9 @@ impl UpdateConstantExpression for TyExpressionVariant {
             EnumTag { exp } => {
                 exp.update_constant_expression(engines, implementing_type);
             }
+            ArrayToSlice { array } => {
+                array.update_constant_expression(engines, implementing_type);
+            }
             UnsafeDowncast { exp, .. } => {
                 exp.update_constant_expression(engines, implementing_type);
             }
@@ -1259,6 +1280,9 @@ impl DebugWithEngines for TyExpressionVariant {
             TyExpressionVariant::EnumTag { exp } => {
                 format!("({:?} as tag)", engines.help_out(exp.return_type))
             }
+            TyExpressionVariant::ArrayToSlice { array } => {
+                format!("({:?} as slice)", engines.help_out(array.return_type))
+            }
             TyExpressionVariant::UnsafeDowncast {
                 exp,
                 variant,
@@ -1302,9 +1326,41 @@ impl DebugWithEngines for TyExpressionVariant {
 
 impl TyExpressionVariant {
     /// Returns `self` as a literal, if possible.
+    ///
+    /// References to constants are replaced by the values of the constants, and the basic
+    /// arithmetic operators from `core::ops` are evaluated when applied to integer literals.
     pub(crate) fn extract_literal_value(&self) -> Option<Literal> {
         match self {
             TyExpressionVariant::Literal(value) => Some(value.clone()),
+            // The value of a configurable can be changed after compilation and cannot be folded.
+            TyExpressionVariant::ConstantExpression { const_decl, .. }
+                if !const_decl.is_configurable =>
+            {
+                const_decl
+                    .value
+                    .as_ref()
+                    .and_then(|value| value.extract_literal_value())
+            }
+            TyExpressionVariant::FunctionApplication {
+                call_path,
+                arguments,
+                ..
+            } if call_path.prefixes.len() == 2
+                && call_path.prefixes[0].as_str() == "core"
+                && call_path.prefixes[1].as_str() == "ops"
+                && arguments.len() == 2 =>
+            {
+                let lhs = arguments[0].1.extract_literal_value()?;
+                let rhs = arguments[1].1.extract_literal_value()?;
+                let op: fn(u64, u64) -> Option<u64> = match call_path.suffix.as_str() {
+                    "add" => u64::checked_add,
+                    "subtract" => u64::checked_sub,
+                    "multiply" => u64::checked_mul,
+                    "divide" => u64::checked_div,
+                    _ => return None,
+                };
+                fold_integer_literals(lhs, rhs, op)
+            }
             _ => None,
         }
     }
@@ -1404,6 +1460,7 @@ impl TyExpressionVariant {
                 .flat_map(|expr| expr.gather_return_statements())
                 .collect(),
             TyExpressionVariant::EnumTag { exp } => exp.gather_return_statements(),
+            TyExpressionVariant::ArrayToSlice { array } => array.gather_return_statements(),
             TyExpressionVariant::UnsafeDowncast { exp, .. } => exp.gather_return_statements(),
 
             TyExpressionVariant::Return(exp) => {
@@ -1423,3 +1480,40 @@ impl TyExpressionVariant {
         }
     }
 }
+
+/// Applies `op` to two integer literals of the same type, or of an integer type and a numeric
+/// literal. Returns `None` if the literals are not integers, if their types differ, or if the
+/// result does not fit into the type.
+fn fold_integer_literals(
+    lhs: Literal,
+    rhs: Literal,
+    op: fn(u64, u64) -> Option<u64>,
+) -> Option<Literal> {
+    fn split(literal: Literal) -> Option<(Option<IntegerBits>, u64)> {
+        match literal {
+            Literal::U8(value) => Some((Some(IntegerBits::Eight), value as u64)),
+            Literal::U16(value) => Some((Some(IntegerBits::Sixteen), value as u64)),
+            Literal::U32(value) => Some((Some(IntegerBits::ThirtyTwo), value as u64)),
+            Literal::U64(value) => Some((Some(IntegerBits::SixtyFour), value)),
+            Literal::Numeric(value) => Some((None, value)),
+            _ => None,
+        }
+    }
+
+    let (lhs_bits, lhs) = split(lhs)?;
+    let (rhs_bits, rhs) = split(rhs)?;
+    let bits = match (lhs_bits, rhs_bits) {
+        (Some(lhs_bits), Some(rhs_bits)) if lhs_bits != rhs_bits => return None,
+        (Some(bits), _) | (_, Some(bits)) => Some(bits),
+        (None, None) => None,
+    };
+    let value = op(lhs, rhs)?;
+    match bits {
+        Some(IntegerBits::Eight) => u8::try_from(value).ok().map(Literal::U8),
+        Some(IntegerBits::Sixteen) => u16::try_from(value).ok().map(Literal::U16),
+        Some(IntegerBits::ThirtyTwo) => u32::try_from(value).ok().map(Literal::U32),
+        Some(IntegerBits::SixtyFour) => Some(Literal::U64(value)),
+        Some(IntegerBits::V256) => None,
+        None => Some(Literal::Numeric(value)),
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
     decl_engine::*,
-    fuel_prelude::fuel_tx::StorageSlot,
+    fuel_prelude::{fuel_tx::StorageSlot, fuel_types::Bytes32},
     language::{parsed, ty::*, Purity},
     type_system::*,
     types::*,
     Engines,
 };
 
+use std::collections::BTreeMap;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
@@ -20,6 +21,9 @@ pub struct TyProgram {
     pub declarations: Vec<TyDecl>,
     pub configurables: Vec<TyConstantDecl>,
     pub storage_slots: Vec<StorageSlot>,
+    /// The path of the storage field, e.g. `config.limits.max`, holding the first word of each
+    /// of the `storage_slots`, by slot key.
+    pub storage_slot_paths: BTreeMap<Bytes32, String>,
     pub logged_types: Vec<(LogId, TypeId)>,
     pub messages_types: Vec<(MessageId, TypeId)>,
 }
@@ -56,7 +60,7 @@ impl TyProgram {
         let mut mains = Vec::new();
         let mut declarations = Vec::<TyDecl>::new();
         let mut abi_entries = Vec::new();
-        let mut fn_declarations = std::collections::HashSet::new();
+        let mut fn_declarations = std::collections::HashSet::<Ident>::new();
         for node in &root.all_nodes {
             match &node.content {
                 TyAstNodeContent::Declaration(TyDecl::FunctionDecl(FunctionDecl {
@@ -71,11 +75,17 @@ impl TyProgram {
                         mains.push(func.clone());
                     }
 
-                    if !fn_declarations.insert(func.name.clone()) {
-                        handler.emit_err(CompileError::MultipleDefinitionsOfFunction {
-                            name: func.name.clone(),
-                            span: func.name.span(),
-                        });
+                    match fn_declarations.get(&func.name) {
+                        Some(previous_name) => {
+                            handler.emit_err(CompileError::MultipleDefinitionsOfFunction {
+                                name: func.name.clone(),
+                                previous_span: previous_name.span(),
+                                span: func.name.span(),
+                            });
+                        }
+                        None => {
+                            fn_declarations.insert(func.name.clone());
+                        }
                     }
 
                     declarations.push(TyDecl::FunctionDecl(FunctionDecl {
@@ -214,6 +224,7 @@ impl TyProgram {
                 if mains.len() > 1 {
                     handler.emit_err(CompileError::MultipleDefinitionsOfFunction {
                         name: mains.last().unwrap().name.clone(),
+                        previous_span: mains[0].name.span(),
                         span: mains.last().unwrap().name.span(),
                     });
                 }
@@ -240,6 +251,7 @@ impl TyProgram {
                 if mains.len() > 1 {
                     handler.emit_err(CompileError::MultipleDefinitionsOfFunction {
                         name: mains.last().unwrap().name.clone(),
+                        previous_span: mains[0].name.span(),
                         span: mains.last().unwrap().name.span(),
                     });
                 }
@@ -258,12 +270,38 @@ impl TyProgram {
                         span: main_func.return_type.span.clone(),
                     });
                 }
+                // Any other return type must be encodable in the return data.
+                if !main_func
+                    .return_type
+                    .type_id
+                    .extract_any_including_self(
+                        engines,
+                        &|type_info| {
+                            matches!(
+                                type_info,
+                                TypeInfo::RawUntypedPtr
+                                    | TypeInfo::Ptr(_)
+                                    | TypeInfo::Slice(_)
+                                    | TypeInfo::ContractCaller { .. }
+                            )
+                        },
+                        vec![],
+                    )
+                    .is_empty()
+                {
+                    handler.emit_err(CompileError::MainReturnTypeNotEncodable {
+                        program_kind: "script".to_string(),
+                        ty: engines.help_out(main_func.return_type.type_id).to_string(),
+                        span: main_func.return_type.span.clone(),
+                    });
+                }
                 TyProgramKind::Script {
                     main_function: main_func,
                 }
             }
         };
-        // check if no ref mut arguments passed to a `main()` in a `script` or `predicate`.
+        // check if no ref mut arguments passed to a `main()` in a `script` or `predicate`, and
+        // that the arguments can be encoded in the script or predicate data.
         match &typed_program_kind {
             TyProgramKind::Script { main_function, .. }
             | TyProgramKind::Predicate { main_function, .. } => {
@@ -274,6 +312,32 @@ impl TyProgram {
                             span: param.name.span(),
                         });
                     }
+                    if !param
+                        .type_argument
+                        .type_id
+                        .extract_any_including_self(
+                            engines,
+                            &|type_info| {
+                                matches!(
+                                    type_info,
+                                    TypeInfo::RawUntypedPtr
+                                        | TypeInfo::RawUntypedSlice
+                                        | TypeInfo::Ptr(_)
+                                        | TypeInfo::Slice(_)
+                                        | TypeInfo::ContractCaller { .. }
+                                )
+                            },
+                            vec![],
+                        )
+                        .is_empty()
+                    {
+                        handler.emit_err(CompileError::MainParameterTypeNotEncodable {
+                            program_kind: typed_program_kind.as_title_str().to_lowercase(),
+                            param_name: param.name.clone(),
+                            ty: engines.help_out(param.type_argument.type_id).to_string(),
+                            span: param.type_argument.span.clone(),
+                        });
+                    }
                 }
             }
             _ => (),
@@ -12,13 +12,21 @@ pub enum VariableMutability {
 }
 
 impl VariableMutability {
+    /// Returns the mutability of a variable or parameter declared with the `ref` and `mut`
+    /// keywords, as given by `is_reference` and `is_mutable`.
+    ///
+    /// The result is mutable if and only if `mut` is present, so `ref` alone is [Immutable],
+    /// and only `ref mut` is [RefMutable]. Note that `mut` alone is [Mutable] also for
+    /// parameters, where it is rejected later on as not supported.
+    ///
+    /// [Immutable]: VariableMutability::Immutable
+    /// [Mutable]: VariableMutability::Mutable
+    /// [RefMutable]: VariableMutability::RefMutable
     pub fn new_from_ref_mut(is_reference: bool, is_mutable: bool) -> VariableMutability {
-        if is_reference {
-            VariableMutability::RefMutable
-        } else if is_mutable {
-            VariableMutability::Mutable
-        } else {
-            VariableMutability::Immutable
+        match (is_reference, is_mutable) {
+            (true, true) => VariableMutability::RefMutable,
+            (false, true) => VariableMutability::Mutable,
+            (_, false) => VariableMutability::Immutable,
         }
     }
 
@@ -37,3 +45,27 @@ impl VariableMutability {
         !self.is_mutable()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_from_ref_mut_covers_all_combinations() {
+        let expected = [
+            (false, false, VariableMutability::Immutable),
+            (false, true, VariableMutability::Mutable),
+            (true, false, VariableMutability::Immutable),
+            (true, true, VariableMutability::RefMutable),
+        ];
+        for (is_reference, is_mutable, mutability) in expected {
+            let actual = VariableMutability::new_from_ref_mut(is_reference, is_mutable);
+            assert_eq!(actual, mutability, "ref: {is_reference}, mut: {is_mutable}");
+            assert_eq!(
+                actual.is_mutable(),
+                is_mutable,
+                "ref: {is_reference}, mut: {is_mutable}"
+            );
+        }
+    }
+}
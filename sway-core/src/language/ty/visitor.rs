@@ -0,0 +1,187 @@
+//! Read-only traversal of the typed tree.
+//!
+//! A pass implements [TyTreeVisitor] and overrides only the hooks it is interested in, e.g.
+//! [TyTreeVisitor::visit_function_parameter]. The default implementation of every other hook
+//! keeps walking the tree by calling the matching `walk_*` function, so the traversal itself is
+//! written only once. An overriding hook calls the `walk_*` function itself if it wants to visit
+//! the children of the node as well.
+//!
+//! The traversal goes down to declarations and function parameters. Expressions are not visited.
+
+use crate::{decl_engine::DeclEngine, language::ty};
+
+/// A read-only visitor of the typed tree. See the [module documentation](self) for how the hooks
+/// and the `walk_*` functions play together.
+pub trait TyTreeVisitor {
+    fn visit_module(&mut self, decl_engine: &DeclEngine, module: &ty::TyModule) {
+        walk_module(self, decl_engine, module);
+    }
+
+    fn visit_ast_node(&mut self, decl_engine: &DeclEngine, node: &ty::TyAstNode) {
+        walk_ast_node(self, decl_engine, node);
+    }
+
+    fn visit_decl(&mut self, decl_engine: &DeclEngine, decl: &ty::TyDecl) {
+        walk_decl(self, decl_engine, decl);
+    }
+
+    fn visit_code_block(&mut self, decl_engine: &DeclEngine, code_block: &ty::TyCodeBlock) {
+        walk_code_block(self, decl_engine, code_block);
+    }
+
+    fn visit_function_decl(&mut self, decl_engine: &DeclEngine, fn_decl: &ty::TyFunctionDecl) {
+        walk_function_decl(self, decl_engine, fn_decl);
+    }
+
+    fn visit_trait_fn(&mut self, trait_fn: &ty::TyTraitFn) {
+        walk_trait_fn(self, trait_fn);
+    }
+
+    fn visit_trait_decl(&mut self, decl_engine: &DeclEngine, trait_decl: &ty::TyTraitDecl) {
+        walk_trait_decl(self, decl_engine, trait_decl);
+    }
+
+    fn visit_abi_decl(&mut self, decl_engine: &DeclEngine, abi_decl: &ty::TyAbiDecl) {
+        walk_abi_decl(self, decl_engine, abi_decl);
+    }
+
+    fn visit_impl_trait(&mut self, decl_engine: &DeclEngine, impl_trait: &ty::TyImplTrait) {
+        walk_impl_trait(self, decl_engine, impl_trait);
+    }
+
+    /// Called for the parameters of functions, methods and trait and ABI method declarations.
+    fn visit_function_parameter(&mut self, _param: &ty::TyFunctionParameter) {}
+}
+
+pub fn walk_module<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    module: &ty::TyModule,
+) {
+    for (_, submodule) in &module.submodules {
+        visitor.visit_module(decl_engine, &submodule.module);
+    }
+    for node in &module.all_nodes {
+        visitor.visit_ast_node(decl_engine, node);
+    }
+}
+
+pub fn walk_ast_node<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    node: &ty::TyAstNode,
+) {
+    if let ty::TyAstNodeContent::Declaration(decl) = &node.content {
+        visitor.visit_decl(decl_engine, decl);
+    }
+}
+
+pub fn walk_decl<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    decl: &ty::TyDecl,
+) {
+    match decl {
+        ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
+            visitor.visit_function_decl(decl_engine, &decl_engine.get_function(decl_id));
+        }
+        ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. }) => {
+            visitor.visit_trait_decl(decl_engine, &decl_engine.get_trait(decl_id));
+        }
+        ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
+            visitor.visit_abi_decl(decl_engine, &decl_engine.get_abi(decl_id));
+        }
+        ty::TyDecl::ImplTrait(ty::ImplTrait { decl_id, .. }) => {
+            visitor.visit_impl_trait(decl_engine, &decl_engine.get_impl_trait(decl_id));
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_code_block<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    code_block: &ty::TyCodeBlock,
+) {
+    for node in &code_block.contents {
+        visitor.visit_ast_node(decl_engine, node);
+    }
+}
+
+pub fn walk_function_decl<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    fn_decl: &ty::TyFunctionDecl,
+) {
+    for param in &fn_decl.parameters {
+        visitor.visit_function_parameter(param);
+    }
+    visitor.visit_code_block(decl_engine, &fn_decl.body);
+}
+
+pub fn walk_trait_fn<V: TyTreeVisitor + ?Sized>(visitor: &mut V, trait_fn: &ty::TyTraitFn) {
+    for param in &trait_fn.parameters {
+        visitor.visit_function_parameter(param);
+    }
+}
+
+pub fn walk_trait_decl<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    trait_decl: &ty::TyTraitDecl,
+) {
+    walk_interface(
+        visitor,
+        decl_engine,
+        &trait_decl.interface_surface,
+        &trait_decl.items,
+    );
+}
+
+pub fn walk_abi_decl<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    abi_decl: &ty::TyAbiDecl,
+) {
+    walk_interface(
+        visitor,
+        decl_engine,
+        &abi_decl.interface_surface,
+        &abi_decl.items,
+    );
+}
+
+pub fn walk_impl_trait<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    impl_trait: &ty::TyImplTrait,
+) {
+    walk_trait_items(visitor, decl_engine, &impl_trait.items);
+}
+
+/// Walks the interface surface and the provided methods of a trait or an ABI.
+fn walk_interface<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    interface_surface: &[ty::TyTraitInterfaceItem],
+    items: &[ty::TyTraitItem],
+) {
+    for item in interface_surface {
+        if let ty::TyTraitInterfaceItem::TraitFn(decl_ref) = item {
+            visitor.visit_trait_fn(&decl_engine.get_trait_fn(decl_ref));
+        }
+    }
+    walk_trait_items(visitor, decl_engine, items);
+}
+
+fn walk_trait_items<V: TyTreeVisitor + ?Sized>(
+    visitor: &mut V,
+    decl_engine: &DeclEngine,
+    items: &[ty::TyTraitItem],
+) {
+    for item in items {
+        if let ty::TyTraitItem::Fn(decl_ref) = item {
+            visitor.visit_function_decl(decl_engine, &decl_engine.get_function(decl_ref));
+        }
+    }
+}
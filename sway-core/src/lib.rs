@@ -21,25 +21,30 @@ pub mod source_map;
 pub mod transform;
 pub mod type_system;
 
-use crate::ir_generation::check_function_purity;
+use crate::ir_generation::{
+    check_constant_overflows, check_function_purity, check_inline_attributes,
+    check_stack_frame_budget,
+};
 use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget};
+pub use build_config::{BuildConfig, BuildTarget, DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT};
 use control_flow_analysis::ControlFlowGraph;
 use metadata::MetadataManager;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use sway_ast::AttributeDecl;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{
     create_o1_pass_group, register_known_passes, Context, Kind, Module, PassManager,
-    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME, MEMCPYOPT_NAME, MISCDEMOTION_NAME,
-    MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME,
+    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME, EFFECTS_NAME, MEMCPYOPT_NAME,
+    MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME,
+};
+use sway_types::constants::{
+    ALLOW_ATTRIBUTE_NAME, DENY_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME,
 };
-use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
 use sway_types::SourceEngine;
 use sway_utils::{time_expr, PerformanceData, PerformanceMetric};
 use transform::{Attribute, AttributeArg, AttributeKind, AttributesMap};
@@ -92,6 +97,7 @@ pub fn parse(
             config.canonical_root_module(),
             None,
             config.build_target,
+            &config.cfg_flags,
         )
         .map(|(kind, lexed, parsed)| {
             let lexed = lexed::LexedProgram {
@@ -124,8 +130,11 @@ fn module_attrs_to_map(
         let attrs = attr_decl.attribute.get().into_iter();
         for attr in attrs {
             let name = attr.name.as_str();
-            if name != DOC_COMMENT_ATTRIBUTE_NAME {
-                // prevent using anything except doc comment attributes
+            if !matches!(
+                name,
+                DOC_COMMENT_ATTRIBUTE_NAME | ALLOW_ATTRIBUTE_NAME | DENY_ATTRIBUTE_NAME
+            ) {
+                // prevent using anything except doc comment and lint level attributes
                 handler.emit_err(CompileError::ExpectedModuleDocComment {
                     span: attr.name.span(),
                 });
@@ -138,12 +147,7 @@ fn module_attrs_to_map(
                     parens
                         .get()
                         .into_iter()
-                        .cloned()
-                        .map(|arg| AttributeArg {
-                            name: arg.name.clone(),
-                            value: arg.value.clone(),
-                            span: arg.span(),
-                        })
+                        .map(AttributeArg::from_lexed)
                         .collect()
                 })
                 .unwrap_or_else(Vec::new);
@@ -156,12 +160,15 @@ fn module_attrs_to_map(
 
             if let Some(attr_kind) = match name {
                 DOC_COMMENT_ATTRIBUTE_NAME => Some(AttributeKind::DocComment),
+                ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
+                DENY_ATTRIBUTE_NAME => Some(AttributeKind::Deny),
                 _ => None,
             } {
                 attrs_map.entry(attr_kind).or_default().push(attribute);
             }
         }
     }
+    to_parsed_lang::check_attribute_args(handler, &attrs_map);
     Ok(AttributesMap::new(Arc::new(attrs_map)))
 }
 
@@ -212,6 +219,7 @@ fn parse_submodules(
     module: &sway_ast::Module,
     module_dir: &Path,
     build_target: BuildTarget,
+    cfg_flags: &HashSet<String>,
 ) -> Submodules {
     // Assume the happy path, so there'll be as many submodules as dependencies, but no more.
     let mut lexed_submods = Vec::with_capacity(module.submodules().count());
@@ -240,6 +248,7 @@ fn parse_submodules(
             submod_path.clone(),
             Some(submod.name.as_str()),
             build_target,
+            cfg_flags,
         ) {
             if !matches!(kind, parsed::TreeType::Library) {
                 let source_id = engines.se().get_source_id(submod_path.as_ref());
@@ -279,6 +288,7 @@ fn parse_module_tree(
     path: Arc<PathBuf>,
     module_name: Option<&str>,
     build_target: BuildTarget,
+    cfg_flags: &HashSet<String>,
 ) -> Result<(parsed::TreeType, lexed::LexedModule, parsed::ParseModule), ErrorEmitted> {
     // Parse this module first.
     let module_dir = path.parent().expect("module file has no parent directory");
@@ -294,11 +304,12 @@ fn parse_module_tree(
         &module.value,
         module_dir,
         build_target,
+        cfg_flags,
     );
 
     // Convert from the raw parsed module to the `ParseTree` ready for type-check.
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
-        &mut to_parsed_lang::Context::new(build_target),
+        &mut to_parsed_lang::Context::new(build_target, cfg_flags.clone()),
         handler,
         engines,
         module.value.clone(),
@@ -356,6 +367,7 @@ pub fn parsed_to_ast(
         parse_program,
         initial_namespace,
         package_name,
+        build_config,
     );
 
     let mut typed_program = match typed_program_opt {
@@ -425,6 +437,9 @@ pub fn parsed_to_ast(
         handler.emit_warn(warn);
     }
 
+    // Predicates state access analysis
+    semantic_analysis::state_access_analysis::analyze_program(handler, engines, &typed_program);
+
     // Check that all storage initializers can be evaluated at compile time.
     let typed_wiss_res = typed_program.get_typed_program_with_initialized_storage_slots(
         handler,
@@ -511,8 +526,11 @@ pub fn compile_to_ast(
         metrics
     );
 
+    let programs = Programs::new(lexed_program, parsed_program, typed_res);
+    transform::LintLevels::new(&programs).apply(handler);
+
     handler.dedup();
-    Ok(Programs::new(lexed_program, parsed_program, typed_res))
+    Ok(programs)
 }
 
 /// Given input Sway source code, try compiling to a `CompiledAsm`,
@@ -551,7 +569,10 @@ pub fn ast_to_asm(
         Err(err) => return Err(*err),
     };
 
-    let asm = match compile_ast_to_ir_to_asm(handler, engines, typed_program, build_config) {
+    let asm_res = compile_ast_to_ir_to_asm(handler, engines, typed_program, build_config);
+    transform::LintLevels::new(programs).apply(handler);
+
+    let asm = match asm_res {
         Ok(res) => res,
         Err(err) => {
             handler.dedup();
@@ -600,9 +621,27 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         }
     }
 
+    // Check the inline attributes on the _unoptimised_ IR, too.
+    check_inline_attributes(handler, &ir, &mut metadata::MetadataManager::default());
+
+    // And the stack frames, before large parameters are demoted to references.
+    if let Some(budget) = build_config.stack_frame_budget {
+        check_stack_frame_budget(
+            handler,
+            &ir,
+            &mut metadata::MetadataManager::default(),
+            budget,
+        );
+    }
+
+    if let Some(inline_threshold) = build_config.inline_threshold {
+        ir.inline_threshold = inline_threshold;
+    }
+
     // Initialize the pass manager and register known passes.
     let mut pass_mgr = PassManager::default();
     register_known_passes(&mut pass_mgr);
+    pass_mgr.verify_after_each(build_config.verify_ir);
     let mut pass_group = create_o1_pass_group();
 
     // Target specific transforms should be moved into something more configured.
@@ -616,6 +655,10 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         pass_group.append_pass(RETDEMOTION_NAME);
         pass_group.append_pass(MISCDEMOTION_NAME);
 
+        // The demoted arguments and return values are passed through memory, which changes the
+        // effects of the functions.
+        pass_group.append_pass(EFFECTS_NAME);
+
         // Convert loads and stores to mem_copys where possible.
         pass_group.append_pass(MEMCPYOPT_NAME);
 
@@ -639,6 +682,9 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     };
     res?;
 
+    // Constant arithmetic which overflows is only visible once the constants are propagated.
+    check_constant_overflows(handler, &ir, &mut metadata::MetadataManager::default());
+
     let final_asm = compile_ir_to_asm(handler, &ir, Some(build_config))?;
 
     Ok(final_asm)
@@ -936,3 +982,50 @@ fn test_unary_ordering() {
         panic!("Was not ast node")
     };
 }
+
+#[test]
+fn test_concurrent_compilations() {
+    // Each compilation owns its engines, so programs that declare types with the same names
+    // can be type checked on separate threads at the same time without seeing each other's types.
+    fn compile(source: &str) -> String {
+        let handler = Handler::default();
+        let engines = Engines::default();
+        let programs = compile_to_ast(
+            &handler,
+            &engines,
+            source.into(),
+            namespace::Module::default(),
+            None,
+            "test",
+            &mut PerformanceData::default(),
+        )
+        .unwrap();
+        assert!(!handler.has_errors());
+        match programs.typed.unwrap().kind {
+            ty::TyProgramKind::Script { main_function } => engines
+                .help_out(main_function.return_type.type_id)
+                .to_string(),
+            _ => unreachable!("the program is a script"),
+        }
+    }
+
+    let sources = [
+        (
+            "script; struct Point { x: u64 } fn main() -> u64 { let p = Point { x: 42 }; p.x }",
+            "u64",
+        ),
+        (
+            "script; struct Point { x: bool } fn main() -> bool { let p = Point { x: true }; p.x }",
+            "bool",
+        ),
+    ];
+    std::thread::scope(|scope| {
+        for (source, return_type) in sources {
+            scope.spawn(move || {
+                for _ in 0..10 {
+                    assert_eq!(compile(source), return_type);
+                }
+            });
+        }
+    });
+}
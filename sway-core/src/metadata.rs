@@ -1,10 +1,10 @@
 use crate::{
     decl_engine::DeclId,
-    language::{ty::TyFunctionDecl, Inline, Purity},
+    language::{ty::TyFunctionDecl, ExternAbi, Inline, Purity},
 };
 
 use sway_ir::{Context, MetadataIndex, Metadatum, Value};
-use sway_types::{SourceId, Span};
+use sway_types::{integer_bits::IntegerBits, SourceId, Span};
 
 use std::{collections::HashMap, path::PathBuf, rc::Rc, sync::Arc};
 
@@ -28,7 +28,10 @@ pub(crate) struct MetadataManager {
     span_md_cache: HashMap<Span, MetadataIndex>,
     file_loc_md_cache: HashMap<SourceId, MetadataIndex>,
     storage_op_md_cache: HashMap<Purity, MetadataIndex>,
+    inferred_storage_op_md: Option<MetadataIndex>,
     inline_md_cache: HashMap<Inline, MetadataIndex>,
+    extern_abi_md_cache: HashMap<ExternAbi, MetadataIndex>,
+    uint_width_md_cache: HashMap<IntegerBits, MetadataIndex>,
     test_decl_index_md_cache: HashMap<DeclId<TyFunctionDecl>, MetadataIndex>,
     config_const_name_md_cache: HashMap<Rc<str>, MetadataIndex>,
 }
@@ -38,6 +41,8 @@ pub(crate) enum StorageOperation {
     Reads,
     Writes,
     ReadsWrites,
+    /// The function has no storage attribute and its storage operations are inferred.
+    Inferred,
 }
 
 impl MetadataManager {
@@ -110,6 +115,7 @@ impl MetadataManager {
                                 "reads" => Some(StorageOperation::Reads),
                                 "writes" => Some(StorageOperation::Writes),
                                 "readswrites" => Some(StorageOperation::ReadsWrites),
+                                "inferred" => Some(StorageOperation::Inferred),
                                 _otherwise => None,
                             }?;
 
@@ -123,12 +129,10 @@ impl MetadataManager {
     }
 
     /// Gets Inline information from metadata index.
-    /// TODO: We temporarily allow this because we need this
-    /// in the sway-ir inliner, but cannot access it. So the code
+    /// TODO: The sway-ir inliner needs this as well, but cannot access it. So the code
     /// itself has been (modified and) copied there. When we decide
     /// on the right place for Metadata to be
     /// (and how it can be accessed form sway-ir), this will be fixed.
-    #[allow(dead_code)]
     pub(crate) fn md_to_inline(
         &mut self,
         context: &Context,
@@ -156,6 +160,21 @@ impl MetadataManager {
         })
     }
 
+    /// Gets the width in bits of the unsigned integers an arithmetic operation is done on, if
+    /// it is narrower than the 64 bits they are compiled to.
+    pub(crate) fn md_to_uint_width(
+        &mut self,
+        context: &Context,
+        md_idx: Option<MetadataIndex>,
+    ) -> Option<u64> {
+        Self::for_each_md_idx(context, md_idx, |md_idx| {
+            md_idx
+                .get_content(context)
+                .unwrap_struct("uint_width", 1)
+                .and_then(|fields| fields[0].unwrap_integer())
+        })
+    }
+
     fn md_to_file_location(
         &mut self,
         context: &Context,
@@ -284,6 +303,17 @@ impl MetadataManager {
         })
     }
 
+    /// Marks a function whose storage operations are inferred instead of declared by an attribute.
+    pub(crate) fn inferred_purity_to_md(&mut self, context: &mut Context) -> Option<MetadataIndex> {
+        Some(*self.inferred_storage_op_md.get_or_insert_with(|| {
+            MetadataIndex::new_struct(
+                context,
+                "storage",
+                vec![Metadatum::String("inferred".to_owned())],
+            )
+        }))
+    }
+
     /// Inserts Inline information into metadata.
     pub(crate) fn inline_to_md(
         &mut self,
@@ -313,6 +343,39 @@ impl MetadataManager {
         )
     }
 
+    /// Inserts the ABI the arguments of a function are passed with into metadata.
+    pub(crate) fn extern_abi_to_md(
+        &mut self,
+        context: &mut Context,
+        abi: ExternAbi,
+    ) -> Option<MetadataIndex> {
+        Some(*self.extern_abi_md_cache.entry(abi).or_insert_with(|| {
+            MetadataIndex::new_struct(
+                context,
+                "extern_abi",
+                vec![Metadatum::String(abi.name().to_owned())],
+            )
+        }))
+    }
+
+    /// Inserts the width of the `u8`, `u16` or `u32` operands of an arithmetic operation into
+    /// metadata.
+    pub(crate) fn uint_width_to_md(
+        &mut self,
+        context: &mut Context,
+        bits: IntegerBits,
+    ) -> Option<MetadataIndex> {
+        let width = match bits {
+            IntegerBits::Eight => 8,
+            IntegerBits::Sixteen => 16,
+            IntegerBits::ThirtyTwo => 32,
+            IntegerBits::SixtyFour | IntegerBits::V256 => return None,
+        };
+        Some(*self.uint_width_md_cache.entry(bits).or_insert_with(|| {
+            MetadataIndex::new_struct(context, "uint_width", vec![Metadatum::Integer(width)])
+        }))
+    }
+
     fn file_location_to_md(
         &mut self,
         context: &mut Context,
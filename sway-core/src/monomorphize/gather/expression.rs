@@ -82,6 +82,9 @@ pub(crate) fn gather_from_exp_inner(
         ty::TyExpressionVariant::EnumTag { exp } => {
             gather_from_exp(ctx.by_ref(), handler, exp)?;
         }
+        ty::TyExpressionVariant::ArrayToSlice { array } => {
+            gather_from_exp(ctx.by_ref(), handler, array)?;
+        }
         ty::TyExpressionVariant::UnsafeDowncast { .. } => todo!(),
         ty::TyExpressionVariant::WhileLoop { .. } => todo!(),
         ty::TyExpressionVariant::Reassignment(_) => todo!(),
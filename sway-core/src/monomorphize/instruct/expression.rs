@@ -82,6 +82,9 @@ pub(crate) fn instruct_exp_inner(
         ty::TyExpressionVariant::EnumTag { exp } => {
             instruct_exp(ctx.by_ref(), handler, exp)?;
         }
+        ty::TyExpressionVariant::ArrayToSlice { array } => {
+            instruct_exp(ctx.by_ref(), handler, array)?;
+        }
         ty::TyExpressionVariant::UnsafeDowncast { .. } => todo!(),
         ty::TyExpressionVariant::WhileLoop { .. } => todo!(),
         ty::TyExpressionVariant::Reassignment(_) => todo!(),
@@ -6,6 +6,7 @@ mod module;
 pub mod namespace;
 mod node_dependencies;
 mod program;
+pub(crate) mod state_access_analysis;
 mod type_check_context;
 pub use ast_node::*;
 pub use namespace::Namespace;
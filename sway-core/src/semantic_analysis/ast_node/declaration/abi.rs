@@ -9,7 +9,7 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use crate::{
     language::{
         parsed::*,
-        ty::{self, TyImplItem, TyTraitItem},
+        ty::{self, TyImplItem, TyTraitItem, TyTreeVisitor},
         CallPath,
     },
     semantic_analysis::declaration::SupertraitOf,
@@ -97,14 +97,6 @@ impl ty::TyAbiDecl {
                     // with the same name as the current interface method
                     error_on_shadowing_superabi_method(&method.name, &mut ctx);
                     let method = ty::TyTraitFn::type_check(handler, ctx.by_ref(), method)?;
-                    for param in &method.parameters {
-                        if param.is_reference || param.is_mutable {
-                            handler.emit_err(CompileError::RefMutableNotAllowedInContractAbi {
-                                param_name: param.name.clone(),
-                                span: param.name.span(),
-                            });
-                        }
-                    }
                     new_interface_surface.push(ty::TyTraitInterfaceItem::TraitFn(
                         ctx.engines.de().insert(method.clone()),
                     ));
@@ -132,11 +124,17 @@ impl ty::TyAbiDecl {
                 }
             };
 
-            if !ids.insert(decl_name.clone()) {
-                handler.emit_err(CompileError::MultipleDefinitionsOfName {
-                    name: decl_name.clone(),
-                    span: decl_name.span(),
-                });
+            match ids.get(&decl_name) {
+                Some(previous_name) => {
+                    handler.emit_err(CompileError::MultipleDefinitionsOfName {
+                        name: decl_name.clone(),
+                        previous_span: previous_name.span(),
+                        span: decl_name.span(),
+                    });
+                }
+                None => {
+                    ids.insert(decl_name.clone());
+                }
             }
         }
 
@@ -147,19 +145,17 @@ impl ty::TyAbiDecl {
                 ty::TyFunctionDecl::type_check(handler, ctx.by_ref(), method.clone(), false, false)
                     .unwrap_or_else(|_| ty::TyFunctionDecl::error(method.clone()));
             error_on_shadowing_superabi_method(&method.name, &mut ctx);
-            for param in &method.parameters {
-                if param.is_reference || param.is_mutable {
-                    handler.emit_err(CompileError::RefMutableNotAllowedInContractAbi {
-                        param_name: param.name.clone(),
-                        span: param.name.span(),
+            match ids.get(&method.name) {
+                Some(previous_name) => {
+                    handler.emit_err(CompileError::MultipleDefinitionsOfName {
+                        name: method.name.clone(),
+                        previous_span: previous_name.span(),
+                        span: method.name.span(),
                     });
                 }
-            }
-            if !ids.insert(method.name.clone()) {
-                handler.emit_err(CompileError::MultipleDefinitionsOfName {
-                    name: method.name.clone(),
-                    span: method.name.span(),
-                });
+                None => {
+                    ids.insert(method.name.clone());
+                }
             }
             new_items.push(TyTraitItem::Fn(ctx.engines.de().insert(method)));
         }
@@ -175,6 +171,7 @@ impl ty::TyAbiDecl {
             span,
             attributes,
         };
+        RefMutableAbiParameters { handler }.visit_abi_decl(ctx.engines.de(), &abi_decl);
         Ok(abi_decl)
     }
 
@@ -351,3 +348,21 @@ impl ty::TyAbiDecl {
         })
     }
 }
+
+/// Reports the `ref`, `mut` and `ref mut` parameters of the methods of an ABI, which are not
+/// allowed in contract ABIs.
+struct RefMutableAbiParameters<'a> {
+    handler: &'a Handler,
+}
+
+impl TyTreeVisitor for RefMutableAbiParameters<'_> {
+    fn visit_function_parameter(&mut self, param: &ty::TyFunctionParameter) {
+        if param.is_reference || param.is_mutable {
+            self.handler
+                .emit_err(CompileError::RefMutableNotAllowedInContractAbi {
+                    param_name: param.name.clone(),
+                    span: param.name.span(),
+                });
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use sway_error::{
     handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
     warning::{CompileWarning, Warning},
 };
 use sway_types::{style::is_screaming_snake_case, Spanned};
@@ -17,7 +18,7 @@ use crate::{
 impl ty::TyConstantDecl {
     pub fn type_check(
         handler: &Handler,
-        mut ctx: TypeCheckContext,
+        ctx: TypeCheckContext,
         decl: ConstantDeclaration,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -33,6 +34,8 @@ impl ty::TyConstantDecl {
             visibility,
         } = decl;
 
+        let mut ctx = ctx.with_deprecated_attributes(&attributes);
+
         type_ascription.type_id = ctx
             .resolve_type_with_self(
                 handler,
@@ -46,6 +49,9 @@ impl ty::TyConstantDecl {
         let mut ctx = ctx
             .by_ref()
             .with_type_annotation(type_ascription.type_id)
+            .with_expected_origin(ExpectedTypeOrigin::TypeAscription {
+                span: type_ascription.span.clone(),
+            })
             .with_help_text(
                 "This declaration's type annotation does not match up with the assigned \
             expression's type.",
@@ -1,4 +1,7 @@
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
+};
 use sway_types::{Named, Spanned};
 
 use crate::{
@@ -38,6 +41,9 @@ impl ty::TyDecl {
                     });
                 let mut ctx = ctx
                     .with_type_annotation(type_ascription.type_id)
+                    .with_expected_origin(ExpectedTypeOrigin::TypeAscription {
+                        span: type_ascription.span.clone(),
+                    })
                     .with_help_text(
                         "Variable declaration's type annotation does not match up \
                         with the assigned expression's type.",
@@ -295,7 +301,12 @@ impl ty::TyDecl {
                         None,
                     )?;
 
-                    let mut ctx = ctx.by_ref().with_type_annotation(type_argument.type_id);
+                    let mut ctx = ctx
+                        .by_ref()
+                        .with_type_annotation(type_argument.type_id)
+                        .with_expected_origin(ExpectedTypeOrigin::TypeAscription {
+                            span: type_argument.span.clone(),
+                        });
                     let initializer =
                         ty::TyExpression::type_check(handler, ctx.by_ref(), initializer)?;
 
@@ -327,8 +338,22 @@ impl ty::TyDecl {
                 let name = decl.name.clone();
                 let ty = decl.ty;
 
+                // create a namespace for the decl, used to create a scope for generics
+                let mut decl_namespace = ctx.namespace.clone();
+                let mut decl_ctx = ctx.by_ref().scoped(&mut decl_namespace);
+
+                // Type check the type parameters and insert them into the decl namespace.
+                let type_parameters = TypeParameter::type_check_type_params(
+                    handler,
+                    decl_ctx.by_ref(),
+                    decl.type_parameters,
+                )?;
+                for p in &type_parameters {
+                    p.insert_into_namespace(handler, decl_ctx.by_ref())?;
+                }
+
                 // Resolve the type that the type alias replaces
-                let new_ty = ctx
+                let new_ty = decl_ctx
                     .resolve_type_with_self(
                         handler,
                         ty.type_id,
@@ -344,6 +369,7 @@ impl ty::TyDecl {
                 let decl = ty::TyTypeAliasDecl {
                     name: name.clone(),
                     attributes: decl.attributes,
+                    type_parameters,
                     ty: TypeArgument {
                         initial_type_id: ty.initial_type_id,
                         type_id: new_ty,
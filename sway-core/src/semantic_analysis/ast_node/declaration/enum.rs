@@ -24,7 +24,9 @@ impl ty::TyEnumDecl {
 
         // create a namespace for the decl, used to create a scope for generics
         let mut decl_namespace = ctx.namespace.clone();
-        let mut ctx = ctx.scoped(&mut decl_namespace);
+        let mut ctx = ctx
+            .scoped(&mut decl_namespace)
+            .with_deprecated_attributes(&attributes);
 
         // Type check the type parameters.
         let new_type_parameters =
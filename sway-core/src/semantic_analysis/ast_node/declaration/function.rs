@@ -4,19 +4,28 @@ pub use function_parameter::*;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
     warning::{CompileWarning, Warning},
 };
 
 use crate::{
+    asm_generation::fuel::compiler_constants::NUM_ARG_REGISTERS,
     language::{
         parsed::*,
         ty::{self, TyCodeBlock},
-        Visibility,
+        ExternAbi, Literal, Purity, Visibility,
     },
-    semantic_analysis::*,
+    semantic_analysis::{node_dependencies::symbols_referred_to_in_block, *},
+    transform::{self, AttributeKind},
     type_system::*,
+    Engines,
+};
+use sway_types::{
+    constants::{DEFAULT_ENTRY_POINT_FN_NAME, INLINE_ALWAYS_NAME, INLINE_NEVER_NAME},
+    integer_bits::IntegerBits,
+    style::is_snake_case,
+    Ident, Span, Spanned,
 };
-use sway_types::{style::is_snake_case, Spanned};
 
 impl ty::TyFunctionDecl {
     pub fn type_check(
@@ -75,6 +84,16 @@ impl ty::TyFunctionDecl {
             })
         }
 
+        // Without a storage attribute, the storage access of free functions and of methods in
+        // `impl Self` blocks, other than entry points, is inferred from their bodies and checked
+        // transitively over the call graph once the program is lowered to IR. Trait and ABI
+        // methods have to declare it, because their callers rely on the interface.
+        let has_inferred_purity = !attributes.contains_key(&AttributeKind::Storage)
+            && !attributes.contains_key(&AttributeKind::Test)
+            && name.as_str() != DEFAULT_ENTRY_POINT_FN_NAME
+            && (!is_method || is_in_impl_self)
+            && matches!(ctx.abi_mode(), AbiMode::NonAbi);
+
         // create a namespace for the function
         let mut fn_namespace = ctx.namespace.clone();
         let mut ctx = ctx
@@ -82,6 +101,7 @@ impl ty::TyFunctionDecl {
             .scoped(&mut fn_namespace)
             .with_purity(purity)
             .with_const_shadowing_mode(ConstShadowingMode::Sequential)
+            .with_deprecated_attributes(&attributes)
             .disallow_functions();
 
         // Type check the type parameters. This will also insert them into the
@@ -96,18 +116,60 @@ impl ty::TyFunctionDecl {
 
         // type check the function parameters, which will also insert them into the namespace
         let mut new_parameters = vec![];
+        let parameter_names = parameters
+            .iter()
+            .map(|param| param.name.clone())
+            .collect::<Vec<_>>();
+        let signature_handler = handler;
         handler.scope(|handler| {
-            for parameter in parameters.into_iter() {
-                new_parameters.push({
-                    let param =
-                        match ty::TyFunctionParameter::type_check(handler, ctx.by_ref(), parameter)
-                        {
-                            Ok(val) => val,
-                            Err(_) => continue,
-                        };
-                    param.insert_into_namespace(handler, ctx.by_ref());
-                    param
-                });
+            for (idx, parameter) in parameters.into_iter().enumerate() {
+                if parameter.is_rest && is_method {
+                    handler.emit_err(CompileError::RestParameterNotAllowed {
+                        span: parameter.type_argument.span.clone(),
+                    });
+                    continue;
+                }
+                if parameter.is_optional && is_method {
+                    handler.emit_err(CompileError::OptionalParameterNotAllowed {
+                        span: Span::join(
+                            parameter.name.span(),
+                            parameter.type_argument.span.clone(),
+                        ),
+                    });
+                    continue;
+                }
+                let param_handler = Handler::default();
+                let param = ty::TyFunctionParameter::type_check(
+                    &param_handler,
+                    ctx.by_ref(),
+                    parameter,
+                    &parameter_names[idx..],
+                );
+                // A parameter whose type failed to resolve is kept, with the recovered type, and
+                // its errors do not abort the rest of the signature.
+                match &param {
+                    Ok(param) if param.resolution_failed => signature_handler.append(param_handler),
+                    _ => handler.append(param_handler),
+                }
+                let Ok(param) = param else {
+                    continue;
+                };
+                param.check_name_not_declared_in(handler, &new_parameters);
+                param.insert_into_namespace(handler, ctx.by_ref());
+                new_parameters.push(param);
+            }
+            // Only trailing arguments can be omitted, so optional parameters must come last.
+            if let Some(optional) = new_parameters.iter().find(|param| param.is_optional) {
+                if new_parameters
+                    .iter()
+                    .skip_while(|param| !param.is_optional)
+                    .any(|param| !param.is_optional)
+                {
+                    handler.emit_err(CompileError::OptionalParameterNotLast {
+                        name: optional.name.clone(),
+                        span: optional.name.span(),
+                    });
+                }
             }
             Ok(())
         })?;
@@ -133,6 +195,18 @@ impl ty::TyFunctionDecl {
             (visibility, matches!(ctx.abi_mode(), AbiMode::ImplAbiFn(..)))
         };
 
+        // Only the public functions of libraries can be called from other modules.
+        if !is_method && visibility.is_public() && matches!(ctx.kind(), TreeType::Library) {
+            check_parameter_types_are_public(handler, &ctx, &name, &new_parameters);
+        }
+
+        if attributes.contains_key(&AttributeKind::Test) {
+            check_test_function_signature(handler, &ctx, &name, &new_parameters, &return_type);
+        }
+
+        check_inline_attributes_do_not_conflict(handler, &name, &attributes);
+        check_extern_abi(handler, &ctx, &attributes, &new_parameters);
+
         let function_decl = ty::TyFunctionDecl {
             name,
             body: TyCodeBlock::default(),
@@ -145,6 +219,7 @@ impl ty::TyFunctionDecl {
             visibility,
             is_contract_call,
             purity,
+            has_inferred_purity,
             where_clause,
         };
 
@@ -157,16 +232,30 @@ impl ty::TyFunctionDecl {
         fn_decl: FunctionDeclaration,
         ty_fn_decl: &mut Self,
     ) -> Result<Self, ErrorEmitted> {
-        let FunctionDeclaration { body, .. } = fn_decl;
+        let FunctionDeclaration {
+            mut body,
+            parameters: parsed_parameters,
+            ..
+        } = fn_decl;
 
         let ty::TyFunctionDecl {
             parameters,
             purity,
+            has_inferred_purity,
             return_type,
             type_parameters,
+            attributes,
             ..
         } = ty_fn_decl;
 
+        // Calls from a function with inferred storage access are not restricted here. Their
+        // storage access is checked transitively in IR instead.
+        let purity = if *has_inferred_purity {
+            Purity::ReadsWrites
+        } else {
+            *purity
+        };
+
         let type_engine = ctx.engines.te();
         let engines = ctx.engines();
 
@@ -175,8 +264,9 @@ impl ty::TyFunctionDecl {
         let mut ctx = ctx
             .by_ref()
             .scoped(&mut fn_namespace)
-            .with_purity(*purity)
+            .with_purity(purity)
             .with_const_shadowing_mode(ConstShadowingMode::Sequential)
+            .with_deprecated_attributes(attributes)
             .disallow_functions();
 
         // Insert the previously type checked type parameters into the current namespace.
@@ -185,10 +275,71 @@ impl ty::TyFunctionDecl {
         }
 
         // Insert the previously type checked function parameters into the namespace.
-        for p in parameters {
+        for p in parameters.iter() {
             p.insert_into_namespace(handler, ctx.by_ref());
         }
 
+        // Bind the elements of the parameters that destructure arrays, like `[a, b]: [u64; 2]`,
+        // at the start of the body.
+        let array_pattern_bindings = parsed_parameters
+            .iter()
+            .filter_map(|parsed| {
+                let array_pattern = parsed.array_pattern.as_ref()?;
+                let param = parameters.iter().find(|p| p.name == parsed.name)?;
+                Some(param.array_pattern_bindings(engines, array_pattern))
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        body.contents.splice(0..0, array_pattern_bindings);
+
+        // Bind the fields of the struct parameters marked with `#[fields]`, like `fee` for
+        // `config.fee`, at the start of the body. Only the fields the body refers to are bound,
+        // but none of the fields may collide with the names already declared in the function.
+        let referred_symbols = symbols_referred_to_in_block(engines, &body);
+        let mut declared_names = parameters
+            .iter()
+            .filter(|p| !p.is_wildcard())
+            .map(|p| p.name.clone())
+            .chain(body.contents.iter().filter_map(|node| match &node.content {
+                AstNodeContent::Declaration(Declaration::VariableDeclaration(decl)) => {
+                    Some(decl.name.clone())
+                }
+                _ => None,
+            }))
+            .collect::<Vec<_>>();
+        let mut field_bindings = vec![];
+        for parsed in parsed_parameters.iter() {
+            let Some(attribute_span) = &parsed.bind_fields else {
+                continue;
+            };
+            let Some(param) = parameters.iter().find(|p| p.name == parsed.name) else {
+                continue;
+            };
+            let field_names = param.struct_field_names(engines);
+            for field_name in field_names.iter() {
+                if let Some(previous) = declared_names.iter().find(|name| *name == field_name) {
+                    handler.emit_err(CompileError::FieldBindingCollision {
+                        field_name: field_name.clone(),
+                        param_name: param.name.clone(),
+                        previous_span: previous.span(),
+                        span: attribute_span.clone(),
+                    });
+                }
+            }
+            let referred_field_names = field_names
+                .iter()
+                .filter(|field_name| referred_symbols.contains(*field_name))
+                .cloned()
+                .collect::<Vec<_>>();
+            field_bindings.extend(param.field_bindings(
+                engines,
+                attribute_span,
+                &referred_field_names,
+            ));
+            declared_names.extend(field_names);
+        }
+        body.contents.splice(0..0, field_bindings);
+
         // type check the function body
         //
         // If there are no implicit block returns, then we do not want to type check them, so we
@@ -196,9 +347,12 @@ impl ty::TyFunctionDecl {
         let (body, _implicit_block_return) = {
             let ctx = ctx
                 .by_ref()
-                .with_purity(*purity)
+                .with_purity(purity)
                 .with_help_text("Function body's return type does not match up with its return type annotation.")
-                .with_type_annotation(return_type.type_id);
+                .with_type_annotation(return_type.type_id)
+                .with_expected_origin(ExpectedTypeOrigin::ReturnType {
+                    span: return_type.span.clone(),
+                });
             ty::TyCodeBlock::type_check(handler, ctx, body).unwrap_or_else(|err| {
                 (
                     ty::TyCodeBlock { contents: vec![] },
@@ -214,12 +368,7 @@ impl ty::TyFunctionDecl {
             .flat_map(|node| node.gather_return_statements())
             .collect();
 
-        unify_return_statements(
-            handler,
-            ctx.by_ref(),
-            &return_statements,
-            return_type.type_id,
-        )?;
+        unify_return_statements(handler, ctx.by_ref(), &return_statements, return_type)?;
 
         return_type.type_id.check_type_parameter_bounds(
             handler,
@@ -228,6 +377,26 @@ impl ty::TyFunctionDecl {
             vec![],
         )?;
 
+        // A rest parameter gets its type when it is forwarded, so it must be forwarded.
+        for p in ty_fn_decl.parameters.iter().filter(|p| p.is_rest) {
+            if matches!(type_engine.get(p.type_argument.type_id), TypeInfo::Unknown) {
+                handler.emit_err(CompileError::RestParameterNotForwarded {
+                    name: p.name.clone(),
+                    span: p.type_argument.span.clone(),
+                });
+            }
+        }
+
+        // Numeric literals get their types from their uses in the body. Once the whole body is
+        // type checked, the unconstrained ones default to `u64`, and the values of the others
+        // must fit into the integer types they got.
+        let numeric_literals: Vec<&ty::TyExpression> = body
+            .contents
+            .iter()
+            .flat_map(|node| node.gather_numeric_literals())
+            .collect();
+        resolve_numeric_literals(handler, engines, &numeric_literals)?;
+
         ty_fn_decl.body = body;
         Ok(ty_fn_decl.clone())
     }
@@ -239,9 +408,12 @@ fn unify_return_statements(
     handler: &Handler,
     ctx: TypeCheckContext,
     return_statements: &[&ty::TyExpression],
-    return_type: TypeId,
+    return_type: &TypeArgument,
 ) -> Result<(), ErrorEmitted> {
     let type_engine = ctx.engines.te();
+    let expected_origin = ExpectedTypeOrigin::ReturnType {
+        span: return_type.span.clone(),
+    };
 
     handler.scope(|handler| {
         for stmt in return_statements.iter() {
@@ -249,10 +421,11 @@ fn unify_return_statements(
                 handler,
                 ctx.engines(),
                 stmt.return_type,
-                return_type,
+                return_type.type_id,
                 ctx.self_type(),
                 &stmt.span,
                 "Return statement must return the declared function return type.",
+                Some(&expected_origin),
                 None,
             );
         }
@@ -260,6 +433,237 @@ fn unify_return_statements(
     })
 }
 
+/// Defaults the types of the `numeric_literals` that are still [TypeInfo::Numeric] to `u64`, and
+/// reports the literals whose values do not fit into the integer types they were unified with.
+fn resolve_numeric_literals(
+    handler: &Handler,
+    engines: &Engines,
+    numeric_literals: &[&ty::TyExpression],
+) -> Result<(), ErrorEmitted> {
+    let type_engine = engines.te();
+    for literal in numeric_literals {
+        let ty::TyExpressionVariant::Literal(Literal::Numeric(value)) = &literal.expression else {
+            continue;
+        };
+        match type_engine.get(literal.return_type) {
+            TypeInfo::Numeric => {
+                type_engine.decay_numeric(handler, engines, literal.return_type, &literal.span)?
+            }
+            ty @ TypeInfo::UnsignedInteger(bits) if bits.would_overflow(*value) => {
+                handler.emit_err(CompileError::IntegerTooLarge {
+                    ty: engines.help_out(ty).to_string(),
+                    span: literal.span.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the types of the parameters of a public function do not refer to private structs
+/// or enums, which cannot be named by the callers in other modules.
+fn check_parameter_types_are_public(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    fn_name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+) {
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    let private_decl = |type_info: &TypeInfo| match type_info {
+        TypeInfo::Struct(decl_ref) => {
+            let struct_decl = decl_engine.get_struct(decl_ref);
+            struct_decl
+                .visibility
+                .is_private()
+                .then_some(struct_decl.call_path.suffix)
+        }
+        TypeInfo::Enum(decl_ref) => {
+            let enum_decl = decl_engine.get_enum(decl_ref);
+            enum_decl
+                .visibility
+                .is_private()
+                .then_some(enum_decl.call_path.suffix)
+        }
+        _ => None,
+    };
+
+    for param in parameters.iter().filter(|param| !param.resolution_failed) {
+        let mut private_decls = param
+            .type_argument
+            .type_id
+            .extract_any_including_self(
+                engines,
+                &|type_info| private_decl(type_info).is_some(),
+                vec![],
+            )
+            .into_keys()
+            .filter_map(|type_id| private_decl(&engines.te().get(type_id)))
+            .collect::<Vec<_>>();
+        // Sort so the errors are always reported in the same order.
+        private_decls.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        private_decls.dedup();
+        for decl_name in private_decls {
+            handler.emit_err(CompileError::PrivateTypeInPublicInterface {
+                fn_name: fn_name.clone(),
+                param_name: param.name.clone(),
+                type_name: decl_name.to_string(),
+                decl_span: decl_name.span(),
+                type_span: param.type_argument.span.clone(),
+            });
+        }
+    }
+}
+
+/// Checks that a test function can be run by the test harness, which calls it without arguments
+/// and only knows how to interpret a unit or a `Result` return value.
+fn check_test_function_signature(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    fn_name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) {
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    if !parameters.is_empty() {
+        handler.emit_err(CompileError::TestFunctionWithParameters {
+            fn_name: fn_name.clone(),
+            span: Span::join_all(
+                parameters
+                    .iter()
+                    .map(|param| Span::join(param.name.span(), param.type_argument.span.clone())),
+            ),
+        });
+    }
+
+    let is_valid_return_type = match engines.te().get_unaliased(return_type.type_id) {
+        TypeInfo::Tuple(fields) => fields.is_empty(),
+        TypeInfo::Enum(decl_ref) => decl_engine.get_enum(&decl_ref).is_result_like(),
+        TypeInfo::ErrorRecovery(_) => true,
+        _ => false,
+    };
+    if !is_valid_return_type {
+        handler.emit_err(CompileError::InvalidTestFunctionReturnType {
+            fn_name: fn_name.clone(),
+            ty: engines.help_out(return_type.type_id).to_string(),
+            span: return_type.span.clone(),
+        });
+    }
+}
+
+/// Checks that the `inline` attributes of the function `fn_name` do not ask for it to be both
+/// always and never inlined. Malformed `inline` attributes are already reported when the
+/// attributes are collected.
+fn check_inline_attributes_do_not_conflict(
+    handler: &Handler,
+    fn_name: &Ident,
+    attributes: &transform::AttributesMap,
+) {
+    let Some(inline_attributes) = attributes.get(&AttributeKind::Inline) else {
+        return;
+    };
+    let mut values = inline_attributes.iter().filter_map(|attribute| {
+        let value = attribute.args.first()?.name.as_str();
+        [INLINE_ALWAYS_NAME, INLINE_NEVER_NAME]
+            .contains(&value)
+            .then_some((value, attribute))
+    });
+    let Some((first_value, first)) = values.next() else {
+        return;
+    };
+    if let Some((value, conflicting)) = values.find(|(value, _)| *value != first_value) {
+        handler.emit_err(CompileError::ConflictingInlineAttributes {
+            fn_name: fn_name.clone(),
+            first_value: first_value.to_string(),
+            first_span: first.span.clone(),
+            value: value.to_string(),
+            span: conflicting.span.clone(),
+        });
+    }
+}
+
+/// Checks that the ABI named in the `extern_abi` attribute, if any, is known and can pass the
+/// `parameters`. No ABI can pass generic parameters, and the `register` ABI can only pass as many
+/// parameters as there are argument registers, each fitting in a register.
+fn check_extern_abi(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    attributes: &transform::AttributesMap,
+    parameters: &[ty::TyFunctionParameter],
+) {
+    let Some(abi_arg) = attributes
+        .get(&AttributeKind::ExternAbi)
+        .and_then(|attributes| attributes.last())
+        .and_then(|attribute| attribute.args.first())
+    else {
+        return;
+    };
+    let Some(abi) = ExternAbi::from_name(abi_arg.name.as_str()) else {
+        handler.emit_err(CompileError::UnknownExternAbi {
+            name: abi_arg.name.to_string(),
+            span: abi_arg.span(),
+        });
+        return;
+    };
+
+    let engines = ctx.engines();
+    for (idx, param) in parameters.iter().enumerate() {
+        if param.resolution_failed {
+            continue;
+        }
+        let type_id = param.type_argument.type_id;
+        let is_generic = !type_id
+            .extract_any_including_self(
+                engines,
+                &|type_info| {
+                    matches!(
+                        type_info,
+                        TypeInfo::UnknownGeneric { .. } | TypeInfo::Placeholder(_)
+                    )
+                },
+                vec![],
+            )
+            .is_empty();
+        let reason = if is_generic {
+            format!("its type \"{}\" is generic", engines.help_out(type_id))
+        } else if abi == ExternAbi::Register
+            && !param.is_reference
+            && !fits_in_register(engines, type_id)
+        {
+            format!(
+                "its type \"{}\" does not fit in a register",
+                engines.help_out(type_id)
+            )
+        } else if abi == ExternAbi::Register && idx >= NUM_ARG_REGISTERS as usize {
+            format!("only the first {NUM_ARG_REGISTERS} arguments are passed in registers")
+        } else {
+            continue;
+        };
+        handler.emit_err(CompileError::ExternAbiParameterNotSupported {
+            abi: abi.name().to_string(),
+            param_name: param.name.clone(),
+            reason,
+            span: param.type_argument.span.clone(),
+        });
+    }
+}
+
+/// Returns true if a value of the type `type_id` is passed in a single register, i.e., it is an
+/// integer of at most 64 bits, a `bool`, a pointer or the unit.
+fn fits_in_register(engines: &Engines, type_id: TypeId) -> bool {
+    match engines.te().get(type_id) {
+        TypeInfo::UnsignedInteger(bits) => !matches!(bits, IntegerBits::V256),
+        TypeInfo::Boolean | TypeInfo::RawUntypedPtr | TypeInfo::Ptr(_) => true,
+        TypeInfo::Tuple(fields) => fields.is_empty(),
+        TypeInfo::Alias { ty, .. } => fits_in_register(engines, ty.type_id),
+        _ => false,
+    }
+}
+
 #[test]
 fn test_function_selector_behavior() {
     use crate::language::Visibility;
@@ -280,6 +684,7 @@ fn test_function_selector_behavior() {
         type_parameters: vec![],
         visibility: Visibility::Public,
         is_contract_call: false,
+        has_inferred_purity: false,
         where_clause: vec![],
     };
 
@@ -300,6 +705,10 @@ fn test_function_selector_behavior() {
                 is_reference: false,
                 is_mutable: false,
                 mutability_span: Span::dummy(),
+                is_rest: false,
+                is_optional: false,
+                resolution_failed: false,
+                allow_unused: false,
                 type_argument: engines
                     .te()
                     .insert(&engines, TypeInfo::Str(Length::new(5, Span::dummy())))
@@ -310,6 +719,10 @@ fn test_function_selector_behavior() {
                 is_reference: false,
                 is_mutable: false,
                 mutability_span: Span::dummy(),
+                is_rest: false,
+                is_optional: false,
+                resolution_failed: false,
+                allow_unused: false,
                 type_argument: TypeArgument {
                     type_id: engines
                         .te()
@@ -328,6 +741,7 @@ fn test_function_selector_behavior() {
         type_parameters: vec![],
         visibility: Visibility::Public,
         is_contract_call: false,
+        has_inferred_purity: false,
         where_clause: vec![],
     };
 
@@ -1,20 +1,38 @@
 use crate::{
-    language::{parsed::FunctionParameter, ty},
-    semantic_analysis::TypeCheckContext,
+    engine_threading::PartialEqWithEngines,
+    language::{
+        parsed::{
+            ArrayExpression, ArrayIndexExpression, ArrayParameterPattern, AstNode, AstNodeContent,
+            Declaration, Expression, ExpressionKind, FunctionParameter, SubfieldExpression,
+            VariableDeclaration,
+        },
+        ty, CallPath, Literal,
+    },
+    semantic_analysis::{node_dependencies::symbols_referred_to_in_expr, TypeCheckContext},
+    transform,
     type_system::*,
+    Engines,
 };
 
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{
+    constants::{ALLOW_UNUSED_NAME, ALLOW_UNUSED_VARIABLES_NAME, VALID_LINT_NAMES},
+    style::find_similar_name,
+    Ident, Span, Spanned,
 };
-use sway_types::Spanned;
 
 impl ty::TyFunctionParameter {
+    /// Type checks the `parameter`. The `undeclared_parameters` are the names of the parameter
+    /// and of the ones after it, which its `typeof` type cannot refer to.
     pub(crate) fn type_check(
         handler: &Handler,
         mut ctx: TypeCheckContext,
         parameter: FunctionParameter,
+        undeclared_parameters: &[Ident],
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
         let engines = ctx.engines();
@@ -25,30 +43,106 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             mut type_argument,
+            is_rest,
+            is_optional,
+            array_pattern,
+            bind_fields,
+            type_of,
+            attributes,
         } = parameter;
 
-        type_argument.type_id = ctx
-            .resolve_type_with_self(
+        let allow_unused = check_allowed_lints(handler, &name, &attributes)?;
+
+        // The type of a rest parameter is unknown until it gets forwarded in the function body.
+        if is_rest {
+            return Ok(ty::TyFunctionParameter {
+                name,
+                is_reference,
+                is_mutable,
+                mutability_span,
+                type_argument,
+                is_rest,
+                is_optional,
+                resolution_failed: false,
+                allow_unused,
+            });
+        }
+
+        let self_wraps_self = check_self_parameter_type(handler, engines, &name, &type_argument)?;
+
+        if let Some(expr) = type_of {
+            let type_id =
+                type_check_typeof(handler, ctx.by_ref(), &name, expr, undeclared_parameters)?;
+            type_argument.type_id = type_id;
+            type_argument.initial_type_id = type_id;
+        }
+
+        if is_optional {
+            if is_reference && is_mutable {
+                return Err(handler.emit_err(CompileError::RefMutableOptionalParameter {
+                    name: name.clone(),
+                    span: mutability_span,
+                }));
+            }
+            type_argument = wrap_in_option(engines, type_argument);
+        }
+
+        let (type_id, resolution_failed) =
+            resolve_parameter_type(handler, &mut ctx, &name, &type_argument);
+        type_argument.type_id = type_id;
+
+        if self_wraps_self && !resolution_failed {
+            check_self_wrapper_is_struct(handler, engines, &type_argument)?;
+        }
+
+        // The type of a parameter which failed to resolve is not checked any further, as that
+        // would only report the same problem again.
+        if !resolution_failed {
+            type_argument.type_id.check_type_parameter_bounds(
                 handler,
-                type_argument.type_id,
+                &ctx,
                 &type_argument.span,
-                EnforceTypeArguments::Yes,
-                None,
-            )
-            .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err)));
+                vec![],
+            )?;
 
-        type_argument.type_id.check_type_parameter_bounds(
-            handler,
-            &ctx,
-            &type_argument.span,
-            vec![],
-        )?;
+            if let Some(array_pattern) = &array_pattern {
+                check_array_pattern(handler, &ctx, array_pattern, &type_argument)?;
+            }
+
+            if let Some(attribute_span) = &bind_fields {
+                check_fields_attribute(handler, &ctx, &name, attribute_span, &type_argument)?;
+            }
+        }
+
+        // Only an explicit `()` annotation resolves to the unit type here. Generic parameters stay
+        // generic in the signature, even if they later get monomorphized to `()`.
+        let is_unit = matches!(
+            type_engine.get(type_argument.type_id),
+            TypeInfo::Tuple(fields) if fields.is_empty()
+        );
+        if is_unit {
+            handler.emit_warn(CompileWarning {
+                span: type_argument.span.clone(),
+                warning_content: Warning::UnitParameter { name: name.clone() },
+            });
+        }
+
+        // Checked here rather than when inserting the parameter into the namespace, which happens
+        // once for the signature and once more for the body.
+        if shadows_std_prelude_item(&ctx, &name) {
+            handler.emit_warn(CompileWarning {
+                span: name.span(),
+                warning_content: Warning::ParameterShadowsPreludeItem { name: name.clone() },
+            });
+        }
 
         let mutability = ty::VariableMutability::new_from_ref_mut(is_reference, is_mutable);
         if mutability == ty::VariableMutability::Mutable {
             return Err(
                 handler.emit_err(CompileError::MutableParameterNotSupported {
                     param_name: name.clone(),
+                    type_span: type_argument.span.clone(),
+                    mutability_span: mutability_span.clone(),
                     span: name.span(),
                 }),
             );
@@ -60,6 +154,10 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            is_rest,
+            is_optional,
+            resolution_failed,
+            allow_unused,
         };
 
         Ok(typed_parameter)
@@ -70,7 +168,6 @@ impl ty::TyFunctionParameter {
         mut ctx: TypeCheckContext,
         parameter: FunctionParameter,
     ) -> Result<Self, ErrorEmitted> {
-        let type_engine = ctx.engines.te();
         let engines = ctx.engines();
 
         let FunctionParameter {
@@ -79,17 +176,48 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             mut type_argument,
+            is_rest,
+            is_optional,
+            array_pattern,
+            bind_fields: _,
+            type_of,
+            attributes,
         } = parameter;
 
-        type_argument.type_id = ctx
-            .resolve_type_with_self(
-                handler,
-                type_argument.type_id,
-                &type_argument.span,
-                EnforceTypeArguments::Yes,
-                None,
-            )
-            .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err)));
+        let allow_unused = check_allowed_lints(handler, &name, &attributes)?;
+
+        if is_rest {
+            return Err(handler.emit_err(CompileError::RestParameterNotAllowed {
+                span: type_argument.span,
+            }));
+        }
+        if is_optional {
+            return Err(handler.emit_err(CompileError::OptionalParameterNotAllowed {
+                span: Span::join(name.span(), type_argument.span),
+            }));
+        }
+        if let Some(array_pattern) = array_pattern {
+            return Err(
+                handler.emit_err(CompileError::ArrayPatternParameterNotAllowed {
+                    span: array_pattern.span,
+                }),
+            );
+        }
+        if type_of.is_some() {
+            return Err(handler.emit_err(CompileError::TypeofParameterNotAllowed {
+                span: type_argument.span,
+            }));
+        }
+
+        let self_wraps_self = check_self_parameter_type(handler, engines, &name, &type_argument)?;
+
+        let (type_id, resolution_failed) =
+            resolve_parameter_type(handler, &mut ctx, &name, &type_argument);
+        type_argument.type_id = type_id;
+
+        if self_wraps_self && !resolution_failed {
+            check_self_wrapper_is_struct(handler, engines, &type_argument)?;
+        }
 
         let typed_parameter = ty::TyFunctionParameter {
             name,
@@ -97,12 +225,186 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            is_rest,
+            is_optional,
+            resolution_failed,
+            allow_unused,
         };
 
         Ok(typed_parameter)
     }
 
+    /// Reports an error if a parameter with the same name is already among the
+    /// `previous_parameters` of the function.
+    pub(crate) fn check_name_not_declared_in(
+        &self,
+        handler: &Handler,
+        previous_parameters: &[ty::TyFunctionParameter],
+    ) {
+        if self.is_wildcard() {
+            return;
+        }
+        if let Some(previous) = previous_parameters
+            .iter()
+            .find(|previous| previous.name == self.name)
+        {
+            handler.emit_err(CompileError::DuplicateParameterName {
+                param_name: self.name.clone(),
+                previous_span: previous.name.span(),
+                type_span: self.type_argument.span.clone(),
+                span: self.name.span(),
+            });
+        }
+    }
+
+    /// Returns the declarations that bind the elements of this parameter, which is an array
+    /// destructured by the `array_pattern`, to be placed at the start of the function body.
+    pub(crate) fn array_pattern_bindings(
+        &self,
+        engines: &Engines,
+        array_pattern: &ArrayParameterPattern,
+    ) -> Vec<AstNode> {
+        let type_engine = engines.te();
+        let span = array_pattern.span.clone();
+        let expression = |kind| Expression {
+            kind,
+            span: span.clone(),
+        };
+        let element = |index: usize| {
+            expression(ExpressionKind::ArrayIndex(ArrayIndexExpression {
+                prefix: Box::new(expression(ExpressionKind::Variable(self.name.clone()))),
+                index: Box::new(expression(ExpressionKind::Literal(Literal::U64(
+                    index as u64,
+                )))),
+            }))
+        };
+        let type_argument = |type_id| TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span: span.clone(),
+            call_path_tree: None,
+        };
+
+        let mut bindings = vec![];
+        match type_engine.get(self.type_argument.type_id) {
+            TypeInfo::Array(elem_type, length) => {
+                bindings.extend(array_pattern.elements.iter().enumerate().filter_map(
+                    |(index, name)| {
+                        let name = name.clone()?;
+                        let type_id = type_engine.insert(engines, TypeInfo::Unknown);
+                        Some((name, type_argument(type_id), element(index)))
+                    },
+                ));
+
+                // The rest is bound to an array of the remaining elements.
+                if let Some(Some(name)) = &array_pattern.rest {
+                    let start = array_pattern.elements.len();
+                    let type_id = type_engine.insert(
+                        engines,
+                        TypeInfo::Array(elem_type, Length::new(length.val() - start, span.clone())),
+                    );
+                    let body = expression(ExpressionKind::Array(ArrayExpression {
+                        contents: (start..length.val()).map(element).collect(),
+                        length_span: None,
+                        length_constant: None,
+                    }));
+                    bindings.push((name.clone(), type_argument(type_id), body));
+                }
+            }
+            // The names bound by the pattern of a parameter whose type failed to resolve refer to
+            // the parameter itself, so that their uses do not report unknown variables.
+            _ if self.resolution_failed => {
+                bindings.extend(array_pattern.bound_names().into_iter().map(|name| {
+                    let type_id = type_engine.insert(engines, TypeInfo::Unknown);
+                    let body = expression(ExpressionKind::Variable(self.name.clone()));
+                    (name, type_argument(type_id), body)
+                }));
+            }
+            _ => (),
+        }
+
+        bindings
+            .into_iter()
+            .map(|(name, type_ascription, body)| AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        body,
+                        is_mutable: false,
+                    },
+                )),
+                span: span.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the names of the fields of this parameter, if it is of a struct type.
+    pub(crate) fn struct_field_names(&self, engines: &Engines) -> Vec<Ident> {
+        match engines.te().get(self.type_argument.type_id) {
+            TypeInfo::Struct(decl_ref) => engines
+                .de()
+                .get_struct(&decl_ref)
+                .fields
+                .into_iter()
+                .map(|field| field.name)
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the declarations that bind the `field_names` of this parameter, which is marked
+    /// with the `#[fields]` attribute at `attribute_span`, to be placed at the start of the
+    /// function body.
+    pub(crate) fn field_bindings(
+        &self,
+        engines: &Engines,
+        attribute_span: &Span,
+        field_names: &[Ident],
+    ) -> Vec<AstNode> {
+        let type_engine = engines.te();
+        let span = attribute_span.clone();
+        let expression = |kind| Expression {
+            kind,
+            span: span.clone(),
+        };
+
+        field_names
+            .iter()
+            .map(|field_name| {
+                let type_id = type_engine.insert(engines, TypeInfo::Unknown);
+                let body = expression(ExpressionKind::Subfield(SubfieldExpression {
+                    prefix: Box::new(expression(ExpressionKind::Variable(self.name.clone()))),
+                    field_to_access: field_name.clone(),
+                }));
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        VariableDeclaration {
+                            name: Ident::new_with_override(
+                                field_name.as_str().to_string(),
+                                span.clone(),
+                            ),
+                            type_ascription: TypeArgument {
+                                type_id,
+                                initial_type_id: type_id,
+                                span: span.clone(),
+                                call_path_tree: None,
+                            },
+                            body,
+                            is_mutable: false,
+                        },
+                    )),
+                    span: span.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Inserts the parameter into the namespace, unless it is a `_` parameter.
     pub fn insert_into_namespace(&self, handler: &Handler, ctx: TypeCheckContext) {
+        if self.is_wildcard() {
+            return;
+        }
         let const_shadowing_mode = ctx.const_shadowing_mode();
         let _ = ctx.namespace.insert_symbol(
             handler,
@@ -112,7 +414,12 @@ impl ty::TyFunctionParameter {
                 body: ty::TyExpression {
                     expression: ty::TyExpressionVariant::FunctionParameter,
                     return_type: self.type_argument.type_id,
-                    span: self.name.span(),
+                    // The declaration of the parameter, including the `ref` keyword, if any.
+                    span: if self.is_reference {
+                        Span::join(self.mutability_span.clone(), self.name.span())
+                    } else {
+                        self.name.span()
+                    },
                 },
                 mutability: ty::VariableMutability::new_from_ref_mut(
                     self.is_reference,
@@ -125,3 +432,322 @@ impl ty::TyFunctionParameter {
         );
     }
 }
+
+/// Checks that the parameter destructured by the `array_pattern` is an array with as many
+/// elements as the pattern binds.
+fn check_array_pattern(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    array_pattern: &ArrayParameterPattern,
+    type_argument: &TypeArgument,
+) -> Result<(), ErrorEmitted> {
+    let engines = ctx.engines();
+    let array_len = match engines.te().get(type_argument.type_id) {
+        TypeInfo::Array(_, length) => length.val(),
+        TypeInfo::ErrorRecovery(err) => return Err(err),
+        _ => {
+            return Err(
+                handler.emit_err(CompileError::ArrayPatternParameterNotArray {
+                    ty: type_argument.type_id.to_diagnostic_string_in_scope(
+                        engines,
+                        None,
+                        ctx.namespace,
+                    ),
+                    type_span: type_argument.span.clone(),
+                    span: array_pattern.span.clone(),
+                }),
+            )
+        }
+    };
+
+    let pattern_len = array_pattern.elements.len();
+    let has_rest = array_pattern.rest.is_some();
+    let matches = if has_rest {
+        pattern_len <= array_len
+    } else {
+        pattern_len == array_len
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(handler.emit_err(CompileError::ArrayPatternArityMismatch {
+            pattern_len,
+            has_rest,
+            array_len,
+            type_span: type_argument.span.clone(),
+            span: array_pattern.span.clone(),
+        }))
+    }
+}
+
+/// Checks that the parameter `name`, marked with the `#[fields]` attribute at `attribute_span`,
+/// is of a struct type.
+fn check_fields_attribute(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    name: &Ident,
+    attribute_span: &Span,
+    type_argument: &TypeArgument,
+) -> Result<(), ErrorEmitted> {
+    let engines = ctx.engines();
+    match engines.te().get(type_argument.type_id) {
+        TypeInfo::Struct(_) => Ok(()),
+        TypeInfo::ErrorRecovery(err) => Err(err),
+        _ => Err(
+            handler.emit_err(CompileError::FieldsAttributeOnNonStructParameter {
+                param_name: name.clone(),
+                ty: type_argument.type_id.to_diagnostic_string_in_scope(
+                    engines,
+                    None,
+                    ctx.namespace,
+                ),
+                type_span: type_argument.span.clone(),
+                span: attribute_span.clone(),
+            }),
+        ),
+    }
+}
+
+/// Checks that the lints given to the `allow` attributes of the parameter `name` are known.
+/// Returns whether the unused parameter warning is allowed by them.
+fn check_allowed_lints(
+    handler: &Handler,
+    name: &Ident,
+    attributes: &transform::AttributesMap,
+) -> Result<bool, ErrorEmitted> {
+    let mut allow_unused = false;
+    let lints = attributes
+        .get(&transform::AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| attr.args.iter());
+    for lint in lints {
+        match lint.name.as_str() {
+            ALLOW_UNUSED_VARIABLES_NAME | ALLOW_UNUSED_NAME => allow_unused = true,
+            lint_name if VALID_LINT_NAMES.contains(&lint_name) => (),
+            lint_name => {
+                return Err(handler.emit_err(CompileError::UnknownLintOnParameter {
+                    lint: lint_name.to_string(),
+                    param_name: name.clone(),
+                    span: lint.name.span(),
+                }))
+            }
+        }
+    }
+    Ok(allow_unused)
+}
+
+/// Returns the type of the `expr` of parameter `param_name`, typed by `typeof(expr)`. The
+/// expression is only type checked, and never evaluated.
+fn type_check_typeof(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    param_name: &Ident,
+    expr: Expression,
+    undeclared_parameters: &[Ident],
+) -> Result<TypeId, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let referred_symbols = symbols_referred_to_in_expr(engines, &expr);
+    let expr_handler = Handler::default();
+    let typed_expr = ty::TyExpression::type_check(
+        &expr_handler,
+        ctx.by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown)),
+        expr,
+    );
+
+    // A parameter which is not declared yet is not in the namespace, and would otherwise be
+    // reported as an unknown variable.
+    if expr_handler.has_errors() {
+        if let Some(reference) = undeclared_parameters
+            .iter()
+            .find_map(|name| referred_symbols.get(name))
+        {
+            return Err(
+                handler.emit_err(CompileError::TypeofRefersToUndeclaredParameter {
+                    name: reference.clone(),
+                    param_name: param_name.clone(),
+                    span: reference.span(),
+                }),
+            );
+        }
+    }
+    handler.append(expr_handler);
+
+    Ok(typed_expr?.return_type)
+}
+
+/// Returns `true` if the parameter `name` shadows an item of the std prelude that is in scope.
+/// The std prelude is only imported if the package depends on `std`, so nothing is shadowed if,
+/// e.g., `implicit-std` is `false`.
+fn shadows_std_prelude_item(ctx: &TypeCheckContext, name: &Ident) -> bool {
+    let prelude_path = ["std", "prelude"].map(|s| Ident::new_no_span(s.into()));
+    let prelude = match ctx.namespace.root().submodule(&prelude_path) {
+        Some(prelude) => prelude,
+        None => return false,
+    };
+    let prelude_decl = match prelude
+        .use_synonyms
+        .get(name)
+        .map(|(_, _, decl, _)| decl)
+        .or_else(|| prelude.symbols.get(name))
+    {
+        Some(decl) => decl,
+        None => return false,
+    };
+
+    // An item declared or imported under the same name takes precedence over the prelude one.
+    let module = ctx.namespace.module();
+    !module.symbols.contains_key(name)
+        && module
+            .use_synonyms
+            .get(name)
+            .map_or(false, |(_, _, decl, _)| {
+                decl.eq(prelude_decl, ctx.engines())
+            })
+}
+
+/// Wraps the type of an optional parameter, like `x?: u64`, into `Option`, like in
+/// `x: Option<u64>`. The path to `Option` is absolute, so that a user type named `Option` does
+/// not shadow it.
+fn wrap_in_option(engines: &Engines, type_argument: TypeArgument) -> TypeArgument {
+    let span = type_argument.span.clone();
+    let type_id = engines.te().insert(
+        engines,
+        TypeInfo::Custom {
+            call_path: CallPath {
+                prefixes: vec![
+                    Ident::new_with_override("std".into(), span.clone()),
+                    Ident::new_with_override("option".into(), span.clone()),
+                ],
+                suffix: Ident::new_with_override("Option".into(), span.clone()),
+                is_absolute: true,
+            },
+            type_arguments: Some(vec![type_argument]),
+        },
+    );
+    TypeArgument {
+        type_id,
+        initial_type_id: type_id,
+        span,
+        call_path_tree: None,
+    }
+}
+
+/// Resolves the type of the parameter `name`, reporting unknown type names as errors of the
+/// parameter. Returns the resolved type, or the one recovered from the errors, and whether there
+/// were any errors.
+fn resolve_parameter_type(
+    handler: &Handler,
+    ctx: &mut TypeCheckContext,
+    name: &Ident,
+    type_argument: &TypeArgument,
+) -> (TypeId, bool) {
+    let engines = ctx.engines();
+    let type_engine = engines.te();
+
+    let resolve_handler = Handler::default();
+    let type_id = ctx
+        .resolve_type_with_self(
+            &resolve_handler,
+            type_argument.type_id,
+            &type_argument.span,
+            EnforceTypeArguments::Yes,
+            None,
+        )
+        .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err)));
+
+    let (errors, warnings) = resolve_handler.consume();
+    let resolution_failed = !errors.is_empty();
+    // Looking up an unknown type name also reports it as a symbol which is not found. Only the
+    // unknown type is reported.
+    let unknown_type_names = errors
+        .iter()
+        .filter_map(|err| match err {
+            CompileError::UnknownTypeName { name, .. } => name.rsplit("::").next(),
+            _ => None,
+        })
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    for err in errors {
+        if let CompileError::SymbolNotFound { name, .. } = &err {
+            if unknown_type_names.iter().any(|ty| ty == name.as_str()) {
+                continue;
+            }
+        }
+        handler.emit_err(match err {
+            CompileError::UnknownTypeName { name: ty, span } => {
+                // Paths are not looked up, only names of types in scope are suggested.
+                let suggestion = if ty.contains("::") {
+                    None
+                } else {
+                    find_similar_name(&ty, ctx.namespace.module().get_all_type_names())
+                        .map(str::to_string)
+                };
+                CompileError::UnknownParameterType {
+                    param_name: name.clone(),
+                    ty,
+                    suggestion,
+                    type_span: type_argument.span.clone(),
+                    span,
+                }
+            }
+            err => err,
+        });
+    }
+    for warn in warnings {
+        handler.emit_warn(warn);
+    }
+
+    (type_id, resolution_failed)
+}
+
+/// Checks that an explicitly given type of the `self` parameter, like in `self: Ptr<Self>`, is
+/// either `Self` or wraps `Self`. This has to happen before the type is resolved, because `Self`
+/// is replaced by the type it stands for during the resolution.
+///
+/// Returns true if the type is a user type wrapping `Self`, which must then resolve to a struct,
+/// as checked by [check_self_wrapper_is_struct].
+fn check_self_parameter_type(
+    handler: &Handler,
+    engines: &Engines,
+    name: &Ident,
+    type_argument: &TypeArgument,
+) -> Result<bool, ErrorEmitted> {
+    if name.as_str() != "self" {
+        return Ok(false);
+    }
+
+    let type_engine = engines.te();
+    let is_self_type = |type_id| matches!(type_engine.get(type_id), TypeInfo::SelfType);
+    match type_engine.get(type_argument.type_id) {
+        TypeInfo::SelfType => Ok(false),
+        TypeInfo::Ptr(pointee) if is_self_type(pointee.type_id) => Ok(false),
+        TypeInfo::Custom {
+            type_arguments: Some(type_arguments),
+            ..
+        } if type_arguments.len() == 1 && is_self_type(type_arguments[0].type_id) => Ok(true),
+        _ => Err(handler.emit_err(CompileError::InvalidSelfParameterType {
+            span: type_argument.span.clone(),
+        })),
+    }
+}
+
+/// Checks that the resolved type of the `self` parameter wrapping `Self`, like `Ptr<Self>`, is a
+/// struct pointing to `Self`. An enum, like `Option<Self>`, may hold no `Self` at all, so it
+/// cannot be a receiver.
+fn check_self_wrapper_is_struct(
+    handler: &Handler,
+    engines: &Engines,
+    type_argument: &TypeArgument,
+) -> Result<(), ErrorEmitted> {
+    match engines.te().get(type_argument.type_id) {
+        TypeInfo::Struct(_) | TypeInfo::ErrorRecovery(_) => Ok(()),
+        _ => Err(handler.emit_err(CompileError::InvalidSelfParameterType {
+            span: type_argument.span.clone(),
+        })),
+    }
+}
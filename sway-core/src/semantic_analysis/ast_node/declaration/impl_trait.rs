@@ -1,7 +1,7 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 use sway_error::{
-    error::{CompileError, InterfaceName},
+    error::{CompileError, InterfaceName, SignatureDifference, SignatureMismatch},
     handler::{ErrorEmitted, Handler},
 };
 use sway_types::{Ident, Span, Spanned};
@@ -16,6 +16,7 @@ use crate::{
     },
     semantic_analysis::{AbiMode, ConstShadowingMode, TypeCheckContext},
     type_system::*,
+    Namespace,
 };
 
 impl ty::TyImplTrait {
@@ -466,6 +467,19 @@ fn type_check_trait_implementation(
         }
     }
 
+    // The type parameters of the trait declaration mapped to the type arguments
+    // of the trait name in the current impl block.
+    let type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
+        trait_type_parameters
+            .iter()
+            .map(|type_param| type_param.type_id)
+            .collect(),
+        trait_type_arguments
+            .iter()
+            .map(|type_arg| type_arg.type_id)
+            .collect(),
+    );
+
     for item in impl_items {
         match item {
             ImplItem::Fn(impl_method) => {
@@ -478,6 +492,7 @@ fn type_check_trait_implementation(
                     is_contract,
                     &impld_item_refs,
                     &method_checklist,
+                    &type_mapping,
                 )
                 .unwrap_or_else(|_| ty::TyFunctionDecl::error(impl_method.clone()));
 
@@ -520,16 +535,6 @@ fn type_check_trait_implementation(
     // the trait name in the current impl block that we are type checking and
     // using the stub decl ids from the interface surface and the new
     // decl ids from the newly implemented methods.
-    let type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
-        trait_type_parameters
-            .iter()
-            .map(|type_param| type_param.type_id)
-            .collect(),
-        trait_type_arguments
-            .iter()
-            .map(|type_arg| type_arg.type_id)
-            .collect(),
-    );
     interface_item_refs.extend(supertrait_interface_item_refs);
     impld_item_refs.extend(supertrait_impld_item_refs);
     let decl_mapping = DeclMapping::from_interface_and_item_and_impld_decl_refs(
@@ -598,6 +603,7 @@ fn type_check_impl_method(
     is_contract: bool,
     impld_item_refs: &ItemMap,
     method_checklist: &BTreeMap<Ident, ty::TyTraitFn>,
+    type_mapping: &TypeSubstMap,
 ) -> Result<ty::TyFunctionDecl, ErrorEmitted> {
     let type_engine = ctx.engines.te();
     let engines = ctx.engines();
@@ -621,10 +627,13 @@ fn type_check_impl_method(
         ty::TyFunctionDecl::type_check(handler, ctx.by_ref(), impl_method.clone(), true, false)?;
 
     // Ensure that there aren't multiple definitions of this function impl'd
-    if impld_item_refs.contains_key(&(impl_method.name.clone(), self_type)) {
+    if let Some(((previous_name, _), _)) =
+        impld_item_refs.get_key_value(&(impl_method.name.clone(), self_type))
+    {
         return Err(
             handler.emit_err(CompileError::MultipleDefinitionsOfFunction {
                 name: impl_method.name.clone(),
+                previous_span: previous_name.span(),
                 span: impl_method.name.span(),
             }),
         );
@@ -649,18 +658,55 @@ fn type_check_impl_method(
     // declaration
     impl_method_signature.replace_self_type(engines, self_type);
 
-    // ensure this fn decl's parameters and signature lines up with the one
-    // in the trait
-    if impl_method.parameters.len() != impl_method_signature.parameters.len() {
-        return Err(handler.emit_err(
-            CompileError::IncorrectNumberOfInterfaceSurfaceFunctionParameters {
-                span: impl_method.parameters_span(),
+    // parameters are matched by their position, but if the implementation declares the same
+    // parameters in a different order, point that out instead of reporting each mismatched type
+    if impl_method.parameters.len() == impl_method_signature.parameters.len()
+        && are_parameters_reordered(
+            engines,
+            &impl_method_signature.parameters,
+            &impl_method.parameters,
+        )
+    {
+        return Err(
+            handler.emit_err(CompileError::InterfaceParametersReordered {
                 fn_name: impl_method.name.clone(),
                 interface_name: interface_name(),
-                num_parameters: impl_method_signature.parameters.len(),
-                provided_parameters: impl_method.parameters.len(),
-            },
-        ));
+                interface_parameters_span: impl_method_signature.parameters_span(),
+                span: impl_method.parameters_span(),
+            }),
+        );
+    }
+
+    // ensure this fn decl's parameters and signature lines up with the one
+    // in the trait
+    if let Some((mismatch, span)) =
+        find_signature_mismatch(engines, &impl_method_signature, &impl_method)
+    {
+        return Err(handler.emit_err(CompileError::TraitImplSignatureMismatch {
+            fn_name: impl_method.name.clone(),
+            interface_name: interface_name(),
+            difference: Box::new(SignatureDifference {
+                expected_signature: signature_to_string(
+                    engines,
+                    Some(type_mapping),
+                    ctx.namespace,
+                    &impl_method_signature.name,
+                    &impl_method_signature.parameters,
+                    &impl_method_signature.return_type,
+                ),
+                provided_signature: signature_to_string(
+                    engines,
+                    None,
+                    ctx.namespace,
+                    &impl_method.name,
+                    &impl_method.parameters,
+                    &impl_method.return_type,
+                ),
+                mismatch,
+            }),
+            interface_fn_span: impl_method_signature.span.clone(),
+            span,
+        }));
     }
 
     handler.scope(|handler| {
@@ -676,36 +722,34 @@ fn type_check_impl_method(
             // Check if we have a non-ref mutable argument. That's not allowed.
             if impl_method_signature_param.is_mutable && !impl_method_signature_param.is_reference {
                 handler.emit_err(CompileError::MutableParameterNotSupported {
-                    param_name: impl_method_signature.name.clone(),
+                    param_name: impl_method_signature_param.name.clone(),
+                    type_span: impl_method_signature_param.type_argument.span.clone(),
+                    mutability_span: impl_method_signature_param.mutability_span.clone(),
                     span: impl_method_signature.name.span(),
                 });
             }
 
             // check if reference / mutability of the parameters is incompatible
-            if impl_method_param.is_mutable != impl_method_signature_param.is_mutable
-                || impl_method_param.is_reference != impl_method_signature_param.is_reference
-            {
+            if impl_method_param.is_reference != impl_method_signature_param.is_reference {
                 handler.emit_err(CompileError::ParameterRefMutabilityMismatch {
-                    span: impl_method_param.mutability_span.clone(),
+                    // a parameter without `ref` or `mut` has no mutability span to point at
+                    span: if impl_method_param.is_reference || impl_method_param.is_mutable {
+                        impl_method_param.mutability_span.clone()
+                    } else {
+                        impl_method_param.name.span()
+                    },
                 });
-            }
-
-            if !type_engine.get(impl_method_param.type_argument.type_id).eq(
-                &type_engine.get(impl_method_signature_param.type_argument.type_id),
-                engines,
-            ) {
-                handler.emit_err(CompileError::MismatchedTypeInInterfaceSurface {
+            } else if impl_method_param.is_mutable != impl_method_signature_param.is_mutable {
+                handler.emit_err(CompileError::ParameterMutabilityMismatch {
                     interface_name: interface_name(),
-                    span: impl_method_param.type_argument.span.clone(),
-                    decl_type: "function".to_string(),
-                    given: engines
-                        .help_out(impl_method_param.type_argument.type_id)
-                        .to_string(),
-                    expected: engines
-                        .help_out(impl_method_signature_param.type_argument.type_id)
-                        .to_string(),
+                    interface_param_name: impl_method_signature_param.name.clone(),
+                    is_mutable_in_interface: impl_method_signature_param.is_mutable,
+                    span: if impl_method_param.is_mutable {
+                        impl_method_param.mutability_span.clone()
+                    } else {
+                        impl_method_param.name.span()
+                    },
                 });
-                continue;
             }
         }
 
@@ -742,6 +786,7 @@ fn type_check_impl_method(
                     fn_name: impl_method.name.clone(),
                     interface_name: interface_name(),
                     missing_impl_attribute: true,
+                    interface_fn_span: impl_method_signature.name.span(),
                     span: impl_method.span.clone(),
                 });
             }
@@ -752,29 +797,13 @@ fn type_check_impl_method(
                     fn_name: impl_method.name.clone(),
                     interface_name: interface_name(),
                     missing_impl_attribute: false,
+                    interface_fn_span: impl_method_signature.name.span(),
                     span: impl_method.span.clone(),
                 });
             }
             (true, true) | (false, false) => (), // no payability mismatch
         }
 
-        if !type_engine.get(impl_method.return_type.type_id).eq(
-            &type_engine.get(impl_method_signature.return_type.type_id),
-            engines,
-        ) {
-            return Err(
-                handler.emit_err(CompileError::MismatchedTypeInInterfaceSurface {
-                    interface_name: interface_name(),
-                    span: impl_method.return_type.span.clone(),
-                    decl_type: "function".to_string(),
-                    expected: engines
-                        .help_out(impl_method_signature.return_type)
-                        .to_string(),
-                    given: engines.help_out(impl_method.return_type).to_string(),
-                }),
-            );
-        }
-
         // We need to add impl type parameters to the  method's type parameters
         // so that in-line monomorphization can complete.
         //
@@ -801,6 +830,196 @@ fn type_check_impl_method(
     })
 }
 
+/// Checks if the positional matching of the `impl_params` against the `interface_params` fails
+/// on their types, while both contain the same parameters, only in a different order.
+fn are_parameters_reordered(
+    engines: &Engines,
+    interface_params: &[ty::TyFunctionParameter],
+    impl_params: &[ty::TyFunctionParameter],
+) -> bool {
+    let type_engine = engines.te();
+
+    let positional_match =
+        interface_params
+            .iter()
+            .zip(impl_params)
+            .all(|(interface_param, impl_param)| {
+                type_engine.get(impl_param.type_argument.type_id).eq(
+                    &type_engine.get(interface_param.type_argument.type_id),
+                    engines,
+                )
+            });
+    if positional_match {
+        return false;
+    }
+
+    let mut unmatched_impl_params = impl_params.iter().collect::<Vec<_>>();
+    interface_params.iter().all(|interface_param| {
+        match unmatched_impl_params
+            .iter()
+            .position(|impl_param| (*impl_param).eq(interface_param, engines))
+        {
+            Some(index) => {
+                unmatched_impl_params.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+/// Finds the first difference between the signature declared in the interface and the one of
+/// the `impl_method`, together with the span in the implementation where it occurs.
+fn find_signature_mismatch(
+    engines: &Engines,
+    interface_fn: &ty::TyTraitFn,
+    impl_method: &ty::TyFunctionDecl,
+) -> Option<(SignatureMismatch, Span)> {
+    let type_engine = engines.te();
+    let interface_params = &interface_fn.parameters;
+    let impl_params = &impl_method.parameters;
+
+    if impl_params.len() < interface_params.len() {
+        let (position, missing) = interface_params
+            .iter()
+            .enumerate()
+            .find(|(_, interface_param)| {
+                !impl_params
+                    .iter()
+                    .any(|impl_param| impl_param.name == interface_param.name)
+            })
+            .unwrap_or((impl_params.len(), &interface_params[impl_params.len()]));
+        return Some((
+            SignatureMismatch::MissingParameter {
+                position,
+                name: missing.name.clone(),
+            },
+            impl_method.parameters_span(),
+        ));
+    }
+
+    if impl_params.len() > interface_params.len() {
+        let (position, extra) = impl_params
+            .iter()
+            .enumerate()
+            .find(|(_, impl_param)| {
+                !interface_params
+                    .iter()
+                    .any(|interface_param| interface_param.name == impl_param.name)
+            })
+            .unwrap_or((interface_params.len(), &impl_params[interface_params.len()]));
+        return Some((
+            SignatureMismatch::ExtraParameter {
+                position,
+                name: extra.name.clone(),
+            },
+            Span::join(extra.name.span(), extra.type_argument.span.clone()),
+        ));
+    }
+
+    // a method taking `self` cannot be implemented by a function taking a regular parameter of
+    // type `Self`, or vice versa, even though their types are the same
+    if let (Some(interface_param), Some(impl_param)) =
+        (interface_params.first(), impl_params.first())
+    {
+        if interface_param.is_self() != impl_param.is_self() {
+            return Some((
+                SignatureMismatch::SelfParameter {
+                    is_method_in_interface: interface_param.is_self(),
+                },
+                if impl_param.is_self() {
+                    impl_param.name.span()
+                } else {
+                    Span::join(
+                        impl_param.name.span(),
+                        impl_param.type_argument.span.clone(),
+                    )
+                },
+            ));
+        }
+    }
+
+    let mismatched_param = interface_params.iter().zip(impl_params).enumerate().find(
+        |(_, (interface_param, impl_param))| {
+            !type_engine.get(impl_param.type_argument.type_id).eq(
+                &type_engine.get(interface_param.type_argument.type_id),
+                engines,
+            )
+        },
+    );
+    if let Some((position, (_, impl_param))) = mismatched_param {
+        return Some((
+            SignatureMismatch::ParameterType {
+                position,
+                name: impl_param.name.clone(),
+            },
+            impl_param.type_argument.span.clone(),
+        ));
+    }
+
+    if !type_engine
+        .get(impl_method.return_type.type_id)
+        .eq(&type_engine.get(interface_fn.return_type.type_id), engines)
+    {
+        return Some((
+            SignatureMismatch::ReturnType,
+            impl_method.return_type.span.clone(),
+        ));
+    }
+
+    None
+}
+
+/// Renders a function signature the way it is written in the source, e.g.
+/// `fn transfer(ref mut self, amount: u64) -> bool`. Types whose names are ambiguous in the
+/// `namespace` are qualified with their module path, and the type parameters in the
+/// `type_mapping` are replaced with their type arguments.
+fn signature_to_string(
+    engines: &Engines,
+    type_mapping: Option<&TypeSubstMap>,
+    namespace: &Namespace,
+    name: &Ident,
+    parameters: &[ty::TyFunctionParameter],
+    return_type: &TypeArgument,
+) -> String {
+    let parameters = parameters
+        .iter()
+        .map(|param| {
+            let prefix = match (param.is_reference, param.is_mutable) {
+                (true, true) => "ref mut ",
+                (true, false) => "ref ",
+                (false, true) => "mut ",
+                (false, false) => "",
+            };
+            if param.is_self() {
+                format!("{prefix}self")
+            } else {
+                format!(
+                    "{prefix}{}: {}",
+                    param.name,
+                    param.type_argument.type_id.to_diagnostic_string_in_scope(
+                        engines,
+                        type_mapping,
+                        namespace
+                    )
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if engines.te().get(return_type.type_id).is_unit() {
+        format!("fn {name}({parameters})")
+    } else {
+        format!(
+            "fn {name}({parameters}) -> {}",
+            return_type
+                .type_id
+                .to_diagnostic_string_in_scope(engines, type_mapping, namespace)
+        )
+    }
+}
+
 fn type_check_const_decl(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -833,10 +1052,13 @@ fn type_check_const_decl(
     let const_name = const_decl.call_path.suffix.clone();
 
     // Ensure that there aren't multiple definitions of this constant
-    if impld_constant_ids.contains_key(&(const_name.clone(), self_type)) {
+    if let Some(((previous_name, _), _)) =
+        impld_constant_ids.get_key_value(&(const_name.clone(), self_type))
+    {
         return Err(
             handler.emit_err(CompileError::MultipleDefinitionsOfConstant {
                 name: const_name.clone(),
+                previous_span: previous_name.span(),
                 span: const_name.span(),
             }),
         );
@@ -1,17 +1,22 @@
 use crate::{
     fuel_prelude::fuel_tx::StorageSlot,
     ir_generation::{
-        const_eval::compile_constant_expression_to_constant, storage::serialize_to_storage_slots,
+        const_eval::compile_constant_expression_to_constant,
+        storage::{serialize_to_storage_slots, serialize_to_words},
     },
     language::ty,
     metadata::MetadataManager,
+    type_system::{TypeId, TypeInfo},
     Engines,
 };
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_ir::{Context, Module};
+use sway_ir::{
+    constant::{Constant, ConstantValue},
+    Context, Module,
+};
 use sway_types::state::StateIndex;
 
 impl ty::TyStorageDecl {
@@ -22,7 +27,7 @@ impl ty::TyStorageDecl {
         context: &mut Context,
         md_mgr: &mut MetadataManager,
         module: Module,
-    ) -> Result<Vec<StorageSlot>, ErrorEmitted> {
+    ) -> Result<Vec<(StorageSlot, String)>, ErrorEmitted> {
         handler.scope(|handler| {
             let storage_slots = self
                 .fields
@@ -47,6 +52,8 @@ impl ty::TyStorageDecl {
 }
 
 impl ty::TyStorageField {
+    /// Returns the storage slots initialized by this field, each together with the path of the
+    /// field, e.g. `config.limits.max`, holding the first word of the slot.
     pub(crate) fn get_initialized_storage_slots(
         &self,
         engines: &Engines,
@@ -54,7 +61,7 @@ impl ty::TyStorageField {
         md_mgr: &mut MetadataManager,
         module: Module,
         ix: &StateIndex,
-    ) -> Result<Vec<StorageSlot>, CompileError> {
+    ) -> Result<Vec<(StorageSlot, String)>, CompileError> {
         compile_constant_expression_to_constant(
             engines,
             context,
@@ -64,6 +71,90 @@ impl ty::TyStorageField {
             None,
             &self.initializer,
         )
-        .map(|constant| serialize_to_storage_slots(&constant, context, ix, &constant.ty, &[]))
+        .map(|constant| {
+            let mut field_paths = vec![];
+            collect_field_paths(
+                engines,
+                context,
+                &constant,
+                self.type_argument.type_id,
+                self.name.to_string(),
+                &mut 0,
+                &mut field_paths,
+            );
+            serialize_to_storage_slots(&constant, context, ix, &constant.ty, &[])
+                .into_iter()
+                .enumerate()
+                .map(|(slot_idx, slot)| {
+                    // Each slot holds four words.
+                    let path = field_paths
+                        .iter()
+                        .rev()
+                        .find(|(offset_in_words, _)| *offset_in_words <= 4 * slot_idx)
+                        .map(|(_, path)| path.clone())
+                        .unwrap_or_else(|| self.name.to_string());
+                    (slot, path)
+                })
+                .collect()
+        })
+    }
+}
+
+/// Appends to `field_paths` the path of every field of `constant` which is not a struct, a tuple
+/// or an array, together with the offset in words at which `serialize_to_words` places it.
+fn collect_field_paths(
+    engines: &Engines,
+    context: &Context,
+    constant: &Constant,
+    type_id: TypeId,
+    path: String,
+    offset_in_words: &mut usize,
+    field_paths: &mut Vec<(usize, String)>,
+) {
+    match (engines.te().get_unaliased(type_id), &constant.value) {
+        (TypeInfo::Struct(decl_ref), ConstantValue::Struct(fields)) => {
+            let decl = engines.de().get_struct(&decl_ref);
+            for (field, field_constant) in decl.fields.iter().zip(fields) {
+                collect_field_paths(
+                    engines,
+                    context,
+                    field_constant,
+                    field.type_argument.type_id,
+                    format!("{path}.{}", field.name),
+                    offset_in_words,
+                    field_paths,
+                );
+            }
+        }
+        (TypeInfo::Tuple(elem_args), ConstantValue::Struct(elems)) => {
+            for (idx, (elem_arg, elem_constant)) in elem_args.iter().zip(elems).enumerate() {
+                collect_field_paths(
+                    engines,
+                    context,
+                    elem_constant,
+                    elem_arg.type_id,
+                    format!("{path}.{idx}"),
+                    offset_in_words,
+                    field_paths,
+                );
+            }
+        }
+        (TypeInfo::Array(elem_arg, _), ConstantValue::Array(elems)) => {
+            for (idx, elem_constant) in elems.iter().enumerate() {
+                collect_field_paths(
+                    engines,
+                    context,
+                    elem_constant,
+                    elem_arg.type_id,
+                    format!("{path}[{idx}]"),
+                    offset_in_words,
+                    field_paths,
+                );
+            }
+        }
+        _ => {
+            field_paths.push((*offset_in_words, path));
+            *offset_in_words += serialize_to_words(constant, context, &constant.ty).len();
+        }
     }
 }
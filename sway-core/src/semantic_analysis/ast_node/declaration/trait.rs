@@ -114,11 +114,17 @@ impl ty::TyTraitDecl {
                 }
             };
 
-            if !ids.insert(decl_name.clone()) {
-                handler.emit_err(CompileError::MultipleDefinitionsOfName {
-                    name: decl_name.clone(),
-                    span: decl_name.span(),
-                });
+            match ids.get(&decl_name) {
+                Some(previous_name) => {
+                    handler.emit_err(CompileError::MultipleDefinitionsOfName {
+                        name: decl_name.clone(),
+                        previous_span: previous_name.span(),
+                        span: decl_name.span(),
+                    });
+                }
+                None => {
+                    ids.insert(decl_name.clone());
+                }
             }
         }
 
@@ -38,16 +38,16 @@ impl ty::TyTraitFn {
         // Type check the parameters.
         let mut typed_parameters = vec![];
         for param in parameters.into_iter() {
-            typed_parameters.push(
-                match ty::TyFunctionParameter::type_check_interface_parameter(
-                    handler,
-                    ctx.by_ref(),
-                    param,
-                ) {
-                    Ok(res) => res,
-                    Err(_) => continue,
-                },
-            );
+            let param = match ty::TyFunctionParameter::type_check_interface_parameter(
+                handler,
+                ctx.by_ref(),
+                param,
+            ) {
+                Ok(res) => res,
+                Err(_) => continue,
+            };
+            param.check_name_not_declared_in(handler, &typed_parameters);
+            typed_parameters.push(param);
         }
 
         // Type check the return type.
@@ -102,6 +102,7 @@ impl ty::TyTraitFn {
             visibility: Visibility::Public,
             type_parameters: vec![],
             is_contract_call: matches!(abi_mode, AbiMode::ImplAbiFn(..)),
+            has_inferred_purity: false,
             where_clause: vec![],
         }
     }
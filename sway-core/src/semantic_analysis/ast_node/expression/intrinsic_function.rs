@@ -416,6 +416,7 @@ fn type_check_gtf(
 /// Signature: `__addr_of<T>(val: T) -> raw_ptr`
 /// Description: Returns the address in memory where `val` is stored.
 /// Constraints: `T` is a reference type.
+///              `val` is a variable, or a field or an element of a variable.
 fn type_check_addr_of(
     handler: &Handler,
     ctx: TypeCheckContext,
@@ -448,6 +449,12 @@ fn type_check_addr_of(
             hint: "Only a reference type can be used as argument here".to_string(),
         }));
     }
+    if !is_place_expression(&exp) {
+        return Err(handler.emit_err(CompileError::IntrinsicArgIsTemporary {
+            name: kind.to_string(),
+            span: exp.span,
+        }));
+    }
 
     let intrinsic_function = ty::TyIntrinsicFunctionKind {
         kind,
@@ -459,6 +466,18 @@ fn type_check_addr_of(
     Ok((intrinsic_function, return_type))
 }
 
+/// Returns true if the expression denotes a memory location that outlives it, i.e., a variable,
+/// or a field or an element of a variable, and not a temporary value.
+fn is_place_expression(exp: &ty::TyExpression) -> bool {
+    match &exp.expression {
+        ty::TyExpressionVariant::VariableExpression { .. } => true,
+        ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix, .. }
+        | ty::TyExpressionVariant::ArrayIndex { prefix, .. } => is_place_expression(prefix),
+        _ => false,
+    }
+}
+
 /// Signature: `__state_load_clear(key: b256, slots: u64) -> bool`
 /// Description: Clears `slots` number of slots (`b256` each) in storage starting at key `key`.
 ///              Returns a Boolean describing whether all the storage slots were previously set.
@@ -1,4 +1,7 @@
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
+};
 use sway_types::Span;
 
 use crate::{
@@ -9,6 +12,7 @@ use crate::{
         },
         TypeCheckContext,
     },
+    types::DeterministicallyAborts,
     CompileError, TypeInfo,
 };
 
@@ -26,17 +30,40 @@ impl ty::TyMatchExpression {
         let mut ctx =
             ctx.with_help_text("all branches of a match statement must return the same type");
 
+        // If the type of the match expression is not expected by the context, the first arm
+        // that does not abort decides the type that the other arms must return.
+        let decl_engine = ctx.engines.de();
+        let mut first_arm_origin = match ctx.engines.te().get(ctx.type_annotation()) {
+            TypeInfo::Unknown => None,
+            _ => ctx.expected_origin().cloned(),
+        };
+        let infers_type = first_arm_origin.is_none();
+
         handler.scope(|handler| {
             for branch in branches.into_iter() {
+                let branch_ctx = match &first_arm_origin {
+                    Some(origin) => ctx.by_ref().with_expected_origin(origin.clone()),
+                    None => ctx.by_ref(),
+                };
                 let (typed_branch, typed_scrutinee) = match ty::TyMatchBranch::type_check(
                     handler,
-                    ctx.by_ref(),
+                    branch_ctx,
                     &typed_value,
                     branch,
                 ) {
                     Ok(res) => res,
                     Err(_) => continue,
                 };
+                if infers_type
+                    && first_arm_origin.is_none()
+                    && !typed_branch
+                        .result
+                        .deterministically_aborts(decl_engine, true)
+                {
+                    first_arm_origin = Some(ExpectedTypeOrigin::MatchArm {
+                        span: typed_branch.result.span.clone(),
+                    });
+                }
                 typed_branches.push(typed_branch);
                 typed_scrutinees.push(typed_scrutinee);
             }
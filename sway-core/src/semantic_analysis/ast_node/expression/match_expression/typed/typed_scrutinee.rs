@@ -2,14 +2,14 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{BaseIdent, Ident, Span, Spanned};
+use sway_types::{integer_bits::IntegerBits, BaseIdent, Ident, Span, Spanned};
 
 use crate::{
     decl_engine::DeclEngineInsert,
     language::{
         parsed::*,
         ty::{self, TyDecl},
-        CallPath,
+        CallPath, Literal,
     },
     semantic_analysis::TypeCheckContext,
     type_system::*,
@@ -123,6 +123,14 @@ fn type_check_variable(
         // If this variable is a constant, then we turn it into a [TyScrutinee::Constant](ty::TyScrutinee::Constant).
         Some(ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. })) => {
             let constant_decl = decl_engine.get_constant(decl_id);
+            if constant_decl.is_configurable {
+                return Err(
+                    handler.emit_err(CompileError::ConfigurableInConstantContext {
+                        name: constant_decl.call_path.suffix.clone(),
+                        span,
+                    }),
+                );
+            }
             let value = match constant_decl.value {
                 Some(ref value) => value,
                 None => {
@@ -133,6 +141,23 @@ fn type_check_variable(
                 }
             };
             let literal = match value.extract_literal_value() {
+                // Constants with an inferred type may still hold a numeric literal. Give it the
+                // type of the constant, so that it can be compared with the other patterns.
+                Some(Literal::Numeric(num)) => match type_engine.get(value.return_type) {
+                    TypeInfo::UnsignedInteger(IntegerBits::Eight) if num <= u8::MAX as u64 => {
+                        Literal::U8(num as u8)
+                    }
+                    TypeInfo::UnsignedInteger(IntegerBits::Sixteen) if num <= u16::MAX as u64 => {
+                        Literal::U16(num as u16)
+                    }
+                    TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo) if num <= u32::MAX as u64 => {
+                        Literal::U32(num as u32)
+                    }
+                    TypeInfo::UnsignedInteger(IntegerBits::SixtyFour) | TypeInfo::Numeric => {
+                        Literal::U64(num)
+                    }
+                    _ => Literal::Numeric(num),
+                },
                 Some(value) => value,
                 None => {
                     return Err(handler.emit_err(CompileError::Unimplemented(
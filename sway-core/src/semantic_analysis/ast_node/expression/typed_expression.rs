@@ -17,7 +17,10 @@ pub(crate) use self::{
 };
 
 use crate::{
-    asm_lang::{virtual_ops::VirtualOp, virtual_register::VirtualRegister},
+    asm_lang::{
+        virtual_ops::VirtualOp,
+        virtual_register::{ConstantRegister, VirtualRegister},
+    },
     decl_engine::*,
     language::{
         parsed::*,
@@ -38,7 +41,9 @@ use sway_error::{
     handler::{ErrorEmitted, Handler},
     warning::{CompileWarning, Warning},
 };
-use sway_types::{integer_bits::IntegerBits, u256::U256, Ident, Named, Span, Spanned};
+use sway_types::{
+    integer_bits::IntegerBits, style::find_similar_name, u256::U256, Ident, Named, Span, Spanned,
+};
 
 use rustc_hash::FxHashSet;
 
@@ -272,6 +277,26 @@ impl ty::TyExpression {
                 let AbiCastExpression { abi_name, address } = *abi_cast_expression;
                 Self::type_check_abi_cast(handler, ctx.by_ref(), abi_name, *address, span)
             }
+            ExpressionKind::Array(ArrayExpression {
+                mut contents,
+                length_span,
+                length_constant: Some(call_path),
+            }) => {
+                // The single value of a repeat expression with a constant length is repeated
+                // here, now that the value of the constant is known.
+                let length = type_engine.resolve_length(
+                    handler,
+                    engines,
+                    Length::from_constant(call_path, length_span.unwrap_or_else(|| span.clone())),
+                    ctx.namespace,
+                    ctx.namespace.mod_path(),
+                )?;
+                let contents = contents
+                    .pop()
+                    .map(|value| vec![value; length.val()])
+                    .unwrap_or_default();
+                Self::type_check_array(handler, ctx.by_ref(), contents, span)
+            }
             ExpressionKind::Array(array_expression) => {
                 Self::type_check_array(handler, ctx.by_ref(), array_expression.contents, span)
             }
@@ -312,6 +337,9 @@ impl ty::TyExpression {
             ExpressionKind::WhileLoop(WhileLoopExpression { condition, body }) => {
                 Self::type_check_while_loop(handler, ctx.by_ref(), *condition, body, span)
             }
+            ExpressionKind::Spread(_) => {
+                Err(handler.emit_err(CompileError::SpreadNotAllowedHere { span }))
+            }
             ExpressionKind::Break => {
                 let expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Break,
@@ -430,6 +458,8 @@ impl ty::TyExpression {
         let decl_engine = ctx.engines.de();
         let engines = ctx.engines();
 
+        ctx.namespace.check_glob_import_ambiguity(handler, &name)?;
+
         let exp = match ctx
             .namespace
             .resolve_symbol(&Handler::default(), &name)
@@ -458,6 +488,13 @@ impl ty::TyExpression {
             Some(ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. })) => {
                 let const_decl = decl_engine.get_constant(decl_id);
                 let decl_name = const_decl.name().clone();
+                ctx.warn_if_deprecated(
+                    handler,
+                    "constant",
+                    &decl_name,
+                    &const_decl.attributes,
+                    &name.span(),
+                );
                 ty::TyExpression {
                     return_type: const_decl.return_type,
                     expression: ty::TyExpressionVariant::ConstantExpression {
@@ -487,6 +524,11 @@ impl ty::TyExpression {
             None => {
                 let err = handler.emit_err(CompileError::UnknownVariable {
                     var_name: name.clone(),
+                    suggestion: find_similar_name(
+                        name.as_str(),
+                        ctx.namespace.module().get_all_variable_names(),
+                    )
+                    .map(str::to_string),
                     span,
                 });
                 ty::TyExpression::error(err, name.span(), engines)
@@ -689,9 +731,15 @@ impl ty::TyExpression {
         let engines = ctx.engines();
 
         // Various checks that we can catch early to check that the assembly is valid. For now,
-        // this includes two checks:
+        // this includes four checks:
         // 1. Check that no control flow opcodes are used.
         // 2. Check that initialized registers are not reassigned in the `asm` block.
+        // 3. Check that the returned register is declared in the `asm` block.
+        // 4. Check that initialized registers are used in the `asm` block.
+        // 5. Check that registers are not declared multiple times.
+        // 6. Check that declared registers do not shadow reserved registers.
+        // 7. Check that instructions only reference declared or reserved registers.
+        // 8. Check that a value computed by the last instruction is returned.
         check_asm_block_validity(handler, &asm)?;
 
         let asm_span = asm
@@ -1016,7 +1064,10 @@ impl ty::TyExpression {
         path.push(before.inner.clone());
         let not_module = {
             let h = Handler::default();
-            ctx.namespace.check_submodule(&h, &path).is_err()
+            ctx.namespace
+                .root()
+                .check_submodule(&h, &ctx.namespace.find_module_path(&path))
+                .is_err()
         };
 
         // Not a module? Not a `Enum::Variant` either?
@@ -1112,15 +1163,16 @@ impl ty::TyExpression {
         let module_probe_handler = Handler::default();
         let is_module = {
             let call_path_binding = unknown_call_path_binding.clone();
+            let module_path = ctx.namespace.find_module_path(
+                &[
+                    call_path_binding.inner.prefixes,
+                    vec![call_path_binding.inner.suffix],
+                ]
+                .concat(),
+            );
             ctx.namespace
-                .check_submodule(
-                    &module_probe_handler,
-                    &[
-                        call_path_binding.inner.prefixes,
-                        vec![call_path_binding.inner.suffix],
-                    ]
-                    .concat(),
-                )
+                .root()
+                .check_submodule(&module_probe_handler, &module_path)
                 .ok()
                 .is_some()
         };
@@ -1226,7 +1278,7 @@ impl ty::TyExpression {
                         .into(),
                     );
                 }
-                instantiate_constant_expression(ctx, const_ref, call_path_binding)
+                instantiate_constant_expression(handler, ctx, const_ref, call_path_binding)
             }
             (false, None, None, None) => {
                 return Err(handler.emit_err(CompileError::SymbolNotFound {
@@ -1317,17 +1369,15 @@ impl ty::TyExpression {
         let decl_engine = ctx.engines.de();
         let engines = ctx.engines();
 
-        // TODO use lib-std's Address type instead of b256
-        // type check the address and make sure it is
+        // type check the address and make sure it is a b256 or a ContractId
         let err_span = address.span();
-        let address_expr = {
-            let ctx = ctx
-                .by_ref()
-                .with_help_text("An address that is being ABI cast must be of type b256")
-                .with_type_annotation(type_engine.insert(engines, TypeInfo::B256));
-            ty::TyExpression::type_check(handler, ctx, address)
-                .unwrap_or_else(|err| ty::TyExpression::error(err, err_span, engines))
-        };
+        let address_expr = type_check_b256_or_contract_id(
+            handler,
+            ctx.by_ref(),
+            address,
+            "An address that is being ABI cast must be of type b256 or ContractId.",
+        )
+        .unwrap_or_else(|err| ty::TyExpression::error(err, err_span, engines));
 
         // look up the call path and get the declaration it references
         let abi = ctx
@@ -1575,6 +1625,49 @@ impl ty::TyExpression {
             }
         }
 
+        // Returns the type parameters of `ty` if it is the struct `name` declared in the module
+        // at `prefixes`.
+        fn struct_type_parameters(
+            ty: TypeId,
+            engines: &Engines,
+            prefixes: &[&str],
+            name: &str,
+        ) -> Option<Vec<TypeParameter>> {
+            match engines.te().get(ty) {
+                TypeInfo::Struct(decl_ref) => {
+                    let decl = engines.de().get_struct(&decl_ref);
+                    let call_path = &decl.call_path;
+                    (call_path.suffix.as_str() == name
+                        && call_path
+                            .prefixes
+                            .iter()
+                            .map(|p| p.as_str())
+                            .eq(prefixes.iter().copied()))
+                    .then(|| decl.type_parameters.clone())
+                }
+                TypeInfo::Alias { ty, .. } => {
+                    struct_type_parameters(ty.type_id, engines, prefixes, name)
+                }
+                _ => None,
+            }
+        }
+
+        // Storage map accesses are of type `core::storage::StorageKey<StorageMap<K, V>>`, where
+        // `StorageMap` is `std::storage::storage_map::StorageMap`.
+        fn is_storage_map_key(ty: TypeId, engines: &Engines) -> bool {
+            struct_type_parameters(ty, engines, &["core", "storage"], "StorageKey")
+                .and_then(|type_parameters| type_parameters.first().map(|param| param.type_id))
+                .and_then(|field_ty| {
+                    struct_type_parameters(
+                        field_ty,
+                        engines,
+                        &["std", "storage", "storage_map"],
+                        "StorageMap",
+                    )
+                })
+                .is_some()
+        }
+
         // If the return type is a static array then create a `ty::TyExpressionVariant::ArrayIndex`.
         if let Some(TypeInfo::Array(elem_type, _)) =
             get_array_type(prefix_te.return_type, type_engine)
@@ -1593,6 +1686,24 @@ impl ty::TyExpression {
                 return_type: elem_type.type_id,
                 span,
             })
+        } else if is_storage_map_key(prefix_te.return_type, engines) {
+            // Indexing into a storage map, like `storage.map[key]`, is a call to `get`, like
+            // `storage.map.get(key)`, which checks the key against the declared key type.
+            let method_name = TypeBinding {
+                inner: MethodName::FromModule {
+                    method_name: Ident::new_with_override("get".into(), span.clone()),
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: span.clone(),
+            };
+            type_check_method_application(
+                handler,
+                ctx,
+                method_name,
+                vec![],
+                vec![prefix, index],
+                span,
+            )
         } else {
             // Otherwise convert into a method call 'index(self, index)' via the std::ops::Index trait.
             let method_name = TypeBinding {
@@ -1704,8 +1815,23 @@ impl ty::TyExpression {
                                 ctx.namespace.resolve_symbol(handler, &name).cloned()?;
                             let variable_decl = unknown_decl.expect_variable(handler).cloned()?;
                             if !variable_decl.mutability.is_mutable() {
+                                let is_parameter = matches!(
+                                    variable_decl.body.expression,
+                                    ty::TyExpressionVariant::FunctionParameter
+                                );
                                 return Err(handler.emit_err(
-                                    CompileError::AssignmentToNonMutable { name, span },
+                                    CompileError::AssignmentToNonMutable {
+                                        name,
+                                        // For a parameter, this also covers a `ref` keyword
+                                        // that has to be replaced by `ref mut`.
+                                        variable_span: if is_parameter {
+                                            variable_decl.body.span.clone()
+                                        } else {
+                                            variable_decl.name.span()
+                                        },
+                                        is_parameter,
+                                        span,
+                                    },
                                 ));
                             }
                             break (name, variable_decl.body.return_type);
@@ -1926,6 +2052,7 @@ mod tests {
                     },
                 ],
                 length_span: None,
+                length_constant: None,
             }),
             span: Span::dummy(),
         };
@@ -1959,6 +2086,7 @@ mod tests {
                     },
                 ],
                 length_span: None,
+                length_constant: None,
             }),
             span: Span::dummy(),
         };
@@ -2003,6 +2131,7 @@ mod tests {
                     },
                 ],
                 length_span: None,
+                length_constant: None,
             }),
             span: Span::dummy(),
         };
@@ -2026,6 +2155,7 @@ mod tests {
             kind: ExpressionKind::Array(ArrayExpression {
                 contents: Vec::new(),
                 length_span: None,
+                length_constant: None,
             }),
             span: Span::dummy(),
         };
@@ -2144,5 +2274,113 @@ fn check_asm_block_validity(handler: &Handler, asm: &AsmExpression) -> Result<()
         handler.emit_err(err);
     }
 
+    // Check #3: Disallow returning a register that is neither declared in the asm block header
+    // nor a reserved register
+    if let Some((returned_register, returned_register_span)) = &asm.returns {
+        let is_declared = asm
+            .registers
+            .iter()
+            .any(|reg| reg.name.as_str() == returned_register.name);
+        if !is_declared && ConstantRegister::parse_register_name(&returned_register.name).is_none()
+        {
+            handler.emit_err(CompileError::UnknownRegister {
+                span: returned_register_span.clone(),
+                initialized_registers: asm
+                    .registers
+                    .iter()
+                    .map(|reg| reg.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            });
+        }
+    }
+
+    // Check #4: Warn about initialized registers that are never used in the asm block
+    for reg in asm.registers.iter().filter(|reg| reg.initializer.is_some()) {
+        let is_used_in_op = asm
+            .body
+            .iter()
+            .any(|op| op.op_args.iter().any(|arg| arg == &reg.name));
+        let is_returned = asm
+            .returns
+            .as_ref()
+            .map_or(false, |(returned_register, _)| {
+                returned_register.name == reg.name.as_str()
+            });
+        if !is_used_in_op && !is_returned {
+            handler.emit_warn(CompileWarning {
+                span: reg.name.span(),
+                warning_content: Warning::UnusedAsmRegister {
+                    name: reg.name.clone(),
+                },
+            });
+        }
+    }
+
+    // Check #5: Disallow declaring a register multiple times
+    for (i, reg) in asm.registers.iter().enumerate() {
+        if let Some(previous) = asm.registers[..i].iter().find(|prev| prev.name == reg.name) {
+            handler.emit_err(CompileError::DuplicateAsmRegister {
+                name: reg.name.to_string(),
+                previous_span: previous.name.span(),
+                span: reg.name.span(),
+            });
+        }
+    }
+
+    // Check #6: Disallow declaring registers named like reserved registers
+    for reg in asm
+        .registers
+        .iter()
+        .filter(|reg| ConstantRegister::parse_register_name(reg.name.as_str()).is_some())
+    {
+        handler.emit_err(CompileError::ReservedAsmRegister {
+            name: reg.name.to_string(),
+            reserved_registers: ConstantRegister::RESERVED_NAMES.join(", "),
+            span: reg.name.span(),
+        });
+    }
+
+    // Check #7: Disallow referencing registers that are neither declared in the asm block header
+    // nor reserved registers
+    for arg in asm.body.iter().flat_map(|op| op.op_args.iter()) {
+        let is_declared = asm.registers.iter().any(|reg| &reg.name == arg);
+        if !is_declared && ConstantRegister::parse_register_name(arg.as_str()).is_none() {
+            handler.emit_err(CompileError::UnknownRegister {
+                span: arg.span(),
+                initialized_registers: asm
+                    .registers
+                    .iter()
+                    .map(|reg| reg.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            });
+        }
+    }
+
+    // Check #8: Warn when the last instruction writes to a declared register but the asm block
+    // does not return a register, which makes the block silently evaluate to `()`
+    if asm.returns.is_none() {
+        if let Some((last_op, _, last_op_span)) = opcodes.last() {
+            for name in last_op
+                .def_registers()
+                .into_iter()
+                .filter_map(|reg| match reg {
+                    VirtualRegister::Virtual(name) => Some(name),
+                    VirtualRegister::Constant(_) => None,
+                })
+            {
+                if asm.registers.iter().any(|decl| decl.name.as_str() == name) {
+                    handler.emit_warn(CompileWarning {
+                        span: last_op_span.clone(),
+                        warning_content: Warning::AsmBlockMissingReturn {
+                            register: name.clone(),
+                        },
+                    });
+                }
+            }
+        }
+    }
+
     Ok(())
 }
@@ -1,3 +1,4 @@
+use sway_error::handler::Handler;
 use sway_types::Spanned;
 
 use crate::{
@@ -8,11 +9,19 @@ use crate::{
 };
 
 pub(crate) fn instantiate_constant_expression(
+    handler: &Handler,
     ctx: TypeCheckContext,
     const_ref: DeclRefConstant,
     call_path_binding: TypeBinding<CallPath>,
 ) -> ty::TyExpression {
     let const_decl = ctx.engines.de().get_constant(const_ref.id());
+    ctx.warn_if_deprecated(
+        handler,
+        "constant",
+        &const_decl.call_path.suffix,
+        &const_decl.attributes,
+        &call_path_binding.inner.suffix.span(),
+    );
     ty::TyExpression {
         return_type: const_decl.return_type,
         span: call_path_binding.span(),
@@ -33,6 +33,21 @@ pub(crate) fn instantiate_enum(
         .expect_variant_from_name(handler, &enum_variant_name)
         .cloned()?;
 
+    ctx.warn_if_deprecated(
+        handler,
+        "enum",
+        &enum_decl.call_path.suffix,
+        &enum_decl.attributes,
+        &call_path_binding.span(),
+    );
+    ctx.warn_if_deprecated(
+        handler,
+        "enum variant",
+        &enum_variant.name,
+        &enum_variant.attributes,
+        &enum_variant_name.span(),
+    );
+
     // Return an error if enum variant is of type unit and it is called with parenthesis.
     // args_opt.is_some() returns true when this variant was called with parenthesis.
     if type_engine
@@ -1,10 +1,19 @@
 use crate::{
     decl_engine::{DeclEngineInsert, DeclRefFunction, ReplaceDecls},
-    language::{ty, *},
-    semantic_analysis::{ast_node::*, TypeCheckContext},
+    language::{
+        parsed::{DelineatedPathExpression, ExpressionKind, TupleIndexExpression},
+        ty, *,
+    },
+    semantic_analysis::{
+        ast_node::{typed_expression::type_check_from_conversion, *},
+        TypeCheckContext,
+    },
+    transform::AttributeKind,
+    type_system::{TypeArgs, UnifyCheck},
+    Engines,
 };
 use std::collections::HashMap;
-use sway_error::error::CompileError;
+use sway_error::{error::CompileError, type_error::ExpectedTypeOrigin};
 use sway_types::Spanned;
 
 #[allow(clippy::too_many_arguments)]
@@ -31,6 +40,14 @@ pub(crate) fn instantiate_function_application(
     }
     let arguments = arguments.unwrap_or_default();
 
+    ctx.warn_if_deprecated(
+        handler,
+        "function",
+        &function_decl.name,
+        &function_decl.attributes,
+        &call_path_binding.inner.suffix.span(),
+    );
+
     // 'purity' is that of the callee, 'opts.purity' of the caller.
     if !ctx.purity().can_call(function_decl.purity) {
         handler.emit_err(CompileError::StorageAccessMismatch {
@@ -39,6 +56,17 @@ pub(crate) fn instantiate_function_application(
         });
     }
 
+    let arguments = fill_optional_arguments(arguments, &function_decl, &span);
+    let arguments = if arguments
+        .iter()
+        .any(|arg| matches!(arg.kind, ExpressionKind::Spread(_)))
+    {
+        expand_spread_argument(handler, ctx.by_ref(), arguments, &function_decl)?
+    } else {
+        arguments
+    };
+    let arguments = pack_rest_arguments(arguments, &function_decl, &span);
+
     // check that the number of parameters and the number of the arguments is the same
     check_function_arguments_arity(
         handler,
@@ -49,14 +77,29 @@ pub(crate) fn instantiate_function_application(
     )?;
 
     let typed_arguments = type_check_arguments(handler, ctx.by_ref(), arguments)?;
+    let typed_arguments = coerce_arguments(handler, ctx.by_ref(), typed_arguments, &function_decl)?;
+    let typed_arguments =
+        coerce_arrays_to_slices(handler, ctx.by_ref(), typed_arguments, &function_decl)?;
 
+    // The explicit type arguments, like in `f::<u64>(..)`, are already bound to the type
+    // parameters of `function_decl`, so the arguments are unified with concrete parameter types.
     let typed_arguments_with_names = unify_arguments_and_parameters(
         handler,
         ctx.by_ref(),
         typed_arguments,
-        &function_decl.parameters,
+        &function_decl,
+        &call_path_binding.type_arguments,
+    )?;
+
+    check_arguments_satisfy_parameter_bounds(
+        handler,
+        &ctx,
+        &function_decl,
+        &typed_arguments_with_names,
     )?;
 
+    check_monomorphization_depth(handler, &ctx, &function_decl, &call_path_binding.span())?;
+
     // Retrieve the implemented traits for the type of the return type and
     // insert them in the broader namespace.
     ctx.namespace
@@ -94,6 +137,206 @@ pub(crate) fn instantiate_function_application(
     Ok(exp)
 }
 
+/// Expands a spread argument like `..rest`, which the parser only accepts as the last argument,
+/// into the positional arguments it stands for. A rest parameter that has not been forwarded yet gets its type from
+/// the remaining parameters of the called function.
+///
+/// If the called function has a rest parameter itself, the expanded arguments are packed again
+/// by [pack_rest_arguments].
+fn expand_spread_argument(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    mut arguments: Vec<Expression>,
+    function_decl: &ty::TyFunctionDecl,
+) -> Result<Vec<Expression>, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    let spread = arguments.pop().expect("the arguments contain a spread");
+    let name = match spread.kind {
+        ExpressionKind::Spread(name) => name,
+        _ => unreachable!("the last argument is the spread"),
+    };
+
+    if !function_decl.type_parameters.is_empty() {
+        return Err(handler.emit_err(CompileError::SpreadIntoGenericFunction {
+            name,
+            fn_name: function_decl.name.clone(),
+            span: spread.span,
+        }));
+    }
+
+    let variable = {
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
+        let variable = Expression {
+            kind: ExpressionKind::Variable(name.clone()),
+            span: name.span(),
+        };
+        ty::TyExpression::type_check(handler, ctx, variable)?
+    };
+
+    let remaining_parameters = function_decl
+        .parameters
+        .get(arguments.len()..)
+        .unwrap_or_default();
+    let mut expected_types: Vec<_> = remaining_parameters
+        .iter()
+        .map(|param| param.type_argument.clone())
+        .collect();
+    // A rest parameter of the called function stands for the arguments it collects, so the
+    // spread argument is expanded into those arguments instead of into a single tuple.
+    if let Some(param) = remaining_parameters.last().filter(|param| param.is_rest) {
+        if let TypeInfo::Tuple(rest_types) = type_engine.get(param.type_argument.type_id) {
+            expected_types.pop();
+            expected_types.extend(rest_types);
+        }
+    }
+    let expanded_len = expected_types.len();
+    let expected = type_engine.insert(engines, TypeInfo::Tuple(expected_types));
+    let parameters_span = if remaining_parameters.is_empty() {
+        function_decl.name.span()
+    } else {
+        Span::join_all(remaining_parameters.iter().map(|param| param.name.span()))
+    };
+
+    handler.scope(|unify_handler| {
+        type_engine.unify(
+            unify_handler,
+            engines,
+            variable.return_type,
+            expected,
+            &spread.span,
+            "",
+            Some(CompileError::SpreadArgumentMismatch {
+                name: name.to_string(),
+                fn_name: function_decl.name.clone(),
+                parameters_span,
+                expected: expected.to_diagnostic_string(engines, None),
+                received: variable.return_type.to_diagnostic_string(engines, None),
+                span: spread.span.clone(),
+            }),
+        );
+        Ok(())
+    })?;
+
+    arguments.extend((0..expanded_len).map(|index| Expression {
+        kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+            prefix: Box::new(Expression {
+                kind: ExpressionKind::Variable(name.clone()),
+                span: spread.span.clone(),
+            }),
+            index,
+            index_span: spread.span.clone(),
+        }),
+        span: spread.span.clone(),
+    }));
+
+    Ok(arguments)
+}
+
+/// Packs the trailing arguments of a call to a function with a rest parameter into the tuple that
+/// the rest parameter stands for.
+fn pack_rest_arguments(
+    mut arguments: Vec<Expression>,
+    function_decl: &ty::TyFunctionDecl,
+    span: &Span,
+) -> Vec<Expression> {
+    let rest_index = match function_decl.parameters.last() {
+        Some(param) if param.is_rest => function_decl.parameters.len() - 1,
+        _ => return arguments,
+    };
+    if arguments.len() < rest_index {
+        return arguments;
+    }
+
+    let rest_arguments = arguments.split_off(rest_index);
+    let rest_span = if rest_arguments.is_empty() {
+        span.clone()
+    } else {
+        Span::join_all(rest_arguments.iter().map(|arg| arg.span.clone()))
+    };
+    arguments.push(Expression {
+        kind: ExpressionKind::Tuple(rest_arguments),
+        span: rest_span,
+    });
+
+    arguments
+}
+
+/// Wraps the arguments passed to the optional parameters of the called function in `Some`, and
+/// passes `None` to the trailing optional parameters whose arguments are omitted.
+///
+/// A spread argument is expanded into arguments of the `Option` types of the parameters, so it
+/// is not wrapped, and the parameters it is expanded into are not omitted.
+fn fill_optional_arguments(
+    arguments: Vec<Expression>,
+    function_decl: &ty::TyFunctionDecl,
+    span: &Span,
+) -> Vec<Expression> {
+    if !function_decl
+        .parameters
+        .iter()
+        .any(|param| param.is_optional)
+    {
+        return arguments;
+    }
+
+    let has_spread = arguments
+        .iter()
+        .any(|arg| matches!(arg.kind, ExpressionKind::Spread(_)));
+    let mut arguments: Vec<_> = arguments
+        .into_iter()
+        .enumerate()
+        .map(|(index, arg)| match function_decl.parameters.get(index) {
+            Some(param) if param.is_optional && !matches!(arg.kind, ExpressionKind::Spread(_)) => {
+                let span = arg.span.clone();
+                option_variant("Some", Some(vec![arg]), span)
+            }
+            _ => arg,
+        })
+        .collect();
+
+    if !has_spread {
+        while function_decl
+            .parameters
+            .get(arguments.len())
+            .map_or(false, |param| param.is_optional)
+        {
+            arguments.push(option_variant("None", None, span.clone()));
+        }
+    }
+
+    arguments
+}
+
+/// Builds `::std::option::Option::<variant>`, applied to `args` if there are any. The path is
+/// absolute so that a user type named `Option` does not shadow it.
+fn option_variant(variant: &str, args: Option<Vec<Expression>>, span: Span) -> Expression {
+    let call_path = CallPath {
+        prefixes: vec![
+            Ident::new_with_override("std".into(), span.clone()),
+            Ident::new_with_override("option".into(), span.clone()),
+            Ident::new_with_override("Option".into(), span.clone()),
+        ],
+        suffix: Ident::new_with_override(variant.into(), span.clone()),
+        is_absolute: true,
+    };
+    Expression {
+        kind: ExpressionKind::DelineatedPath(Box::new(DelineatedPathExpression {
+            call_path_binding: TypeBinding {
+                inner: call_path,
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: span.clone(),
+            },
+            args,
+        })),
+        span,
+    }
+}
+
 /// Type checks the arguments.
 fn type_check_arguments(
     handler: &Handler,
@@ -120,24 +363,154 @@ fn type_check_arguments(
     })
 }
 
+/// Converts the arguments passed to the parameters listed in the `#[coerce(..)]` attributes of
+/// the called function into the types of the parameters, if the types differ and the type of the
+/// parameter implements `From` for the type of the argument.
+pub(crate) fn coerce_arguments(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    typed_arguments: Vec<ty::TyExpression>,
+    function_decl: &ty::TyFunctionDecl,
+) -> Result<Vec<ty::TyExpression>, ErrorEmitted> {
+    let engines = ctx.engines();
+    let coerced_parameters = function_decl
+        .attributes
+        .get(&AttributeKind::Coerce)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| attr.args.iter())
+        .map(|arg| &arg.name)
+        .collect::<Vec<_>>();
+    if coerced_parameters.is_empty() {
+        return Ok(typed_arguments);
+    }
+
+    handler.scope(|handler| {
+        let mut coerced_arguments = vec![];
+        for (arg, param) in typed_arguments
+            .into_iter()
+            .zip(function_decl.parameters.iter())
+        {
+            let needs_conversion = coerced_parameters.contains(&&param.name)
+                && !UnifyCheck::coercion(engines)
+                    .check(param.type_argument.type_id, arg.return_type);
+            if !needs_conversion {
+                coerced_arguments.push(arg);
+                continue;
+            }
+            let converted = type_check_from_conversion(
+                handler,
+                ctx.by_ref(),
+                arg.clone(),
+                &param.type_argument,
+            )
+            .unwrap_or_else(|err| Some(ty::TyExpression::error(err, arg.span.clone(), engines)));
+            coerced_arguments.push(converted.unwrap_or(arg));
+        }
+        Ok(coerced_arguments)
+    })
+}
+
+/// Coerces the arguments of an array type passed to parameters of a slice type, like `[u64; 3]`
+/// passed to `__slice[u64]`, into slices of the elements of the arrays. The length of the array
+/// becomes the length of the slice at runtime.
+///
+/// The elements are not converted, so the element type of the array must unify with the element
+/// type of the slice.
+pub(crate) fn coerce_arrays_to_slices(
+    handler: &Handler,
+    ctx: TypeCheckContext,
+    typed_arguments: Vec<ty::TyExpression>,
+    function_decl: &ty::TyFunctionDecl,
+) -> Result<Vec<ty::TyExpression>, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    handler.scope(|handler| {
+        let coerced_arguments = typed_arguments
+            .into_iter()
+            .zip(function_decl.parameters.iter())
+            .map(|(arg, param)| {
+                if param.resolution_failed {
+                    return arg;
+                }
+                let TypeInfo::Slice(slice_elem) =
+                    type_engine.get_unaliased(param.type_argument.type_id)
+                else {
+                    return arg;
+                };
+                let TypeInfo::Array(array_elem, _) = type_engine.get_unaliased(arg.return_type)
+                else {
+                    return arg;
+                };
+
+                let elem_handler = Handler::default();
+                type_engine.unify(
+                    &elem_handler,
+                    engines,
+                    array_elem.type_id,
+                    slice_elem.type_id,
+                    &arg.span,
+                    "",
+                    None,
+                );
+                let (errors, warnings) = elem_handler.consume();
+                for warning in warnings {
+                    handler.emit_warn(warning);
+                }
+                if !errors.is_empty() {
+                    let err = handler.emit_err(CompileError::ArrayToSliceElementMismatch {
+                        array_elem: engines.help_out(array_elem.type_id).to_string(),
+                        slice_elem: engines.help_out(slice_elem.type_id).to_string(),
+                        param_name: param.name.clone(),
+                        span: arg.span.clone(),
+                    });
+                    return ty::TyExpression::error(err, arg.span, engines);
+                }
+
+                let span = arg.span.clone();
+                ty::TyExpression {
+                    expression: ty::TyExpressionVariant::ArrayToSlice {
+                        array: Box::new(arg),
+                    },
+                    return_type: param.type_argument.type_id,
+                    span,
+                }
+            })
+            .collect();
+
+        Ok(coerced_arguments)
+    })
+}
+
 /// Unifies the types of the arguments with the types of the parameters. Returns
 /// a list of the arguments with the names of the corresponding parameters.
 fn unify_arguments_and_parameters(
     handler: &Handler,
     ctx: TypeCheckContext,
     typed_arguments: Vec<ty::TyExpression>,
-    parameters: &[ty::TyFunctionParameter],
+    function_decl: &ty::TyFunctionDecl,
+    type_arguments: &TypeArgs,
 ) -> Result<Vec<(Ident, ty::TyExpression)>, ErrorEmitted> {
     let type_engine = ctx.engines.te();
     let engines = ctx.engines();
     let mut typed_arguments_and_names = vec![];
 
     handler.scope(|handler| {
-        for (arg, param) in typed_arguments.into_iter().zip(parameters.iter()) {
+        for (arg, param) in typed_arguments
+            .into_iter()
+            .zip(function_decl.parameters.iter())
+        {
+            // The argument cannot be checked against a type which failed to resolve.
+            if param.resolution_failed {
+                typed_arguments_and_names.push((param.name.clone(), arg));
+                continue;
+            }
+
             // unify the type of the argument with the type of the param
 
             let unify_res = handler.scope(|unify_handler| {
-                type_engine.unify(
+                type_engine.unify_with_origin(
                     unify_handler,
                     engines,
                     arg.return_type,
@@ -146,6 +519,12 @@ fn unify_arguments_and_parameters(
                     "The argument that has been provided to this function's type does \
             not match the declared type of the parameter in the function \
             declaration.",
+                    Some(&parameter_type_origin(
+                        engines,
+                        function_decl,
+                        type_arguments,
+                        param,
+                    )),
                     None,
                 );
                 Ok(())
@@ -154,14 +533,7 @@ fn unify_arguments_and_parameters(
                 continue;
             }
 
-            // check for matching mutability
-            let param_mutability =
-                ty::VariableMutability::new_from_ref_mut(param.is_reference, param.is_mutable);
-            if arg.gather_mutability().is_immutable() && param_mutability.is_mutable() {
-                handler.emit_err(CompileError::ImmutableArgumentToMutableParameter {
-                    span: arg.span.clone(),
-                });
-            }
+            check_argument_mutability(handler, &ctx, &arg, param);
 
             typed_arguments_and_names.push((param.name.clone(), arg));
         }
@@ -170,6 +542,172 @@ fn unify_arguments_and_parameters(
     })
 }
 
+/// Checks that the type bound to each parameter declared as of a constrained generic type, like
+/// `x: T` where `T: Display`, implements all the traits required by the type parameter.
+///
+/// Unlike the check done when gathering the decl mapping from the trait constraints, the error
+/// points to the offending argument and names the parameter it is passed to.
+fn check_arguments_satisfy_parameter_bounds(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    function_decl: &ty::TyFunctionDecl,
+    typed_arguments_with_names: &[(Ident, ty::TyExpression)],
+) -> Result<(), ErrorEmitted> {
+    let engines = ctx.engines();
+
+    handler.scope(|handler| {
+        for (param_name, arg) in typed_arguments_with_names {
+            let Some(param) = function_decl
+                .parameters
+                .iter()
+                .find(|param| param.name == *param_name && !param.resolution_failed)
+            else {
+                continue;
+            };
+            let Some(type_param) = function_decl.type_parameters.iter().find(|type_param| {
+                type_param.type_id == param.type_argument.type_id
+                    && !type_param.trait_constraints.is_empty()
+            }) else {
+                continue;
+            };
+
+            let Ok(unsatisfied_traits) = ctx
+                .namespace
+                .implemented_traits
+                .unsatisfied_trait_constraints_for_type(
+                    handler,
+                    type_param.type_id,
+                    &type_param.trait_constraints,
+                    &arg.span,
+                    engines,
+                )
+            else {
+                continue;
+            };
+            for trait_name in unsatisfied_traits {
+                handler.emit_err(CompileError::ArgumentDoesNotSatisfyParameterBound {
+                    ty: engines.help_out(arg.return_type).to_string(),
+                    trait_name: trait_name.to_string(),
+                    param_name: param_name.to_string(),
+                    type_param: type_param.name_ident.to_string(),
+                    type_span: param.type_argument.span.clone(),
+                    span: arg.span.clone(),
+                });
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Returns where the type expected for the argument of `param` comes from. If the declared type
+/// of `param` refers to a type parameter that is given explicitly at the call site, like `T` in
+/// `f::<u64>(..)`, that is the explicit type argument. Otherwise it is the declared type itself.
+fn parameter_type_origin(
+    engines: &Engines,
+    function_decl: &ty::TyFunctionDecl,
+    type_arguments: &TypeArgs,
+    param: &ty::TyFunctionParameter,
+) -> ExpectedTypeOrigin {
+    let explicit_type_arguments = match type_arguments {
+        TypeArgs::Regular(type_arguments) => type_arguments.as_slice(),
+        TypeArgs::Prefix(_) => &[],
+    };
+    let refers_to = |name: &Ident| {
+        let is_type_param = |type_info: &TypeInfo| {
+            matches!(type_info, TypeInfo::Custom { call_path, .. }
+                if call_path.prefixes.is_empty() && call_path.suffix == *name)
+        };
+        !param
+            .type_argument
+            .initial_type_id
+            .extract_any_including_self(engines, &is_type_param, vec![])
+            .is_empty()
+    };
+
+    function_decl
+        .type_parameters
+        .iter()
+        .filter(|type_param| !type_param.is_from_parent)
+        .zip(explicit_type_arguments)
+        .find(|(type_param, _)| refers_to(&type_param.name_ident))
+        .map(
+            |(type_param, type_argument)| ExpectedTypeOrigin::TypeArgument {
+                name: type_param.name_ident.clone(),
+                span: type_argument.span.clone(),
+            },
+        )
+        .unwrap_or_else(|| ExpectedTypeOrigin::Parameter {
+            name: param.name.clone(),
+            span: param.type_argument.span.clone(),
+        })
+}
+
+/// Checks that an argument passed to a mutable parameter is mutable itself, i.e., that it is a
+/// mutable variable, or a field or an element of a mutable variable.
+///
+/// A storage access, like `storage.counter`, can be passed to a mutable parameter as well. The
+/// parameter then refers to the storage slots, and writes through it go to storage. Whether the
+/// caller is allowed to access storage is checked via the storage annotations of the callee.
+pub(crate) fn check_argument_mutability(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    arg: &ty::TyExpression,
+    param: &ty::TyFunctionParameter,
+) {
+    if matches!(arg.expression, ty::TyExpressionVariant::StorageAccess(_)) {
+        return;
+    }
+
+    let param_mutability =
+        ty::VariableMutability::new_from_ref_mut(param.is_reference, param.is_mutable);
+    if arg.gather_mutability().is_immutable() && param_mutability.is_mutable() {
+        let immutable_variable = arg.gather_variable_name().and_then(|name| {
+            match ctx.namespace.resolve_symbol(&Handler::default(), name) {
+                Ok(ty::TyDecl::VariableDecl(variable_decl)) => Some(variable_decl.name.clone()),
+                _ => None,
+            }
+        });
+        handler.emit_err(CompileError::ImmutableArgumentToMutableParameter {
+            span: arg.span.clone(),
+            immutable_variable,
+        });
+    }
+}
+
+/// Checks that the types the parameters of a generic function are instantiated with are not
+/// nested deeper than the monomorphization depth limit. Guards against chains of generic calls
+/// that keep wrapping their type arguments, like `f<Vec<Vec<Vec<T>>>>`, and would otherwise
+/// instantiate an ever growing number of functions.
+pub(crate) fn check_monomorphization_depth(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    function_decl: &ty::TyFunctionDecl,
+    span: &Span,
+) -> Result<(), ErrorEmitted> {
+    if function_decl.type_parameters.is_empty() {
+        return Ok(());
+    }
+
+    let engines = ctx.engines();
+    let limit = ctx.monomorphization_depth_limit();
+    handler.scope(|handler| {
+        for param in &function_decl.parameters {
+            let depth = param.type_argument.type_id.nesting_depth(engines);
+            if depth > limit {
+                handler.emit_err(CompileError::MonomorphizationRecursionLimit {
+                    name: param.name.clone(),
+                    ty: engines.help_out(param.type_argument.type_id).to_string(),
+                    depth,
+                    limit,
+                    type_span: param.type_argument.span.clone(),
+                    span: span.clone(),
+                });
+            }
+        }
+        Ok(())
+    })
+}
+
 pub(crate) fn check_function_arguments_arity(
     handler: &Handler,
     arguments_len: usize,
@@ -187,7 +725,7 @@ pub(crate) fn check_function_arguments_arity(
     match expected.cmp(&received) {
         std::cmp::Ordering::Equal => Ok(()),
         std::cmp::Ordering::Less => {
-            Err(handler.emit_err(CompileError::TooFewArgumentsForFunction {
+            Err(handler.emit_err(CompileError::TooManyArgumentsForFunction {
                 span: call_path.span(),
                 method_name: function_decl.name.clone(),
                 dot_syntax_used: is_method_call_syntax_used,
@@ -196,7 +734,7 @@ pub(crate) fn check_function_arguments_arity(
             }))
         }
         std::cmp::Ordering::Greater => {
-            Err(handler.emit_err(CompileError::TooManyArgumentsForFunction {
+            Err(handler.emit_err(CompileError::TooFewArgumentsForFunction {
                 span: call_path.span(),
                 method_name: function_decl.name.clone(),
                 dot_syntax_used: is_method_call_syntax_used,
@@ -1,6 +1,7 @@
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
 };
 use sway_types::Span;
 
@@ -25,10 +26,10 @@ pub(crate) fn instantiate_if_expression(
     let then_deterministically_aborts = then.deterministically_aborts(decl_engine, true);
     if !then_deterministically_aborts {
         // if this does not deterministically_abort, check the block return type
-        let ty_to_check = if r#else.is_some() {
-            ctx.type_annotation()
+        let (ty_to_check, expected_origin) = if r#else.is_some() {
+            (ctx.type_annotation(), ctx.expected_origin())
         } else {
-            type_engine.insert(engines, TypeInfo::Tuple(vec![]))
+            (type_engine.insert(engines, TypeInfo::Tuple(vec![])), None)
         };
         type_engine.unify_with_self(
             handler,
@@ -38,16 +39,22 @@ pub(crate) fn instantiate_if_expression(
             ctx.self_type(),
             &then.span,
             "`then` branch must return expected type.",
+            expected_origin,
             None,
         );
     }
     let mut else_deterministically_aborts = false;
     let r#else = r#else.map(|r#else| {
         else_deterministically_aborts = r#else.deterministically_aborts(decl_engine, true);
-        let ty_to_check = if then_deterministically_aborts {
-            ctx.type_annotation()
+        let (ty_to_check, expected_origin) = if then_deterministically_aborts {
+            (ctx.type_annotation(), ctx.expected_origin().cloned())
         } else {
-            then.return_type
+            (
+                then.return_type,
+                Some(ExpectedTypeOrigin::IfBranch {
+                    span: then.span.clone(),
+                }),
+            )
         };
         if !else_deterministically_aborts {
             // if this does not deterministically_abort, check the block return type
@@ -59,6 +66,7 @@ pub(crate) fn instantiate_if_expression(
                 ctx.self_type(),
                 &r#else.span,
                 "`else` branch must return expected type.",
+                expected_origin.as_ref(),
                 None,
             );
         }
@@ -82,6 +90,7 @@ pub(crate) fn instantiate_if_expression(
             &span,
             "The two branches of an if expression must return the same type.",
             None,
+            None,
         );
 
         let (new_errors, new_warnings) = h.consume();
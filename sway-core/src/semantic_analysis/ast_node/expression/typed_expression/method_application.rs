@@ -3,12 +3,17 @@ use crate::{
     language::{parsed::*, ty, *},
     semantic_analysis::*,
     type_system::*,
+    Engines,
+};
+use ast_node::typed_expression::{
+    check_argument_mutability, check_function_arguments_arity, check_monomorphization_depth,
+    coerce_arguments, coerce_arrays_to_slices, instantiate_struct_field_access,
 };
-use ast_node::typed_expression::check_function_arguments_arity;
 use std::collections::{HashMap, VecDeque};
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    type_error::TypeError,
 };
 use sway_types::{constants, integer_bits::IntegerBits};
 use sway_types::{constants::CONTRACT_CALL_COINS_PARAMETER_NAME, Spanned};
@@ -57,6 +62,14 @@ pub(crate) fn type_check_method_application(
         }));
     }
 
+    ctx.warn_if_deprecated(
+        handler,
+        "method",
+        &method.name,
+        &method.attributes,
+        &method_name_binding.inner.easy_name().span(),
+    );
+
     // check the function storage purity
     if !method.is_contract_call {
         // 'method.purity' is that of the callee, 'opts.purity' of the caller.
@@ -76,57 +89,47 @@ pub(crate) fn type_check_method_application(
     // generate the map of the contract call params
     let mut contract_call_params_map = HashMap::new();
     if method.is_contract_call {
-        for param_name in &[
-            constants::CONTRACT_CALL_GAS_PARAMETER_NAME,
-            constants::CONTRACT_CALL_COINS_PARAMETER_NAME,
-            constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME,
-        ] {
-            if contract_call_params
-                .iter()
-                .filter(|&param| param.name.span().as_str() == *param_name)
-                .count()
-                > 1
-            {
+        for param in contract_call_params {
+            let param_name = param.name.span().as_str().to_string();
+            if contract_call_params_map.contains_key(&param_name) {
                 handler.emit_err(CompileError::ContractCallParamRepeated {
-                    param_name: param_name.to_string(),
-                    span: span.clone(),
+                    param_name,
+                    span: param.name.span(),
                 });
+                continue;
             }
-        }
-
-        for param in contract_call_params {
-            match param.name.span().as_str() {
+            let typed_param = match param_name.as_str() {
                 constants::CONTRACT_CALL_GAS_PARAMETER_NAME
-                | constants::CONTRACT_CALL_COINS_PARAMETER_NAME
-                | constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME => {
-                    let type_annotation = type_engine.insert(
-                        engines,
-                        if param.name.span().as_str()
-                            != constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME
-                        {
-                            TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)
-                        } else {
-                            TypeInfo::B256
-                        },
-                    );
+                | constants::CONTRACT_CALL_COINS_PARAMETER_NAME => {
                     let ctx = ctx
                         .by_ref()
-                        .with_help_text("")
-                        .with_type_annotation(type_annotation);
-                    contract_call_params_map.insert(
-                        param.name.to_string(),
-                        ty::TyExpression::type_check(handler, ctx, param.value).unwrap_or_else(
-                            |err| ty::TyExpression::error(err, span.clone(), engines),
-                        ),
-                    );
+                        .with_help_text(
+                            "Contract call parameters \"gas\" and \"coins\" must be of type u64.",
+                        )
+                        .with_type_annotation(
+                            type_engine
+                                .insert(engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                        );
+                    ty::TyExpression::type_check(handler, ctx, param.value)
+                        .unwrap_or_else(|err| ty::TyExpression::error(err, span.clone(), engines))
                 }
+                constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME => type_check_b256_or_contract_id(
+                    handler,
+                    ctx.by_ref(),
+                    param.value,
+                    "Contract call parameter \"asset_id\" must be of type b256 or AssetId.",
+                )
+                .unwrap_or_else(|err| ty::TyExpression::error(err, span.clone(), engines)),
                 _ => {
                     handler.emit_err(CompileError::UnrecognizedContractParam {
-                        param_name: param.name.to_string(),
+                        suggestion: closest_contract_call_param_name(&param_name),
+                        param_name,
                         span: param.name.span().clone(),
                     });
+                    continue;
                 }
             };
+            contract_call_params_map.insert(param_name, typed_param);
         }
 
         // check if method is non-payable but we do not know _statically_
@@ -150,6 +153,8 @@ pub(crate) fn type_check_method_application(
                 );
             }
         }
+
+        insert_default_contract_call_params(engines, &mut contract_call_params_map, &span);
     }
 
     // If this function is being called with method call syntax, a.b(c),
@@ -202,11 +207,14 @@ pub(crate) fn type_check_method_application(
                     .resolve_symbol(&Handler::default(), name)
                     .cloned()?;
 
-                let is_decl_mutable = match unknown_decl {
-                    ty::TyDecl::ConstantDecl { .. } => false,
+                let (is_decl_mutable, decl_name) = match unknown_decl {
+                    ty::TyDecl::ConstantDecl(ty::ConstantDecl {
+                        name: constant_name,
+                        ..
+                    }) => (false, constant_name),
                     _ => {
                         let variable_decl = unknown_decl.expect_variable(handler).cloned()?;
-                        variable_decl.mutability.is_mutable()
+                        (variable_decl.mutability.is_mutable(), variable_decl.name)
                     }
                 };
 
@@ -214,6 +222,7 @@ pub(crate) fn type_check_method_application(
                     return Err(handler.emit_err(CompileError::MethodRequiresMutableSelf {
                         method_name: method_name_binding.inner.easy_name(),
                         variable_name: name.clone(),
+                        variable_span: decl_name.span(),
                         span: span.clone(),
                     }));
                 }
@@ -318,10 +327,17 @@ pub(crate) fn type_check_method_application(
         is_method_call_syntax_used,
     )?;
 
+    let args_buf: VecDeque<_> =
+        coerce_arguments(handler, ctx.by_ref(), args_buf.into(), &method)?.into();
+    let args_buf: VecDeque<_> =
+        coerce_arrays_to_slices(handler, ctx.by_ref(), args_buf.into(), &method)?.into();
+
     // unify the types of the arguments with the types of the parameters from the function declaration
     let typed_arguments_with_names =
         unify_arguments_and_parameters(handler, ctx.by_ref(), args_buf, &method.parameters)?;
 
+    check_monomorphization_depth(handler, &ctx, &method, &call_path.span())?;
+
     // Retrieve the implemented traits for the type of the return type and
     // insert them in the broader namespace.
     ctx.namespace
@@ -359,6 +375,257 @@ pub(crate) fn type_check_method_application(
     Ok(exp)
 }
 
+/// Type checks the call `T::from(arg)` of an already type-checked argument, which converts the
+/// argument into the type `T` of a parameter by using a `From` implementation of `T`.
+///
+/// Returns `None` if `T` cannot be converted from the type of the argument. It is an error if
+/// the conversion is ambiguous, because `T` implements `From` for more than one type that the
+/// argument can be coerced to.
+pub(crate) fn type_check_from_conversion(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    arg: ty::TyExpression,
+    param_type: &TypeArgument,
+) -> Result<Option<ty::TyExpression>, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    let span = arg.span.clone();
+    let mut method_name_binding = TypeBinding {
+        inner: MethodName::FromType {
+            call_path_binding: TypeBinding {
+                inner: CallPath {
+                    prefixes: vec![],
+                    suffix: (
+                        type_engine.get(param_type.type_id),
+                        Ident::new(param_type.span.clone()),
+                    ),
+                    is_absolute: false,
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: param_type.span.clone(),
+            },
+            method_name: Ident::new_with_override("from".to_string(), span.clone()),
+        },
+        type_arguments: TypeArgs::Regular(vec![]),
+        span: span.clone(),
+    };
+
+    // If there is no conversion, the mismatch between the argument and the parameter is reported
+    // when they are unified, so only the ambiguity errors are kept.
+    let resolve_handler = Handler::default();
+    let (decl_ref, call_path_typeid) = match resolve_method_name(
+        &resolve_handler,
+        ctx.by_ref().with_type_annotation(param_type.type_id),
+        &mut method_name_binding,
+        VecDeque::from([arg.clone()]),
+    ) {
+        Ok(resolved) => resolved,
+        Err(_) => {
+            let (errors, _) = resolve_handler.consume();
+            return errors
+                .into_iter()
+                .filter(|err| matches!(err, CompileError::MultipleApplicableItemsInScope { .. }))
+                .map(|err| handler.emit_err(err))
+                .last()
+                .map_or(Ok(None), Err);
+        }
+    };
+    let mut method = decl_engine.get_function(&decl_ref);
+
+    // When no `from` method accepts the argument, the first `from` method is returned.
+    let accepts_argument = matches!(method.parameters.as_slice(), [param]
+        if UnifyCheck::coercion(engines).check(param.type_argument.type_id, arg.return_type));
+    if !accepts_argument {
+        return Ok(None);
+    }
+
+    let typed_arguments_with_names = unify_arguments_and_parameters(
+        handler,
+        ctx.by_ref(),
+        VecDeque::from([arg]),
+        &method.parameters,
+    )?;
+
+    ctx.namespace
+        .insert_trait_implementation_for_type(engines, method.return_type.type_id);
+
+    let decl_mapping = TypeParameter::gather_decl_mapping_from_trait_constraints(
+        handler,
+        &ctx,
+        &method.type_parameters,
+        &span,
+    )?;
+    method.replace_decls(&decl_mapping, handler, &ctx)?;
+    let call_path = CallPath {
+        prefixes: vec![],
+        suffix: method.name.clone(),
+        is_absolute: false,
+    };
+    let return_type = method.return_type.type_id;
+    let new_decl_ref = decl_engine
+        .insert(method)
+        .with_parent(decl_engine, (*decl_ref.id()).into());
+
+    Ok(Some(ty::TyExpression {
+        expression: ty::TyExpressionVariant::FunctionApplication {
+            call_path,
+            contract_call_params: HashMap::new(),
+            arguments: typed_arguments_with_names,
+            fn_ref: new_decl_ref,
+            selector: None,
+            type_binding: Some(method_name_binding.strip_inner()),
+            call_path_typeid: Some(call_path_typeid),
+        },
+        return_type,
+        span,
+    }))
+}
+
+/// Type checks an expression that can be either a `b256` or a `ContractId`, like `AssetId`, as
+/// the `asset_id` contract call parameter or the address in an ABI cast. A `ContractId` is
+/// replaced by the `b256` it wraps.
+pub(crate) fn type_check_b256_or_contract_id(
+    handler: &Handler,
+    ctx: TypeCheckContext,
+    value: Expression,
+    help_text: &str,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    // Only the `ContractId` of the standard library is accepted, not any struct of that name.
+    let std_contract_id_call_path = CallPath {
+        prefixes: vec![
+            Ident::new_no_span("std".to_string()),
+            Ident::new_no_span("contract_id".to_string()),
+        ],
+        suffix: Ident::new_no_span("ContractId".to_string()),
+        is_absolute: true,
+    };
+    let std_contract_id_call_path = match ctx.namespace.root().resolve_call_path(
+        &Handler::default(),
+        &[],
+        &std_contract_id_call_path,
+    ) {
+        Ok(ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. })) => {
+            Some(decl_engine.get_struct(decl_id).call_path)
+        }
+        _ => None,
+    };
+
+    let ctx = ctx
+        .with_help_text("")
+        .with_type_annotation(type_engine.insert(engines, TypeInfo::Unknown));
+    let typed_value = ty::TyExpression::type_check(handler, ctx, value)?;
+
+    match type_engine.get_unaliased(typed_value.return_type) {
+        TypeInfo::B256 | TypeInfo::ErrorRecovery(_) => Ok(typed_value),
+        TypeInfo::Struct(decl_ref)
+            if std_contract_id_call_path.as_ref()
+                == Some(&decl_engine.get_struct(&decl_ref).call_path) =>
+        {
+            let span = typed_value.span.clone();
+            instantiate_struct_field_access(
+                handler,
+                engines,
+                typed_value,
+                Ident::new_no_span("value".to_string()),
+                span,
+            )
+        }
+        _ => Err(
+            handler.emit_err(CompileError::TypeError(TypeError::MismatchedType {
+                expected: "b256".to_string(),
+                received: typed_value.return_type.to_diagnostic_string(engines, None),
+                help_text: help_text.to_string(),
+                span: typed_value.span,
+                expected_origin: None,
+            })),
+        ),
+    }
+}
+
+/// Inserts the default values of the contract call parameters that are not given explicitly, so
+/// that the typed contract call always carries the complete set of parameters.
+fn insert_default_contract_call_params(
+    engines: &Engines,
+    contract_call_params: &mut HashMap<String, ty::TyExpression>,
+    span: &Span,
+) {
+    let type_engine = engines.te();
+    let u64_type = type_engine.insert(engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+
+    contract_call_params
+        .entry(constants::CONTRACT_CALL_COINS_PARAMETER_NAME.to_string())
+        .or_insert_with(|| ty::TyExpression {
+            expression: ty::TyExpressionVariant::Literal(Literal::U64(
+                constants::CONTRACT_CALL_COINS_PARAMETER_DEFAULT_VALUE,
+            )),
+            return_type: u64_type,
+            span: span.clone(),
+        });
+    contract_call_params
+        .entry(constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME.to_string())
+        .or_insert_with(|| ty::TyExpression {
+            expression: ty::TyExpressionVariant::Literal(Literal::B256(
+                constants::CONTRACT_CALL_ASSET_ID_PARAMETER_DEFAULT_VALUE,
+            )),
+            return_type: type_engine.insert(engines, TypeInfo::B256),
+            span: span.clone(),
+        });
+    // By default, all the remaining gas is forwarded to the called contract.
+    contract_call_params
+        .entry(constants::CONTRACT_CALL_GAS_PARAMETER_NAME.to_string())
+        .or_insert_with(|| ty::TyExpression {
+            expression: ty::TyExpressionVariant::AsmExpression {
+                registers: vec![],
+                body: vec![],
+                returns: Some((
+                    AsmRegister {
+                        name: "cgas".to_string(),
+                    },
+                    Span::from_string("cgas".to_string()),
+                )),
+                whole_block_span: span.clone(),
+            },
+            return_type: u64_type,
+            span: span.clone(),
+        });
+}
+
+/// Returns the contract call parameter name closest to the unrecognized `param_name`, if any is
+/// close enough to be a likely typo.
+fn closest_contract_call_param_name(param_name: &str) -> Option<&'static str> {
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b = b.chars().collect::<Vec<_>>();
+        let mut row = (0..=b.len()).collect::<Vec<_>>();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = diagonal + usize::from(a_char != *b_char);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+            }
+        }
+        row[b.len()]
+    }
+
+    [
+        constants::CONTRACT_CALL_GAS_PARAMETER_NAME,
+        constants::CONTRACT_CALL_COINS_PARAMETER_NAME,
+        constants::CONTRACT_CALL_ASSET_ID_PARAMETER_NAME,
+    ]
+    .into_iter()
+    .map(|name| (edit_distance(param_name, name), name))
+    .filter(|(distance, name)| *distance <= name.len() / 2)
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, name)| name)
+}
+
 /// Unifies the types of the arguments with the types of the parameters. Returns
 /// a list of the arguments with the names of the corresponding parameters.
 fn unify_arguments_and_parameters(
@@ -373,6 +640,12 @@ fn unify_arguments_and_parameters(
 
     handler.scope(|handler| {
         for (arg, param) in arguments.into_iter().zip(parameters.iter()) {
+            // The argument cannot be checked against a type which failed to resolve.
+            if param.resolution_failed {
+                typed_arguments_and_names.push((param.name.clone(), arg));
+                continue;
+            }
+
             // unify the type of the argument with the type of the param
             let unify_res = handler.scope(|handler| {
                 type_engine.unify_with_self(
@@ -383,10 +656,14 @@ fn unify_arguments_and_parameters(
                     ctx.self_type(),
                     &arg.span,
                     "This argument's type is not castable to the declared parameter type.",
+                    None,
                     Some(CompileError::ArgumentParameterTypeMismatch {
                         span: arg.span.clone(),
-                        provided: engines.help_out(arg.return_type).to_string(),
-                        should_be: engines.help_out(param.type_argument.type_id).to_string(),
+                        provided: arg.return_type.to_diagnostic_string(engines, None),
+                        should_be: param
+                            .type_argument
+                            .type_id
+                            .to_diagnostic_string(engines, None),
                     }),
                 );
                 Ok(())
@@ -395,6 +672,11 @@ fn unify_arguments_and_parameters(
                 continue;
             }
 
+            // the mutability of `self` is checked separately
+            if !param.is_self() {
+                check_argument_mutability(handler, &ctx, &arg, param);
+            }
+
             typed_arguments_and_names.push((param.name.clone(), arg));
         }
         Ok(typed_arguments_and_names)
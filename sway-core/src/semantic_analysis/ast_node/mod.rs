@@ -47,6 +47,7 @@ impl ty::TyAstNode {
                                 &path,
                                 engines,
                                 a.is_absolute,
+                                &node.span,
                             );
                             if import.is_ok() {
                                 handler.append(star_import_handler);
@@ -61,6 +62,7 @@ impl ty::TyAstNode {
                                         engines,
                                         enum_name,
                                         a.is_absolute,
+                                        &node.span,
                                     );
                                     if variant_import.is_ok() {
                                         handler.append(variant_import_handler);
@@ -97,6 +99,15 @@ impl ty::TyAstNode {
                             if import.is_ok() {
                                 handler.append(item_import_handler);
                                 import
+                            } else if ctx
+                                .namespace
+                                .root()
+                                .submodule(&[path.clone(), vec![s.clone()]].concat())
+                                .is_some()
+                            {
+                                // if it doesn't work it could be a module import
+                                ctx.namespace
+                                    .module_import(handler, &path, s, a.alias.clone())
                             } else {
                                 // if it doesn't work it could be an enum variant import
                                 if let Some((enum_name, path)) = path.split_last() {
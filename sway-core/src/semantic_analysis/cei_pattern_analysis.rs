@@ -304,6 +304,7 @@ fn analyze_expression(
         | TupleElemAccess { prefix: expr, .. }
         | Return(expr)
         | EnumTag { exp: expr }
+        | ArrayToSlice { array: expr }
         | UnsafeDowncast { exp: expr, .. }
         | AbiCast { address: expr, .. } => analyze_expression(engines, expr, block_name, warnings),
         EnumInstantiation { contents, .. } => match contents {
@@ -554,6 +555,7 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         StructFieldAccess { prefix: expr, .. }
         | TupleElemAccess { prefix: expr, .. }
         | EnumTag { exp: expr }
+        | ArrayToSlice { array: expr }
         | UnsafeDowncast { exp: expr, .. }
         | Return(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
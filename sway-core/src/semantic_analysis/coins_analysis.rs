@@ -75,6 +75,7 @@ pub fn possibly_nonzero_u64_expression(
         | AbiName(_)
         | UnsafeDowncast { .. }
         | EnumTag { .. }
+        | ArrayToSlice { .. }
         | Break
         | Continue
         | Reassignment(_)
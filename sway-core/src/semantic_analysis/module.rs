@@ -1,7 +1,13 @@
-use sway_error::handler::{ErrorEmitted, Handler};
+use petgraph::{algo::tarjan_scc, prelude::NodeIndex, Graph};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_types::Span;
 
 use crate::{
     language::{parsed::*, ty, ModName},
+    namespace::{Path, PathBuf},
     semantic_analysis::*,
 };
 
@@ -22,10 +28,13 @@ impl ty::TyModule {
             ..
         } = parsed;
 
-        // Type-check submodules first in order of declaration.
-        let submodules_res = submodules
-            .iter()
-            .map(|(name, submodule)| {
+        // Type-check submodules first in order of dependency.
+        let submodules_order =
+            order_submodules_by_dependency(handler, ctx.namespace.mod_path(), submodules)?;
+        let submodules_res = submodules_order
+            .into_iter()
+            .map(|index| {
+                let (name, submodule) = &submodules[index];
                 Ok((
                     name.clone(),
                     ty::TySubmodule::type_check(handler, ctx.by_ref(), name.clone(), submodule)?,
@@ -89,3 +98,136 @@ impl ty::TySubmodule {
         })
     }
 }
+
+/// Order the `submodules` of the module at `mod_path` so that each submodule is type-checked after
+/// the sibling submodules it imports from, and otherwise in order of declaration.
+///
+/// Returns the indices of the `submodules` in the order in which they should be type-checked.
+/// Submodules that import from each other cannot be ordered, and each such cycle is reported with a
+/// [CompileError::ModuleDependencyCycle].
+fn order_submodules_by_dependency(
+    handler: &Handler,
+    mod_path: &Path,
+    submodules: &[(ModName, ParseSubmodule)],
+) -> Result<Vec<usize>, ErrorEmitted> {
+    // An edge from `a` to `b` means that `a` imports from `b`, and is labeled with the span of the
+    // first `use` statement that does so.
+    let mut graph = Graph::<usize, Span>::new();
+    let nodes: Vec<NodeIndex> = (0..submodules.len()).map(|i| graph.add_node(i)).collect();
+    for (i, (name, submodule)) in submodules.iter().enumerate() {
+        let submod_path: PathBuf = mod_path.iter().chain(Some(name)).cloned().collect();
+        let mut imports = vec![];
+        gather_imported_module_paths(&submod_path, &submodule.module, &mut imports);
+        for (import_path, use_span) in imports {
+            if import_path.len() <= mod_path.len() || !import_path.starts_with(mod_path) {
+                continue;
+            }
+            let sibling = &import_path[mod_path.len()];
+            if let Some(j) = submodules.iter().position(|(name, _)| name == sibling) {
+                if i != j && !graph.contains_edge(nodes[i], nodes[j]) {
+                    graph.add_edge(nodes[i], nodes[j], use_span);
+                }
+            }
+        }
+    }
+
+    let mut cycles: Vec<Vec<NodeIndex>> = tarjan_scc(&graph)
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .collect();
+    if !cycles.is_empty() {
+        cycles.sort_by_key(|component| component.iter().min().cloned());
+        let mut error_emitted = None;
+        for component in cycles {
+            let cycle = find_cycle(&graph, &component);
+            let module_name = |node: NodeIndex| {
+                mod_path
+                    .iter()
+                    .chain(Some(&submodules[graph[node]].0))
+                    .map(|ident| ident.as_str())
+                    .collect::<Vec<_>>()
+                    .join("::")
+            };
+            let use_spans = cycle
+                .iter()
+                .zip(cycle.iter().cycle().skip(1))
+                .map(|(a, b)| graph[graph.find_edge(*a, *b).unwrap()].clone())
+                .collect();
+            error_emitted = Some(handler.emit_err(CompileError::ModuleDependencyCycle {
+                modules: cycle.into_iter().map(module_name).collect(),
+                use_spans,
+            }));
+        }
+        return Err(error_emitted.unwrap());
+    }
+
+    // Repeatedly pick the first declared submodule whose dependencies are all ordered already.
+    let mut order = Vec::with_capacity(submodules.len());
+    let mut is_ordered = vec![false; submodules.len()];
+    while order.len() < submodules.len() {
+        let next = nodes
+            .iter()
+            .find(|node| {
+                !is_ordered[graph[**node]]
+                    && graph
+                        .neighbors(**node)
+                        .all(|dependency| is_ordered[graph[dependency]])
+            })
+            .expect("the dependency graph has no cycles");
+        is_ordered[graph[*next]] = true;
+        order.push(graph[*next]);
+    }
+    Ok(order)
+}
+
+/// Find the shortest cycle through the first declared submodule of the strongly connected
+/// `component`, which has to contain more than one submodule.
+fn find_cycle(graph: &Graph<usize, Span>, component: &[NodeIndex]) -> Vec<NodeIndex> {
+    let start = *component.iter().min().unwrap();
+    let mut predecessors = vec![None; graph.node_count()];
+    let mut queue = std::collections::VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for neighbor in graph.neighbors(node) {
+            if neighbor == start {
+                let mut cycle = vec![node];
+                while let Some(predecessor) = predecessors[cycle[cycle.len() - 1].index()] {
+                    cycle.push(predecessor);
+                }
+                cycle.reverse();
+                return cycle;
+            }
+            if component.contains(&neighbor) && predecessors[neighbor.index()].is_none() {
+                predecessors[neighbor.index()] = Some(node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    unreachable!("a strongly connected component with more than one node contains a cycle")
+}
+
+/// Gather the paths of the modules imported by the `use` statements of the module at `mod_path`
+/// and of all of its submodules, along with the spans of those `use` statements.
+fn gather_imported_module_paths(
+    mod_path: &Path,
+    module: &ParseModule,
+    imports: &mut Vec<(PathBuf, Span)>,
+) {
+    for node in &module.tree.root_nodes {
+        if let AstNodeContent::UseStatement(use_stmt) = &node.content {
+            let import_path = if use_stmt.is_absolute {
+                use_stmt.call_path.clone()
+            } else {
+                mod_path
+                    .iter()
+                    .chain(&use_stmt.call_path)
+                    .cloned()
+                    .collect()
+            };
+            imports.push((import_path, node.span.clone()));
+        }
+    }
+    for (name, submodule) in &module.submodules {
+        let submod_path: PathBuf = mod_path.iter().chain(Some(name)).cloned().collect();
+        gather_imported_module_paths(&submod_path, &submodule.module, imports);
+    }
+}
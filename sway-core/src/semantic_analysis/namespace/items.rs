@@ -16,7 +16,7 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{span::Span, Spanned};
+use sway_types::{span::Span, style::find_similar_name, Spanned};
 
 use std::sync::Arc;
 
@@ -30,6 +30,8 @@ pub(crate) enum GlobImport {
 pub(super) type SymbolMap = im::OrdMap<Ident, ty::TyDecl>;
 pub(super) type UseSynonyms = im::HashMap<Ident, (Vec<Ident>, GlobImport, ty::TyDecl, bool)>;
 pub(super) type UseAliases = im::HashMap<String, Ident>;
+pub(super) type UseModuleSynonyms = im::HashMap<Ident, PathBuf>;
+pub(super) type GlobImportSources = im::HashMap<Ident, Vec<(PathBuf, ty::TyDecl, Span)>>;
 
 /// The set of items that exist within some lexical scope via declaration or importing.
 #[derive(Clone, Debug, Default)]
@@ -47,11 +49,60 @@ pub struct Items {
     /// Aliases are introduced with syntax like `use foo::bar as baz;` syntax, where `baz` is an
     /// alias for `bar`.
     pub(crate) use_aliases: UseAliases,
+    /// Represents the absolute path of a module imported under a local name.
+    ///
+    /// For example, in `use foo::bar as baz;` where `bar` is a submodule of `foo`, we store a
+    /// mapping from `baz` to the path `foo::bar`, so that `baz::qux` resolves to `foo::bar::qux`.
+    pub(crate) use_module_synonyms: UseModuleSynonyms,
+    /// Represents every module a symbol was glob-imported from, along with the imported
+    /// declaration and the span of the `use` statement that imported it.
+    ///
+    /// Two glob imports bringing different declarations under the same name are not an error on
+    /// their own. The ambiguity is only reported if the symbol is actually used.
+    ///
+    /// Prelude imports are not tracked here, so glob imports take precedence over them.
+    pub(crate) glob_import_sources: GlobImportSources,
     /// If there is a storage declaration (which are only valid in contracts), store it here.
     pub(crate) declared_storage: Option<DeclRefStorage>,
 }
 
+/// The names declared in or imported into a scope at some point of type-checking.
+///
+/// The maps of names are persistent, so taking a snapshot is cheap, and two snapshots share the
+/// maps unless a name has been declared or imported in between.
+#[derive(Clone)]
+pub(crate) struct NamesSnapshot {
+    symbols: SymbolMap,
+    use_synonyms: UseSynonyms,
+    use_aliases: UseAliases,
+    use_module_synonyms: UseModuleSynonyms,
+    glob_import_sources: GlobImportSources,
+}
+
+impl NamesSnapshot {
+    /// Returns true if no name has been declared in or imported into the scope of the `items`
+    /// since the snapshot was taken. A false result does not imply that the names have changed.
+    pub(crate) fn is_current_in(&self, items: &Items) -> bool {
+        self.symbols.ptr_eq(&items.symbols)
+            && self.use_synonyms.ptr_eq(&items.use_synonyms)
+            && self.use_aliases.ptr_eq(&items.use_aliases)
+            && self.use_module_synonyms.ptr_eq(&items.use_module_synonyms)
+            && self.glob_import_sources.ptr_eq(&items.glob_import_sources)
+    }
+}
+
 impl Items {
+    /// Takes a snapshot of the names currently declared in or imported into this scope.
+    pub(crate) fn names_snapshot(&self) -> NamesSnapshot {
+        NamesSnapshot {
+            symbols: self.symbols.clone(),
+            use_synonyms: self.use_synonyms.clone(),
+            use_aliases: self.use_aliases.clone(),
+            use_module_synonyms: self.use_module_synonyms.clone(),
+            glob_import_sources: self.glob_import_sources.clone(),
+        }
+    }
+
     /// Immutable access to the inner symbol map.
     pub fn symbols(&self) -> &SymbolMap {
         &self.symbols
@@ -90,8 +141,9 @@ impl Items {
         handler: &Handler,
         decl_ref: DeclRefStorage,
     ) -> Result<(), ErrorEmitted> {
-        if self.declared_storage.is_some() {
+        if let Some(previous_decl_ref) = &self.declared_storage {
             return Err(handler.emit_err(CompileError::MultipleStorageDeclarations {
+                previous_span: previous_decl_ref.span(),
                 span: decl_ref.span(),
             }));
         }
@@ -103,6 +155,42 @@ impl Items {
         self.symbols().keys()
     }
 
+    /// Returns the names of all the types declared in or imported into this scope.
+    ///
+    /// Generic type parameters are not included.
+    pub(crate) fn get_all_type_names(&self) -> impl Iterator<Item = &str> {
+        self.get_all_names_of(|decl| {
+            matches!(
+                decl,
+                TyDecl::StructDecl(_) | TyDecl::EnumDecl(_) | TyDecl::TypeAliasDecl(_)
+            )
+        })
+    }
+
+    /// Returns the names of all the variables and constants declared in or imported into this
+    /// scope.
+    pub(crate) fn get_all_variable_names(&self) -> impl Iterator<Item = &str> {
+        self.get_all_names_of(|decl| {
+            matches!(decl, TyDecl::VariableDecl(_) | TyDecl::ConstantDecl(_))
+        })
+    }
+
+    fn get_all_names_of(
+        &self,
+        filter: impl Fn(&TyDecl) -> bool + Copy,
+    ) -> impl Iterator<Item = &str> {
+        self.symbols
+            .iter()
+            .filter(move |(_, decl)| filter(decl))
+            .map(|(name, _)| name.as_str())
+            .chain(
+                self.use_synonyms
+                    .iter()
+                    .filter(move |(_, (_, _, decl, _))| filter(decl))
+                    .map(|(name, _)| name.as_str()),
+            )
+    }
+
     pub(crate) fn insert_symbol(
         &mut self,
         handler: &Handler,
@@ -179,6 +267,7 @@ impl Items {
                     ) => {
                         handler.emit_err(CompileError::MultipleDefinitionsOfConstant {
                             name: name.clone(),
+                            previous_span: ident.span(),
                             span: name.span(),
                         });
                     }
@@ -203,6 +292,7 @@ impl Items {
                     ) => {
                         handler.emit_err(CompileError::MultipleDefinitionsOfName {
                             name: name.clone(),
+                            previous_span: ident.span(),
                             span: name.span(),
                         });
                     }
@@ -243,6 +333,70 @@ impl Items {
         Ok(())
     }
 
+    /// Insert a synonym for `symbol` introduced by a glob import from the module at `src`.
+    ///
+    /// Explicit imports always take precedence over glob imports, so an existing explicit
+    /// synonym is never replaced.
+    pub(crate) fn insert_glob_use_synonym(
+        &mut self,
+        symbol: Ident,
+        src: PathBuf,
+        decl: ty::TyDecl,
+        is_src_absolute: bool,
+        use_span: Span,
+    ) {
+        if let Some((_, GlobImport::No, _, _)) = self.use_synonyms.get(&symbol) {
+            return;
+        }
+        let sources = self.glob_import_sources.entry(symbol.clone()).or_default();
+        if !sources.iter().any(|(path, _, _)| *path == src) {
+            sources.push((src.clone(), decl.clone(), use_span));
+        }
+        self.use_synonyms
+            .insert(symbol, (src, GlobImport::Yes, decl, is_src_absolute));
+    }
+
+    /// Report an error if `symbol` is glob-imported from more than one module with different
+    /// declarations and isn't disambiguated by an explicit import.
+    pub(crate) fn check_glob_import_ambiguity(
+        &self,
+        handler: &Handler,
+        symbol: &Ident,
+    ) -> Result<(), ErrorEmitted> {
+        if !matches!(
+            self.use_synonyms.get(symbol),
+            Some((_, GlobImport::Yes, _, _))
+        ) {
+            return Ok(());
+        }
+        let sources = match self.glob_import_sources.get(symbol) {
+            Some(sources) if sources.len() > 1 => sources,
+            _ => return Ok(()),
+        };
+        let first_decl_span = sources[0].1.span();
+        if sources
+            .iter()
+            .all(|(_, decl, _)| decl.span() == first_decl_span)
+        {
+            return Ok(());
+        }
+        Err(handler.emit_err(CompileError::AmbiguousGlobImport {
+            name: symbol.clone(),
+            span: symbol.span(),
+            sources: sources
+                .iter()
+                .map(|(path, _, use_span)| {
+                    let path = path
+                        .iter()
+                        .map(|ident| ident.as_str())
+                        .collect::<Vec<_>>()
+                        .join("::");
+                    (path, use_span.clone())
+                })
+                .collect(),
+        }))
+    }
+
     pub(crate) fn check_symbol(&self, name: &Ident) -> Result<&ty::TyDecl, CompileError> {
         self.symbols
             .get(name)
@@ -337,6 +491,11 @@ impl Items {
             None => {
                 return Err(handler.emit_err(CompileError::UnknownVariable {
                     var_name: base_name.clone(),
+                    suggestion: find_similar_name(
+                        base_name.as_str(),
+                        self.get_all_variable_names(),
+                    )
+                    .map(str::to_string),
                     span: base_name.span(),
                 }));
             }
@@ -388,6 +547,11 @@ impl Items {
                                 field_name: field_name.clone(),
                                 struct_name: struct_decl.call_path.suffix,
                                 available_fields: available_fields.join(", "),
+                                suggestion: find_similar_name(
+                                    field_name.as_str(),
+                                    available_fields,
+                                )
+                                .map(str::to_string),
                                 span: field_name.span(),
                             }));
                         }
@@ -430,6 +594,7 @@ impl Items {
                     symbol_span = index_span.clone();
                     full_span_for_error = index_span.clone();
                 }
+                (TypeInfo::ErrorRecovery(err), _) => return Err(err),
                 (actually, ty::ProjectionKind::StructField { .. }) => {
                     return Err(handler.emit_err(CompileError::FieldAccessOnNonStruct {
                         span: full_span_for_error,
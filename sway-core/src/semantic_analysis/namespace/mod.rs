@@ -7,6 +7,7 @@ mod submodule_namespace;
 mod trait_map;
 
 pub use items::Items;
+pub(crate) use items::NamesSnapshot;
 pub use module::Module;
 pub use namespace::Namespace;
 pub use root::Root;
@@ -240,6 +240,7 @@ impl Module {
         dst: &Path,
         engines: &Engines,
         is_src_absolute: bool,
+        use_span: &Span,
     ) -> Result<(), ErrorEmitted> {
         self.check_module_privacy(handler, src, dst)?;
 
@@ -259,15 +260,13 @@ impl Module {
         dst_ns
             .implemented_traits
             .extend(implemented_traits, engines);
-        for symbol_and_decl in symbols_and_decls {
-            dst_ns.use_synonyms.insert(
-                symbol_and_decl.0,
-                (
-                    src.to_vec(),
-                    GlobImport::Yes,
-                    symbol_and_decl.1,
-                    is_src_absolute,
-                ),
+        for (symbol, decl) in symbols_and_decls {
+            dst_ns.insert_glob_use_synonym(
+                symbol,
+                src.to_vec(),
+                decl,
+                is_src_absolute,
+                use_span.clone(),
             );
         }
 
@@ -457,6 +456,37 @@ impl Module {
         Ok(())
     }
 
+    /// Import the submodule `item` of the `src` module into the `dst` module, so that its items
+    /// can be referred to through `item`, or through `alias` if one is given.
+    ///
+    /// Paths are assumed to be relative to `self`.
+    pub(crate) fn module_import(
+        &mut self,
+        handler: &Handler,
+        src: &Path,
+        item: &Ident,
+        dst: &Path,
+        alias: Option<Ident>,
+    ) -> Result<(), ErrorEmitted> {
+        let module_path: PathBuf = src.iter().chain(Some(item)).cloned().collect();
+        self.check_module_privacy(handler, &module_path, dst)?;
+        self.check_submodule(handler, &module_path)?;
+
+        let name = alias.unwrap_or_else(|| item.clone());
+        let dst_ns = &mut self[dst];
+        let shadows_other_module = dst_ns.submodules.contains_key(name.as_str())
+            || matches!(
+                dst_ns.use_module_synonyms.get(&name),
+                Some(path) if *path != module_path
+            );
+        if shadows_other_module {
+            return Err(handler.emit_err(CompileError::ShadowsOtherSymbol { name }));
+        }
+        dst_ns.use_module_synonyms.insert(name, module_path);
+
+        Ok(())
+    }
+
     /// Pull a single variant `variant` from the enum `enum_name` from the given `src` module and import it into the `dst` module.
     ///
     /// Paths are assumed to be relative to `self`.
@@ -560,6 +590,7 @@ impl Module {
     /// Pull all variants from the enum `enum_name` from the given `src` module and import them all into the `dst` module.
     ///
     /// Paths are assumed to be relative to `self`.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn variant_star_import(
         &mut self,
         handler: &Handler,
@@ -568,6 +599,7 @@ impl Module {
         engines: &Engines,
         enum_name: &Ident,
         is_src_absolute: bool,
+        use_span: &Span,
     ) -> Result<(), ErrorEmitted> {
         self.check_module_privacy(handler, src, dst)?;
 
@@ -601,18 +633,16 @@ impl Module {
 
                         // import it this way.
                         let dst_ns = &mut self[dst];
-                        dst_ns.use_synonyms.insert(
+                        dst_ns.insert_glob_use_synonym(
                             variant_name.clone(),
-                            (
-                                src.to_vec(),
-                                GlobImport::Yes,
-                                TyDecl::EnumVariantDecl(ty::EnumVariantDecl {
-                                    enum_ref: enum_ref.clone(),
-                                    variant_name,
-                                    variant_decl_span: variant_decl.span.clone(),
-                                }),
-                                is_src_absolute,
-                            ),
+                            src.to_vec(),
+                            TyDecl::EnumVariantDecl(ty::EnumVariantDecl {
+                                enum_ref: enum_ref.clone(),
+                                variant_name,
+                                variant_decl_span: variant_decl.span.clone(),
+                            }),
+                            is_src_absolute,
+                            use_span.clone(),
                         );
                     }
                 } else {
@@ -12,9 +12,12 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{span::Span, Spanned};
+use sway_types::{span::Span, style::find_similar_name, Spanned};
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// The maximum number of traits suggested for import when a method is not found.
+const MAX_IMPORTABLE_TRAITS: usize = 3;
 
 /// The set of items that represent the namespace context passed throughout type checking.
 #[derive(Clone, Debug)]
@@ -62,7 +65,8 @@ impl Namespace {
         &'a self,
         prefixes: impl IntoIterator<Item = &'a Ident>,
     ) -> PathBuf {
-        self.mod_path.iter().chain(prefixes).cloned().collect()
+        let prefixes: Vec<_> = prefixes.into_iter().cloned().collect();
+        self.root.resolve_module_path(&self.mod_path, &prefixes)
     }
 
     /// A reference to the root of the project namespace.
@@ -272,6 +276,14 @@ impl Namespace {
         let eq_check = UnifyCheck::non_dynamic_equality(engines);
         let coercion_check = UnifyCheck::coercion(engines);
 
+        // The error of a type that failed to resolve has already been reported. Don't look up
+        // methods on it, because it coerces to any parameter type and would match any method.
+        for type_id in std::iter::once(type_id).chain(args_buf.get(0).map(|arg| arg.return_type)) {
+            if let TypeInfo::ErrorRecovery(err) = type_engine.get(type_id) {
+                return Err(err);
+            }
+        }
+
         // default numeric types to u64
         if type_engine.contains_numeric(decl_engine, type_id) {
             type_engine.decay_numeric(handler, engines, type_id, &method_name.span())?;
@@ -451,42 +463,227 @@ impl Namespace {
             return Ok(method_decl_ref);
         }
 
-        if let Some(TypeInfo::ErrorRecovery(err)) =
-            args_buf.get(0).map(|x| type_engine.get(x.return_type))
+        if let Some(method_decl_ref) = self.find_method_for_wrapped_type(
+            handler,
+            type_id,
+            method_prefix,
+            method_name,
+            args_buf,
+            engines,
+        )? {
+            return Ok(method_decl_ref);
+        }
+
+        if try_inserting_trait_impl_on_failure {
+            // Retrieve the implemented traits for the type and insert them in the namespace.
+            // insert_trait_implementation_for_type is already called when we do type check of structs, enums, arrays and tuples.
+            // In cases such as blanket trait implementation and usage of builtin types a method may not be found because
+            // insert_trait_implementation_for_type has yet to be called for that type.
+            self.insert_trait_implementation_for_type(engines, type_id);
+
+            return self.find_method_for_type(
+                handler,
+                type_id,
+                method_prefix,
+                method_name,
+                self_type,
+                annotation_type,
+                args_buf,
+                as_trait,
+                engines,
+                false,
+            );
+        }
+        if let TypeInfo::ContractCaller {
+            abi_name: AbiName::Known(abi_name),
+            ..
+        } = type_engine.get(type_id)
         {
-            Err(err)
+            if let Ok(ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. })) =
+                self.resolve_call_path(&Handler::default(), &abi_name)
+            {
+                let abi_decl = decl_engine.get_abi(decl_id);
+                let abi_method_names = abi_decl
+                    .interface_surface
+                    .iter()
+                    .filter_map(|item| match item {
+                        ty::TyTraitInterfaceItem::TraitFn(decl_ref) => Some(decl_ref.name()),
+                        ty::TyTraitInterfaceItem::Constant(_) => None,
+                    })
+                    .chain(abi_decl.items.iter().filter_map(|item| match item {
+                        ty::TyTraitItem::Fn(decl_ref) => Some(decl_ref.name()),
+                        ty::TyTraitItem::Constant(_) => None,
+                    }))
+                    .map(Ident::as_str);
+                return Err(handler.emit_err(CompileError::AbiMethodNotFound {
+                    method_name: method_name.clone(),
+                    abi_name: abi_name.suffix.clone(),
+                    suggestion: find_similar_name(method_name.as_str(), abi_method_names)
+                        .map(str::to_string),
+                    span: method_name.span(),
+                }));
+            }
+        }
+        let type_name = if let Some(call_path) = qualified_call_path {
+            format!("{} as {}", engines.help_out(type_id), call_path)
         } else {
-            if try_inserting_trait_impl_on_failure {
-                // Retrieve the implemented traits for the type and insert them in the namespace.
-                // insert_trait_implementation_for_type is already called when we do type check of structs, enums, arrays and tuples.
-                // In cases such as blanket trait implementation and usage of builtin types a method may not be found because
-                // insert_trait_implementation_for_type has yet to be called for that type.
-                self.insert_trait_implementation_for_type(engines, type_id);
-
-                return self.find_method_for_type(
-                    handler,
-                    type_id,
-                    method_prefix,
-                    method_name,
-                    self_type,
-                    annotation_type,
-                    args_buf,
-                    as_trait,
-                    engines,
-                    false,
-                );
+            engines.help_out(type_id).to_string()
+        };
+        Err(handler.emit_err(CompileError::MethodNotFound {
+            method_name: method_name.clone(),
+            type_name,
+            suggestion: self.find_similar_method_name(engines, type_id, method_name, args_buf),
+            importable_traits: self.find_importable_traits(engines, type_id, method_name),
+            span: method_name.span(),
+        }))
+    }
+
+    /// Finds the public traits that implement the method `method_name` for the type `type_id`,
+    /// but are not in scope. All the modules of the package and its dependencies are searched.
+    /// Returns at most [MAX_IMPORTABLE_TRAITS] paths of the traits, as they would be written
+    /// in a `use` statement.
+    fn find_importable_traits(
+        &self,
+        engines: &Engines,
+        type_id: TypeId,
+        method_name: &Ident,
+    ) -> Vec<String> {
+        let traits_in_scope = self
+            .implemented_traits
+            .get_trait_names_for_type(engines, type_id);
+        let package_name = self.root.module.name.as_ref();
+
+        let mut paths = BTreeSet::new();
+        let mut modules = vec![&self.root.module];
+        while let Some(module) = modules.pop() {
+            modules.extend(module.submodules().values());
+            for trait_name in module
+                .implemented_traits
+                .get_trait_names_for_type_and_item_name(engines, type_id, method_name.as_str())
+            {
+                if traits_in_scope.contains(&trait_name) {
+                    continue;
+                }
+                // The full path of a trait declared in this package starts with the package name,
+                // while in a `use` statement the package root is denoted by a leading `::`.
+                let is_local =
+                    package_name.is_some() && trait_name.prefixes.first() == package_name;
+                let call_path = CallPath {
+                    prefixes: trait_name.prefixes[usize::from(is_local)..].to_vec(),
+                    suffix: trait_name.suffix,
+                    is_absolute: true,
+                };
+                let handler = Handler::default();
+                let is_public = self
+                    .root
+                    .resolve_call_path_with_visibility_check(&handler, engines, &[], &call_path)
+                    .map(|decl| decl.visibility(engines.de()).is_public())
+                    .unwrap_or(false);
+                if is_public && !handler.has_errors() {
+                    paths.insert(format!("{}{call_path}", if is_local { "::" } else { "" }));
+                }
             }
-            let type_name = if let Some(call_path) = qualified_call_path {
-                format!("{} as {}", engines.help_out(type_id), call_path)
-            } else {
-                engines.help_out(type_id).to_string()
-            };
-            Err(handler.emit_err(CompileError::MethodNotFound {
-                method_name: method_name.clone(),
-                type_name,
-                span: method_name.span(),
-            }))
         }
+        paths.into_iter().take(MAX_IMPORTABLE_TRAITS).collect()
+    }
+
+    /// Finds the name of a method of the type `type_id` that is similar to `method_name`, for a
+    /// "did you mean" suggestion. Methods that take as many arguments as given in `args_buf` are
+    /// preferred. For enums, the names of the variants are suggested as well, since `E::Variant(..)`
+    /// with a misspelled variant looks like a call of an associated function.
+    fn find_similar_method_name(
+        &self,
+        engines: &Engines,
+        type_id: TypeId,
+        method_name: &Ident,
+        args_buf: &VecDeque<ty::TyExpression>,
+    ) -> Option<String> {
+        let decl_engine = engines.de();
+        let methods = self
+            .get_methods_for_type(engines, type_id)
+            .iter()
+            .map(|decl_ref| decl_engine.get_function(decl_ref))
+            .collect::<Vec<_>>();
+        let variants = match engines.te().get(type_id) {
+            TypeInfo::Enum(decl_ref) => decl_engine.get_enum(&decl_ref).variants,
+            _ => vec![],
+        };
+        let variant_names = variants.iter().map(|variant| variant.name.as_str());
+
+        let same_arity_method_names = methods
+            .iter()
+            .filter(|method| method.parameters.len() == args_buf.len())
+            .map(|method| method.name.as_str());
+        find_similar_name(
+            method_name.as_str(),
+            same_arity_method_names.chain(variant_names.clone()),
+        )
+        .or_else(|| {
+            find_similar_name(
+                method_name.as_str(),
+                methods
+                    .iter()
+                    .map(|method| method.name.as_str())
+                    .chain(variant_names),
+            )
+        })
+        .map(str::to_string)
+    }
+
+    /// Given a type wrapping other types, like `Ptr<S>`, find a method of one of the wrapped
+    /// types whose `self` parameter has the type of the wrapper, like `fn method(self: Ptr<Self>)`
+    /// in an `impl S`. The wrapper is then passed to the method as `self` as is.
+    fn find_method_for_wrapped_type(
+        &mut self,
+        handler: &Handler,
+        type_id: TypeId,
+        method_prefix: &Path,
+        method_name: &Ident,
+        args_buf: &VecDeque<ty::TyExpression>,
+        engines: &Engines,
+    ) -> Result<Option<DeclRefFunction>, ErrorEmitted> {
+        let decl_engine = engines.de();
+        let type_engine = engines.te();
+
+        let wrapped_type_ids = match type_engine.get(type_id) {
+            TypeInfo::Struct(decl_ref) => decl_engine
+                .get_struct(&decl_ref)
+                .type_parameters
+                .iter()
+                .map(|type_param| type_param.type_id)
+                .collect::<Vec<_>>(),
+            TypeInfo::Ptr(pointee) => vec![pointee.type_id],
+            _ => return Ok(None),
+        };
+
+        let coercion_check = UnifyCheck::coercion(engines);
+        for wrapped_type_id in wrapped_type_ids {
+            let items = self.find_items_for_type(
+                handler,
+                wrapped_type_id,
+                method_prefix,
+                method_name,
+                wrapped_type_id,
+                engines,
+            )?;
+            for item in items {
+                let ty::TyTraitItem::Fn(decl_ref) = item else {
+                    continue;
+                };
+                let method = decl_engine.get_function(&decl_ref);
+                let takes_wrapper_as_self = method.parameters.len() == args_buf.len()
+                    && matches!(
+                        method.parameters.first(),
+                        Some(param) if param.is_self()
+                            && coercion_check.check(param.type_argument.type_id, type_id)
+                    );
+                if takes_wrapper_as_self {
+                    return Ok(Some(decl_ref));
+                }
+            }
+        }
+
+        Ok(None)
     }
 
     /// Given a name and a type (plus a `self_type` to potentially
@@ -531,9 +728,10 @@ impl Namespace {
         src: &Path,
         engines: &Engines,
         is_absolute: bool,
+        use_span: &Span,
     ) -> Result<(), ErrorEmitted> {
         self.root
-            .star_import(handler, src, &self.mod_path, engines, is_absolute)
+            .star_import(handler, src, &self.mod_path, engines, is_absolute, use_span)
     }
 
     /// Short-hand for performing a [Module::variant_star_import] with `mod_path` as the destination.
@@ -544,6 +742,7 @@ impl Namespace {
         engines: &Engines,
         enum_name: &Ident,
         is_absolute: bool,
+        use_span: &Span,
     ) -> Result<(), ErrorEmitted> {
         self.root.variant_star_import(
             handler,
@@ -552,6 +751,7 @@ impl Namespace {
             engines,
             enum_name,
             is_absolute,
+            use_span,
         )
     }
 
@@ -589,6 +789,18 @@ impl Namespace {
         )
     }
 
+    /// Short-hand for performing a [Module::module_import] with `mod_path` as the destination.
+    pub(crate) fn module_import(
+        &mut self,
+        handler: &Handler,
+        src: &Path,
+        item: &Ident,
+        alias: Option<Ident>,
+    ) -> Result<(), ErrorEmitted> {
+        self.root
+            .module_import(handler, src, item, &self.mod_path, alias)
+    }
+
     /// Short-hand for performing a [Module::variant_import] with `mod_path` as the destination.
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn variant_import(
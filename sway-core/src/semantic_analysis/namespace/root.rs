@@ -10,7 +10,7 @@ use crate::{
     Engines, Ident,
 };
 
-use super::{module::Module, namespace::Namespace, Path};
+use super::{module::Module, namespace::Namespace, Path, PathBuf};
 
 /// The root module, from which all other modules can be accessed.
 ///
@@ -28,7 +28,7 @@ pub struct Root {
 impl Root {
     /// Resolve a symbol that is potentially prefixed with some path, e.g. `foo::bar::symbol`.
     ///
-    /// This is short-hand for concatenating the `mod_path` with the `call_path`'s prefixes and
+    /// This is short-hand for resolving the `call_path`'s prefixes relative to the `mod_path` and
     /// then calling `resolve_symbol` with the resulting path and call_path's suffix.
     pub(crate) fn resolve_call_path(
         &self,
@@ -36,14 +36,25 @@ impl Root {
         mod_path: &Path,
         call_path: &CallPath,
     ) -> Result<&ty::TyDecl, ErrorEmitted> {
-        let symbol_path: Vec<_> = mod_path
-            .iter()
-            .chain(&call_path.prefixes)
-            .cloned()
-            .collect();
+        let symbol_path = self.resolve_module_path(mod_path, &call_path.prefixes);
         self.resolve_symbol(handler, &symbol_path, &call_path.suffix)
     }
 
+    /// Resolve the path of the module that the given `prefixes` point to, relative to the module
+    /// at `mod_path`.
+    ///
+    /// This is the concatenation of the `mod_path` with the `prefixes`, unless the first prefix
+    /// names a module imported into the module at `mod_path`, e.g. via `use foo::bar as baz;`, in
+    /// which case it is replaced by the absolute path of the imported module.
+    pub(crate) fn resolve_module_path(&self, mod_path: &Path, prefixes: &[Ident]) -> PathBuf {
+        let imported_module_path = prefixes.split_first().and_then(|(first, rest)| {
+            self.submodule(mod_path)
+                .and_then(|module| module.use_module_synonyms.get(first))
+                .map(|path| path.iter().chain(rest).cloned().collect())
+        });
+        imported_module_path.unwrap_or_else(|| mod_path.iter().chain(prefixes).cloned().collect())
+    }
+
     /// Resolve a symbol that is potentially prefixed with some path, e.g. `foo::bar::symbol`.
     ///
     /// This will concatenate the `mod_path` with the `call_path`'s prefixes and
@@ -67,8 +78,19 @@ impl Root {
         }
 
         // check the visibility of the call path elements
-        // we don't check the first prefix because direct children are always accessible
-        for prefix in iter_prefixes(&call_path.prefixes).skip(1) {
+        // we don't check the first prefix because direct children are always accessible,
+        // and the visibility of an imported module was already checked when importing it
+        let imported_module_path = self
+            .submodule(mod_path)
+            .and_then(|module| module.use_module_synonyms.get(&call_path.prefixes[0]));
+        let (module_path, checked_prefixes) = match imported_module_path {
+            Some(path) => (
+                self.resolve_module_path(mod_path, &call_path.prefixes),
+                path.len(),
+            ),
+            None => (call_path.prefixes.clone(), 1),
+        };
+        for prefix in iter_prefixes(&module_path).skip(checked_prefixes) {
             let module = self.check_submodule(handler, prefix)?;
             if module.visibility.is_private() {
                 let prefix_last = prefix[prefix.len() - 1].clone();
@@ -95,6 +117,8 @@ impl Root {
     ///
     /// If the symbol is within the given module's namespace via import, we recursively traverse
     /// imports until we find the original declaration.
+    ///
+    /// A symbol glob-imported from more than one module is reported as ambiguous.
     pub(crate) fn resolve_symbol(
         &self,
         handler: &Handler,
@@ -102,6 +126,7 @@ impl Root {
         symbol: &Ident,
     ) -> Result<&ty::TyDecl, ErrorEmitted> {
         self.check_submodule(handler, mod_path).and_then(|module| {
+            module.check_glob_import_ambiguity(handler, symbol)?;
             let true_symbol = self[mod_path]
                 .use_aliases
                 .get(symbol.as_str())
@@ -128,13 +128,16 @@ impl TraitMap {
             for item in items.iter() {
                 match item {
                     TyImplItem::Fn(decl_ref) => {
-                        if trait_items
-                            .insert(decl_ref.name().clone().to_string(), item.clone())
-                            .is_some()
+                        if let Some(previous_item) =
+                            trait_items.insert(decl_ref.name().clone().to_string(), item.clone())
                         {
                             // duplicate method name
                             handler.emit_err(CompileError::MultipleDefinitionsOfName {
                                 name: decl_ref.name().clone(),
+                                previous_span: match previous_item {
+                                    TyImplItem::Fn(previous_ref) => previous_ref.span(),
+                                    TyImplItem::Constant(previous_ref) => previous_ref.span(),
+                                },
                                 span: decl_ref.span(),
                             });
                         }
@@ -654,7 +657,7 @@ impl TraitMap {
         {
             for type_id in all_types.iter_mut() {
                 let type_info = type_engine.get(*type_id);
-                if !type_info.can_change(decl_engine) && *type_id == *map_type_id {
+                if !type_info.can_change(engines) && *type_id == *map_type_id {
                     trait_map.insert_inner(
                         map_trait_name.clone(),
                         impl_span.clone(),
@@ -851,6 +854,40 @@ impl TraitMap {
         trait_names
     }
 
+    /// Find the names of the traits implemented for `type_id` that provide an
+    /// item named `item_name`.
+    ///
+    /// Notes:
+    /// - "impl self" blocks are not considered traits here and are skipped
+    pub(crate) fn get_trait_names_for_type_and_item_name(
+        &self,
+        engines: &Engines,
+        type_id: TypeId,
+        item_name: &str,
+    ) -> Vec<CallPath> {
+        let type_engine = engines.te();
+        let unify_check = UnifyCheck::non_dynamic_equality(engines);
+        let mut trait_names = vec![];
+        // small performance gain in bad case
+        if matches!(type_engine.get(type_id), TypeInfo::ErrorRecovery(_)) {
+            return trait_names;
+        }
+        for entry in self.trait_impls.iter() {
+            if entry.key.trait_decl_span.is_some()
+                && entry.value.trait_items.contains_key(item_name)
+                && unify_check.check(type_id, entry.key.type_id)
+            {
+                let trait_call_path = CallPath {
+                    prefixes: entry.key.name.prefixes.clone(),
+                    suffix: entry.key.name.suffix.name.clone(),
+                    is_absolute: entry.key.name.is_absolute,
+                };
+                trait_names.push(trait_call_path);
+            }
+        }
+        trait_names
+    }
+
     /// Checks to see if the trait constraints are satisfied for a given type.
     pub(crate) fn check_if_trait_constraints_are_satisfied_for_type(
         &self,
@@ -860,6 +897,36 @@ impl TraitMap {
         access_span: &Span,
         engines: &Engines,
     ) -> Result<(), ErrorEmitted> {
+        let unsatisfied_traits = self.unsatisfied_trait_constraints_for_type(
+            handler,
+            type_id,
+            constraints,
+            access_span,
+            engines,
+        )?;
+
+        handler.scope(|handler| {
+            for trait_name in unsatisfied_traits {
+                // TODO: use a better span
+                handler.emit_err(CompileError::TraitConstraintNotSatisfied {
+                    ty: engines.help_out(type_id).to_string(),
+                    trait_name: trait_name.to_string(),
+                    span: access_span.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the names of the traits in `constraints` that are not implemented for a given type.
+    pub(crate) fn unsatisfied_trait_constraints_for_type(
+        &self,
+        handler: &Handler,
+        type_id: TypeId,
+        constraints: &[TraitConstraint],
+        access_span: &Span,
+        engines: &Engines,
+    ) -> Result<Vec<Ident>, ErrorEmitted> {
         let type_engine = engines.te();
         let _decl_engine = engines.de();
         let unify_check = UnifyCheck::non_dynamic_equality(engines);
@@ -930,16 +997,9 @@ impl TraitMap {
         let relevant_impld_traits_names: BTreeSet<Ident> =
             relevant_impld_traits.keys().cloned().collect();
 
-        handler.scope(|handler| {
-            for trait_name in required_traits_names.difference(&relevant_impld_traits_names) {
-                // TODO: use a better span
-                handler.emit_err(CompileError::TraitConstraintNotSatisfied {
-                    ty: engines.help_out(type_id).to_string(),
-                    trait_name: trait_name.to_string(),
-                    span: access_span.clone(),
-                });
-            }
-            Ok(())
-        })
+        Ok(required_traits_names
+            .difference(&relevant_impld_traits_names)
+            .cloned()
+            .collect())
     }
 }
@@ -30,8 +30,20 @@ pub(crate) fn order_ast_nodes_by_dependency(
             .filter_map(|node| Dependencies::gather_from_decl_node(engines, node)),
     );
 
+    // Constants are tracked as plain symbols, like types, but a cycle between them is reported
+    // differently, so we need to know which symbols are constants.
+    let constants = nodes
+        .iter()
+        .filter_map(|node| match &node.content {
+            AstNodeContent::Declaration(Declaration::ConstantDeclaration(decl)) => {
+                Some(decl.name.clone())
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
     // Check here for recursive calls now that we have a nice map of the dependencies to help us.
-    let mut errors = find_recursive_decls(&decl_dependencies);
+    let mut errors = find_recursive_decls(&decl_dependencies, &constants);
 
     handler.scope(|handler| {
         // Because we're pulling these errors out of a HashMap they'll probably be in a funny
@@ -54,18 +66,66 @@ pub(crate) fn order_ast_nodes_by_dependency(
         }))
 }
 
+/// Returns the names of the variables and other plain symbols which the `expr` refers to.
+pub(crate) fn symbols_referred_to_in_expr(engines: &Engines, expr: &Expression) -> HashSet<Ident> {
+    Dependencies {
+        deps: HashSet::new(),
+    }
+    .gather_from_expr(engines, expr)
+    .deps
+    .into_iter()
+    .filter_map(|dep| match dep {
+        DependentSymbol::Symbol(name) => Some(name),
+        _ => None,
+    })
+    .collect()
+}
+
+/// Returns the names of the variables and other plain symbols which the `block` refers to.
+pub(crate) fn symbols_referred_to_in_block(engines: &Engines, block: &CodeBlock) -> HashSet<Ident> {
+    Dependencies {
+        deps: HashSet::new(),
+    }
+    .gather_from_block(engines, block)
+    .deps
+    .into_iter()
+    .filter_map(|dep| match dep {
+        DependentSymbol::Symbol(name) => Some(name),
+        _ => None,
+    })
+    .collect()
+}
+
 // -------------------------------------------------------------------------------------------------
 // Recursion detection.
 
-fn find_recursive_decls(decl_dependencies: &DependencyMap) -> Vec<CompileError> {
-    decl_dependencies
+fn find_recursive_decls(
+    decl_dependencies: &DependencyMap,
+    constants: &HashSet<Ident>,
+) -> Vec<CompileError> {
+    let mut errors = decl_dependencies
         .iter()
-        .filter_map(|(dep_sym, _)| find_recursive_decl(decl_dependencies, dep_sym))
-        .collect()
+        .filter_map(|(dep_sym, _)| find_recursive_decl(decl_dependencies, constants, dep_sym))
+        .collect::<Vec<_>>();
+
+    // Every constant in a cycle finds the same cycle, starting from itself.  Only report it once,
+    // for the constant which is declared first.
+    errors.sort_by_key(|err| err.span().start());
+    let mut reported_cycles = HashSet::new();
+    errors.retain(|err| match err {
+        CompileError::ConstantDependencyCycle { references, .. } => {
+            let mut cycle = references.iter().map(Ident::to_string).collect::<Vec<_>>();
+            cycle.sort();
+            reported_cycles.insert(cycle)
+        }
+        _ => true,
+    });
+    errors
 }
 
 fn find_recursive_decl(
     decl_dependencies: &DependencyMap,
+    constants: &HashSet<Ident>,
     dep_sym: &DependentSymbol,
 ) -> Option<CompileError> {
     match dep_sym {
@@ -73,9 +133,23 @@ fn find_recursive_decl(
             let mut chain = Vec::new();
             find_recursive_call_chain(decl_dependencies, dep_sym, fn_span, &mut chain)
         }
+        DependentSymbol::Symbol(sym_ident) if constants.contains(sym_ident) => {
+            let mut chain = Vec::new();
+            find_recursive_symbol_chain(
+                decl_dependencies,
+                dep_sym,
+                &mut chain,
+                build_constant_cycle_error,
+            )
+        }
         DependentSymbol::Symbol(_) => {
             let mut chain = Vec::new();
-            find_recursive_type_chain(decl_dependencies, dep_sym, &mut chain)
+            find_recursive_symbol_chain(
+                decl_dependencies,
+                dep_sym,
+                &mut chain,
+                build_recursive_type_error,
+            )
         }
         _otherwise => None,
     }
@@ -115,10 +189,17 @@ fn find_recursive_call_chain(
     }
 }
 
-fn find_recursive_type_chain(
+/// Looks for a chain of symbols depending on each other, back to the first symbol in `chain`.
+///
+/// The first entry of `chain` is the name of the declaration the search started at, and the
+/// following entries are the references to the other symbols in the chain.  When the chain is
+/// closed the error is built from the declaration name, the references in between and the final
+/// reference back to the declaration.
+fn find_recursive_symbol_chain(
     decl_dependencies: &DependencyMap,
     dep_sym: &DependentSymbol,
     chain: &mut Vec<Ident>,
+    build_error: fn(&Ident, &[Ident], &Ident) -> CompileError,
 ) -> Option<CompileError> {
     if let DependentSymbol::Symbol(sym_ident) = dep_sym {
         if chain.contains(sym_ident) {
@@ -126,15 +207,14 @@ fn find_recursive_type_chain(
             return if &chain[0] != sym_ident {
                 None
             } else {
-                Some(build_recursive_type_error(sym_ident.clone(), &chain[1..]))
+                Some(build_error(&chain[0], &chain[1..], sym_ident))
             };
         }
         decl_dependencies.get(dep_sym).and_then(|deps_set| {
             chain.push(sym_ident.clone());
-            let result = deps_set
-                .deps
-                .iter()
-                .find_map(|dep_sym| find_recursive_type_chain(decl_dependencies, dep_sym, chain));
+            let result = deps_set.deps.iter().find_map(|dep_sym| {
+                find_recursive_symbol_chain(decl_dependencies, dep_sym, chain, build_error)
+            });
             chain.pop();
             result
         })
@@ -173,7 +253,8 @@ fn build_recursion_error(fn_sym: Ident, span: Span, chain: &[Ident]) -> CompileE
     }
 }
 
-fn build_recursive_type_error(name: Ident, chain: &[Ident]) -> CompileError {
+fn build_recursive_type_error(_decl_name: &Ident, chain: &[Ident], name: &Ident) -> CompileError {
+    let name = name.clone();
     let span = name.span();
     match chain.len() {
         // An empty chain indicates immediate recursion.
@@ -201,6 +282,13 @@ fn build_recursive_type_error(name: Ident, chain: &[Ident]) -> CompileError {
     }
 }
 
+fn build_constant_cycle_error(name: &Ident, chain: &[Ident], closing_ref: &Ident) -> CompileError {
+    CompileError::ConstantDependencyCycle {
+        name: name.clone(),
+        references: chain.iter().chain(Some(closing_ref)).cloned().collect(),
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Dependency gathering.
 
@@ -443,6 +531,7 @@ impl Dependencies {
         } = fn_decl;
         self.gather_from_iter(parameters.iter(), |deps, param| {
             deps.gather_from_type_argument(engines, &param.type_argument)
+                .gather_from_opt_expr(engines, param.type_of.as_ref())
         })
         .gather_from_type_argument(engines, return_type)
         .gather_from_block(engines, body)
@@ -493,10 +582,15 @@ impl Dependencies {
                     deps.gather_from_match_branch(engines, branch)
                 }),
             ExpressionKind::CodeBlock(contents) => self.gather_from_block(engines, contents),
-            ExpressionKind::Array(array_expression) => self
-                .gather_from_iter(array_expression.contents.iter(), |deps, expr| {
+            ExpressionKind::Array(array_expression) => {
+                let this = self.gather_from_iter(array_expression.contents.iter(), |deps, expr| {
                     deps.gather_from_expr(engines, expr)
-                }),
+                });
+                match &array_expression.length_constant {
+                    Some(call_path) => this.gather_from_call_path(call_path, false, false),
+                    None => this,
+                }
+            }
             ExpressionKind::ArrayIndex(ArrayIndexExpression { prefix, index, .. }) => self
                 .gather_from_expr(engines, prefix)
                 .gather_from_expr(engines, index),
@@ -577,6 +671,7 @@ impl Dependencies {
             | ExpressionKind::Break
             | ExpressionKind::Continue
             | ExpressionKind::StorageAccess(_)
+            | ExpressionKind::Spread(_)
             | ExpressionKind::Error(_, _) => self,
 
             ExpressionKind::Tuple(fields) => self.gather_from_iter(fields.iter(), |deps, field| {
@@ -707,7 +802,13 @@ impl Dependencies {
             TypeInfo::Tuple(elems) => self.gather_from_iter(elems.iter(), |deps, elem| {
                 deps.gather_from_type_argument(engines, elem)
             }),
-            TypeInfo::Array(elem_type, _) => self.gather_from_type_argument(engines, elem_type),
+            TypeInfo::Array(elem_type, length) => {
+                let this = self.gather_from_type_argument(engines, elem_type);
+                match length.constant() {
+                    Some(call_path) => this.gather_from_call_path(call_path, false, false),
+                    None => this,
+                }
+            }
             TypeInfo::Struct(decl_ref) => self.gather_from_iter(
                 decl_engine.get_struct(decl_ref).fields.iter(),
                 |deps, field| deps.gather_from_type_argument(engines, &field.type_argument),
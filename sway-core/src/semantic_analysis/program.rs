@@ -1,12 +1,14 @@
 use crate::{
+    build_config::DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT,
     language::{parsed::ParseProgram, ty},
     metadata::MetadataManager,
     semantic_analysis::{
         namespace::{self, Namespace},
         TypeCheckContext,
     },
-    Engines,
+    BuildConfig, Engines,
 };
+use std::collections::BTreeMap;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{Context, Module};
 
@@ -21,10 +23,15 @@ impl ty::TyProgram {
         parsed: &ParseProgram,
         initial_namespace: namespace::Module,
         package_name: &str,
+        build_config: Option<&BuildConfig>,
     ) -> Result<Self, ErrorEmitted> {
         let mut namespace = Namespace::init_root(initial_namespace);
-        let ctx =
-            TypeCheckContext::from_root(&mut namespace, engines).with_kind(parsed.kind.clone());
+        let monomorphization_depth_limit = build_config
+            .map(|config| config.monomorphization_depth_limit)
+            .unwrap_or(DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT);
+        let ctx = TypeCheckContext::from_root(&mut namespace, engines)
+            .with_kind(parsed.kind.clone())
+            .with_monomorphization_depth_limit(monomorphization_depth_limit);
         let ParseProgram { root, kind } = parsed;
         ty::TyModule::type_check(handler, ctx, root).and_then(|root| {
             let res = Self::validate_root(handler, engines, &root, kind.clone(), package_name);
@@ -34,6 +41,7 @@ impl ty::TyProgram {
                 declarations,
                 configurables,
                 storage_slots: vec![],
+                storage_slot_paths: BTreeMap::new(),
                 logged_types: vec![],
                 messages_types: vec![],
             })
@@ -64,25 +72,35 @@ impl ty::TyProgram {
                         ..
                     })) => {
                         let decl = decl_engine.get_storage(decl_id);
-                        let mut storage_slots = decl.get_initialized_storage_slots(
-                            handler, engines, context, md_mgr, module,
-                        )?;
+                        let (mut storage_slots, storage_slot_paths): (Vec<_>, BTreeMap<_, _>) =
+                            decl.get_initialized_storage_slots(
+                                handler, engines, context, md_mgr, module,
+                            )?
+                            .into_iter()
+                            .map(|(slot, path)| {
+                                let key = *slot.key();
+                                (slot, (key, path))
+                            })
+                            .unzip();
                         // Sort the slots to standardize the output. Not strictly required by the
                         // spec.
                         storage_slots.sort();
                         Ok(Self {
                             storage_slots,
+                            storage_slot_paths,
                             ..self
                         })
                     }
                     _ => Ok(Self {
                         storage_slots: vec![],
+                        storage_slot_paths: BTreeMap::new(),
                         ..self
                     }),
                 }
             }
             _ => Ok(Self {
                 storage_slots: vec![],
+                storage_slot_paths: BTreeMap::new(),
                 ..self
             }),
         }
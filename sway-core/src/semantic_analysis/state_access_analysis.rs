@@ -0,0 +1,242 @@
+// Predicates are evaluated without access to the blockchain state, so they can neither read
+// nor write contract storage, call contracts or log. Such operations compile fine but make
+// the predicate revert at runtime. This analysis walks the call graph of the predicate's
+// `main()` and reports each kind of forbidden operation, together with the chain of calls
+// leading to it. The opcodes in `asm` blocks are not considered here, because all of them are
+// reported by `check_predicate_opcodes` on the generated code.
+
+use crate::{decl_engine::DeclId, language::ty, Engines};
+use std::collections::HashMap;
+use sway_error::{error::CompileError, handler::Handler};
+use sway_types::{Span, Spanned};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StateAccess {
+    StorageRead,
+    StorageWrite,
+    ContractCall,
+    Log,
+}
+
+impl StateAccess {
+    fn as_str(&self) -> &'static str {
+        use StateAccess::*;
+        match self {
+            StorageRead => "storage read",
+            StorageWrite => "storage write",
+            ContractCall => "contract call",
+            Log => "log",
+        }
+    }
+}
+
+/// The state accesses an expression performs, either directly or through the functions it calls.
+///
+/// Each access is witnessed by the chain of spans leading to it: the spans of the calls,
+/// followed by the span of the access itself. Only the first access of each kind is kept.
+#[derive(Clone, Default)]
+struct StateAccesses(Vec<(StateAccess, Vec<Span>)>);
+
+impl StateAccesses {
+    fn contains(&self, access: StateAccess) -> bool {
+        self.0.iter().any(|(existing, _)| *existing == access)
+    }
+
+    /// Records a direct state access.
+    fn access(&mut self, access: StateAccess, span: &Span) {
+        if !self.contains(access) {
+            self.0.push((access, vec![span.clone()]));
+        }
+    }
+
+    /// Records the state accesses of a function called at `span`.
+    fn call(&mut self, callee: &StateAccesses, span: &Span) {
+        for (access, chain) in callee.0.iter() {
+            if !self.contains(*access) {
+                let chain = std::iter::once(span.clone())
+                    .chain(chain.iter().cloned())
+                    .collect();
+                self.0.push((*access, chain));
+            }
+        }
+    }
+
+    fn extend(&mut self, other: StateAccesses) {
+        for (access, chain) in other.0 {
+            if !self.contains(access) {
+                self.0.push((access, chain));
+            }
+        }
+    }
+}
+
+pub(crate) fn analyze_program(handler: &Handler, engines: &Engines, prog: &ty::TyProgram) {
+    // Contracts and scripts have access to the state, and libraries are checked
+    // as part of the programs using them.
+    if let ty::TyProgramKind::Predicate { main_function } = &prog.kind {
+        let mut memos = HashMap::new();
+        let accesses = accesses_of_codeblock(engines, &mut memos, &main_function.body);
+        for (access, chain) in accesses.0 {
+            handler.emit_err(CompileError::StateAccessInPredicate {
+                access: access.as_str(),
+                chain,
+                span: main_function.name.span(),
+            });
+        }
+    }
+}
+
+type Memos = HashMap<DeclId<ty::TyFunctionDecl>, StateAccesses>;
+
+fn accesses_of_codeblock(
+    engines: &Engines,
+    memos: &mut Memos,
+    codeblock: &ty::TyCodeBlock,
+) -> StateAccesses {
+    let mut accesses = StateAccesses::default();
+    for ast_node in codeblock.contents.iter() {
+        match &ast_node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(var_decl)) => {
+                accesses.extend(accesses_of_expression(engines, memos, &var_decl.body))
+            }
+            ty::TyAstNodeContent::Expression(expr)
+            | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => {
+                accesses.extend(accesses_of_expression(engines, memos, expr))
+            }
+            // Declarations (except variable declarations) are not allowed in the body of a function
+            ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect(_) => (),
+        }
+    }
+    accesses
+}
+
+fn accesses_of_expressions<'a>(
+    engines: &Engines,
+    memos: &mut Memos,
+    exprs: impl IntoIterator<Item = &'a ty::TyExpression>,
+) -> StateAccesses {
+    let mut accesses = StateAccesses::default();
+    for expr in exprs {
+        accesses.extend(accesses_of_expression(engines, memos, expr));
+    }
+    accesses
+}
+
+fn accesses_of_expression(
+    engines: &Engines,
+    memos: &mut Memos,
+    expr: &ty::TyExpression,
+) -> StateAccesses {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        // Storage accesses only compute the storage keys, the state is accessed by the
+        // intrinsics and the `asm` blocks used to read and write them.
+        Literal(_)
+        | ConstantExpression { .. }
+        | VariableExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | Break
+        | Continue
+        | AbiName(_) => StateAccesses::default(),
+        Reassignment(reassgn) => accesses_of_expression(engines, memos, &reassgn.rhs),
+        LazyOperator { lhs, rhs, .. }
+        | ArrayIndex {
+            prefix: lhs,
+            index: rhs,
+        } => accesses_of_expressions(engines, memos, [lhs.as_ref(), rhs.as_ref()]),
+        Tuple { fields: exprs }
+        | Array {
+            elem_type: _,
+            contents: exprs,
+        } => accesses_of_expressions(engines, memos, exprs),
+        StructExpression { fields, .. } => {
+            accesses_of_expressions(engines, memos, fields.iter().map(|field| &field.value))
+        }
+        CodeBlock(codeblock) => accesses_of_codeblock(engines, memos, codeblock),
+        MatchExp { desugared, .. } => accesses_of_expression(engines, memos, desugared),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => accesses_of_expressions(
+            engines,
+            memos,
+            [condition.as_ref(), then.as_ref()]
+                .into_iter()
+                .chain(r#else.as_deref()),
+        ),
+        StructFieldAccess { prefix: expr, .. }
+        | TupleElemAccess { prefix: expr, .. }
+        | EnumTag { exp: expr }
+        | ArrayToSlice { array: expr }
+        | UnsafeDowncast { exp: expr, .. }
+        | AbiCast { address: expr, .. }
+        | Return(expr) => accesses_of_expression(engines, memos, expr),
+        EnumInstantiation { contents, .. } => {
+            accesses_of_expressions(engines, memos, contents.as_deref())
+        }
+        WhileLoop { condition, body } => {
+            let mut accesses = accesses_of_expression(engines, memos, condition);
+            accesses.extend(accesses_of_codeblock(engines, memos, body));
+            accesses
+        }
+        IntrinsicFunction(intrinsic) => {
+            let mut accesses = accesses_of_expressions(engines, memos, &intrinsic.arguments);
+            if let Some(access) = access_of_intrinsic(&intrinsic.kind) {
+                accesses.access(access, &expr.span);
+            }
+            accesses
+        }
+        FunctionApplication {
+            arguments,
+            contract_call_params,
+            fn_ref,
+            selector,
+            ..
+        } => {
+            let mut accesses = accesses_of_expressions(
+                engines,
+                memos,
+                arguments
+                    .iter()
+                    .map(|(_, arg)| arg)
+                    .chain(contract_call_params.values()),
+            );
+            if selector.is_some() {
+                accesses.access(StateAccess::ContractCall, &expr.span);
+            } else {
+                let fn_accesses = match memos.get(fn_ref.id()) {
+                    Some(fn_accesses) => fn_accesses.clone(),
+                    None => {
+                        let fn_decl = engines.de().get_function(fn_ref);
+                        let fn_accesses = accesses_of_codeblock(engines, memos, &fn_decl.body);
+                        memos.insert(*fn_ref.id(), fn_accesses.clone());
+                        fn_accesses
+                    }
+                };
+                accesses.call(&fn_accesses, &expr.span);
+            }
+            accesses
+        }
+        AsmExpression { registers, .. } => accesses_of_expressions(
+            engines,
+            memos,
+            registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref()),
+        ),
+    }
+}
+
+fn access_of_intrinsic(intrinsic: &sway_ast::Intrinsic) -> Option<StateAccess> {
+    use sway_ast::Intrinsic::*;
+    match intrinsic {
+        StateLoadWord | StateLoadQuad => Some(StateAccess::StorageRead),
+        StateClear | StateStoreWord | StateStoreQuad => Some(StateAccess::StorageWrite),
+        Log => Some(StateAccess::Log),
+        Revert | IsReferenceType | IsStrType | SizeOfType | SizeOfVal | SizeOfStr | Eq | Gt
+        | Lt | Gtf | AddrOf | Add | Sub | Mul | Div | And | Or | Xor | Mod | Rsh | Lsh | PtrAdd
+        | PtrSub | Not | Smo => None,
+    }
+}
@@ -1,17 +1,25 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use crate::{
+    build_config::DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT,
     engine_threading::*,
     language::{parsed::TreeType, ty::TyDecl, Purity, Visibility},
-    namespace::Path,
+    namespace::{Items, NamesSnapshot, Path},
     semantic_analysis::{
         ast_node::{AbiMode, ConstShadowingMode},
         Namespace,
     },
+    transform,
     type_system::{
         EnforceTypeArguments, MonomorphizeHelper, SubstTypes, TypeArgument, TypeId, TypeInfo,
     },
 };
-use sway_error::handler::{ErrorEmitted, Handler};
-use sway_types::{span::Span, Ident};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    type_error::ExpectedTypeOrigin,
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{span::Span, Ident, Spanned};
 
 /// Contextual state tracked and accumulated throughout type-checking.
 pub struct TypeCheckContext<'a> {
@@ -39,6 +47,11 @@ pub struct TypeCheckContext<'a> {
     ///
     /// Assists type inference.
     type_annotation: TypeId,
+    /// The place in source code that requires the `type_annotation`, if known, e.g., the
+    /// declared return type of the function whose body is being type-checked.
+    ///
+    /// Reported along with the type mismatches against the `type_annotation`.
+    expected_origin: Option<ExpectedTypeOrigin>,
     /// Whether or not we're within an `abi` implementation.
     ///
     /// This is `ImplAbiFn` while checking `abi` implementations whether at their original impl
@@ -63,6 +76,18 @@ pub struct TypeCheckContext<'a> {
     /// disallowing functions from being defined inside of another function
     /// body).
     disallow_functions: bool,
+
+    /// Indicates whether uses of `#[deprecated]` items are allowed without a warning, i.e., when
+    /// type checking an item that is itself deprecated.
+    allow_deprecated: bool,
+
+    /// How deeply the type arguments of the types a generic function is instantiated with may be
+    /// nested.
+    monomorphization_depth_limit: usize,
+
+    /// Memoizes the results of [TypeCheckContext::resolve_type_with_self]. It is shared by all of
+    /// the contexts derived from this one within the same scope.
+    resolved_types: Rc<RefCell<ResolvedTypesCache>>,
 }
 
 impl<'a> TypeCheckContext<'a> {
@@ -83,6 +108,7 @@ impl<'a> TypeCheckContext<'a> {
             namespace,
             engines,
             type_annotation: engines.te().insert(engines, TypeInfo::Unknown),
+            expected_origin: None,
             help_text: "",
             // TODO: Contract? Should this be passed in based on program kind (aka TreeType)?
             self_type: engines.te().insert(engines, TypeInfo::Contract),
@@ -91,6 +117,9 @@ impl<'a> TypeCheckContext<'a> {
             purity: Purity::default(),
             kind: TreeType::Contract,
             disallow_functions: false,
+            allow_deprecated: false,
+            monomorphization_depth_limit: DEFAULT_MONOMORPHIZATION_DEPTH_LIMIT,
+            resolved_types: Rc::default(),
         }
     }
 
@@ -106,6 +135,7 @@ impl<'a> TypeCheckContext<'a> {
         TypeCheckContext {
             namespace: self.namespace,
             type_annotation: self.type_annotation,
+            expected_origin: self.expected_origin.clone(),
             self_type: self.self_type,
             abi_mode: self.abi_mode.clone(),
             const_shadowing_mode: self.const_shadowing_mode,
@@ -114,6 +144,9 @@ impl<'a> TypeCheckContext<'a> {
             kind: self.kind.clone(),
             engines: self.engines,
             disallow_functions: self.disallow_functions,
+            allow_deprecated: self.allow_deprecated,
+            monomorphization_depth_limit: self.monomorphization_depth_limit,
+            resolved_types: self.resolved_types.clone(),
         }
     }
 
@@ -122,6 +155,7 @@ impl<'a> TypeCheckContext<'a> {
         TypeCheckContext {
             namespace,
             type_annotation: self.type_annotation,
+            expected_origin: self.expected_origin.clone(),
             self_type: self.self_type,
             abi_mode: self.abi_mode,
             const_shadowing_mode: self.const_shadowing_mode,
@@ -130,6 +164,11 @@ impl<'a> TypeCheckContext<'a> {
             kind: self.kind,
             engines: self.engines,
             disallow_functions: self.disallow_functions,
+            allow_deprecated: self.allow_deprecated,
+            monomorphization_depth_limit: self.monomorphization_depth_limit,
+            resolved_types: Rc::new(RefCell::new(ResolvedTypesCache::scoped(
+                self.resolved_types,
+            ))),
         }
     }
 
@@ -149,7 +188,8 @@ impl<'a> TypeCheckContext<'a> {
         // engine here once they're added.
         let Self { namespace, .. } = self;
         let mut submod_ns = namespace.enter_submodule(mod_name, visibility, module_span);
-        let submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns, self.engines);
+        let submod_ctx = TypeCheckContext::from_module_namespace(&mut submod_ns, self.engines)
+            .with_monomorphization_depth_limit(self.monomorphization_depth_limit);
         with_submod_ctx(submod_ctx)
     }
 
@@ -159,9 +199,22 @@ impl<'a> TypeCheckContext<'a> {
     }
 
     /// Map this `TypeCheckContext` instance to a new one with the given type annotation.
+    ///
+    /// The origin of the previous type annotation is dropped, see
+    /// [TypeCheckContext::with_expected_origin].
     pub(crate) fn with_type_annotation(self, type_annotation: TypeId) -> Self {
         Self {
             type_annotation,
+            expected_origin: None,
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with the given origin of the type
+    /// annotation.
+    pub(crate) fn with_expected_origin(self, expected_origin: ExpectedTypeOrigin) -> Self {
+        Self {
+            expected_origin: Some(expected_origin),
             ..self
         }
     }
@@ -197,6 +250,28 @@ impl<'a> TypeCheckContext<'a> {
         Self { self_type, ..self }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one with `allow_deprecated` set to `true` if
+    /// the given attributes mark the item being type checked as `#[deprecated]`. Uses of other
+    /// deprecated items from within a deprecated item do not produce warnings.
+    pub(crate) fn with_deprecated_attributes(self, attributes: &transform::AttributesMap) -> Self {
+        Self {
+            allow_deprecated: self.allow_deprecated || attributes.deprecation().is_some(),
+            ..self
+        }
+    }
+
+    /// Map this `TypeCheckContext` instance to a new one with the given monomorphization depth
+    /// limit.
+    pub(crate) fn with_monomorphization_depth_limit(
+        self,
+        monomorphization_depth_limit: usize,
+    ) -> Self {
+        Self {
+            monomorphization_depth_limit,
+            ..self
+        }
+    }
+
     /// Map this `TypeCheckContext` instance to a new one with
     /// `disallow_functions` set to `true`.
     pub(crate) fn disallow_functions(self) -> Self {
@@ -226,6 +301,10 @@ impl<'a> TypeCheckContext<'a> {
         self.type_annotation
     }
 
+    pub(crate) fn expected_origin(&self) -> Option<&ExpectedTypeOrigin> {
+        self.expected_origin.as_ref()
+    }
+
     pub(crate) fn abi_mode(&self) -> AbiMode {
         self.abi_mode.clone()
     }
@@ -251,6 +330,36 @@ impl<'a> TypeCheckContext<'a> {
         self.disallow_functions
     }
 
+    pub(crate) fn monomorphization_depth_limit(&self) -> usize {
+        self.monomorphization_depth_limit
+    }
+
+    /// Emits a [Warning::UsingDeprecated] at `span` if `attributes` mark the used item as
+    /// `#[deprecated]`, unless uses of deprecated items are allowed in this context.
+    pub(crate) fn warn_if_deprecated(
+        &self,
+        handler: &Handler,
+        kind: &'static str,
+        name: &Ident,
+        attributes: &transform::AttributesMap,
+        span: &Span,
+    ) {
+        if self.allow_deprecated {
+            return;
+        }
+        if let Some(note) = attributes.deprecation() {
+            handler.emit_warn(CompileWarning {
+                span: span.clone(),
+                warning_content: Warning::UsingDeprecated {
+                    kind,
+                    name: name.as_str().to_string(),
+                    note,
+                    decl_span: name.span(),
+                },
+            });
+        }
+    }
+
     // Provide some convenience functions around the inner context.
 
     /// Short-hand for calling the `monomorphize` function in the type engine
@@ -280,6 +389,10 @@ impl<'a> TypeCheckContext<'a> {
 
     /// Short-hand for calling [Namespace::resolve_type_with_self] with the `self_type` provided by
     /// the `TypeCheckContext`.
+    ///
+    /// Resolutions that neither create new types nor emit any diagnostics are memoized, so that
+    /// resolving an already resolved type again is cheap. Those that involve types which the type
+    /// engine may still substitute are forgotten as soon as it does so.
     pub(crate) fn resolve_type_with_self(
         &mut self,
         handler: &Handler,
@@ -288,15 +401,91 @@ impl<'a> TypeCheckContext<'a> {
         enforce_type_args: EnforceTypeArguments,
         type_info_prefix: Option<&Path>,
     ) -> Result<TypeId, ErrorEmitted> {
-        self.namespace.resolve_type_with_self(
+        let resolved = self.resolve_type_with_self_memoized(
             handler,
+            type_id,
+            span,
+            enforce_type_args,
+            type_info_prefix,
+        )?;
+        self.warn_if_deprecated_type(handler, resolved, span);
+        Ok(resolved)
+    }
+
+    fn resolve_type_with_self_memoized(
+        &mut self,
+        handler: &Handler,
+        type_id: TypeId,
+        span: &Span,
+        enforce_type_args: EnforceTypeArguments,
+        type_info_prefix: Option<&Path>,
+    ) -> Result<TypeId, ErrorEmitted> {
+        // Types resolved relative to some other module path are not memoized, nor are the types
+        // other than the types named in the code, and the tuples and arrays of them, since they
+        // resolve to themselves without any lookup.
+        let type_info = self.engines.te().get(type_id);
+        if type_info_prefix.is_some()
+            || !matches!(
+                type_info,
+                TypeInfo::Custom { .. } | TypeInfo::Tuple(_) | TypeInfo::Array(..)
+            )
+        {
+            return self.namespace.resolve_type_with_self(
+                handler,
+                self.engines(),
+                type_id,
+                self.self_type,
+                span,
+                enforce_type_args,
+                type_info_prefix,
+            );
+        }
+
+        let key = (type_id, self.self_type, enforce_type_args);
+        let generation = self.engines.te().generation();
+        if let Some(resolved) =
+            self.resolved_types
+                .borrow()
+                .get(&key, generation, self.namespace.module())
+        {
+            return Ok(resolved);
+        }
+
+        let resolve_handler = Handler::default();
+        let res = self.namespace.resolve_type_with_self(
+            &resolve_handler,
             self.engines(),
             type_id,
             self.self_type,
             span,
             enforce_type_args,
-            type_info_prefix,
-        )
+            None,
+        );
+        let has_diagnostics = resolve_handler.has_errors() || resolve_handler.has_warnings();
+        handler.append(resolve_handler);
+
+        // The diagnostics point at this use of the type, so they are not replayed for the other
+        // uses, which are resolved again instead.
+        let resolved = match res {
+            Ok(resolved) if !has_diagnostics => resolved,
+            _ => return res,
+        };
+
+        // Every use of the type gets the same resolved type, so it must not contain inference
+        // variables, which each use unifies on its own.
+        if !may_resolve_to_inference_variables(self.engines, &type_info, enforce_type_args) {
+            // A resolution that yields the type itself did not look up any names.
+            let names = (resolved != type_id).then(|| self.namespace.module().names_snapshot());
+            self.resolved_types.borrow_mut().insert(
+                key,
+                ResolvedType {
+                    type_id: resolved,
+                    generation,
+                    names,
+                },
+            );
+        }
+        Ok(resolved)
     }
 
     /// Short-hand for calling [Namespace::resolve_type_without_self]
@@ -307,13 +496,44 @@ impl<'a> TypeCheckContext<'a> {
         span: &Span,
         type_info_prefix: Option<&Path>,
     ) -> Result<TypeId, ErrorEmitted> {
-        self.namespace.resolve_type_without_self(
+        let resolved = self.namespace.resolve_type_without_self(
             handler,
             self.engines(),
             type_id,
             span,
             type_info_prefix,
-        )
+        )?;
+        self.warn_if_deprecated_type(handler, resolved, span);
+        Ok(resolved)
+    }
+
+    fn warn_if_deprecated_type(&self, handler: &Handler, type_id: TypeId, span: &Span) {
+        if self.allow_deprecated {
+            return;
+        }
+        match self.engines.te().get(type_id) {
+            TypeInfo::Struct(decl_ref) => {
+                let decl = self.engines.de().get_struct(&decl_ref);
+                self.warn_if_deprecated(
+                    handler,
+                    "struct",
+                    &decl.call_path.suffix,
+                    &decl.attributes,
+                    span,
+                );
+            }
+            TypeInfo::Enum(decl_ref) => {
+                let decl = self.engines.de().get_enum(&decl_ref);
+                self.warn_if_deprecated(
+                    handler,
+                    "enum",
+                    &decl.call_path.suffix,
+                    &decl.attributes,
+                    span,
+                );
+            }
+            _ => {}
+        }
     }
 
     /// Short-hand around `type_system::unify_with_self`, where the `TypeCheckContext` provides the
@@ -327,6 +547,7 @@ impl<'a> TypeCheckContext<'a> {
             self.self_type(),
             span,
             self.help_text(),
+            self.expected_origin(),
             None,
         )
     }
@@ -348,3 +569,100 @@ impl<'a> TypeCheckContext<'a> {
         self.engines
     }
 }
+
+/// Returns true if resolving the `type_info` may give a type that contains inference variables,
+/// e.g., for a type argument given as `_`, or for the omitted type arguments of a generic type.
+fn may_resolve_to_inference_variables(
+    engines: &Engines,
+    type_info: &TypeInfo,
+    enforce_type_args: EnforceTypeArguments,
+) -> bool {
+    let any_may = |type_arguments: &[TypeArgument]| {
+        type_arguments.iter().any(|type_argument| {
+            may_resolve_to_inference_variables(
+                engines,
+                &engines.te().get(type_argument.type_id),
+                enforce_type_args,
+            )
+        })
+    };
+    match type_info {
+        TypeInfo::Custom {
+            type_arguments: Some(type_arguments),
+            ..
+        } if !type_arguments.is_empty() => any_may(type_arguments),
+        TypeInfo::Custom { .. } => matches!(enforce_type_args, EnforceTypeArguments::No),
+        TypeInfo::Tuple(fields) => any_may(fields),
+        TypeInfo::Array(elem_ty, _) => any_may(std::slice::from_ref(elem_ty)),
+        TypeInfo::UnsignedInteger(_)
+        | TypeInfo::Boolean
+        | TypeInfo::B256
+        | TypeInfo::Str(_)
+        | TypeInfo::RawUntypedPtr
+        | TypeInfo::RawUntypedSlice
+        | TypeInfo::Contract
+        | TypeInfo::SelfType
+        | TypeInfo::UnknownGeneric { .. }
+        | TypeInfo::TypeParam(_)
+        | TypeInfo::ErrorRecovery(_) => false,
+        _ => true,
+    }
+}
+
+/// Maps the [TypeId]s resolved by [TypeCheckContext::resolve_type_with_self], together with the
+/// `self_type` and type arguments enforcement they were resolved with, to their resolved [TypeId].
+///
+/// Each scope has its own cache, since resolving a type copies the trait implementations that
+/// apply to it into the namespace of the scope. The resolutions cached for the enclosing scopes
+/// hold in the scope too.
+#[derive(Default)]
+struct ResolvedTypesCache {
+    parent: Option<Rc<RefCell<ResolvedTypesCache>>>,
+    resolved: HashMap<(TypeId, TypeId, EnforceTypeArguments), ResolvedType>,
+}
+
+/// A cached resolution of a type, and the conditions under which it still holds.
+struct ResolvedType {
+    type_id: TypeId,
+    /// The type engine generation the resolution was computed in. A substitution in the type
+    /// engine may change the types the resolution looked at.
+    generation: usize,
+    /// The names in scope when the resolution was computed, if it looked any up.
+    names: Option<NamesSnapshot>,
+}
+
+impl ResolvedTypesCache {
+    fn scoped(parent: Rc<RefCell<ResolvedTypesCache>>) -> Self {
+        Self {
+            parent: Some(parent),
+            resolved: HashMap::new(),
+        }
+    }
+
+    fn get(
+        &self,
+        key: &(TypeId, TypeId, EnforceTypeArguments),
+        generation: usize,
+        items: &Items,
+    ) -> Option<TypeId> {
+        match self.resolved.get(key) {
+            Some(resolved)
+                if resolved.generation == generation
+                    && resolved
+                        .names
+                        .as_ref()
+                        .map_or(true, |names| names.is_current_in(items)) =>
+            {
+                Some(resolved.type_id)
+            }
+            _ => self
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.borrow().get(key, generation, items)),
+        }
+    }
+
+    fn insert(&mut self, key: (TypeId, TypeId, EnforceTypeArguments), resolved: ResolvedType) {
+        self.resolved.insert(key, resolved);
+    }
+}
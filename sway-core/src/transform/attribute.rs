@@ -22,7 +22,10 @@
 
 use sway_ast::Literal;
 use sway_types::{
-    constants::{ALLOW_DEAD_CODE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME},
+    constants::{
+        DEPRECATED_NOTE_ARG_NAME, INLINE_ALWAYS_NAME, INLINE_NEVER_NAME,
+        TEST_SHOULD_REVERT_ARG_NAME, VALID_LINT_NAMES,
+    },
     Ident, Span, Spanned,
 };
 
@@ -32,9 +35,27 @@ use std::{collections::HashMap, hash::Hash, sync::Arc};
 pub struct AttributeArg {
     pub name: Ident,
     pub value: Option<Literal>,
+    /// The nested arguments, like `testnet` in `#[cfg(not(testnet))]`.
+    pub args: Vec<AttributeArg>,
     pub span: Span,
 }
 
+impl AttributeArg {
+    /// Converts an argument of an attribute in the lexed tree, including its nested arguments.
+    pub(crate) fn from_lexed(arg: &sway_ast::attribute::AttributeArg) -> Self {
+        AttributeArg {
+            name: arg.name.clone(),
+            value: arg.value.clone(),
+            args: arg
+                .args
+                .as_ref()
+                .map(|args| args.get().into_iter().map(Self::from_lexed).collect())
+                .unwrap_or_default(),
+            span: arg.span(),
+        }
+    }
+}
+
 impl Spanned for AttributeArg {
     fn span(&self) -> Span {
         self.span.clone()
@@ -60,8 +81,14 @@ pub enum AttributeKind {
     Inline,
     Test,
     Payable,
+    Builder,
+    Coerce,
+    Fields,
     Allow,
+    Deny,
     Cfg,
+    Deprecated,
+    ExternAbi,
 }
 
 impl AttributeKind {
@@ -72,11 +99,17 @@ impl AttributeKind {
             AttributeKind::Doc => (0, None),
             AttributeKind::DocComment => (0, None),
             AttributeKind::Storage => (0, None),
-            AttributeKind::Inline => (0, None),
-            AttributeKind::Test => (0, None),
+            AttributeKind::Inline => (1, Some(1)),
+            AttributeKind::Test => (0, Some(1)),
             AttributeKind::Payable => (0, None),
-            AttributeKind::Allow => (1, Some(1)),
+            AttributeKind::Builder => (0, Some(0)),
+            AttributeKind::Coerce => (1, None),
+            AttributeKind::Fields => (0, Some(0)),
+            AttributeKind::Allow => (1, None),
+            AttributeKind::Deny => (1, None),
             AttributeKind::Cfg => (1, Some(1)),
+            AttributeKind::Deprecated => (0, Some(1)),
+            AttributeKind::ExternAbi => (1, Some(1)),
         }
     }
 
@@ -86,14 +119,26 @@ impl AttributeKind {
             AttributeKind::Doc => None,
             AttributeKind::DocComment => None,
             AttributeKind::Storage => None,
-            AttributeKind::Inline => None,
-            AttributeKind::Test => None,
-            AttributeKind::Payable => None,
-            AttributeKind::Allow => Some(vec![ALLOW_DEAD_CODE_NAME.to_string()]),
-            AttributeKind::Cfg => Some(vec![
-                CFG_TARGET_ARG_NAME.to_string(),
-                CFG_PROGRAM_TYPE_ARG_NAME.to_string(),
+            AttributeKind::Inline => Some(vec![
+                INLINE_ALWAYS_NAME.to_string(),
+                INLINE_NEVER_NAME.to_string(),
             ]),
+            AttributeKind::Test => Some(vec![TEST_SHOULD_REVERT_ARG_NAME.to_string()]),
+            AttributeKind::Payable => None,
+            AttributeKind::Builder => None,
+            AttributeKind::Coerce => None,
+            AttributeKind::Fields => None,
+            AttributeKind::Allow | AttributeKind::Deny => Some(
+                VALID_LINT_NAMES
+                    .iter()
+                    .map(|lint| lint.to_string())
+                    .collect(),
+            ),
+            // Besides the predicates, any flag name can be given.
+            AttributeKind::Cfg => None,
+            AttributeKind::Deprecated => Some(vec![DEPRECATED_NOTE_ARG_NAME.to_string()]),
+            // The ABI names are validated when the function is type checked.
+            AttributeKind::ExternAbi => None,
         }
     }
 }
@@ -128,6 +173,21 @@ impl AttributesMap {
     pub fn inner(&self) -> &HashMap<AttributeKind, Vec<Attribute>> {
         &self.0
     }
+
+    /// Returns `Some` if the item is marked `#[deprecated]`, holding the `note` given to the
+    /// attribute, if any.
+    pub fn deprecation(&self) -> Option<Option<String>> {
+        let attrs = self.get(&AttributeKind::Deprecated)?;
+        let note = attrs
+            .iter()
+            .flat_map(|attr| attr.args.iter())
+            .find(|arg| arg.name.as_str() == DEPRECATED_NOTE_ARG_NAME)
+            .and_then(|arg| match &arg.value {
+                Some(Literal::String(note)) => Some(note.parsed.clone()),
+                _ => None,
+            });
+        Some(note)
+    }
 }
 
 impl std::ops::Deref for AttributesMap {
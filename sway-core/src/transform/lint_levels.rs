@@ -0,0 +1,227 @@
+//! Lint levels given by the `allow` and `deny` attributes.
+//!
+//! A lint level applies to all the warnings of the lint whose spans lie within the attributed
+//! item, e.g.,
+//!
+//!   #[allow(dead_code)]
+//!   impl Foo {
+//!       fn unused() {}
+//!   }
+//!
+//! does not report the unused method. Inner attributes at the top of a module, like
+//! `#![deny(deprecated)]`, apply to the whole module. If several lint levels apply to a warning,
+//! the one given by the innermost item wins. The `unused` lint group stands for all the lints
+//! about unused code.
+
+use sway_ast::{attribute::Annotated, AttributeDecl, ItemKind, Module};
+use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
+use sway_types::{
+    constants::{ALLOW_ATTRIBUTE_NAME, ALLOW_UNUSED_NAME, DENY_ATTRIBUTE_NAME, UNUSED_LINT_GROUP},
+    SourceId, Span, Spanned,
+};
+
+use crate::language::{lexed::LexedModule, parsed::ParseModule, Programs};
+
+use super::AttributeKind;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LintLevel {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug)]
+enum LintScopeExtent {
+    /// The whole module with the given source.
+    Module(SourceId),
+    /// The item with the given span.
+    Item(Span),
+}
+
+#[derive(Debug)]
+struct LintScope {
+    extent: LintScopeExtent,
+    lint: String,
+    level: LintLevel,
+}
+
+impl LintScope {
+    fn contains(&self, span: &Span) -> bool {
+        match &self.extent {
+            LintScopeExtent::Module(source_id) => span.source_id() == Some(source_id),
+            LintScopeExtent::Item(item_span) => {
+                item_span.source_id() == span.source_id()
+                    && item_span.start() <= span.start()
+                    && span.end() <= item_span.end()
+            }
+        }
+    }
+
+    /// Module scopes are wider than any item scope.
+    fn width(&self) -> usize {
+        match &self.extent {
+            LintScopeExtent::Module(_) => usize::MAX,
+            LintScopeExtent::Item(span) => span.end() - span.start(),
+        }
+    }
+}
+
+/// The lint levels of all the modules and items of a program.
+#[derive(Debug, Default)]
+pub(crate) struct LintLevels {
+    scopes: Vec<LintScope>,
+}
+
+impl LintLevels {
+    pub(crate) fn new(programs: &Programs) -> Self {
+        let mut lint_levels = LintLevels::default();
+        lint_levels.collect_from_parsed_module(&programs.parsed.root);
+        lint_levels.collect_from_lexed_module(&programs.lexed.root);
+        lint_levels
+    }
+
+    /// Removes the warnings of allowed lints from the `handler` and turns the warnings of denied
+    /// lints into errors.
+    pub(crate) fn apply(&self, handler: &Handler) {
+        if self.scopes.is_empty() {
+            return;
+        }
+
+        let mut denied = vec![];
+        handler.retain_warnings(|warning| match self.level_of(warning) {
+            None => true,
+            Some(LintLevel::Allow) => false,
+            Some(LintLevel::Deny) => {
+                denied.push(warning.clone());
+                false
+            }
+        });
+
+        for warning in denied {
+            handler.emit_err(CompileError::DeniedLint {
+                lint: warning.lint_name().unwrap_or_default().to_string(),
+                warning: Box::new(warning.warning_content),
+                span: warning.span,
+            });
+        }
+    }
+
+    fn level_of(&self, warning: &CompileWarning) -> Option<LintLevel> {
+        let lint = warning.lint_name()?;
+        self.scopes
+            .iter()
+            .filter(|scope| {
+                (scope.lint == lint
+                    || (scope.lint == ALLOW_UNUSED_NAME && UNUSED_LINT_GROUP.contains(&lint)))
+                    && scope.contains(&warning.span)
+            })
+            .min_by_key(|scope| scope.width())
+            .map(|scope| scope.level)
+    }
+
+    fn collect_from_parsed_module(&mut self, module: &ParseModule) {
+        if let Some(source_id) = module.span.source_id() {
+            for (kind, level) in [
+                (AttributeKind::Allow, LintLevel::Allow),
+                (AttributeKind::Deny, LintLevel::Deny),
+            ] {
+                let lints = module
+                    .attributes
+                    .get(&kind)
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|attr| attr.args.iter());
+                for lint in lints {
+                    self.scopes.push(LintScope {
+                        extent: LintScopeExtent::Module(*source_id),
+                        lint: lint.name.as_str().to_string(),
+                        level,
+                    });
+                }
+            }
+        }
+        for (_, submodule) in &module.submodules {
+            self.collect_from_parsed_module(&submodule.module);
+        }
+    }
+
+    fn collect_from_lexed_module(&mut self, module: &LexedModule) {
+        self.collect_from_items(&module.tree);
+        for (_, submodule) in &module.submodules {
+            self.collect_from_lexed_module(&submodule.module);
+        }
+    }
+
+    fn collect_from_items(&mut self, module: &Module) {
+        for item in &module.items {
+            self.collect_from_attributes(&item.attribute_list, item.span());
+            match &item.value {
+                ItemKind::Struct(item_struct) => {
+                    for field in item_struct.fields.get() {
+                        self.collect_from_annotated(field);
+                    }
+                }
+                ItemKind::Enum(item_enum) => {
+                    for variant in item_enum.fields.get() {
+                        self.collect_from_annotated(variant);
+                    }
+                }
+                ItemKind::Storage(item_storage) => {
+                    for field in item_storage.fields.get() {
+                        self.collect_from_annotated(field);
+                    }
+                }
+                ItemKind::Impl(item_impl) => {
+                    for impl_item in item_impl.contents.get() {
+                        self.collect_from_annotated(impl_item);
+                    }
+                }
+                ItemKind::Trait(item_trait) => {
+                    for (trait_item, _) in item_trait.trait_items.get() {
+                        self.collect_from_annotated(trait_item);
+                    }
+                    for trait_def in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.get()) {
+                        self.collect_from_annotated(trait_def);
+                    }
+                }
+                ItemKind::Abi(item_abi) => {
+                    for (abi_item, _) in item_abi.abi_items.get() {
+                        self.collect_from_annotated(abi_item);
+                    }
+                    for abi_def in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.get()) {
+                        self.collect_from_annotated(abi_def);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_from_annotated<T: Spanned>(&mut self, annotated: &Annotated<T>) {
+        let span = match annotated.attribute_list.first() {
+            Some(attr) => Span::join(attr.span(), annotated.value.span()),
+            None => annotated.value.span(),
+        };
+        self.collect_from_attributes(&annotated.attribute_list, span);
+    }
+
+    fn collect_from_attributes(&mut self, attribute_list: &[AttributeDecl], span: Span) {
+        for attr in attribute_list
+            .iter()
+            .flat_map(|attr_decl| attr_decl.attribute.get())
+        {
+            let level = match attr.name.as_str() {
+                ALLOW_ATTRIBUTE_NAME => LintLevel::Allow,
+                DENY_ATTRIBUTE_NAME => LintLevel::Deny,
+                _ => continue,
+            };
+            for lint in attr.args.iter().flat_map(|args| args.get()) {
+                self.scopes.push(LintScope {
+                    extent: LintScopeExtent::Item(span.clone()),
+                    lint: lint.name.as_str().to_string(),
+                    level,
+                });
+            }
+        }
+    }
+}
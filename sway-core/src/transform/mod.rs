@@ -1,4 +1,6 @@
 mod attribute;
+mod lint_levels;
 pub(crate) mod to_parsed_lang;
 
 pub use attribute::*;
+pub(crate) use lint_levels::LintLevels;
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::{language::parsed::TreeType, BuildTarget};
 
 #[derive(Default)]
@@ -11,21 +13,32 @@ pub struct Context {
     /// Unique suffix used to generate unique names for destructured tuples
     destructured_tuple_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for parameters that destructure arrays
+    destructured_array_param_unique_suffix: usize,
+
     /// Unique suffix used to generate unique names for vars returned from `match` expressions
     match_expression_return_var_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for keys of compound assignments to storage
+    /// maps
+    storage_map_key_var_unique_suffix: usize,
+
     /// The build target
     build_target: BuildTarget,
 
     /// The build target
     program_type: Option<TreeType>,
+
+    /// The flags set for conditional compilation
+    cfg_flags: HashSet<String>,
 }
 
 impl Context {
     /// Create a new context
-    pub fn new(build_target: BuildTarget) -> Self {
+    pub fn new(build_target: BuildTarget, cfg_flags: HashSet<String>) -> Self {
         Self {
             build_target,
+            cfg_flags,
             ..Default::default()
         }
     }
@@ -52,6 +65,13 @@ impl Context {
         self.destructured_tuple_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for a parameter that destructures
+    /// an array
+    pub fn next_destructured_array_param_unique_suffix(&mut self) -> usize {
+        self.destructured_array_param_unique_suffix += 1;
+        self.destructured_array_param_unique_suffix
+    }
+
     /// Returns a unique suffix used to generate a unique name for a var returned from a `match`
     /// expressions
     pub fn next_match_expression_return_var_unique_suffix(&mut self) -> usize {
@@ -59,6 +79,13 @@ impl Context {
         self.match_expression_return_var_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for a var holding the key of a
+    /// compound assignment to a storage map
+    pub fn next_storage_map_key_var_unique_suffix(&mut self) -> usize {
+        self.storage_map_key_var_unique_suffix += 1;
+        self.storage_map_key_var_unique_suffix
+    }
+
     /// Returns the build target
     pub fn build_target(&self) -> BuildTarget {
         self.build_target
@@ -69,6 +96,11 @@ impl Context {
         self.program_type.clone()
     }
 
+    /// Returns whether the conditional compilation `flag` is set
+    pub fn is_cfg_flag_set(&self, flag: &str) -> bool {
+        self.cfg_flags.contains(flag)
+    }
+
     /// Update the value of `program_type`
     pub fn set_program_type(&mut self, program_type: TreeType) {
         self.program_type = Some(program_type);
@@ -8,7 +8,9 @@ use crate::{
 use itertools::Itertools;
 use sway_ast::{
     attribute::Annotated,
+    brackets::SquareBrackets,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
+    keywords::{EqToken, Keyword, LetToken, MutToken, RefToken, SemicolonToken, Token},
     ty::TyTupleDescriptor,
     AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
     CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
@@ -16,21 +18,26 @@ use sway_ast::{
     Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
     ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
     MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, Traits, Ty, TypeField, UseTree, WhereClause,
+    PathTypeSegment, Pattern, PatternArrayElement, PatternStructField, PubToken, Punctuated,
+    QualifiedPathRoot, Statement, StatementLet, Submodule, Traits, Ty, TypeField, UseTree,
+    WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
     constants::{
-        ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
-        DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
-        MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
-        STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME,
-        TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
+        ALLOW_ATTRIBUTE_NAME, ALLOW_DEAD_CODE_NAME, ARRAY_PARAM_NAME_PREFIX,
+        BUILDER_ATTRIBUTE_NAME, CFG_ALL_ARG_NAME, CFG_ANY_ARG_NAME, CFG_ATTRIBUTE_NAME,
+        CFG_NOT_ARG_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, COERCE_ATTRIBUTE_NAME,
+        DENY_ATTRIBUTE_NAME, DEPRECATED_ATTRIBUTE_NAME, DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME,
+        DOC_COMMENT_ATTRIBUTE_NAME, EXTERN_ABI_ATTRIBUTE_NAME, FIELDS_ATTRIBUTE_NAME,
+        INLINE_ATTRIBUTE_NAME, MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME,
+        STORAGE_MAP_KEY_VAR_NAME_PREFIX, STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME,
+        STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME, TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
     },
     integer_bits::IntegerBits,
+    style::to_upper_camel_case,
 };
 use sway_types::{Ident, Span, Spanned};
 
@@ -178,7 +185,31 @@ fn item_to_ast_nodes(
                 &function_declaration.parameters,
                 "a free function",
             )?;
-            decl(Declaration::FunctionDeclaration(function_declaration))
+            // An invalid builder is reported without giving up on the function itself.
+            let builder_declarations = if !function_declaration
+                .attributes
+                .contains_key(&AttributeKind::Builder)
+            {
+                vec![]
+            } else if !is_root {
+                let error = ConvertParseTreeError::BuilderFnOnlyAllowedAtModuleLevel {
+                    span: function_declaration.span.clone(),
+                };
+                handler.emit_err(error.into());
+                vec![]
+            } else {
+                function_declaration_to_builder_declarations(
+                    context,
+                    handler,
+                    engines,
+                    &function_declaration,
+                )
+                .unwrap_or_default()
+            };
+            iter::once(Declaration::FunctionDeclaration(function_declaration))
+                .chain(builder_declarations)
+                .map(AstNodeContent::Declaration)
+                .collect()
         }
         ItemKind::Trait(item_trait) => decl(Declaration::TraitDeclaration(
             item_trait_to_trait_declaration(context, handler, engines, item_trait, attributes)?,
@@ -460,12 +491,20 @@ fn item_fn_to_function_declaration(
     context: &mut Context,
     handler: &Handler,
     engines: &Engines,
-    item_fn: ItemFn,
+    mut item_fn: ItemFn,
     attributes: AttributesMap,
     parent_generic_params_opt: Option<GenericParams>,
     parent_where_clause_opt: Option<WhereClause>,
 ) -> Result<FunctionDeclaration, ErrorEmitted> {
     let span = item_fn.span();
+    let (fn_args, destructuring_statements) =
+        fn_args_take_at_patterns(handler, item_fn.fn_signature.arguments.inner)?;
+    item_fn.fn_signature.arguments.inner = fn_args;
+    item_fn
+        .body
+        .inner
+        .statements
+        .splice(0..0, destructuring_statements);
     let return_type = match item_fn.fn_signature.return_type_opt {
         Some((_right_arrow, ty)) => ty_to_type_argument(context, handler, engines, ty)?,
         None => {
@@ -478,18 +517,20 @@ fn item_fn_to_function_declaration(
             }
         }
     };
+    let parameters = fn_args_to_function_parameters(
+        context,
+        handler,
+        engines,
+        item_fn.fn_signature.arguments.into_inner(),
+    )?;
+    check_coerced_parameters(handler, &attributes, &parameters)?;
     Ok(FunctionDeclaration {
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
         name: item_fn.fn_signature.name,
         visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
         body: braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?,
-        parameters: fn_args_to_function_parameters(
-            context,
-            handler,
-            engines,
-            item_fn.fn_signature.arguments.into_inner(),
-        )?,
+        parameters,
         span,
         return_type,
         type_parameters: generic_params_opt_to_type_parameters_with_parent(
@@ -512,6 +553,504 @@ fn item_fn_to_function_declaration(
     })
 }
 
+/// Replaces the `name @ pattern` parameters of a function with plain `name` parameters, and
+/// returns the `let pattern = name;` statements that destructure them at the start of its body.
+fn fn_args_take_at_patterns(
+    handler: &Handler,
+    fn_args: FnArgs,
+) -> Result<(FnArgs, Vec<Statement>), ErrorEmitted> {
+    let mut statements = Vec::new();
+    let fn_args = match fn_args {
+        FnArgs::Static { args, rest_opt } => FnArgs::Static {
+            args: punctuated_fn_args_take_at_patterns(handler, args, &mut statements)?,
+            rest_opt,
+        },
+        FnArgs::NonStatic {
+            self_token,
+            ref_self,
+            mutable_self,
+            self_ty_opt,
+            args_opt,
+        } => FnArgs::NonStatic {
+            self_token,
+            ref_self,
+            mutable_self,
+            self_ty_opt,
+            args_opt: match args_opt {
+                Some((comma_token, args)) => Some((
+                    comma_token,
+                    punctuated_fn_args_take_at_patterns(handler, args, &mut statements)?,
+                )),
+                None => None,
+            },
+        },
+    };
+    Ok((fn_args, statements))
+}
+
+fn punctuated_fn_args_take_at_patterns(
+    handler: &Handler,
+    args: Punctuated<FnArg, CommaToken>,
+    statements: &mut Vec<Statement>,
+) -> Result<Punctuated<FnArg, CommaToken>, ErrorEmitted> {
+    let Punctuated {
+        value_separator_pairs,
+        final_value_opt,
+    } = args;
+    let value_separator_pairs = value_separator_pairs
+        .into_iter()
+        .map(|(fn_arg, comma_token)| {
+            let fn_arg = fn_arg_take_at_pattern(handler, fn_arg, statements)?;
+            Ok((fn_arg, comma_token))
+        })
+        .collect::<Result<_, _>>()?;
+    let final_value_opt = match final_value_opt {
+        Some(fn_arg) => Some(Box::new(fn_arg_take_at_pattern(
+            handler, *fn_arg, statements,
+        )?)),
+        None => None,
+    };
+    Ok(Punctuated {
+        value_separator_pairs,
+        final_value_opt,
+    })
+}
+
+fn fn_arg_take_at_pattern(
+    handler: &Handler,
+    fn_arg: FnArg,
+    statements: &mut Vec<Statement>,
+) -> Result<FnArg, ErrorEmitted> {
+    match fn_arg.pattern {
+        Pattern::At {
+            name,
+            at_token,
+            pattern,
+        } => {
+            check_at_pattern_bindings(handler, &name, &pattern)?;
+            let span = at_token.span();
+            statements.push(Statement::Let(StatementLet {
+                let_token: LetToken::new(span.clone()),
+                pattern: *pattern,
+                ty_opt: None,
+                eq_token: EqToken::new(span.clone()),
+                expr: Expr::Path(PathExpr {
+                    root_opt: None,
+                    prefix: PathExprSegment {
+                        name: name.clone(),
+                        generics_opt: None,
+                    },
+                    suffix: vec![],
+                    incomplete_suffix: false,
+                }),
+                semicolon_token: SemicolonToken::new(span),
+            }));
+            Ok(FnArg {
+                pattern: Pattern::AmbiguousSingleIdent(name),
+                ..fn_arg
+            })
+        }
+        _ => Ok(fn_arg),
+    }
+}
+
+/// Checks that the pattern of `name @ pattern` does not bind `name` again.
+fn check_at_pattern_bindings(
+    handler: &Handler,
+    name: &Ident,
+    pattern: &Pattern,
+) -> Result<(), ErrorEmitted> {
+    match pattern_bound_names(pattern)
+        .into_iter()
+        .find(|bound_name| bound_name == name)
+    {
+        Some(bound_name) => {
+            let error = ConvertParseTreeError::DuplicatePatternBinding {
+                span: bound_name.span(),
+                name: bound_name,
+            };
+            Err(handler.emit_err(error.into()))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Returns the identifiers bound by a pattern.
+fn pattern_bound_names(pattern: &Pattern) -> Vec<Ident> {
+    match pattern {
+        Pattern::Var { name, .. } | Pattern::AmbiguousSingleIdent(name) => vec![name.clone()],
+        Pattern::At { name, pattern, .. } => std::iter::once(name.clone())
+            .chain(pattern_bound_names(pattern))
+            .collect(),
+        Pattern::Struct { fields, .. } => fields
+            .get()
+            .into_iter()
+            .flat_map(|field| match field {
+                PatternStructField::Field {
+                    field_name,
+                    pattern_opt: None,
+                } => vec![field_name.clone()],
+                PatternStructField::Field {
+                    pattern_opt: Some((_colon_token, pattern)),
+                    ..
+                } => pattern_bound_names(pattern),
+                PatternStructField::Rest { .. } => vec![],
+            })
+            .collect(),
+        Pattern::Constructor { args, .. } | Pattern::Tuple(args) => args
+            .get()
+            .into_iter()
+            .flat_map(pattern_bound_names)
+            .collect(),
+        Pattern::Or { lhs, .. } => pattern_bound_names(lhs),
+        Pattern::Array(elements) => elements
+            .get()
+            .into_iter()
+            .flat_map(|element| match element {
+                PatternArrayElement::Pattern(pattern) => pattern_bound_names(pattern),
+                PatternArrayElement::Rest { name_opt, .. } => name_opt.iter().cloned().collect(),
+            })
+            .collect(),
+        Pattern::Wildcard { .. }
+        | Pattern::Literal(..)
+        | Pattern::Constant(..)
+        | Pattern::Error(..) => vec![],
+    }
+}
+
+/// Checks that the arguments of the `#[coerce(..)]` attributes of a function name its parameters.
+fn check_coerced_parameters(
+    handler: &Handler,
+    attributes: &AttributesMap,
+    parameters: &[FunctionParameter],
+) -> Result<(), ErrorEmitted> {
+    let coerced_parameters = attributes
+        .get(&AttributeKind::Coerce)
+        .into_iter()
+        .flatten()
+        .flat_map(|attr| attr.args.iter());
+    handler.scope(|handler| {
+        for arg in coerced_parameters {
+            if !parameters.iter().any(|param| param.name == arg.name) {
+                let error = ConvertParseTreeError::CoercedParameterNotFound {
+                    name: arg.name.clone(),
+                    span: arg.span(),
+                };
+                handler.emit_err(error.into());
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Generates the builder of a function annotated with `#[builder]`, which collects the
+/// function's arguments one at a time using setters named after its parameters and then
+/// calls the function with them.
+///
+/// The builder of `fn transfer(to: Identity, amount: u64) -> bool` is used like
+/// `TransferBuilder {}.to(to).amount(5).call()`. Every setter returns a new struct that holds
+/// the arguments collected so far, so the setters are called in the order of the parameters
+/// and `call` is only available once all of the arguments are given:
+///
+/// ```ignore
+/// struct TransferBuilder {}
+/// struct TransferBuilder1 { to: Identity }
+/// struct TransferBuilder2 { to: Identity, amount: u64 }
+///
+/// impl TransferBuilder {
+///     fn to(self, to: Identity) -> TransferBuilder1 { TransferBuilder1 { to } }
+/// }
+/// impl TransferBuilder1 {
+///     fn amount(self, amount: u64) -> TransferBuilder2 {
+///         TransferBuilder2 { to: self.to, amount }
+///     }
+/// }
+/// impl TransferBuilder2 {
+///     fn call(self) -> bool { transfer(self.to, self.amount) }
+/// }
+/// ```
+///
+/// Setters are named after the parameters without their leading underscores.
+fn function_declaration_to_builder_declarations(
+    _context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    function_declaration: &FunctionDeclaration,
+) -> Result<Vec<Declaration>, ErrorEmitted> {
+    let type_engine = engines.te();
+    let FunctionDeclaration {
+        name: fn_name,
+        parameters,
+        return_type,
+        type_parameters,
+        purity,
+        attributes,
+        visibility,
+        span,
+        ..
+    } = function_declaration;
+
+    if !type_parameters.is_empty() {
+        let error = ConvertParseTreeError::BuilderFnCannotBeGeneric { span: span.clone() };
+        return Err(handler.emit_err(error.into()));
+    }
+
+    let mut setter_names = HashSet::<String>::default();
+    for parameter in parameters {
+        if parameter.is_rest {
+            let error = ConvertParseTreeError::BuilderFnCannotHaveRestParameter {
+                span: parameter.type_argument.span.clone(),
+            };
+            return Err(handler.emit_err(error.into()));
+        }
+        if parameter.is_reference && parameter.is_mutable {
+            let error = ConvertParseTreeError::BuilderParameterCannotBeRefMut {
+                name: parameter.name.clone(),
+                span: parameter.mutability_span.clone(),
+            };
+            return Err(handler.emit_err(error.into()));
+        }
+        let setter_name = builder_setter_name(&parameter.name);
+        if !setter_names.insert(setter_name.clone()) {
+            let error = ConvertParseTreeError::DuplicateBuilderSetter {
+                name: setter_name,
+                span: parameter.name.span(),
+            };
+            return Err(handler.emit_err(error.into()));
+        }
+    }
+
+    // The generated items are used only through the builder, so they are not reported as dead
+    // code if the builder is never used.
+    let allow_dead_code = || {
+        let attribute = Attribute {
+            name: Ident::new_with_override(ALLOW_ATTRIBUTE_NAME.to_string(), span.clone()),
+            args: vec![AttributeArg {
+                name: Ident::new_with_override(ALLOW_DEAD_CODE_NAME.to_string(), span.clone()),
+                value: None,
+                args: vec![],
+                span: span.clone(),
+            }],
+            span: span.clone(),
+        };
+        AttributesMap::new(Arc::new(HashMap::from([(
+            AttributeKind::Allow,
+            vec![attribute],
+        )])))
+    };
+    // Each use of a type gets its own type ID, like it would when written out in the source.
+    let copy_type_argument = |type_argument: &TypeArgument| {
+        let type_id = type_engine.insert(engines, type_engine.get(type_argument.type_id));
+        TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span: type_argument.span.clone(),
+            call_path_tree: type_argument.call_path_tree.clone(),
+        }
+    };
+    let stage_name = |stage: usize| {
+        let name = to_upper_camel_case(fn_name.as_str());
+        let name = match stage {
+            0 => format!("{name}Builder"),
+            stage => format!("{name}Builder{stage}"),
+        };
+        Ident::new_with_override(name, fn_name.span())
+    };
+    let stage_type_argument = |stage: usize| {
+        let type_id = type_engine.insert(
+            engines,
+            TypeInfo::Custom {
+                call_path: stage_name(stage).into(),
+                type_arguments: None,
+            },
+        );
+        TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span: fn_name.span(),
+            call_path_tree: None,
+        }
+    };
+    let expression = |kind| Expression {
+        kind,
+        span: span.clone(),
+    };
+    let self_ident = Ident::new_with_override("self".to_string(), span.clone());
+    let self_parameter = || {
+        let type_id = type_engine.insert(engines, TypeInfo::SelfType);
+        FunctionParameter {
+            name: self_ident.clone(),
+            is_reference: false,
+            is_mutable: false,
+            mutability_span: Span::dummy(),
+            type_argument: TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: span.clone(),
+                call_path_tree: None,
+            },
+            is_rest: false,
+            is_optional: false,
+            array_pattern: None,
+            bind_fields: None,
+            type_of: None,
+            attributes: Default::default(),
+        }
+    };
+    let self_field = |parameter: &FunctionParameter| {
+        expression(ExpressionKind::Subfield(SubfieldExpression {
+            prefix: Box::new(expression(ExpressionKind::Variable(self_ident.clone()))),
+            field_to_access: parameter.name.clone(),
+        }))
+    };
+    let method = |name: Ident,
+                  purity: Purity,
+                  attributes: AttributesMap,
+                  parameters: Vec<FunctionParameter>,
+                  return_type: TypeArgument,
+                  body: Expression| FunctionDeclaration {
+        purity,
+        attributes,
+        name,
+        visibility: *visibility,
+        body: CodeBlock {
+            contents: vec![AstNode {
+                content: AstNodeContent::ImplicitReturnExpression(body),
+                span: span.clone(),
+            }],
+            whole_block_span: span.clone(),
+        },
+        parameters,
+        span: span.clone(),
+        return_type,
+        type_parameters: vec![],
+        where_clause: vec![],
+    };
+
+    let mut struct_declarations = vec![];
+    let mut impl_declarations = vec![];
+    for stage in 0..=parameters.len() {
+        let collected = &parameters[..stage];
+        struct_declarations.push(Declaration::StructDeclaration(StructDeclaration {
+            name: stage_name(stage),
+            attributes: allow_dead_code(),
+            fields: collected
+                .iter()
+                .map(|parameter| StructField {
+                    name: parameter.name.clone(),
+                    attributes: AttributesMap::default(),
+                    span: parameter.name.span(),
+                    type_argument: copy_type_argument(&parameter.type_argument),
+                })
+                .collect(),
+            type_parameters: vec![],
+            visibility: *visibility,
+            span: span.clone(),
+        }));
+
+        let item = match parameters.get(stage) {
+            // The setter of the next parameter moves the collected arguments to the next stage.
+            Some(parameter) => {
+                let next_stage = stage_name(stage + 1);
+                let fields = collected
+                    .iter()
+                    .map(|field| StructExpressionField {
+                        name: field.name.clone(),
+                        value: self_field(field),
+                        span: field.name.span(),
+                    })
+                    .chain(iter::once(StructExpressionField {
+                        name: parameter.name.clone(),
+                        value: expression(ExpressionKind::Variable(parameter.name.clone())),
+                        span: parameter.name.span(),
+                    }))
+                    .collect();
+                let body = expression(ExpressionKind::Struct(Box::new(StructExpression {
+                    call_path_binding: TypeBinding {
+                        inner: next_stage.into(),
+                        type_arguments: TypeArgs::Regular(vec![]),
+                        span: span.clone(),
+                    },
+                    fields,
+                })));
+                method(
+                    Ident::new_with_override(
+                        builder_setter_name(&parameter.name),
+                        parameter.name.span(),
+                    ),
+                    Purity::Pure,
+                    allow_dead_code(),
+                    vec![
+                        self_parameter(),
+                        FunctionParameter {
+                            name: parameter.name.clone(),
+                            is_reference: false,
+                            is_mutable: false,
+                            mutability_span: Span::dummy(),
+                            type_argument: copy_type_argument(&parameter.type_argument),
+                            is_rest: false,
+                            is_optional: false,
+                            array_pattern: None,
+                            bind_fields: None,
+                            type_of: None,
+                            attributes: Default::default(),
+                        },
+                    ],
+                    stage_type_argument(stage + 1),
+                    body,
+                )
+            }
+            // Once all of the arguments are collected, `call` calls the function with them.
+            None => {
+                let body = expression(ExpressionKind::FunctionApplication(Box::new(
+                    FunctionApplicationExpression {
+                        call_path_binding: TypeBinding {
+                            inner: fn_name.clone().into(),
+                            type_arguments: TypeArgs::Regular(vec![]),
+                            span: fn_name.span(),
+                        },
+                        arguments: parameters.iter().map(self_field).collect(),
+                    },
+                )));
+                let mut call_attributes = allow_dead_code();
+                if let Some(storage) = attributes.get(&AttributeKind::Storage) {
+                    let mut attributes = (**call_attributes).clone();
+                    attributes.insert(AttributeKind::Storage, storage.clone());
+                    call_attributes = AttributesMap::new(Arc::new(attributes));
+                }
+                method(
+                    Ident::new_with_override("call".to_string(), fn_name.span()),
+                    *purity,
+                    call_attributes,
+                    vec![self_parameter()],
+                    copy_type_argument(return_type),
+                    body,
+                )
+            }
+        };
+        impl_declarations.push(Declaration::ImplSelf(ImplSelf {
+            impl_type_parameters: vec![],
+            implementing_for: stage_type_argument(stage),
+            items: vec![ImplItem::Fn(item)],
+            block_span: span.clone(),
+        }));
+    }
+
+    Ok(struct_declarations
+        .into_iter()
+        .chain(impl_declarations)
+        .collect())
+}
+
+/// Returns the name of the builder setter for the parameter `name`, which is the name without its
+/// leading underscores.
+fn builder_setter_name(name: &Ident) -> String {
+    match name.as_str().trim_start_matches('_') {
+        "" => name.as_str().to_string(),
+        setter_name => setter_name.to_string(),
+    }
+}
+
 fn get_attributed_purity(
     _context: &mut Context,
     handler: &Handler,
@@ -658,6 +1197,12 @@ fn item_impl_to_declaration(
             if !cfg_eval(context, handler, &attributes)? {
                 return Ok(None);
             }
+            if attributes.contains_key(&AttributeKind::Builder) {
+                let error = ConvertParseTreeError::BuilderFnOnlyAllowedAtModuleLevel {
+                    span: item.value.span(),
+                };
+                handler.emit_err(error.into());
+            }
             Ok(Some(match item.value {
                 sway_ast::ItemImplItem::Fn(fn_item) => item_fn_to_function_declaration(
                     context,
@@ -997,6 +1542,13 @@ fn item_type_alias_to_type_alias_declaration(
     Ok(TypeAliasDeclaration {
         name: item_type_alias.name.clone(),
         attributes,
+        type_parameters: generic_params_opt_to_type_parameters(
+            context,
+            handler,
+            engines,
+            item_type_alias.generics,
+            None,
+        )?,
         ty: ty_to_type_argument(context, handler, engines, item_type_alias.ty)?,
         visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
         span,
@@ -1196,53 +1748,99 @@ fn fn_args_to_function_parameters(
     fn_args: FnArgs,
 ) -> Result<Vec<FunctionParameter>, ErrorEmitted> {
     let function_parameters = match fn_args {
-        FnArgs::Static(args) => args
-            .into_iter()
-            .map(|fn_arg| fn_arg_to_function_parameter(context, handler, engines, fn_arg))
-            .collect::<Result<_, _>>()?,
+        FnArgs::Static { args, rest_opt } => {
+            let mut function_parameters = args
+                .into_iter()
+                .map(|fn_arg| fn_arg_to_function_parameter(context, handler, engines, fn_arg))
+                .filter_map_ok(|fn_param| fn_param)
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(rest) = rest_opt {
+                // The type of a rest parameter is inferred from the function it is forwarded to.
+                let type_id = engines.te().insert(engines, TypeInfo::Unknown);
+                function_parameters.push(FunctionParameter {
+                    name: rest.name.clone(),
+                    is_reference: false,
+                    is_mutable: false,
+                    mutability_span: Span::dummy(),
+                    type_argument: TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: rest.span(),
+                        call_path_tree: None,
+                    },
+                    is_rest: true,
+                    is_optional: false,
+                    array_pattern: None,
+                    bind_fields: None,
+                    type_of: None,
+                    attributes: Default::default(),
+                });
+            }
+            function_parameters
+        }
         FnArgs::NonStatic {
             self_token,
             ref_self,
             mutable_self,
+            self_ty_opt,
             args_opt,
         } => {
-            let mutability_span = match (&ref_self, &mutable_self) {
-                (None, None) => Span::dummy(),
-                (None, Some(mutable)) => mutable.span(),
-                (Some(reference), None) => reference.span(),
-                (Some(reference), Some(mutable)) => Span::join(reference.span(), mutable.span()),
+            let mutability_span = ref_mut_to_mutability_span(handler, &ref_self, &mutable_self);
+            // `self` is of type `Self`, unless its type is given explicitly, like in
+            // `self: Ptr<Self>`.
+            let type_argument = match self_ty_opt {
+                Some((_colon_token, ty)) => ty_to_type_argument(context, handler, engines, *ty)?,
+                None => {
+                    let type_id = engines.te().insert(engines, TypeInfo::SelfType);
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: self_token.span(),
+                        call_path_tree: None,
+                    }
+                }
             };
-            let type_id = engines.te().insert(engines, TypeInfo::SelfType);
             let mut function_parameters = vec![FunctionParameter {
                 name: Ident::new(self_token.span()),
                 is_reference: ref_self.is_some(),
                 is_mutable: mutable_self.is_some(),
                 mutability_span,
-                type_argument: TypeArgument {
-                    type_id,
-                    initial_type_id: type_id,
-                    span: self_token.span(),
-                    call_path_tree: None,
-                },
+                type_argument,
+                is_rest: false,
+                is_optional: false,
+                array_pattern: None,
+                bind_fields: None,
+                type_of: None,
+                attributes: Default::default(),
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
-                    let function_parameter =
-                        fn_arg_to_function_parameter(context, handler, engines, arg)?;
-                    function_parameters.push(function_parameter);
+                    if let Some(function_parameter) =
+                        fn_arg_to_function_parameter(context, handler, engines, arg)?
+                    {
+                        function_parameters.push(function_parameter);
+                    }
                 }
             }
             function_parameters
         }
     };
 
+    // The names bound by array patterns must be unique, too.
+    let bound_names =
+        function_parameters
+            .iter()
+            .flat_map(|fn_param| match &fn_param.array_pattern {
+                Some(array_pattern) => array_pattern.bound_names(),
+                None => vec![fn_param.name.clone()],
+            });
     let mut unique_params = HashSet::<Ident>::default();
-    for fn_param in &function_parameters {
-        let already_used = !unique_params.insert(fn_param.name.clone());
+    for name in bound_names {
+        let already_used = !unique_params.insert(name.clone());
         if already_used {
             let error = ConvertParseTreeError::DuplicateParameterIdentifier {
-                name: fn_param.name.clone(),
-                span: fn_param.name.span(),
+                span: name.span(),
+                name,
             };
             return Err(handler.emit_err(error.into()));
         }
@@ -1289,7 +1887,7 @@ fn ty_to_type_info(
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
             TypeInfo::Array(
                 ty_to_type_argument(context, handler, engines, *ty_array_descriptor.ty)?,
-                expr_to_length(context, handler, *ty_array_descriptor.length)?,
+                expr_to_array_length(context, handler, *ty_array_descriptor.length)?,
             )
         }
         Ty::Str { length, .. } => {
@@ -1304,6 +1902,11 @@ fn ty_to_type_info(
             let type_argument = ty_to_type_argument(context, handler, engines, *ty.into_inner())?;
             TypeInfo::Slice(type_argument)
         }
+        Ty::TypeOf { typeof_token, expr } => {
+            let span = Span::join(typeof_token.span(), expr.span());
+            let error = ConvertParseTreeError::TypeofTypesNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     Ok(type_info)
 }
@@ -1825,6 +2428,7 @@ fn expr_to_expression(
                     let array_expression = ArrayExpression {
                         contents,
                         length_span: None,
+                        length_constant: None,
                     };
                     Expression {
                         kind: ExpressionKind::Array(array_expression),
@@ -1834,13 +2438,27 @@ fn expr_to_expression(
                 ExprArrayDescriptor::Repeat { value, length, .. } => {
                     let expression = expr_to_expression(context, handler, engines, *value)?;
                     let length_span = length.span();
-                    let length = expr_to_usize(context, handler, *length)?;
-                    let contents = iter::repeat_with(|| expression.clone())
-                        .take(length)
-                        .collect();
-                    let array_expression = ArrayExpression {
-                        contents,
-                        length_span: Some(length_span),
+                    let array_expression = match *length {
+                        // The value of a constant is only known after type checking, so the
+                        // value is repeated then.
+                        Expr::Path(path_expr) => ArrayExpression {
+                            contents: vec![expression],
+                            length_span: Some(length_span),
+                            length_constant: Some(path_expr_to_call_path(
+                                context, handler, path_expr,
+                            )?),
+                        },
+                        length => {
+                            let length = expr_to_usize(context, handler, length)?;
+                            let contents = iter::repeat_with(|| expression.clone())
+                                .take(length)
+                                .collect();
+                            ArrayExpression {
+                                contents,
+                                length_span: Some(length_span),
+                                length_constant: None,
+                            }
+                        }
                     };
                     Expression {
                         kind: ExpressionKind::Array(array_expression),
@@ -2057,6 +2675,10 @@ fn expr_to_expression(
             let expr = expr_to_expression(context, handler, engines, *expr)?;
             op_call("not", bang_token.span(), span, &[expr])?
         }
+        Expr::Spread { name, .. } => Expression {
+            kind: ExpressionKind::Spread(name),
+            span,
+        },
         Expr::Pow {
             lhs,
             double_star_token,
@@ -2226,6 +2848,96 @@ fn expr_to_expression(
             }),
             span,
         },
+        // Assigning to an index into storage, like `storage.map[key] = value`, is sugar for
+        // `storage.map.insert(key, value)`. Compound assignments, like `storage.map[key] += 1`,
+        // read the current value first. The key is evaluated only once, by binding it to a
+        // variable: `{ let k = key; storage.map.insert(k, storage.map.get(k).read() + 1) }`.
+        Expr::Reassignment {
+            assignable: Assignable::Index { target, arg },
+            expr,
+            reassignment_op:
+                ReassignmentOp {
+                    variant: op_variant,
+                    span: op_span,
+                },
+        } if is_storage_assignable(&target) => {
+            let map = assignable_to_expression(context, handler, engines, *target)?;
+            let key = expr_to_expression(context, handler, engines, *arg.into_inner())?;
+            let value = expr_to_expression(context, handler, engines, *expr)?;
+            match op_variant {
+                ReassignmentOpVariant::Equals => {
+                    storage_method_call("insert", span, vec![map, key, value])
+                }
+                op_variant => {
+                    let key_span = key.span();
+                    let key_var_name = Ident::new_with_override(
+                        format!(
+                            "{}{}",
+                            STORAGE_MAP_KEY_VAR_NAME_PREFIX,
+                            context.next_storage_map_key_var_unique_suffix(),
+                        ),
+                        key_span.clone(),
+                    );
+                    let key_var_exp = Expression {
+                        kind: ExpressionKind::Variable(key_var_name.clone()),
+                        span: key_span,
+                    };
+                    let current_value = storage_method_call(
+                        "read",
+                        span.clone(),
+                        vec![storage_method_call(
+                            "get",
+                            span.clone(),
+                            vec![map.clone(), key_var_exp.clone()],
+                        )],
+                    );
+                    let value = op_call(
+                        op_variant.core_name(),
+                        op_span,
+                        span.clone(),
+                        &vec![current_value, value],
+                    )?;
+                    Expression {
+                        kind: ExpressionKind::CodeBlock(CodeBlock {
+                            contents: vec![
+                                AstNode {
+                                    content: AstNodeContent::Declaration(
+                                        Declaration::VariableDeclaration(VariableDeclaration {
+                                            type_ascription: {
+                                                let type_id =
+                                                    engines.te().insert(engines, TypeInfo::Unknown);
+                                                TypeArgument {
+                                                    type_id,
+                                                    initial_type_id: type_id,
+                                                    span: key_var_name.span(),
+                                                    call_path_tree: None,
+                                                }
+                                            },
+                                            name: key_var_name,
+                                            is_mutable: false,
+                                            body: key,
+                                        }),
+                                    ),
+                                    span: span.clone(),
+                                },
+                                AstNode {
+                                    content: AstNodeContent::ImplicitReturnExpression(
+                                        storage_method_call(
+                                            "insert",
+                                            span.clone(),
+                                            vec![map, key_var_exp, value],
+                                        ),
+                                    ),
+                                    span: span.clone(),
+                                },
+                            ],
+                            whole_block_span: span.clone(),
+                        }),
+                        span,
+                    }
+                }
+            }
+        }
         Expr::Reassignment {
             assignable,
             expr,
@@ -2306,6 +3018,39 @@ fn op_call(
     })
 }
 
+/// Returns `true` if `assignable` is a storage field, like `storage.foo.bar`, possibly accessed
+/// through indices into storage maps, like `storage.foo[key]`.
+fn is_storage_assignable(assignable: &Assignable) -> bool {
+    match assignable {
+        Assignable::FieldProjection { target, .. } => match &**target {
+            Assignable::Var(name) => name.as_str() == "storage",
+            target => is_storage_assignable(target),
+        },
+        Assignable::Index { target, .. } => is_storage_assignable(target),
+        _ => false,
+    }
+}
+
+/// Creates a call to the method `name` of a storage type, like `StorageMap::get`, whose
+/// receiver is the first of the `args`.
+fn storage_method_call(name: &'static str, span: Span, args: Vec<Expression>) -> Expression {
+    let method_name_binding = TypeBinding {
+        inner: MethodName::FromModule {
+            method_name: Ident::new_with_override(name.into(), span.clone()),
+        },
+        type_arguments: TypeArgs::Regular(vec![]),
+        span: span.clone(),
+    };
+    Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding,
+            contract_call_params: Vec::new(),
+            arguments: args,
+        })),
+        span,
+    }
+}
+
 fn storage_field_to_storage_field(
     context: &mut Context,
     handler: &Handler,
@@ -2379,18 +3124,27 @@ fn statement_to_ast_nodes(
     Ok(ast_nodes)
 }
 
+/// Converts a parameter, or returns `None` if the parameter is compiled out by its `cfg`
+/// attributes.
 fn fn_arg_to_function_parameter(
     context: &mut Context,
     handler: &Handler,
     engines: &Engines,
     fn_arg: FnArg,
-) -> Result<FunctionParameter, ErrorEmitted> {
+) -> Result<Option<FunctionParameter>, ErrorEmitted> {
+    let attributes = item_attrs_to_map(context, handler, &fn_arg.attribute_list)?;
+    if !cfg_eval(context, handler, &attributes)? {
+        return Ok(None);
+    }
     let pat_span = fn_arg.pattern.span();
+    let mut array_pattern = None;
     let (reference, mutable, name) = match fn_arg.pattern {
-        Pattern::Wildcard { .. } => {
-            let error = ConvertParseTreeError::WildcardPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
+        // A `_` parameter is accepted, but it is not inserted into the namespace.
+        Pattern::Wildcard { underscore_token } => (
+            None,
+            None,
+            Ident::new_with_override("_".into(), underscore_token.span()),
+        ),
         Pattern::Or { .. } => {
             let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
@@ -2422,21 +3176,112 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
         }
+        Pattern::At { .. } => {
+            let error = ConvertParseTreeError::AtPatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Array(elements) => {
+            array_pattern = Some(pattern_array_to_array_parameter_pattern(handler, elements)?);
+            // The array is passed under a generated name, and destructured in the body.
+            let name = Ident::new_with_override(
+                format!(
+                    "{}{}",
+                    ARRAY_PARAM_NAME_PREFIX,
+                    context.next_destructured_array_param_unique_suffix()
+                ),
+                pat_span,
+            );
+            (None, None, name)
+        }
     };
-    let mutability_span = match (&reference, &mutable) {
-        (None, None) => Span::dummy(),
-        (None, Some(mutable)) => mutable.span(),
-        (Some(reference), None) => reference.span(),
-        (Some(reference), Some(mutable)) => Span::join(reference.span(), mutable.span()),
+    let mutability_span = ref_mut_to_mutability_span(handler, &reference, &mutable);
+    let (type_argument, type_of) = match fn_arg.ty {
+        // The type stays unknown until the expression is type checked with the signature.
+        Ty::TypeOf { typeof_token, expr } => {
+            let span = Span::join(typeof_token.span(), expr.span());
+            let expr = expr_to_expression(context, handler, engines, *expr.into_inner())?;
+            let type_id = engines.te().insert(engines, TypeInfo::Unknown);
+            let type_argument = TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span,
+                call_path_tree: None,
+            };
+            (type_argument, Some(expr))
+        }
+        ty => (ty_to_type_argument(context, handler, engines, ty)?, None),
     };
     let function_parameter = FunctionParameter {
         name,
         is_reference: reference.is_some(),
         is_mutable: mutable.is_some(),
         mutability_span,
-        type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
+        type_argument,
+        is_rest: false,
+        is_optional: fn_arg.question_mark_token.is_some(),
+        array_pattern,
+        bind_fields: attributes
+            .get(&AttributeKind::Fields)
+            .and_then(|attrs| attrs.last())
+            .map(|attr| attr.span.clone()),
+        type_of,
+        attributes,
     };
-    Ok(function_parameter)
+    Ok(Some(function_parameter))
+}
+
+/// Converts the array pattern of a parameter, like `[first, _, ..rest]`, whose elements can only
+/// be names, wildcards and a trailing rest.
+fn pattern_array_to_array_parameter_pattern(
+    handler: &Handler,
+    elements: SquareBrackets<Punctuated<PatternArrayElement, CommaToken>>,
+) -> Result<ArrayParameterPattern, ErrorEmitted> {
+    let span = elements.span();
+    let mut array_pattern = ArrayParameterPattern {
+        elements: vec![],
+        rest: None,
+        span,
+    };
+    for element in elements.into_inner() {
+        match element {
+            PatternArrayElement::Rest { name_opt, .. } => array_pattern.rest = Some(name_opt),
+            PatternArrayElement::Pattern(pattern) => match *pattern {
+                Pattern::AmbiguousSingleIdent(name) => array_pattern.elements.push(Some(name)),
+                Pattern::Wildcard { .. } => array_pattern.elements.push(None),
+                pattern => {
+                    let error = ConvertParseTreeError::ArrayPatternElementNotSupported {
+                        span: pattern.span(),
+                    };
+                    return Err(handler.emit_err(error.into()));
+                }
+            },
+        }
+    }
+    Ok(array_pattern)
+}
+
+/// Returns the span covering the `ref` and `mut` keywords of a parameter, and warns if they
+/// are spelled as `mut ref` instead of `ref mut`.
+fn ref_mut_to_mutability_span(
+    handler: &Handler,
+    reference: &Option<RefToken>,
+    mutable: &Option<MutToken>,
+) -> Span {
+    match (reference, mutable) {
+        (None, None) => Span::dummy(),
+        (None, Some(mutable)) => mutable.span(),
+        (Some(reference), None) => reference.span(),
+        (Some(reference), Some(mutable)) => {
+            let span = Span::join(reference.span(), mutable.span());
+            if mutable.span().start() < reference.span().start() {
+                handler.emit_warn(CompileWarning {
+                    span: span.clone(),
+                    warning_content: Warning::MutRefKeywordOrder,
+                });
+            }
+            span
+        }
+    }
 }
 
 fn expr_to_length(
@@ -2448,6 +3293,23 @@ fn expr_to_length(
     Ok(Length::new(expr_to_usize(context, handler, expr)?, span))
 }
 
+/// Like [expr_to_length], but also accepts a path to a constant, whose value is resolved during
+/// type checking.
+fn expr_to_array_length(
+    context: &mut Context,
+    handler: &Handler,
+    expr: Expr,
+) -> Result<Length, ErrorEmitted> {
+    match expr {
+        Expr::Path(path_expr) => {
+            let span = path_expr.span();
+            let call_path = path_expr_to_call_path(context, handler, path_expr)?;
+            Ok(Length::from_constant(call_path, span))
+        }
+        expr => expr_to_length(context, handler, expr),
+    }
+}
+
 fn expr_to_usize(
     _context: &mut Context,
     handler: &Handler,
@@ -3300,6 +4162,37 @@ fn statement_let_to_ast_nodes(
                 let error = ConvertParseTreeError::OrPatternsNotSupportedHere { span };
                 return Err(handler.emit_err(error.into()));
             }
+            Pattern::Array(..) => {
+                let error = ConvertParseTreeError::ArrayPatternsNotSupportedHere { span };
+                return Err(handler.emit_err(error.into()));
+            }
+            Pattern::At { name, pattern, .. } => {
+                check_at_pattern_bindings(handler, &name, &pattern)?;
+
+                // Bind the whole value to the name first, and then destructure the variable.
+                let mut ast_nodes = unfold(
+                    context,
+                    handler,
+                    engines,
+                    Pattern::AmbiguousSingleIdent(name.clone()),
+                    ty_opt,
+                    expression,
+                    span.clone(),
+                )?;
+                ast_nodes.extend(unfold(
+                    context,
+                    handler,
+                    engines,
+                    *pattern,
+                    None,
+                    Expression {
+                        kind: ExpressionKind::Variable(name),
+                        span: span.clone(),
+                    },
+                    span,
+                )?);
+                ast_nodes
+            }
             Pattern::Tuple(pat_tuple) => {
                 let mut ast_nodes = Vec::new();
 
@@ -3629,6 +4522,14 @@ fn pattern_to_scrutinee(
             },
             span,
         },
+        Pattern::At { .. } => {
+            let error = ConvertParseTreeError::AtPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Array(..) => {
+            let error = ConvertParseTreeError::ArrayPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
         Pattern::Error(spans, err) => Scrutinee::Error { spans, err },
     };
     Ok(scrutinee)
@@ -3661,6 +4562,7 @@ fn ty_to_type_parameter(
         Ty::Str { .. } => panic!("str types are not allowed in this position"),
         Ty::Ptr { .. } => panic!("__ptr types are not allowed in this position"),
         Ty::Slice { .. } => panic!("__slice types are not allowed in this position"),
+        Ty::TypeOf { .. } => panic!("typeof types are not allowed in this position"),
     };
     let custom_type = type_engine.insert(
         engines,
@@ -4025,12 +4927,7 @@ fn item_attrs_to_map(
                     parens
                         .get()
                         .into_iter()
-                        .cloned()
-                        .map(|arg| AttributeArg {
-                            name: arg.name.clone(),
-                            value: arg.value.clone(),
-                            span: arg.span(),
-                        })
+                        .map(AttributeArg::from_lexed)
                         .collect()
                 })
                 .unwrap_or_else(Vec::new);
@@ -4048,8 +4945,14 @@ fn item_attrs_to_map(
                 INLINE_ATTRIBUTE_NAME => Some(AttributeKind::Inline),
                 TEST_ATTRIBUTE_NAME => Some(AttributeKind::Test),
                 PAYABLE_ATTRIBUTE_NAME => Some(AttributeKind::Payable),
+                BUILDER_ATTRIBUTE_NAME => Some(AttributeKind::Builder),
+                COERCE_ATTRIBUTE_NAME => Some(AttributeKind::Coerce),
+                FIELDS_ATTRIBUTE_NAME => Some(AttributeKind::Fields),
                 ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
+                DENY_ATTRIBUTE_NAME => Some(AttributeKind::Deny),
                 CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
+                DEPRECATED_ATTRIBUTE_NAME => Some(AttributeKind::Deprecated),
+                EXTERN_ABI_ATTRIBUTE_NAME => Some(AttributeKind::ExternAbi),
                 _ => None,
             } {
                 match attrs_map.get_mut(&attr_kind) {
@@ -4064,8 +4967,17 @@ fn item_attrs_to_map(
         }
     }
 
-    // Check attribute arguments
-    for (attribute_kind, attributes) in &attrs_map {
+    check_attribute_args(handler, &attrs_map);
+    Ok(AttributesMap::new(Arc::new(attrs_map)))
+}
+
+/// Warns about attributes in `attrs_map` with an unexpected number of arguments or with argument
+/// values that the attribute does not expect.
+pub(crate) fn check_attribute_args(
+    handler: &Handler,
+    attrs_map: &HashMap<AttributeKind, Vec<Attribute>>,
+) {
+    for (attribute_kind, attributes) in attrs_map {
         for attribute in attributes {
             // check attribute arguments length
             let (expected_min_len, expected_max_len) =
@@ -4102,7 +5014,6 @@ fn item_attrs_to_map(
             }
         }
     }
-    Ok(AttributesMap::new(Arc::new(attrs_map)))
 }
 
 fn error_if_self_param_is_not_allowed(
@@ -4137,74 +5048,98 @@ pub fn cfg_eval(
     if let Some(cfg_attrs) = attrs_map.get(&AttributeKind::Cfg) {
         for cfg_attr in cfg_attrs {
             for arg in &cfg_attr.args {
-                match arg.name.as_str() {
-                    CFG_TARGET_ARG_NAME => {
-                        if let Some(value) = &arg.value {
-                            if let sway_ast::Literal::String(value_str) = value {
-                                if let Ok(target) = BuildTarget::from_str(value_str.parsed.as_str())
-                                {
-                                    if target != context.build_target() {
-                                        return Ok(false);
-                                    }
-                                } else {
-                                    let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
-                                        span: value.span(),
-                                        value: value.span().str(),
-                                    };
-                                    return Err(handler.emit_err(error.into()));
-                                }
-                            } else {
-                                let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
-                                    span: value.span(),
-                                    value: value.span().str(),
-                                };
-                                return Err(handler.emit_err(error.into()));
-                            }
-                        } else {
-                            let error = ConvertParseTreeError::ExpectedCfgTargetArgValue {
-                                span: arg.span(),
-                            };
-                            return Err(handler.emit_err(error.into()));
-                        }
-                    }
-                    CFG_PROGRAM_TYPE_ARG_NAME => {
-                        if let Some(value) = &arg.value {
-                            if let sway_ast::Literal::String(value_str) = value {
-                                if let Ok(program_type) =
-                                    TreeType::from_str(value_str.parsed.as_str())
-                                {
-                                    if program_type != context.program_type().unwrap() {
-                                        return Ok(false);
-                                    }
-                                } else {
-                                    let error =
-                                        ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
-                                            span: value.span(),
-                                            value: value.span().str(),
-                                        };
-                                    return Err(handler.emit_err(error.into()));
-                                }
-                            } else {
-                                let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
-                                    span: value.span(),
-                                    value: value.span().str(),
-                                };
-                                return Err(handler.emit_err(error.into()));
-                            }
-                        } else {
-                            let error = ConvertParseTreeError::ExpectedCfgTargetArgValue {
-                                span: arg.span(),
-                            };
-                            return Err(handler.emit_err(error.into()));
-                        }
+                if !cfg_predicate_eval(context, handler, arg)? {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Evaluates a single cfg predicate, e.g., `target = "fuel"`, `testnet`, or
+/// `any(testnet, not(program_type = "script"))`.
+fn cfg_predicate_eval(
+    context: &Context,
+    handler: &Handler,
+    arg: &AttributeArg,
+) -> Result<bool, ErrorEmitted> {
+    let invalid_predicate = || {
+        let error = ConvertParseTreeError::InvalidCfgPredicate {
+            span: arg.span(),
+            predicate: arg.span().str(),
+        };
+        handler.emit_err(error.into())
+    };
+    match arg.name.as_str() {
+        CFG_TARGET_ARG_NAME => {
+            if let Some(value) = &arg.value {
+                if let sway_ast::Literal::String(value_str) = value {
+                    if let Ok(target) = BuildTarget::from_str(value_str.parsed.as_str()) {
+                        Ok(target == context.build_target())
+                    } else {
+                        let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
+                            span: value.span(),
+                            value: value.span().str(),
+                        };
+                        Err(handler.emit_err(error.into()))
                     }
-                    _ => {
-                        // Already checked with `AttributeKind::expected_args_*`
-                        unreachable!("cfg attribute should only have the `target` or the `program_type` argument");
+                } else {
+                    let error = ConvertParseTreeError::InvalidCfgTargetArgValue {
+                        span: value.span(),
+                        value: value.span().str(),
+                    };
+                    Err(handler.emit_err(error.into()))
+                }
+            } else {
+                let error = ConvertParseTreeError::ExpectedCfgTargetArgValue { span: arg.span() };
+                Err(handler.emit_err(error.into()))
+            }
+        }
+        CFG_PROGRAM_TYPE_ARG_NAME => {
+            if let Some(value) = &arg.value {
+                if let sway_ast::Literal::String(value_str) = value {
+                    if let Ok(program_type) = TreeType::from_str(value_str.parsed.as_str()) {
+                        Ok(program_type == context.program_type().unwrap())
+                    } else {
+                        let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
+                            span: value.span(),
+                            value: value.span().str(),
+                        };
+                        Err(handler.emit_err(error.into()))
                     }
+                } else {
+                    let error = ConvertParseTreeError::InvalidCfgProgramTypeArgValue {
+                        span: value.span(),
+                        value: value.span().str(),
+                    };
+                    Err(handler.emit_err(error.into()))
                 }
+            } else {
+                let error = ConvertParseTreeError::ExpectedCfgTargetArgValue { span: arg.span() };
+                Err(handler.emit_err(error.into()))
             }
         }
+        _ if arg.value.is_some() => Err(invalid_predicate()),
+        CFG_NOT_ARG_NAME => match arg.args.as_slice() {
+            [predicate] => Ok(!cfg_predicate_eval(context, handler, predicate)?),
+            _ => Err(invalid_predicate()),
+        },
+        // All the nested predicates are evaluated, so that all the invalid ones get reported.
+        CFG_ANY_ARG_NAME | CFG_ALL_ARG_NAME => {
+            let is_any = arg.name.as_str() == CFG_ANY_ARG_NAME;
+            let mut result = !is_any;
+            let mut error = None;
+            for predicate in &arg.args {
+                match cfg_predicate_eval(context, handler, predicate) {
+                    Ok(value) if is_any => result |= value,
+                    Ok(value) => result &= value,
+                    Err(err) => error = Some(err),
+                }
+            }
+            error.map_or(Ok(result), Err)
+        }
+        _ if !arg.args.is_empty() => Err(invalid_predicate()),
+        flag => Ok(context.is_cfg_flag_set(flag)),
     }
-    Ok(true)
 }
@@ -1,20 +1,43 @@
 use sway_types::{span::Span, Spanned};
 
+use crate::language::CallPath;
+
 /// Describes a fixed length for types that needs it such as arrays and strings
 #[derive(Debug, Clone, Hash)]
 pub struct Length {
     val: usize,
     span: Span,
+    /// The constant the length refers to, until it is resolved to the value of that constant.
+    constant: Option<CallPath>,
 }
 
 impl Length {
     pub fn new(val: usize, span: Span) -> Self {
-        Length { val, span }
+        Length {
+            val,
+            span,
+            constant: None,
+        }
+    }
+
+    /// Creates a length given by the value of the constant at `call_path`. The value is only known
+    /// once the length is resolved, see `TypeEngine::resolve_length`.
+    pub fn from_constant(call_path: CallPath, span: Span) -> Self {
+        Length {
+            val: 0,
+            span,
+            constant: Some(call_path),
+        }
     }
 
     pub fn val(&self) -> usize {
         self.val
     }
+
+    /// Returns the constant this length refers to, if it has not been resolved yet.
+    pub fn constant(&self) -> Option<&CallPath> {
+        self.constant.as_ref()
+    }
 }
 
 impl Spanned for Length {
@@ -45,6 +45,11 @@ impl HashWithEngines for TypeArgument {
 impl EqWithEngines for TypeArgument {}
 impl PartialEqWithEngines for TypeArgument {
     fn eq(&self, other: &Self, engines: &Engines) -> bool {
+        // Structurally equal types that can not change are interned by the [TypeEngine],
+        // so for them comparing the ids is enough.
+        if self.type_id == other.type_id {
+            return true;
+        }
         let type_engine = engines.te();
         type_engine
             .get(self.type_id)
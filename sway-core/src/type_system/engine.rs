@@ -1,28 +1,44 @@
 use core::fmt::Write;
 use hashbrown::hash_map::RawEntryMut;
 use hashbrown::HashMap;
-use std::sync::RwLock;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    RwLock,
+};
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::integer_bits::IntegerBits;
 
 use crate::concurrent_slab::ListDisplay;
 use crate::{
-    concurrent_slab::ConcurrentSlab, decl_engine::*, engine_threading::*, language::ty,
-    namespace::Path, type_system::priv_prelude::*, Namespace,
+    concurrent_slab::ConcurrentSlab,
+    decl_engine::*,
+    engine_threading::*,
+    language::{ty, Literal},
+    namespace::Path,
+    type_system::priv_prelude::*,
+    Namespace,
 };
 
-use sway_error::{error::CompileError, type_error::TypeError};
+use sway_error::{
+    error::CompileError,
+    type_error::{ExpectedTypeOrigin, TypeError},
+};
 use sway_types::{span::Span, Ident, Spanned};
 
 #[derive(Debug, Default)]
 pub struct TypeEngine {
     pub(super) slab: ConcurrentSlab<TypeInfo>,
     id_map: RwLock<HashMap<TypeInfo, TypeId>>,
+    /// Incremented every time a [TypeInfo] is substituted in place by [TypeEngine::replace].
+    generation: AtomicUsize,
 }
 
 impl TypeEngine {
     /// Inserts a [TypeInfo] into the [TypeEngine] and returns a [TypeId]
     /// referring to that [TypeInfo].
+    ///
+    /// A [TypeInfo] that can not change (see [TypeInfo::can_change]) is interned: inserting
+    /// a structurally equal one again returns the existing [TypeId].
     pub(crate) fn insert(&self, engines: &Engines, ty: TypeInfo) -> TypeId {
         let mut id_map = self.id_map.write().unwrap();
 
@@ -34,9 +50,7 @@ impl TypeEngine {
             .from_hash(ty_hash, |x| x.eq(&ty, engines));
         match raw_entry {
             RawEntryMut::Occupied(o) => return *o.get(),
-            RawEntryMut::Vacant(_) if ty.can_change(engines.de()) => {
-                TypeId::new(self.slab.insert(ty))
-            }
+            RawEntryMut::Vacant(_) if ty.can_change(engines) => TypeId::new(self.slab.insert(ty)),
             RawEntryMut::Vacant(v) => {
                 let type_id = TypeId::new(self.slab.insert(ty.clone()));
                 v.insert_with_hasher(ty_hash, ty, type_id, make_hasher(&hash_builder, engines));
@@ -45,6 +59,29 @@ impl TypeEngine {
         }
     }
 
+    /// Replaces the [TypeInfo] referred to by `id` with `new_value`, provided that it is still
+    /// equal to `prev_value`. Otherwise, returns the actual current [TypeInfo] of `id`.
+    pub(crate) fn replace(
+        &self,
+        engines: &Engines,
+        id: TypeId,
+        prev_value: &TypeInfo,
+        new_value: TypeInfo,
+    ) -> Option<TypeInfo> {
+        let res = self.slab.replace(id, prev_value, new_value, engines);
+        if res.is_none() {
+            self.generation.fetch_add(1, Ordering::Relaxed);
+        }
+        res
+    }
+
+    /// Returns a counter that changes whenever a [TypeInfo] is substituted in place, e.g. when
+    /// unifying types during monomorphization. Anything derived from the current contents of
+    /// the [TypeEngine] can use it to find out when it has gone stale.
+    pub(crate) fn generation(&self) -> usize {
+        self.generation.load(Ordering::Relaxed)
+    }
+
     /// Performs a lookup of `id` into the [TypeEngine].
     pub fn get(&self, id: TypeId) -> TypeInfo {
         self.slab.get(id.index())
@@ -115,6 +152,11 @@ impl TypeEngine {
                 if let EnforceTypeArguments::Yes = enforce_type_arguments {
                     return Err(handler.emit_err(CompileError::NeedsTypeArguments {
                         name: value.name().clone(),
+                        type_parameters: value
+                            .type_parameters()
+                            .iter()
+                            .map(|type_param| type_param.name_ident.to_string())
+                            .collect(),
                         span: call_site_span.clone(),
                     }));
                 }
@@ -193,17 +235,19 @@ impl TypeEngine {
         self_type: TypeId,
         span: &Span,
         help_text: &str,
+        expected_origin: Option<&ExpectedTypeOrigin>,
         err_override: Option<CompileError>,
     ) {
         received.replace_self_type(engines, self_type);
         expected.replace_self_type(engines, self_type);
-        self.unify(
+        self.unify_with_origin(
             handler,
             engines,
             received,
             expected,
             span,
             help_text,
+            expected_origin,
             err_override,
         )
     }
@@ -225,6 +269,33 @@ impl TypeEngine {
         span: &Span,
         help_text: &str,
         err_override: Option<CompileError>,
+    ) {
+        self.unify_with_origin(
+            handler,
+            engines,
+            received,
+            expected,
+            span,
+            help_text,
+            None,
+            err_override,
+        )
+    }
+
+    /// Same as [TypeEngine::unify], but the "mismatched type" errors also point
+    /// to the `expected_origin`, the place in source code that requires the
+    /// `expected` type.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn unify_with_origin(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+        received: TypeId,
+        expected: TypeId,
+        span: &Span,
+        help_text: &str,
+        expected_origin: Option<&ExpectedTypeOrigin>,
+        err_override: Option<CompileError>,
     ) {
         if !UnifyCheck::coercion(engines).check(received, expected) {
             // create a "mismatched type" error unless the `err_override`
@@ -235,17 +306,18 @@ impl TypeEngine {
                 }
                 None => {
                     handler.emit_err(CompileError::TypeError(TypeError::MismatchedType {
-                        expected: engines.help_out(expected).to_string(),
-                        received: engines.help_out(received).to_string(),
+                        expected: expected.to_diagnostic_string(engines, None),
+                        received: received.to_diagnostic_string(engines, None),
                         help_text: help_text.to_string(),
                         span: span.clone(),
+                        expected_origin: expected_origin.cloned(),
                     }));
                 }
             }
             return;
         }
         let h = Handler::default();
-        Unifier::new(engines, help_text).unify(handler, received, expected, span);
+        Unifier::new(engines, help_text, expected_origin).unify(handler, received, expected, span);
         match err_override {
             Some(err_override) if h.has_errors() => {
                 handler.emit_err(err_override);
@@ -375,6 +447,54 @@ impl TypeEngine {
         Ok(())
     }
 
+    /// Resolve a [Length] that refers to a constant to the value of that constant. Lengths given
+    /// by a literal are returned unchanged.
+    pub(crate) fn resolve_length(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+        length: Length,
+        namespace: &Namespace,
+        mod_path: &Path,
+    ) -> Result<Length, ErrorEmitted> {
+        let call_path = match length.constant() {
+            Some(call_path) => call_path,
+            None => return Ok(length),
+        };
+        let decl = namespace
+            .root()
+            .resolve_call_path_with_visibility_check(handler, engines, mod_path, call_path)?;
+        let value = match decl {
+            ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => {
+                let const_decl = engines.de().get_constant(decl_id);
+                if const_decl.is_configurable {
+                    return Err(
+                        handler.emit_err(CompileError::ConfigurableInConstantContext {
+                            name: const_decl.call_path.suffix,
+                            span: length.span(),
+                        }),
+                    );
+                }
+                const_decl
+                    .value
+                    .and_then(|value| value.extract_literal_value())
+            }
+            _ => None,
+        };
+        let val = match value {
+            Some(Literal::U8(val)) => val as usize,
+            Some(Literal::U16(val)) => val as usize,
+            Some(Literal::U32(val)) => val as usize,
+            Some(Literal::U64(val) | Literal::Numeric(val)) => val as usize,
+            _ => {
+                return Err(handler.emit_err(CompileError::NonConstantArrayLength {
+                    span: length.span(),
+                }))
+            }
+        };
+        Ok(Length::new(val, length.span()))
+    }
+
     /// Resolve the type of the given [TypeId], replacing any instances of
     /// [TypeInfo::Custom] with either a monomorphized struct, monomorphized
     /// enum, or a reference to a type parameter.
@@ -474,10 +594,20 @@ impl TypeEngine {
                         decl_id: original_id,
                         ..
                     })) => {
-                        let new_copy = decl_engine.get_type_alias(&original_id);
+                        // get the copy from the declaration engine
+                        let mut new_copy = decl_engine.get_type_alias(&original_id);
 
-                        // TODO: monomorphize the copy, in place, when generic type aliases are
-                        // supported
+                        // monomorphize the copy, in place
+                        self.monomorphize(
+                            handler,
+                            engines,
+                            &mut new_copy,
+                            &mut type_arguments.unwrap_or_default(),
+                            enforce_type_arguments,
+                            span,
+                            namespace,
+                            mod_path,
+                        )?;
 
                         let type_id = new_copy.create_type_id(engines);
                         namespace.insert_trait_implementation_for_type(engines, type_id);
@@ -510,6 +640,7 @@ impl TypeEngine {
                     )
                     .unwrap_or_else(|err| self.insert(engines, TypeInfo::ErrorRecovery(err)));
 
+                let n = self.resolve_length(handler, engines, n, namespace, module_path)?;
                 let type_id = self.insert(engines, TypeInfo::Array(elem_ty, n));
 
                 // take any trait methods that apply to this type and copy them to the new type
@@ -573,6 +704,11 @@ impl TypeEngine {
         )
     }
 
+    /// Returns the number of types inserted into the [TypeEngine] so far.
+    pub fn size(&self) -> usize {
+        self.slab.with_slice(|elems| elems.len())
+    }
+
     /// Pretty print method for printing the [TypeEngine]. This method is
     /// manually implemented to avoid implementation overhead regarding using
     /// [DisplayWithEngines].
@@ -623,7 +759,7 @@ pub(crate) trait MonomorphizeHelper {
 ///   }
 /// }
 /// ```
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum EnforceTypeArguments {
     Yes,
     No,
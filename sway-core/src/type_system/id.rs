@@ -7,10 +7,11 @@ use sway_types::{BaseIdent, Span};
 use crate::{
     decl_engine::{DeclEngine, DeclEngineInsert},
     engine_threading::*,
-    language::CallPath,
+    language::{ty, CallPath},
     semantic_analysis::TypeCheckContext,
     type_system::priv_prelude::*,
     types::*,
+    Namespace,
 };
 
 use std::{
@@ -18,6 +19,10 @@ use std::{
     fmt,
 };
 
+/// The number of levels of nested types that [TypeId::to_diagnostic_string] renders. Deeper
+/// types are abbreviated with `...`.
+const MAX_DIAGNOSTIC_TYPE_DEPTH: usize = 7;
+
 /// A identifier to uniquely refer to our type terms
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Ord, PartialOrd, Debug)]
 pub struct TypeId(usize);
@@ -282,6 +287,91 @@ impl TypeId {
         self.0
     }
 
+    /// Renders the type the way diagnostics show it to the user.
+    ///
+    /// Generic types that have a substitution in `type_mapping` are rendered as the type they
+    /// are substituted with, e.g. `Vec<(u64, b256)>` instead of `Vec<T>`. Tuples are rendered as
+    /// `(u64, b256)`, arrays as `[u8; 32]` and string arrays as `str[8]`. Types nested deeper
+    /// than [MAX_DIAGNOSTIC_TYPE_DEPTH] levels are abbreviated with `...`, e.g.
+    /// `Option<Option<...>>`.
+    pub(crate) fn to_diagnostic_string(
+        self,
+        engines: &Engines,
+        type_mapping: Option<&TypeSubstMap>,
+    ) -> String {
+        self.to_diagnostic_string_at_depth(engines, type_mapping, None, 0)
+    }
+
+    /// Renders the type like [TypeId::to_diagnostic_string] does, except that structs and enums
+    /// are qualified with the path of their module if their name alone refers to a different
+    /// declaration in the `namespace`, e.g., `other::Config` if a local `Config` is in scope.
+    pub(crate) fn to_diagnostic_string_in_scope(
+        self,
+        engines: &Engines,
+        type_mapping: Option<&TypeSubstMap>,
+        namespace: &Namespace,
+    ) -> String {
+        self.to_diagnostic_string_at_depth(engines, type_mapping, Some(namespace), 0)
+    }
+
+    fn to_diagnostic_string_at_depth(
+        self,
+        engines: &Engines,
+        type_mapping: Option<&TypeSubstMap>,
+        namespace: Option<&Namespace>,
+        depth: usize,
+    ) -> String {
+        if depth >= MAX_DIAGNOSTIC_TYPE_DEPTH {
+            return "...".into();
+        }
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
+        let inner = |type_id: TypeId| {
+            type_id.to_diagnostic_string_at_depth(engines, type_mapping, namespace, depth + 1)
+        };
+        let name = |call_path: &CallPath| match namespace {
+            Some(namespace) => name_in_scope(engines, namespace, call_path),
+            None => call_path.suffix.to_string(),
+        };
+        let with_type_arguments = |name: String, type_parameters: &[TypeParameter]| {
+            if type_parameters.is_empty() {
+                name
+            } else {
+                let type_arguments = type_parameters
+                    .iter()
+                    .map(|type_param| inner(type_param.type_id))
+                    .collect::<Vec<_>>();
+                format!("{name}<{}>", type_arguments.join(", "))
+            }
+        };
+        let type_id = type_mapping
+            .and_then(|type_mapping| type_mapping.find_generic_match(self, engines))
+            .unwrap_or(self);
+        match type_engine.get(type_id) {
+            TypeInfo::Struct(decl_ref) => {
+                let decl = decl_engine.get_struct(&decl_ref);
+                with_type_arguments(name(&decl.call_path), &decl.type_parameters)
+            }
+            TypeInfo::Enum(decl_ref) => {
+                let decl = decl_engine.get_enum(&decl_ref);
+                with_type_arguments(name(&decl.call_path), &decl.type_parameters)
+            }
+            TypeInfo::Tuple(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| inner(field.type_id))
+                    .collect::<Vec<_>>();
+                format!("({})", fields.join(", "))
+            }
+            TypeInfo::Array(elem_ty, length) => {
+                format!("[{}; {}]", inner(elem_ty.type_id), length.val())
+            }
+            TypeInfo::Ptr(ty) => format!("__ptr[{}]", inner(ty.type_id)),
+            TypeInfo::Slice(ty) => format!("__slice[{}]", inner(ty.type_id)),
+            _ => engines.help_out(type_id).to_string(),
+        }
+    }
+
     pub(crate) fn get_type_parameters(
         &self,
         type_engine: &TypeEngine,
@@ -300,6 +390,32 @@ impl TypeId {
         }
     }
 
+    /// Returns how deeply the type arguments of the type are nested, e.g., `0` for `u64`, `1` for
+    /// `Vec<u64>` and `2` for `Vec<Option<u64>>`. Tuples, arrays, pointers and slices count as
+    /// a level of nesting as well.
+    pub(crate) fn nesting_depth(&self, engines: &Engines) -> usize {
+        let type_engine = engines.te();
+        let inner_types = match type_engine.get(*self) {
+            TypeInfo::Enum(_) | TypeInfo::Struct(_) => self
+                .get_type_parameters(type_engine, engines.de())
+                .unwrap_or_default()
+                .iter()
+                .map(|type_param| type_param.type_id)
+                .collect(),
+            TypeInfo::Tuple(fields) => fields.iter().map(|field| field.type_id).collect(),
+            TypeInfo::Array(elem_ty, _) | TypeInfo::Ptr(elem_ty) | TypeInfo::Slice(elem_ty) => {
+                vec![elem_ty.type_id]
+            }
+            TypeInfo::Alias { ty, .. } => return ty.type_id.nesting_depth(engines),
+            _ => vec![],
+        };
+        inner_types
+            .iter()
+            .map(|type_id| 1 + type_id.nesting_depth(engines))
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Indicates of a given type is generic or not. Rely on whether the type is `Custom` and
     /// consider the special case where the resolved type is a struct or enum with a name that
     /// matches the name of the `Custom`.
@@ -445,3 +561,35 @@ impl TypeId {
         })
     }
 }
+
+/// Returns the name of the struct or enum declared at the full `call_path`, qualified with its
+/// module path if the name alone refers to something else in the `namespace`.
+fn name_in_scope(engines: &Engines, namespace: &Namespace, call_path: &CallPath) -> String {
+    let decl_engine = engines.de();
+    let declared_call_path = match namespace.resolve_symbol(&Handler::default(), &call_path.suffix)
+    {
+        Ok(ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. })) => {
+            Some(decl_engine.get_struct(decl_id).call_path)
+        }
+        Ok(ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. })) => {
+            Some(decl_engine.get_enum(decl_id).call_path)
+        }
+        Ok(_) => None,
+        // The name is not in scope at all, so it can not be confused with anything.
+        Err(_) => return call_path.suffix.to_string(),
+    };
+    if declared_call_path.as_ref() == Some(call_path) {
+        return call_path.suffix.to_string();
+    }
+
+    // Types declared in the current package are qualified relative to the package root.
+    let package_name = namespace.root().module.name.as_ref();
+    call_path
+        .prefixes
+        .iter()
+        .skip(usize::from(call_path.prefixes.first() == package_name))
+        .chain(std::iter::once(&call_path.suffix))
+        .map(|ident| ident.as_str())
+        .collect::<Vec<_>>()
+        .join("::")
+}
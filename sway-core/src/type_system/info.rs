@@ -9,7 +9,7 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{integer_bits::IntegerBits, span::Span, Spanned};
+use sway_types::{integer_bits::IntegerBits, span::Span, style::find_similar_name, Spanned};
 
 use std::{
     cmp::Ordering,
@@ -1312,6 +1312,8 @@ impl TypeInfo {
                             field_name: first.clone(),
                             struct_name: decl.call_path.suffix.clone(),
                             available_fields: available_fields.join(", "),
+                            suggestion: find_similar_name(first.as_str(), available_fields)
+                                .map(str::to_string),
                             span: first.span(),
                         }));
                     }
@@ -1342,9 +1344,13 @@ impl TypeInfo {
         }
     }
 
-    pub(crate) fn can_change(&self, decl_engine: &DeclEngine) -> bool {
+    /// Returns true if the [TypeInfo] can still be refined, e.g., by unification, and so can
+    /// not be shared between all the places where a structurally equal type is used.
+    pub(crate) fn can_change(&self, engines: &Engines) -> bool {
         // TODO: there might be an optimization here that if the type params hold
         // only non-dynamic types, then it doesn't matter that there are type params
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
         match self {
             TypeInfo::Enum(decl_ref) => {
                 let decl = decl_engine.get_enum(decl_ref);
@@ -1363,13 +1369,19 @@ impl TypeInfo {
             | TypeInfo::Ptr(..)
             | TypeInfo::Slice(..)
             | TypeInfo::ErrorRecovery(_) => false,
+            // Tuples and arrays of types that can not change can not change either, and
+            // interning them means that, e.g., the unit type is shared by all functions.
+            TypeInfo::Tuple(fields) => fields
+                .iter()
+                .any(|field| type_engine.get(field.type_id).can_change(engines)),
+            TypeInfo::Array(elem_ty, length) => {
+                length.constant().is_some() || type_engine.get(elem_ty.type_id).can_change(engines)
+            }
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
             | TypeInfo::SelfType
-            | TypeInfo::Tuple(_)
-            | TypeInfo::Array(_, _)
             | TypeInfo::Contract
             | TypeInfo::Storage { .. }
             | TypeInfo::Numeric
@@ -207,3 +207,233 @@ fn unify_numerics_2() {
         TypeInfo::UnsignedInteger(IntegerBits::Eight)
     ));
 }
+
+#[test]
+fn diagnostic_type_strings() {
+    use crate::{
+        decl_engine::DeclEngineInsert,
+        language::{ty, Visibility},
+        Engines, Ident,
+    };
+
+    let engines = Engines::default();
+    let type_engine = engines.te();
+    let sp = Span::dummy();
+
+    let insert = |type_info: TypeInfo| type_engine.insert(&engines, type_info);
+    let tuple = |fields: Vec<TypeId>| {
+        insert(TypeInfo::Tuple(
+            fields.into_iter().map(TypeArgument::from).collect(),
+        ))
+    };
+    let array = |elem_ty: TypeId, length: usize| {
+        insert(TypeInfo::Array(
+            elem_ty.into(),
+            Length::new(length, sp.clone()),
+        ))
+    };
+    let type_parameter = |name: &str, type_id: TypeId| TypeParameter {
+        type_id,
+        initial_type_id: type_id,
+        name_ident: Ident::new_no_span(name.into()),
+        trait_constraints: vec![],
+        trait_constraints_span: sp.clone(),
+        is_from_parent: false,
+    };
+    let generic_struct = |name: &str, type_argument: TypeId| {
+        let decl_ref = engines.de().insert(ty::TyStructDecl {
+            call_path: Ident::new_no_span(name.into()).into(),
+            fields: vec![],
+            type_parameters: vec![type_parameter("T", type_argument)],
+            visibility: Visibility::Public,
+            span: sp.clone(),
+            attributes: AttributesMap::default(),
+        });
+        insert(TypeInfo::Struct(decl_ref))
+    };
+    let option = |type_argument: TypeId| {
+        let decl_ref = engines.de().insert(TyEnumDecl {
+            call_path: Ident::new_no_span("Option".into()).into(),
+            type_parameters: vec![type_parameter("T", type_argument)],
+            variants: vec![],
+            span: sp.clone(),
+            visibility: Visibility::Public,
+            attributes: AttributesMap::default(),
+        });
+        insert(TypeInfo::Enum(decl_ref))
+    };
+
+    let u8_type = insert(TypeInfo::UnsignedInteger(IntegerBits::Eight));
+    let u16_type = insert(TypeInfo::UnsignedInteger(IntegerBits::Sixteen));
+    let u64_type = insert(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+    let b256_type = insert(TypeInfo::B256);
+    let bool_type = insert(TypeInfo::Boolean);
+    let generic_type = insert(TypeInfo::UnknownGeneric {
+        name: Ident::new_no_span("T".into()),
+        trait_constraints: VecSet(vec![]),
+    });
+    let u64_b256 = tuple(vec![u64_type, b256_type]);
+    let deeply_nested = (0..8).fold(u64_type, |type_id, _| option(type_id));
+
+    let no_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(vec![], vec![]);
+    let t_to_u64_b256 =
+        TypeSubstMap::from_type_parameters_and_type_arguments(vec![generic_type], vec![u64_b256]);
+
+    let types = [
+        (u64_type, &no_mapping),
+        (tuple(vec![]), &no_mapping),
+        (u64_b256, &no_mapping),
+        (array(u8_type, 32), &no_mapping),
+        (
+            insert(TypeInfo::Str(Length::new(8, sp.clone()))),
+            &no_mapping,
+        ),
+        (
+            tuple(vec![tuple(vec![u8_type, bool_type]), array(u64_type, 2)]),
+            &no_mapping,
+        ),
+        (array(tuple(vec![u8_type, u16_type]), 4), &no_mapping),
+        (option(u64_type), &no_mapping),
+        (generic_type, &no_mapping),
+        (generic_type, &t_to_u64_b256),
+        (generic_struct("Vec", generic_type), &no_mapping),
+        (generic_struct("Vec", generic_type), &t_to_u64_b256),
+        (
+            generic_struct("Wrapper", option(array(generic_type, 3))),
+            &t_to_u64_b256,
+        ),
+        (
+            insert(TypeInfo::Slice(tuple(vec![generic_type, u8_type]).into())),
+            &t_to_u64_b256,
+        ),
+        (deeply_nested, &no_mapping),
+    ];
+    let rendered = types
+        .iter()
+        .map(|(type_id, type_mapping)| type_id.to_diagnostic_string(&engines, Some(type_mapping)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    insta::assert_snapshot!(rendered, @r###"
+    u64
+    ()
+    (u64, b256)
+    [u8; 32]
+    str[8]
+    ((u8, bool), [u64; 2])
+    [(u8, u16); 4]
+    Option<u64>
+    T
+    (u64, b256)
+    Vec<T>
+    Vec<(u64, b256)>
+    Wrapper<Option<[(u64, b256); 3]>>
+    __slice[((u64, b256), u8)]
+    Option<Option<Option<Option<Option<Option<Option<...>>>>>>>
+    "###);
+}
+
+#[test]
+fn find_match_reuses_matches() {
+    use crate::{
+        decl_engine::DeclEngineInsert,
+        language::{ty, Visibility},
+        Engines, Ident,
+    };
+
+    let engines = Engines::default();
+    let type_engine = engines.te();
+    let sp = Span::dummy();
+
+    let generic_type = type_engine.insert(
+        &engines,
+        TypeInfo::UnknownGeneric {
+            name: Ident::new_no_span("T".into()),
+            trait_constraints: VecSet(vec![]),
+        },
+    );
+    let decl_ref = engines.de().insert(ty::TyStructDecl {
+        call_path: Ident::new_no_span("Wrapper".into()).into(),
+        fields: vec![],
+        type_parameters: vec![TypeParameter {
+            type_id: generic_type,
+            initial_type_id: generic_type,
+            name_ident: Ident::new_no_span("T".into()),
+            trait_constraints: vec![],
+            trait_constraints_span: sp.clone(),
+            is_from_parent: false,
+        }],
+        visibility: Visibility::Public,
+        span: sp,
+        attributes: AttributesMap::default(),
+    });
+    let wrapper_type = type_engine.insert(&engines, TypeInfo::Struct(decl_ref));
+    let bool_type = type_engine.insert(&engines, TypeInfo::Boolean);
+
+    let type_mapping =
+        TypeSubstMap::from_type_parameters_and_type_arguments(vec![generic_type], vec![bool_type]);
+
+    // Every occurrence of `Wrapper<T>` is substituted with the same `Wrapper<bool>`.
+    let first = type_mapping.find_match(wrapper_type, &engines);
+    let second = type_mapping.find_match(wrapper_type, &engines);
+    assert!(first.is_some());
+    assert_eq!(first, second);
+    assert_eq!(
+        engines.help_out(first.unwrap()).to_string(),
+        "Wrapper<bool>"
+    );
+
+    // Types without generics have no match, also when looked up again.
+    assert_eq!(type_mapping.find_match(bool_type, &engines), None);
+    assert_eq!(type_mapping.find_match(bool_type, &engines), None);
+}
+
+#[test]
+fn concrete_tuples_and_arrays_are_interned() {
+    use crate::{language::CallPath, Engines, Ident};
+    let engines = Engines::default();
+    let type_engine = engines.te();
+
+    let u64_type = type_engine.insert(&engines, TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+    let unit = || TypeInfo::Tuple(vec![]);
+    let pair = || TypeInfo::Tuple(vec![u64_type.into(), u64_type.into()]);
+    let array = || TypeInfo::Array(u64_type.into(), Length::new(3, Span::dummy()));
+
+    // Structurally equal concrete types share their id.
+    assert_eq!(
+        type_engine.insert(&engines, unit()),
+        type_engine.insert(&engines, unit())
+    );
+    assert_eq!(
+        type_engine.insert(&engines, pair()),
+        type_engine.insert(&engines, pair())
+    );
+    assert_eq!(
+        type_engine.insert(&engines, array()),
+        type_engine.insert(&engines, array())
+    );
+    assert_ne!(
+        type_engine.insert(&engines, pair()),
+        type_engine.insert(&engines, unit())
+    );
+
+    // Types that can still change get their own ids, because unifying one of them must not
+    // change the others.
+    let unknown_type = type_engine.insert(&engines, TypeInfo::Unknown);
+    let unknown = || TypeInfo::Tuple(vec![unknown_type.into()]);
+    assert_ne!(
+        type_engine.insert(&engines, unknown()),
+        type_engine.insert(&engines, unknown())
+    );
+    let unresolved_length = || {
+        let constant: CallPath = Ident::new_no_span("LEN".into()).into();
+        TypeInfo::Array(
+            u64_type.into(),
+            Length::from_constant(constant, Span::dummy()),
+        )
+    };
+    assert_ne!(
+        type_engine.insert(&engines, unresolved_length()),
+        type_engine.insert(&engines, unresolved_length())
+    );
+}
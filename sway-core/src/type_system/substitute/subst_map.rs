@@ -1,4 +1,8 @@
-use std::{collections::BTreeMap, fmt};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 use crate::{
     decl_engine::{DeclEngine, DeclEngineInsert},
@@ -13,6 +17,35 @@ type DestinationType = TypeId;
 /// and a [DestinationType] (RHS).
 pub struct TypeSubstMap {
     mapping: BTreeMap<SourceType, DestinationType>,
+    /// Substituting the types of a whole function body finds matches for the same types over
+    /// and over again, so the matches found are remembered.
+    matches: RefCell<MatchCache>,
+}
+
+/// The results of [TypeSubstMap::find_match], keyed by the [TypeId] they were found for.
+///
+/// A match depends on the current contents of the [TypeEngine], so the cache is only valid for
+/// the [TypeEngine::generation] it was filled in.
+#[derive(Default)]
+struct MatchCache {
+    generation: usize,
+    matches: HashMap<TypeId, Option<TypeId>>,
+}
+
+impl MatchCache {
+    fn get(&mut self, generation: usize, type_id: TypeId) -> Option<Option<TypeId>> {
+        if self.generation != generation {
+            self.generation = generation;
+            self.matches.clear();
+        }
+        self.matches.get(&type_id).copied()
+    }
+
+    fn insert(&mut self, generation: usize, type_id: TypeId, found: Option<TypeId>) {
+        if self.generation == generation {
+            self.matches.insert(type_id, found);
+        }
+    }
 }
 
 impl DebugWithEngines for TypeSubstMap {
@@ -50,6 +83,13 @@ impl fmt::Debug for TypeSubstMap {
 }
 
 impl TypeSubstMap {
+    fn new(mapping: BTreeMap<SourceType, DestinationType>) -> TypeSubstMap {
+        TypeSubstMap {
+            mapping,
+            matches: RefCell::default(),
+        }
+    }
+
     /// Returns `true` if the [TypeSubstMap] is empty.
     pub(crate) fn is_empty(&self) -> bool {
         self.mapping.is_empty()
@@ -73,7 +113,7 @@ impl TypeSubstMap {
                 )
             })
             .collect();
-        TypeSubstMap { mapping }
+        TypeSubstMap::new(mapping)
     }
 
     /// Constructs a new [TypeSubstMap] from a superset [TypeId] and a subset
@@ -130,9 +170,9 @@ impl TypeSubstMap {
         subset: TypeId,
     ) -> TypeSubstMap {
         match (type_engine.get(superset), type_engine.get(subset)) {
-            (TypeInfo::UnknownGeneric { .. }, _) => TypeSubstMap {
-                mapping: BTreeMap::from([(superset, subset)]),
-            },
+            (TypeInfo::UnknownGeneric { .. }, _) => {
+                TypeSubstMap::new(BTreeMap::from([(superset, subset)]))
+            }
             (
                 TypeInfo::Custom {
                     type_arguments: type_parameters,
@@ -249,12 +289,10 @@ impl TypeSubstMap {
             | (TypeInfo::ErrorRecovery(_), TypeInfo::ErrorRecovery(_))
             | (TypeInfo::Str(_), TypeInfo::Str(_))
             | (TypeInfo::UnsignedInteger(_), TypeInfo::UnsignedInteger(_))
-            | (TypeInfo::ContractCaller { .. }, TypeInfo::ContractCaller { .. }) => TypeSubstMap {
-                mapping: BTreeMap::new(),
-            },
-            _ => TypeSubstMap {
-                mapping: BTreeMap::new(),
-            },
+            | (TypeInfo::ContractCaller { .. }, TypeInfo::ContractCaller { .. }) => {
+                TypeSubstMap::new(BTreeMap::new())
+            }
+            _ => TypeSubstMap::new(BTreeMap::new()),
         }
     }
 
@@ -290,7 +328,21 @@ impl TypeSubstMap {
         type_arguments: Vec<DestinationType>,
     ) -> TypeSubstMap {
         let mapping = type_parameters.into_iter().zip(type_arguments).collect();
-        TypeSubstMap { mapping }
+        TypeSubstMap::new(mapping)
+    }
+
+    /// Returns the type that the generic type `type_id` is substituted with, if any.
+    ///
+    /// Unlike [TypeSubstMap::find_match], this only looks up generic types themselves and
+    /// never creates new types for compound types whose sub-types have a match.
+    pub(crate) fn find_generic_match(&self, type_id: TypeId, engines: &Engines) -> Option<TypeId> {
+        let type_info = engines.te().get(type_id);
+        match type_info {
+            TypeInfo::Custom { .. }
+            | TypeInfo::UnknownGeneric { .. }
+            | TypeInfo::Placeholder(_) => iter_for_match(engines, self, &type_info),
+            _ => None,
+        }
     }
 
     /// Given a [TypeId] `type_id`, find (or create) a match for `type_id` in
@@ -310,7 +362,23 @@ impl TypeSubstMap {
     ///     finds a match in a recursive call to `find_match`
     ///
     /// A match cannot be found in any other circumstance.
+    ///
+    /// Matches are cached, so finding a match for the same `type_id` again, e.g. for another
+    /// occurrence of `Option<T>` in a function body, neither descends into the type nor
+    /// creates another new type.
     pub(crate) fn find_match(&self, type_id: TypeId, engines: &Engines) -> Option<TypeId> {
+        let generation = engines.te().generation();
+        if let Some(found) = self.matches.borrow_mut().get(generation, type_id) {
+            return found;
+        }
+        let found = self.find_match_uncached(type_id, engines);
+        self.matches
+            .borrow_mut()
+            .insert(engines.te().generation(), type_id, found);
+        found
+    }
+
+    fn find_match_uncached(&self, type_id: TypeId, engines: &Engines) -> Option<TypeId> {
         let type_engine = engines.te();
         let decl_engine = engines.de();
         let type_info = type_engine.get(type_id);
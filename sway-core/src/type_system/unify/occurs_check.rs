@@ -34,4 +34,15 @@ impl<'a> OccursCheck<'a> {
         let other_generics = other.extract_nested_generics(self.engines);
         other_generics.contains(&self.engines.help_out(generic))
     }
+
+    /// Checks whether the type being inferred, i.e., the [TypeInfo::Unknown] or
+    /// [TypeInfo::Placeholder] `variable`, occurs in `other` and returns true if so.
+    ///
+    /// Unlike generics, which are compared by name, the `variable` is compared by its
+    /// [TypeId], so other types being inferred at the same time are not mistaken for it.
+    pub(super) fn check_variable(&self, variable: TypeId, other: &TypeInfo) -> bool {
+        // Types that can not change can not contain a type being inferred.
+        other.can_change(self.engines)
+            && other.extract_inner_types(self.engines).contains(&variable)
+    }
 }
@@ -1,6 +1,8 @@
-use std::fmt;
-
-use sway_error::{handler::Handler, type_error::TypeError};
+use sway_error::{
+    error::CompileError,
+    handler::Handler,
+    type_error::{ExpectedTypeOrigin, TypeError},
+};
 use sway_types::{Ident, Span};
 
 use crate::{engine_threading::*, language::ty, type_system::priv_prelude::*};
@@ -11,14 +13,20 @@ use super::occurs_check::OccursCheck;
 pub(crate) struct Unifier<'a> {
     engines: &'a Engines,
     help_text: String,
+    expected_origin: Option<ExpectedTypeOrigin>,
 }
 
 impl<'a> Unifier<'a> {
     /// Creates a new [Unifier].
-    pub(crate) fn new(engines: &'a Engines, help_text: &str) -> Unifier<'a> {
+    pub(crate) fn new(
+        engines: &'a Engines,
+        help_text: &str,
+        expected_origin: Option<&ExpectedTypeOrigin>,
+    ) -> Unifier<'a> {
         Unifier {
             engines,
             help_text: help_text.to_string(),
+            expected_origin: expected_origin.cloned(),
         }
     }
 
@@ -34,12 +42,11 @@ impl<'a> Unifier<'a> {
     ) {
         let type_engine = self.engines.te();
         if type_engine
-            .slab
             .replace(
+                self.engines,
                 received,
                 received_type_info,
                 expected_type_info,
-                self.engines,
             )
             .is_some()
         {
@@ -59,12 +66,11 @@ impl<'a> Unifier<'a> {
     ) {
         let type_engine = self.engines.te();
         if type_engine
-            .slab
             .replace(
+                self.engines,
                 expected,
                 expected_type_info,
                 received_type_info,
-                self.engines,
             )
             .is_some()
         {
@@ -102,6 +108,9 @@ impl<'a> Unifier<'a> {
             (Array(re, rc), Array(ee, ec)) if rc.val() == ec.val() => {
                 self.unify_arrays(handler, received, expected, span, re.type_id, ee.type_id)
             }
+            (Slice(re), Slice(ee)) => {
+                self.unify_arrays(handler, received, expected, span, re.type_id, ee.type_id)
+            }
             (Struct(r_decl_ref), Struct(e_decl_ref)) => {
                 let r_decl = self.engines.de().get_struct(&r_decl_ref);
                 let e_decl = self.engines.de().get_struct(&e_decl_ref);
@@ -125,8 +134,12 @@ impl<'a> Unifier<'a> {
             }
 
             // Type aliases and the types they encapsulate coerce to each other.
-            (Alias { ty, .. }, _) => self.unify(handler, ty.type_id, expected, span),
-            (_, Alias { ty, .. }) => self.unify(handler, received, ty.type_id, span),
+            (Alias { name, ty }, _) => {
+                self.unify_alias(handler, &name, ty.type_id, expected, true, span)
+            }
+            (_, Alias { name, ty }) => {
+                self.unify_alias(handler, &name, ty.type_id, received, false, span)
+            }
 
             // Let empty enums to coerce to any other type. This is useful for Never enum.
             (Enum(r_decl_ref), _)
@@ -214,9 +227,15 @@ impl<'a> Unifier<'a> {
             // they match and make the one we know nothing about reference the
             // one we may know something about.
             (Unknown, Unknown) => (),
+            (Unknown, e) if self.occurs_in(received, &e) => {
+                self.emit_infinite_type(handler, &Unknown, &e, span)
+            }
             (Unknown, e) => {
                 self.replace_received_with_expected(handler, received, expected, &Unknown, e, span)
             }
+            (r, Unknown) if self.occurs_in(expected, &r) => {
+                self.emit_infinite_type(handler, &Unknown, &r, span)
+            }
             (r, Unknown) => {
                 self.replace_expected_with_received(handler, received, expected, r, &Unknown, span)
             }
@@ -224,9 +243,15 @@ impl<'a> Unifier<'a> {
             (r @ Placeholder(_), e @ Placeholder(_)) => {
                 self.replace_expected_with_received(handler, received, expected, r, &e, span)
             }
+            (r @ Placeholder(_), e) if self.occurs_in(received, &e) => {
+                self.emit_infinite_type(handler, &r, &e, span)
+            }
             (r @ Placeholder(_), e) => {
                 self.replace_received_with_expected(handler, received, expected, &r, e, span)
             }
+            (r, e @ Placeholder(_)) if self.occurs_in(expected, &r) => {
+                self.emit_infinite_type(handler, &e, &r, span)
+            }
             (r, e @ Placeholder(_)) => {
                 self.replace_expected_with_received(handler, received, expected, r, &e, span)
             }
@@ -254,14 +279,15 @@ impl<'a> Unifier<'a> {
             // If no previous attempts to unify were successful, raise an error.
             (TypeInfo::ErrorRecovery(_), _) => (),
             (_, TypeInfo::ErrorRecovery(_)) => (),
-            (r, e) => {
-                let (received, expected) = self.assign_args(r, e);
+            _ => {
+                let (received, expected) = self.assign_args(received, expected);
                 handler.emit_err(
                     TypeError::MismatchedType {
                         expected,
                         received,
                         help_text: self.help_text.clone(),
                         span: span.clone(),
+                        expected_origin: self.expected_origin.clone(),
                     }
                     .into(),
                 );
@@ -269,10 +295,113 @@ impl<'a> Unifier<'a> {
         }
     }
 
+    /// Unifies the type `aliased` that the type alias `name` stands for with `other`, which is
+    /// the expected type if `alias_is_received`, and the received type otherwise.
+    ///
+    /// Aliases are transparent for unification, but if the aliased type as a whole does not
+    /// match, the mismatch names the alias as it was written, together with the type it stands
+    /// for, like `Balance (alias of u64)`.
+    fn unify_alias(
+        &self,
+        handler: &Handler,
+        name: &Ident,
+        aliased: TypeId,
+        other: TypeId,
+        alias_is_received: bool,
+        span: &Span,
+    ) {
+        let alias_handler = Handler::default();
+        if alias_is_received {
+            self.unify(&alias_handler, aliased, other, span);
+        } else {
+            self.unify(&alias_handler, other, aliased, span);
+        }
+
+        let aliased_description = self.describe(aliased);
+        let (errors, warnings) = alias_handler.consume();
+        for error in errors {
+            let error = match error {
+                CompileError::TypeError(TypeError::MismatchedType {
+                    mut expected,
+                    mut received,
+                    help_text,
+                    span,
+                    expected_origin,
+                }) => {
+                    let alias_side = if alias_is_received {
+                        &mut received
+                    } else {
+                        &mut expected
+                    };
+                    if *alias_side == aliased_description {
+                        *alias_side = self.describe_alias(name, aliased);
+                    }
+                    TypeError::MismatchedType {
+                        expected,
+                        received,
+                        help_text,
+                        span,
+                        expected_origin,
+                    }
+                    .into()
+                }
+                error => error,
+            };
+            handler.emit_err(error);
+        }
+        for warning in warnings {
+            handler.emit_warn(warning);
+        }
+    }
+
+    /// Returns how a type mismatch describes the type `id` as a whole.
+    fn describe(&self, id: TypeId) -> String {
+        match self.engines.te().get(id) {
+            TypeInfo::Alias { name, ty } => self.describe_alias(&name, ty.type_id),
+            _ => id.to_diagnostic_string(self.engines, None),
+        }
+    }
+
+    /// Describes the type alias `name`, which stands for `aliased`, by the type it ultimately
+    /// stands for, looking through aliases of aliases.
+    fn describe_alias(&self, name: &Ident, aliased: TypeId) -> String {
+        let mut unaliased = aliased;
+        while let TypeInfo::Alias { ty, .. } = self.engines.te().get(unaliased) {
+            unaliased = ty.type_id;
+        }
+        format!(
+            "{name} (alias of {})",
+            unaliased.to_diagnostic_string(self.engines, None)
+        )
+    }
+
     fn occurs_check(&self, generic: TypeInfo, other: &TypeInfo) -> bool {
         OccursCheck::new(self.engines).check(generic, other)
     }
 
+    /// Checks whether the type being inferred, `variable`, occurs in `other`, in which case
+    /// replacing `variable` with `other` would create an infinite type.
+    fn occurs_in(&self, variable: TypeId, other: &TypeInfo) -> bool {
+        OccursCheck::new(self.engines).check_variable(variable, other)
+    }
+
+    fn emit_infinite_type(
+        &self,
+        handler: &Handler,
+        variable: &TypeInfo,
+        other: &TypeInfo,
+        span: &Span,
+    ) {
+        handler.emit_err(
+            TypeError::InfiniteType {
+                variable: self.engines.help_out(variable).to_string(),
+                ty: self.engines.help_out(other).to_string(),
+                span: span.clone(),
+            }
+            .into(),
+        );
+    }
+
     fn unify_strs(
         &self,
         handler: &Handler,
@@ -290,6 +419,7 @@ impl<'a> Unifier<'a> {
                     received,
                     help_text: self.help_text.clone(),
                     span: span.clone(),
+                    expected_origin: self.expected_origin.clone(),
                 }
                 .into(),
             );
@@ -333,6 +463,7 @@ impl<'a> Unifier<'a> {
                     received,
                     help_text: self.help_text.clone(),
                     span: span.clone(),
+                    expected_origin: self.expected_origin.clone(),
                 }
                 .into(),
             );
@@ -370,6 +501,7 @@ impl<'a> Unifier<'a> {
                     received,
                     help_text: self.help_text.clone(),
                     span: span.clone(),
+                    expected_origin: self.expected_origin.clone(),
                 }
                 .into(),
             );
@@ -399,6 +531,7 @@ impl<'a> Unifier<'a> {
                     received,
                     help_text: self.help_text.clone(),
                     span: span.clone(),
+                    expected_origin: self.expected_origin.clone(),
                 }
                 .into(),
             );
@@ -409,12 +542,9 @@ impl<'a> Unifier<'a> {
         }
     }
 
-    fn assign_args<T>(&self, r: T, e: T) -> (String, String)
-    where
-        WithEngines<'a, T>: fmt::Debug,
-    {
-        let r = format!("{:?}", self.engines.help_out(r));
-        let e = format!("{:?}", self.engines.help_out(e));
+    fn assign_args(&self, r: TypeId, e: TypeId) -> (String, String) {
+        let r = r.to_diagnostic_string(self.engines, None);
+        let e = e.to_diagnostic_string(self.engines, None);
         (r, e)
     }
 }
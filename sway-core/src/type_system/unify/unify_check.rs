@@ -232,6 +232,9 @@ impl<'a> UnifyCheck<'a> {
             (Array(l0, l1), Array(r0, r1)) => {
                 return self.check_inner(l0.type_id, r0.type_id) && l1.val() == r1.val();
             }
+            (Slice(l0), Slice(r0)) => {
+                return self.check_inner(l0.type_id, r0.type_id);
+            }
             (Tuple(l_types), Tuple(r_types)) => {
                 let l_types = l_types.iter().map(|x| x.type_id).collect::<Vec<_>>();
                 let r_types = r_types.iter().map(|x| x.type_id).collect::<Vec<_>>();
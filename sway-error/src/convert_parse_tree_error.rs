@@ -53,14 +53,20 @@ pub enum ConvertParseTreeError {
     ConstructorPatternsNotSupportedHere { span: Span },
     #[error("struct patterns not supported in this position")]
     StructPatternsNotSupportedHere { span: Span },
-    #[error("wildcard patterns not supported in this position")]
-    WildcardPatternsNotSupportedHere { span: Span },
     #[error("or patterns not supported in this position")]
     OrPatternsNotSupportedHere { span: Span },
     #[error("tuple patterns not supported in this position")]
     TuplePatternsNotSupportedHere { span: Span },
     #[error("ref patterns not supported in this position")]
     RefPatternsNotSupportedHere { span: Span },
+    #[error("@ patterns not supported in this position")]
+    AtPatternsNotSupportedHere { span: Span },
+    #[error("array patterns not supported in this position")]
+    ArrayPatternsNotSupportedHere { span: Span },
+    #[error("array patterns of parameters can only bind names, `_` and a trailing `..rest`")]
+    ArrayPatternElementNotSupported { span: Span },
+    #[error("identifier \"{name}\" is bound more than once in the same pattern")]
+    DuplicatePatternBinding { name: Ident, span: Span },
     #[error("constructor patterns require a single argument")]
     ConstructorPatternOneArg { span: Span },
     #[error("constructor patterns cannot contain sub-patterns")]
@@ -69,6 +75,8 @@ pub enum ConvertParseTreeError {
     PathsNotSupportedHere { span: Span },
     #[error("Fully specified types are not supported in this position. Try importing the type and referring to it here.")]
     FullySpecifiedTypesNotSupported { span: Span },
+    #[error("typeof types are only supported as the types of function parameters")]
+    TypeofTypesNotSupportedHere { span: Span },
     #[error("ContractCaller requires exactly one generic argument")]
     ContractCallerOneGenericArg { span: Span },
     #[error("ContractCaller requires a named type for its generic argument")]
@@ -97,6 +105,18 @@ pub enum ConvertParseTreeError {
     SelfParameterNotAllowedForFn { fn_kind: String, span: Span },
     #[error("test functions are only allowed at module level")]
     TestFnOnlyAllowedAtModuleLevel { span: Span },
+    #[error("builder functions are only allowed at module level")]
+    BuilderFnOnlyAllowedAtModuleLevel { span: Span },
+    #[error("builders cannot be generated for generic functions")]
+    BuilderFnCannotBeGeneric { span: Span },
+    #[error("builders cannot be generated for functions with a rest parameter")]
+    BuilderFnCannotHaveRestParameter { span: Span },
+    #[error("builders cannot forward the `ref mut` parameter \"{name}\"")]
+    BuilderParameterCannotBeRefMut { name: Ident, span: Span },
+    #[error("builder setter \"{name}\" is generated for more than one parameter")]
+    DuplicateBuilderSetter { name: String, span: Span },
+    #[error("\"{name}\" in the `coerce` attribute is not a parameter of the function")]
+    CoercedParameterNotFound { name: Ident, span: Span },
     #[error("`impl Self` for contracts is not supported")]
     SelfImplForContract { span: Span },
     #[error("Cannot attach a documentation comment to a dependency.")]
@@ -121,6 +141,11 @@ pub enum ConvertParseTreeError {
     InvalidCfgProgramTypeArgValue { span: Span, value: String },
     #[error("Expected a value for the program_type argument")]
     ExpectedCfgProgramTypeArgValue { span: Span },
+    #[error(
+        "Invalid cfg predicate \"{predicate}\". Expected a flag, \"target = ...\", \
+         \"program_type = ...\", \"not(...)\", \"any(...)\" or \"all(...)\"."
+    )]
+    InvalidCfgPredicate { span: Span, predicate: String },
     #[error("Unexpected call path segments between qualified root and method name.")]
     UnexpectedCallPathPrefixAfterQualifiedRoot { span: Span },
 }
@@ -153,14 +178,18 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ConstantPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::StructPatternsNotSupportedHere { span } => span.clone(),
-            ConvertParseTreeError::WildcardPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::OrPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::RefPatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::AtPatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::ArrayPatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::ArrayPatternElementNotSupported { span } => span.clone(),
+            ConvertParseTreeError::DuplicatePatternBinding { span, .. } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
             ConvertParseTreeError::PathsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullySpecifiedTypesNotSupported { span } => span.clone(),
+            ConvertParseTreeError::TypeofTypesNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::ContractCallerOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span } => span.clone(),
             ConvertParseTreeError::InvalidAttributeArgument { span, .. } => span.clone(),
@@ -175,6 +204,12 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::DuplicateParameterIdentifier { span, .. } => span.clone(),
             ConvertParseTreeError::SelfParameterNotAllowedForFn { span, .. } => span.clone(),
             ConvertParseTreeError::TestFnOnlyAllowedAtModuleLevel { span } => span.clone(),
+            ConvertParseTreeError::BuilderFnOnlyAllowedAtModuleLevel { span } => span.clone(),
+            ConvertParseTreeError::BuilderFnCannotBeGeneric { span } => span.clone(),
+            ConvertParseTreeError::BuilderFnCannotHaveRestParameter { span } => span.clone(),
+            ConvertParseTreeError::BuilderParameterCannotBeRefMut { span, .. } => span.clone(),
+            ConvertParseTreeError::DuplicateBuilderSetter { span, .. } => span.clone(),
+            ConvertParseTreeError::CoercedParameterNotFound { span, .. } => span.clone(),
             ConvertParseTreeError::SelfImplForContract { span, .. } => span.clone(),
             ConvertParseTreeError::CannotDocCommentDependency { span } => span.clone(),
             ConvertParseTreeError::CannotAnnotateDependency { span } => span.clone(),
@@ -187,6 +222,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ExpectedCfgTargetArgValue { span } => span.clone(),
             ConvertParseTreeError::InvalidCfgProgramTypeArgValue { span, .. } => span.clone(),
             ConvertParseTreeError::ExpectedCfgProgramTypeArgValue { span } => span.clone(),
+            ConvertParseTreeError::InvalidCfgPredicate { span, .. } => span.clone(),
             ConvertParseTreeError::UnexpectedCallPathPrefixAfterQualifiedRoot { span } => {
                 span.clone()
             }
@@ -9,6 +9,9 @@ pub struct Diagnostic {
     pub(crate) issue: Issue,
     pub(crate) hints: Vec<Hint>,
     pub(crate) help: Vec<String>,
+    /// The code of an old-style diagnostic, which does not have a [Reason] to carry it.
+    pub(crate) code: Option<Code>,
+    pub(crate) suggestion: Option<Suggestion>,
 }
 
 impl Diagnostic {
@@ -35,6 +38,14 @@ impl Diagnostic {
         &self.issue
     }
 
+    /// The code of the diagnostic, e.g., `E4019`, if it has one.
+    pub fn code(&self) -> Option<&str> {
+        match &self.reason {
+            Some(reason) => Some(reason.code()),
+            None => self.code.as_ref().map(Code::as_str),
+        }
+    }
+
     /// All the labels, potentially in different source files.
     pub fn labels(&self) -> Vec<&Label> {
         let mut labels = Vec::<&Label>::new();
@@ -52,6 +63,16 @@ impl Diagnostic {
         labels
     }
 
+    /// The labels of the hints that point to places in source code other than the issue,
+    /// potentially in different source files.
+    pub fn secondary_labels(&self) -> Vec<&Label> {
+        self.hints
+            .iter()
+            .filter(|hint| hint.is_in_source() && hint.span != self.issue.span)
+            .map(|hint| &hint.label)
+            .collect()
+    }
+
     /// All the labels in the source file found at `source_path`.
     pub fn labels_in_source(&self, source_path: &SourcePath) -> Vec<&Label> {
         self.labels()
@@ -76,6 +97,11 @@ impl Diagnostic {
         self.help.iter().filter(|help| !help.is_empty())
     }
 
+    /// The replacement of a piece of source code that would resolve the diagnostic, if known.
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        self.suggestion.as_ref()
+    }
+
     /// All the source files that are related to the diagnostic.
     /// This means the source file of the issue itself as well
     /// as source files of all the hints.
@@ -306,6 +332,38 @@ impl std::ops::Deref for Hint {
     }
 }
 
+/// Replacement text for a span of source code, e.g., the similarly named
+/// variable that was probably meant instead of an unknown one.
+#[derive(Debug)]
+pub struct Suggestion {
+    span: Span,
+    replacement: String,
+    source_path: Option<SourcePath>,
+}
+
+impl Suggestion {
+    pub fn new(source_engine: &SourceEngine, span: Span, replacement: String) -> Self {
+        let source_path = Label::get_source_path(source_engine, &span);
+        Self {
+            span,
+            replacement,
+            source_path,
+        }
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    pub fn replacement(&self) -> &str {
+        self.replacement.as_ref()
+    }
+
+    pub fn source_path(&self) -> Option<&SourcePath> {
+        self.source_path.as_ref()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct SourcePath {
     path_buf: PathBuf,
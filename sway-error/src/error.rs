@@ -1,11 +1,15 @@
 use crate::convert_parse_tree_error::ConvertParseTreeError;
-use crate::diagnostic::{Code, Diagnostic, Hint, Issue, Reason, ToDiagnostic};
+use crate::diagnostic::{Code, Diagnostic, Hint, Issue, Reason, Suggestion, ToDiagnostic};
 use crate::lex_error::LexError;
 use crate::parser_error::ParseError;
 use crate::type_error::TypeError;
+use crate::warning::Warning;
 
 use core::fmt;
-use sway_types::constants::STORAGE_PURITY_ATTRIBUTE_NAME;
+use sway_types::constants::{
+    EXTERN_ABI_MEMORY_NAME, EXTERN_ABI_REGISTER_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
+    VALID_LINT_NAMES,
+};
 use sway_types::{Ident, SourceEngine, Span, Spanned};
 use thiserror::Error;
 
@@ -24,12 +28,44 @@ impl fmt::Display for InterfaceName {
     }
 }
 
+/// The first difference found between the signature of a function declared in a trait or ABI
+/// and the signature of its implementation. Positions of parameters are zero-based.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SignatureMismatch {
+    /// The parameter `name` has a different type than the parameter at the same position in the declaration.
+    ParameterType { position: usize, name: Ident },
+    /// The parameter `name` of the declaration is missing in the implementation.
+    MissingParameter { position: usize, name: Ident },
+    /// The parameter `name` of the implementation is not declared in the declaration.
+    ExtraParameter { position: usize, name: Ident },
+    /// The declaration is a method taking `self` and the implementation is not, or vice versa.
+    SelfParameter { is_method_in_interface: bool },
+    ReturnType,
+}
+
+/// The signatures compared by [CompileError::TraitImplSignatureMismatch], and their first
+/// difference. Kept out of the error itself to keep [CompileError] small.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignatureDifference {
+    /// The signature of the function in the declaration, with `Self` substituted.
+    pub expected_signature: String,
+    pub provided_signature: String,
+    pub mismatch: SignatureMismatch,
+}
+
 // TODO: since moving to using Idents instead of strings, there are a lot of redundant spans in
 // this type.
 #[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CompileError {
-    #[error("Variable \"{var_name}\" does not exist in this scope.")]
-    UnknownVariable { var_name: Ident, span: Span },
+    #[error(
+        "Variable \"{var_name}\" does not exist in this scope.{}",
+        suggestion.as_ref().map(|name| format!(" Did you mean \"{name}\"?")).unwrap_or_default()
+    )]
+    UnknownVariable {
+        var_name: Ident,
+        suggestion: Option<String>,
+        span: Span,
+    },
     #[error("Identifier \"{name}\" was used as a variable, but it is actually a {what_it_is}.")]
     NotAVariable {
         name: Ident,
@@ -67,13 +103,35 @@ pub enum CompileError {
     #[error("Script declaration contains no main function. Scripts require a main function.")]
     NoScriptMainFunction(Span),
     #[error("Function \"{name}\" was already defined in scope.")]
-    MultipleDefinitionsOfFunction { name: Ident, span: Span },
+    MultipleDefinitionsOfFunction {
+        name: Ident,
+        previous_span: Span,
+        span: Span,
+    },
     #[error("Name \"{name}\" is defined multiple times.")]
-    MultipleDefinitionsOfName { name: Ident, span: Span },
+    MultipleDefinitionsOfName {
+        name: Ident,
+        previous_span: Span,
+        span: Span,
+    },
     #[error("Constant \"{name}\" was already defined in scope.")]
-    MultipleDefinitionsOfConstant { name: Ident, span: Span },
-    #[error("Assignment to immutable variable. Variable {name} is not declared as mutable.")]
-    AssignmentToNonMutable { name: Ident, span: Span },
+    MultipleDefinitionsOfConstant {
+        name: Ident,
+        previous_span: Span,
+        span: Span,
+    },
+    #[error(
+        "Assignment to immutable {kind}. {} {name} is not declared as mutable.",
+        if *is_parameter { "Parameter" } else { "Variable" },
+        kind = if *is_parameter { "parameter" } else { "variable" },
+    )]
+    AssignmentToNonMutable {
+        name: Ident,
+        variable_span: Span,
+        /// True if the immutable variable is a function parameter.
+        is_parameter: bool,
+        span: Span,
+    },
     #[error(
         "Cannot call method \"{method_name}\" on variable \"{variable_name}\" because \
             \"{variable_name}\" is not declared as mutable."
@@ -81,14 +139,61 @@ pub enum CompileError {
     MethodRequiresMutableSelf {
         method_name: Ident,
         variable_name: Ident,
+        variable_span: Span,
         span: Span,
     },
     #[error(
         "This parameter was declared as mutable, which is not supported yet, did you mean to use ref mut?"
     )]
-    MutableParameterNotSupported { param_name: Ident, span: Span },
+    MutableParameterNotSupported {
+        param_name: Ident,
+        type_span: Span,
+        mutability_span: Span,
+        span: Span,
+    },
+    #[error("Parameter \"{param_name}\" is declared multiple times.")]
+    DuplicateParameterName {
+        param_name: Ident,
+        previous_span: Span,
+        type_span: Span,
+        span: Span,
+    },
+    /// The same error as [CompileError::UnknownTypeName], reported when resolving the type of
+    /// a function parameter.
+    #[error(
+        "Unknown type name \"{ty}\".{}",
+        suggestion.as_ref().map(|name| format!(" Did you mean \"{name}\"?")).unwrap_or_default()
+    )]
+    UnknownParameterType {
+        param_name: Ident,
+        ty: String,
+        suggestion: Option<String>,
+        type_span: Span,
+        span: Span,
+    },
+    #[error(
+        "Private type \"{type_name}\" is used in the parameter \"{param_name}\" of the public \
+         function \"{fn_name}\"."
+    )]
+    PrivateTypeInPublicInterface {
+        fn_name: Ident,
+        param_name: Ident,
+        type_name: String,
+        decl_span: Span,
+        type_span: Span,
+    },
+    #[error(
+        "Invalid type of the \"self\" parameter. The type of \"self\" must be \"Self\", or a struct \
+         wrapping \"Self\", like \"Ptr<Self>\"."
+    )]
+    InvalidSelfParameterType { span: Span },
     #[error("Cannot pass immutable argument to mutable parameter.")]
-    ImmutableArgumentToMutableParameter { span: Span },
+    ImmutableArgumentToMutableParameter {
+        span: Span,
+        /// The immutable variable that the argument is, or that the argument is a field or an
+        /// element of, if any.
+        immutable_variable: Option<Ident>,
+    },
     #[error("ref mut or mut parameter is not allowed for contract ABI function.")]
     RefMutableNotAllowedInContractAbi { param_name: Ident, span: Span },
     #[error(
@@ -157,8 +262,17 @@ pub enum CompileError {
     DoesNotTakeTypeArgumentsAsPrefix { name: Ident, span: Span },
     #[error("Type arguments are not allowed for this type.")]
     TypeArgumentsNotAllowed { span: Span },
-    #[error("\"{name}\" needs type arguments.")]
-    NeedsTypeArguments { name: Ident, span: Span },
+    #[error(
+        "\"{name}\" needs type arguments. Expected {} type {}, as in \"{name}<{}>\".",
+        type_parameters.len(),
+        if type_parameters.len() == 1usize { "argument" } else { "arguments" },
+        type_parameters.join(", ")
+    )]
+    NeedsTypeArguments {
+        name: Ident,
+        type_parameters: Vec<String>,
+        span: Span,
+    },
     #[error(
         "Enum with name \"{name}\" could not be found in this scope. Perhaps you need to import \
          it?"
@@ -176,10 +290,26 @@ pub enum CompileError {
         struct_name: Ident,
         span: Span,
     },
-    #[error("No method named \"{method_name}\" found for type \"{type_name}\".")]
+    #[error(
+        "No method named \"{method_name}\" found for type \"{type_name}\".{}",
+        suggestion.as_ref().map(|name| format!(" Did you mean \"{name}\"?")).unwrap_or_default()
+    )]
     MethodNotFound {
         method_name: Ident,
         type_name: String,
+        suggestion: Option<String>,
+        /// Paths of the public traits that are not in scope, but implement the method for the type.
+        importable_traits: Vec<String>,
+        span: Span,
+    },
+    #[error(
+        "No method named \"{method_name}\" found in ABI \"{abi_name}\".{}",
+        suggestion.as_ref().map(|name| format!(" Did you mean \"{name}\"?")).unwrap_or_default()
+    )]
+    AbiMethodNotFound {
+        method_name: Ident,
+        abi_name: Ident,
+        suggestion: Option<String>,
         span: Span,
     },
     #[error("Module \"{name}\" could not be found.")]
@@ -229,13 +359,15 @@ pub enum CompileError {
     #[error("This is a {actually}, not a type alias")]
     DeclIsNotATypeAlias { actually: String, span: Span },
     #[error(
-        "Field \"{field_name}\" not found on struct \"{struct_name}\". Available fields are:\n \
-         {available_fields}"
+        "Field \"{field_name}\" not found on struct \"{struct_name}\".{} Available fields are:\n \
+         {available_fields}",
+        suggestion.as_ref().map(|name| format!(" Did you mean \"{name}\"?")).unwrap_or_default()
     )]
     FieldNotFound {
         field_name: Ident,
         available_fields: String,
         struct_name: Ident,
+        suggestion: Option<String>,
         span: Span,
     },
     #[error("Could not find symbol \"{name}\" in this scope.")]
@@ -244,6 +376,28 @@ pub enum CompileError {
     ImportPrivateSymbol { name: Ident, span: Span },
     #[error("Module \"{name}\" is private.")]
     ImportPrivateModule { name: Ident, span: Span },
+    #[error(
+        "\"{name}\" is ambiguous, imported from both {}.",
+        sources.iter().map(|(path, _)| format!("\"{path}\"")).collect::<Vec<_>>().join(" and ")
+    )]
+    AmbiguousGlobImport {
+        name: Ident,
+        span: Span,
+        sources: Vec<(String, Span)>,
+    },
+    #[error(
+        "Module \"{}\" depends on itself through the module dependency cycle {}.",
+        modules[0],
+        modules.iter().chain(modules.first()).map(String::as_str).collect::<Vec<_>>().join(" -> ")
+    )]
+    ModuleDependencyCycle {
+        /// The modules in the cycle, each one depending on the next one, and the last one on the
+        /// first one.
+        modules: Vec<String>,
+        /// The spans of the `use` statements that introduce the dependencies, in the order of
+        /// `modules`.
+        use_spans: Vec<Span>,
+    },
     #[error(
         "Because this if expression's value is used, an \"else\" branch is required and it must \
          return type \"{r#type}\""
@@ -273,7 +427,8 @@ pub enum CompileError {
         function_name: Ident,
     },
     #[error(
-        "Expected Module level doc comment. All other attributes are unsupported at this level."
+        "Expected Module level doc comment. Only \"allow\" and \"deny\" attributes are also \
+         supported at this level."
     )]
     ExpectedModuleDocComment { span: Span },
     #[error(
@@ -288,10 +443,14 @@ pub enum CompileError {
     MissingImmediate { span: Span },
     #[error("This immediate value is invalid.")]
     InvalidImmediateValue { span: Span },
-    #[error("Variant \"{variant_name}\" does not exist on enum \"{enum_name}\"")]
+    #[error(
+        "Variant \"{variant_name}\" does not exist on enum \"{enum_name}\"{}",
+        suggestion.as_ref().map(|name| format!(". Did you mean \"{name}\"?")).unwrap_or_default()
+    )]
     UnknownEnumVariant {
         enum_name: Ident,
         variant_name: Ident,
+        suggestion: Option<String>,
         span: Span,
     },
     #[error("Unknown opcode: \"{op_name}\".")]
@@ -404,12 +563,20 @@ pub enum CompileError {
         type_implementing_for: String,
         span: Span,
     },
-    #[error("The function \"{fn_name}\" in {interface_name} is defined with {num_parameters} parameters, but the provided implementation has {provided_parameters} parameters.")]
-    IncorrectNumberOfInterfaceSurfaceFunctionParameters {
+    #[error("The signature of the function \"{fn_name}\" differs from the one in the {interface_name} declaration.")]
+    TraitImplSignatureMismatch {
+        fn_name: Ident,
+        interface_name: InterfaceName,
+        difference: Box<SignatureDifference>,
+        /// The span of the function in the declaration.
+        interface_fn_span: Span,
+        span: Span,
+    },
+    #[error("The parameters of the function \"{fn_name}\" appear reordered compared to the {interface_name} declaration.")]
+    InterfaceParametersReordered {
         fn_name: Ident,
         interface_name: InterfaceName,
-        num_parameters: usize,
-        provided_parameters: usize,
+        interface_parameters_span: Span,
         span: Span,
     },
     #[error("This parameter was declared as type {should_be}, but argument of type {provided} was provided.")]
@@ -436,6 +603,32 @@ pub enum CompileError {
         type_chain: String, // Pretty list of symbols, e.g., "a, b and c".
         span: Span,
     },
+    #[error("Parameter \"{name}\" is instantiated with type \"{ty}\", whose type arguments are nested {depth} levels deep. The monomorphization depth limit is {limit}.")]
+    MonomorphizationRecursionLimit {
+        name: Ident,
+        ty: String,
+        depth: usize,
+        limit: usize,
+        type_span: Span,
+        span: Span,
+    },
+    #[error(
+        "Constant \"{name}\" depends on itself through the cycle \"{name} -> {}\".",
+        references.iter().map(Ident::as_str).collect::<Vec<_>>().join(" -> ")
+    )]
+    ConstantDependencyCycle {
+        /// The constant whose declaration starts the cycle.
+        name: Ident,
+        /// The references that make up the cycle. Each one refers to the next constant in the
+        /// cycle from the value of the previous one, starting with the value of `name`. The last
+        /// one refers back to `name`.
+        references: Vec<Ident>,
+    },
+    #[error(
+        "The length of an array must be an integer literal, or a constant whose value is an \
+         integer known at compile time."
+    )]
+    NonConstantArrayLength { span: Span },
     #[error("The GM (get-metadata) opcode, when called from an external context, will cause the VM to panic.")]
     GMFromExternalContext { span: Span },
     #[error("The MINT opcode cannot be used in an external context.")]
@@ -511,6 +704,13 @@ pub enum CompileError {
     },
     #[error("Impure function inside of non-contract. Contract storage is only accessible from contracts.")]
     ImpureInNonContract { span: Span },
+    #[error("This predicate performs a {access}, which is not allowed in predicates.")]
+    StateAccessInPredicate {
+        access: &'static str,
+        /// The spans of the calls leading to the access, followed by the span of the access.
+        chain: Vec<Span>,
+        span: Span,
+    },
     #[error(
         "This function performs a storage {storage_op} but does not have the required \
         attribute(s).  Try adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to the function \
@@ -519,12 +719,72 @@ pub enum CompileError {
     ImpureInPureContext {
         storage_op: &'static str,
         attrs: String,
+        /// The spans of the calls leading to a storage read, followed by the span of the read,
+        /// if the function is missing the read attribute.
+        read_chain: Vec<Span>,
+        /// The spans of the calls leading to a storage write, followed by the span of the write,
+        /// if the function is missing the write attribute.
+        write_chain: Vec<Span>,
         span: Span,
     },
     #[error(
         "Parameter reference type or mutability mismatch between the trait function declaration and its implementation."
     )]
     ParameterRefMutabilityMismatch { span: Span },
+    #[error("Mutability of parameter \"{interface_param_name}\" differs from the one in the {interface_name} declaration.")]
+    ParameterMutabilityMismatch {
+        interface_name: InterfaceName,
+        /// The name of the parameter in the interface declaration.
+        interface_param_name: Ident,
+        is_mutable_in_interface: bool,
+        span: Span,
+    },
+    #[error("Rest parameters are only allowed in free functions.")]
+    RestParameterNotAllowed { span: Span },
+    #[error("Rest parameter \"{name}\" is never forwarded to another function.")]
+    RestParameterNotForwarded { name: Ident, span: Span },
+    #[error("Spread arguments are only allowed as the last argument of a function call.")]
+    SpreadNotAllowedHere { span: Span },
+    #[error("Rest parameter \"{name}\" cannot be forwarded to generic function \"{fn_name}\".")]
+    SpreadIntoGenericFunction {
+        name: Ident,
+        fn_name: Ident,
+        span: Span,
+    },
+    #[error("Spread argument \"..{name}\" of type \"{received}\" does not match the remaining parameters of function \"{fn_name}\", of type \"{expected}\".")]
+    SpreadArgumentMismatch {
+        name: String,
+        fn_name: Ident,
+        /// The span of the parameters of `fn_name` that the spread argument is expanded into.
+        parameters_span: Span,
+        expected: String,
+        received: String,
+        span: Span,
+    },
+    #[error("Optional parameters are only allowed in free functions.")]
+    OptionalParameterNotAllowed { span: Span },
+    #[error("Optional parameter \"{name}\" can only be followed by other optional parameters.")]
+    OptionalParameterNotLast { name: Ident, span: Span },
+    #[error("Optional parameter \"{name}\" cannot be a \"ref mut\" parameter.")]
+    RefMutableOptionalParameter { name: Ident, span: Span },
+    #[error("Array patterns are only allowed in parameters of functions with bodies.")]
+    ArrayPatternParameterNotAllowed { span: Span },
+    #[error("Array pattern cannot destructure parameter of type \"{ty}\", which is not an array.")]
+    ArrayPatternParameterNotArray {
+        ty: String,
+        type_span: Span,
+        span: Span,
+    },
+    #[error("Array pattern binds {}{pattern_len} elements, but the parameter is an array of length {array_len}.", if *has_rest { "at least " } else { "" })]
+    ArrayPatternArityMismatch {
+        /// The number of elements bound by the pattern, not counting the rest.
+        pattern_len: usize,
+        /// Whether the pattern ends in a rest, like `..rest`.
+        has_rest: bool,
+        array_len: usize,
+        type_span: Span,
+        span: Span,
+    },
     #[error("Literal value is too large for type {ty}.")]
     IntegerTooLarge { span: Span, ty: String },
     #[error("Literal value underflows type {ty}.")]
@@ -546,9 +806,14 @@ pub enum CompileError {
     )]
     ContractCallParamRepeated { param_name: String, span: Span },
     #[error(
-        "Unrecognized contract ABI method parameter \"{param_name}\". The only available parameters are \"gas\", \"coins\", and \"asset_id\""
+        "Unrecognized contract ABI method parameter \"{param_name}\". The only available parameters are \"gas\", \"coins\", and \"asset_id\"{}",
+        suggestion.map(|name| format!(". Did you mean \"{name}\"?")).unwrap_or_default()
     )]
-    UnrecognizedContractParam { param_name: String, span: Span },
+    UnrecognizedContractParam {
+        param_name: String,
+        suggestion: Option<&'static str>,
+        span: Span,
+    },
     #[error("Attempting to specify a contract method parameter for a non-contract function call")]
     CallParamForNonContractCallMethod { span: Span },
     #[error("Storage field {name} does not exist")]
@@ -556,7 +821,7 @@ pub enum CompileError {
     #[error("No storage has been declared")]
     NoDeclaredStorage { span: Span },
     #[error("Multiple storage declarations were found")]
-    MultipleStorageDeclarations { span: Span },
+    MultipleStorageDeclarations { previous_span: Span, span: Span },
     #[error("Type {ty} can only be declared directly as a storage field")]
     InvalidStorageOnlyTypeDecl { ty: String, span: Span },
     #[error(
@@ -585,6 +850,11 @@ pub enum CompileError {
         span: Span,
         hint: String,
     },
+    #[error(
+        "The argument to intrinsic \"{name}\" must be a variable, or a field or an element of a \
+         variable, but it is a temporary value."
+    )]
+    IntrinsicArgIsTemporary { name: String, span: Span },
     #[error("Call to \"{name}\" expects {expected} arguments")]
     IntrinsicIncorrectNumArgs {
         name: String,
@@ -613,16 +883,153 @@ pub enum CompileError {
     TypeNotAllowedInContractStorage { ty: String, span: Span },
     #[error("ref mut parameter not allowed for main()")]
     RefMutableNotAllowedInMain { param_name: Ident, span: Span },
+    #[error(
+        "The type \"{ty}\" of parameter \"{param_name}\" cannot be encoded in the data of a \
+            {program_kind}, so it is not allowed in `main()`."
+    )]
+    MainParameterTypeNotEncodable {
+        program_kind: String,
+        param_name: Ident,
+        ty: String,
+        span: Span,
+    },
     #[error(
         "Returning a type containing `raw_slice` from `main()` is not allowed. \
             Consider converting it into a flat `raw_slice` first."
     )]
     NestedSliceReturnNotAllowedInMain { span: Span },
+    #[error(
+        "The return type \"{ty}\" cannot be encoded in the return data of a {program_kind}, \
+            so it is not allowed in `main()`."
+    )]
+    MainReturnTypeNotEncodable {
+        program_kind: String,
+        ty: String,
+        span: Span,
+    },
+    #[error("Test function \"{fn_name}\" cannot have parameters.")]
+    TestFunctionWithParameters { fn_name: Ident, span: Span },
+    #[error(
+        "Test function \"{fn_name}\" returns \"{ty}\", but test functions can only \
+            return \"()\" or a \"Result\"."
+    )]
+    InvalidTestFunctionReturnType {
+        fn_name: Ident,
+        ty: String,
+        span: Span,
+    },
+    #[error(
+        "Function \"{fn_name}\" is attributed with both #[inline({first_value})] and \
+            #[inline({value})]."
+    )]
+    ConflictingInlineAttributes {
+        fn_name: Ident,
+        /// The argument of the first `inline` attribute, e.g., "always".
+        first_value: String,
+        first_span: Span,
+        /// The argument of the conflicting `inline` attribute, e.g., "never".
+        value: String,
+        span: Span,
+    },
+    #[error(
+        "This arithmetic operation always overflows: \"{lhs} {op} {rhs}\" is out of the range \
+            of {bits}-bit unsigned integers and would revert at runtime."
+    )]
+    ConstantArithmeticOverflow {
+        /// The operator, e.g., "+".
+        op: String,
+        lhs: u64,
+        rhs: u64,
+        /// The width of the operands, e.g., 8 for `u8`.
+        bits: u64,
+        span: Span,
+    },
+    #[error("`{ty}` does not implement `{trait_name}`, required by parameter `{param_name}`.")]
+    ArgumentDoesNotSatisfyParameterBound {
+        ty: String,
+        trait_name: String,
+        param_name: String,
+        /// The name of the generic type parameter declaring the bound, e.g., "T".
+        type_param: String,
+        /// The span of the declared type of the parameter.
+        type_span: Span,
+        span: Span,
+    },
+    #[error("Attribute \"fields\" cannot bind the fields of parameter \"{param_name}\" of type \"{ty}\", which is not a struct.")]
+    FieldsAttributeOnNonStructParameter {
+        param_name: Ident,
+        ty: String,
+        type_span: Span,
+        span: Span,
+    },
+    #[error("Field \"{field_name}\" of parameter \"{param_name}\" cannot be bound, because \"{field_name}\" is already declared in the function.")]
+    FieldBindingCollision {
+        field_name: Ident,
+        param_name: Ident,
+        /// The span of the existing declaration of `field_name`.
+        previous_span: Span,
+        /// The span of the `#[fields]` attribute.
+        span: Span,
+    },
+    #[error("\"typeof\" types are only allowed in parameters of functions with bodies.")]
+    TypeofParameterNotAllowed { span: Span },
+    #[error("Type of parameter \"{param_name}\" cannot refer to parameter \"{name}\", which is not declared before it.")]
+    TypeofRefersToUndeclaredParameter {
+        name: Ident,
+        param_name: Ident,
+        span: Span,
+    },
+    #[error("Parameter \"{param_name}\" has an attribute with an unknown lint \"{lint}\".")]
+    UnknownLintOnParameter {
+        lint: String,
+        param_name: Ident,
+        span: Span,
+    },
+    #[error("Unknown ABI \"{name}\". The supported ABIs are \"register\" and \"memory\".")]
+    UnknownExternAbi { name: String, span: Span },
+    #[error(
+        "Parameter \"{param_name}\" cannot be passed with the \"{abi}\" ABI, because {reason}."
+    )]
+    ExternAbiParameterNotSupported {
+        abi: String,
+        param_name: Ident,
+        /// Why the parameter cannot be passed with the ABI, e.g., "its type \"T\" is generic".
+        reason: String,
+        span: Span,
+    },
+    #[error("An array of \"{array_elem}\" cannot be coerced to a slice of \"{slice_elem}\".")]
+    ArrayToSliceElementMismatch {
+        array_elem: String,
+        slice_elem: String,
+        param_name: Ident,
+        span: Span,
+    },
+    #[error("{warning} This warning is an error, because the \"{lint}\" lint is denied.")]
+    DeniedLint {
+        lint: String,
+        warning: Box<Warning>,
+        span: Span,
+    },
     #[error(
         "Register \"{name}\" is initialized and later reassigned which is not allowed. \
             Consider assigning to a different register inside the ASM block."
     )]
     InitializedRegisterReassignment { name: String, span: Span },
+    #[error("Register \"{name}\" is declared multiple times in this asm block.")]
+    DuplicateAsmRegister {
+        name: String,
+        previous_span: Span,
+        span: Span,
+    },
+    #[error(
+        "Register \"{name}\" cannot be declared in an asm block because it is a reserved register. \
+         Reserved registers are: {reserved_registers}."
+    )]
+    ReservedAsmRegister {
+        name: String,
+        reserved_registers: String,
+        span: Span,
+    },
     #[error("Control flow VM instructions are not allowed in assembly blocks.")]
     DisallowedControlFlowInstruction { name: String, span: Span },
     #[error("Calling private library method {name} is not allowed.")]
@@ -644,10 +1051,14 @@ pub enum CompileError {
         fn_name: Ident,
         interface_name: InterfaceName,
         missing_impl_attribute: bool,
+        /// The span of the method name in the interface declaration.
+        interface_fn_span: Span,
         span: Span,
     },
     #[error("Configurable constants are not allowed in libraries.")]
     ConfigurableInLibrary { span: Span },
+    #[error("Configurable constant \"{name}\" cannot be used here, because its value is only known when the program is deployed.")]
+    ConfigurableInConstantContext { name: Ident, span: Span },
     #[error("Multiple applicable items in scope. {}", {
         let mut candidates = "".to_string();
         for (index, as_trait) in as_traits.iter().enumerate() {
@@ -676,6 +1087,114 @@ pub enum CompileError {
     AbiSupertraitMethodCallAsContractCall { fn_name: Ident, span: Span },
 }
 
+/// The parameter an error in a function parameter declaration is reported for.
+///
+/// Gives tools a structured view of the error that does not depend on its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterErrorInfo<'a> {
+    /// The stable code of the error.
+    pub code: Code,
+    pub name: &'a Ident,
+    pub type_span: &'a Span,
+}
+
+impl CompileError {
+    /// Returns the [ParameterErrorInfo] of the error, if the error is reported for a function
+    /// parameter declaration.
+    pub fn parameter_error_info(&self) -> Option<ParameterErrorInfo> {
+        use CompileError::*;
+        let (name, type_span) = match self {
+            MutableParameterNotSupported {
+                param_name,
+                type_span,
+                ..
+            } => (param_name, type_span),
+            DuplicateParameterName {
+                param_name,
+                type_span,
+                ..
+            } => (param_name, type_span),
+            UnknownParameterType {
+                param_name,
+                type_span,
+                ..
+            } => (param_name, type_span),
+            PrivateTypeInPublicInterface {
+                param_name,
+                type_span,
+                ..
+            } => (param_name, type_span),
+            MonomorphizationRecursionLimit {
+                name, type_span, ..
+            } => (name, type_span),
+            _ => return None,
+        };
+        Some(ParameterErrorInfo {
+            code: self.code(),
+            name,
+            type_span,
+        })
+    }
+
+    /// Returns the [Suggestion] to replace a misspelled name with the similar one
+    /// found by the compiler, or to declare an immutable variable as mutable, if any.
+    fn suggestion(&self, source_engine: &SourceEngine) -> Option<Suggestion> {
+        use CompileError::*;
+        let (span, replacement) = match self {
+            AssignmentToNonMutable {
+                name,
+                variable_span,
+                is_parameter,
+                ..
+            } => (
+                variable_span.clone(),
+                if *is_parameter {
+                    format!("ref mut {name}")
+                } else {
+                    format!("mut {name}")
+                },
+            ),
+            UnknownVariable {
+                var_name,
+                suggestion: Some(suggestion),
+                ..
+            } => (var_name.span(), suggestion.clone()),
+            UnknownParameterType {
+                span,
+                suggestion: Some(suggestion),
+                ..
+            } => (span.clone(), suggestion.clone()),
+            MethodNotFound {
+                method_name,
+                suggestion: Some(suggestion),
+                ..
+            }
+            | AbiMethodNotFound {
+                method_name,
+                suggestion: Some(suggestion),
+                ..
+            } => (method_name.span(), suggestion.clone()),
+            FieldNotFound {
+                field_name,
+                suggestion: Some(suggestion),
+                ..
+            } => (field_name.span(), suggestion.clone()),
+            UnknownEnumVariant {
+                variant_name,
+                suggestion: Some(suggestion),
+                ..
+            } => (variant_name.span(), suggestion.clone()),
+            UnrecognizedContractParam {
+                suggestion: Some(suggestion),
+                span,
+                ..
+            } => (span.clone(), suggestion.to_string()),
+            _ => return None,
+        };
+        Some(Suggestion::new(source_engine, span, replacement))
+    }
+}
+
 impl std::convert::From<TypeError> for CompileError {
     fn from(other: TypeError) -> CompileError {
         CompileError::TypeError(other)
@@ -702,7 +1221,11 @@ impl Spanned for CompileError {
             MultipleDefinitionsOfConstant { span, .. } => span.clone(),
             AssignmentToNonMutable { span, .. } => span.clone(),
             MutableParameterNotSupported { span, .. } => span.clone(),
-            ImmutableArgumentToMutableParameter { span } => span.clone(),
+            DuplicateParameterName { span, .. } => span.clone(),
+            UnknownParameterType { span, .. } => span.clone(),
+            PrivateTypeInPublicInterface { type_span, .. } => type_span.clone(),
+            InvalidSelfParameterType { span } => span.clone(),
+            ImmutableArgumentToMutableParameter { span, .. } => span.clone(),
             RefMutableNotAllowedInContractAbi { span, .. } => span.clone(),
             MethodRequiresMutableSelf { span, .. } => span.clone(),
             AssociatedFunctionCalledAsMethod { span, .. } => span.clone(),
@@ -721,6 +1244,7 @@ impl Spanned for CompileError {
             StructMissingField { span, .. } => span.clone(),
             StructDoesNotHaveField { span, .. } => span.clone(),
             MethodNotFound { span, .. } => span.clone(),
+            AbiMethodNotFound { span, .. } => span.clone(),
             ModuleNotFound { span, .. } => span.clone(),
             NotATuple { span, .. } => span.clone(),
             NotAStruct { span, .. } => span.clone(),
@@ -730,6 +1254,8 @@ impl Spanned for CompileError {
             SymbolNotFound { span, .. } => span.clone(),
             ImportPrivateSymbol { span, .. } => span.clone(),
             ImportPrivateModule { span, .. } => span.clone(),
+            AmbiguousGlobImport { span, .. } => span.clone(),
+            ModuleDependencyCycle { use_spans, .. } => use_spans[0].clone(),
             NoElseBranch { span, .. } => span.clone(),
             NotAType { span, .. } => span.clone(),
             MissingEnumInstantiator { span, .. } => span.clone(),
@@ -770,12 +1296,16 @@ impl Spanned for CompileError {
                 second_impl_span, ..
             } => second_impl_span.clone(),
             DuplicateDeclDefinedForType { span, .. } => span.clone(),
-            IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
+            TraitImplSignatureMismatch { span, .. } => span.clone(),
+            InterfaceParametersReordered { span, .. } => span.clone(),
             ArgumentParameterTypeMismatch { span, .. } => span.clone(),
             RecursiveCall { span, .. } => span.clone(),
             RecursiveCallChain { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
             RecursiveTypeChain { span, .. } => span.clone(),
+            MonomorphizationRecursionLimit { span, .. } => span.clone(),
+            ConstantDependencyCycle { name, .. } => name.span(),
+            NonConstantArrayLength { span } => span.clone(),
             GMFromExternalContext { span, .. } => span.clone(),
             MintFromExternalContext { span, .. } => span.clone(),
             BurnFromExternalContext { span, .. } => span.clone(),
@@ -805,8 +1335,21 @@ impl Spanned for CompileError {
             DeclIsNotAConstant { span, .. } => span.clone(),
             DeclIsNotATypeAlias { span, .. } => span.clone(),
             ImpureInNonContract { span, .. } => span.clone(),
+            StateAccessInPredicate { span, .. } => span.clone(),
             ImpureInPureContext { span, .. } => span.clone(),
             ParameterRefMutabilityMismatch { span, .. } => span.clone(),
+            ParameterMutabilityMismatch { span, .. } => span.clone(),
+            RestParameterNotAllowed { span } => span.clone(),
+            RestParameterNotForwarded { span, .. } => span.clone(),
+            SpreadNotAllowedHere { span } => span.clone(),
+            SpreadIntoGenericFunction { span, .. } => span.clone(),
+            SpreadArgumentMismatch { span, .. } => span.clone(),
+            OptionalParameterNotAllowed { span } => span.clone(),
+            OptionalParameterNotLast { span, .. } => span.clone(),
+            RefMutableOptionalParameter { span, .. } => span.clone(),
+            ArrayPatternParameterNotAllowed { span } => span.clone(),
+            ArrayPatternParameterNotArray { span, .. } => span.clone(),
+            ArrayPatternArityMismatch { span, .. } => span.clone(),
             IntegerTooLarge { span, .. } => span.clone(),
             IntegerTooSmall { span, .. } => span.clone(),
             IntegerContainsInvalidDigit { span, .. } => span.clone(),
@@ -829,6 +1372,7 @@ impl Spanned for CompileError {
             NonConstantDeclValue { span } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
+            IntrinsicArgIsTemporary { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
             BreakOutsideLoop { span } => span.clone(),
@@ -837,14 +1381,33 @@ impl Spanned for CompileError {
             ContractIdValueNotALiteral { span } => span.clone(),
             TypeNotAllowedInContractStorage { span, .. } => span.clone(),
             RefMutableNotAllowedInMain { span, .. } => span.clone(),
+            MainParameterTypeNotEncodable { span, .. } => span.clone(),
             NestedSliceReturnNotAllowedInMain { span } => span.clone(),
+            MainReturnTypeNotEncodable { span, .. } => span.clone(),
+            TestFunctionWithParameters { span, .. } => span.clone(),
+            InvalidTestFunctionReturnType { span, .. } => span.clone(),
+            ConflictingInlineAttributes { span, .. } => span.clone(),
+            ConstantArithmeticOverflow { span, .. } => span.clone(),
+            ArgumentDoesNotSatisfyParameterBound { span, .. } => span.clone(),
+            FieldsAttributeOnNonStructParameter { span, .. } => span.clone(),
+            FieldBindingCollision { span, .. } => span.clone(),
+            TypeofParameterNotAllowed { span } => span.clone(),
+            TypeofRefersToUndeclaredParameter { span, .. } => span.clone(),
+            UnknownLintOnParameter { span, .. } => span.clone(),
+            UnknownExternAbi { span, .. } => span.clone(),
+            ExternAbiParameterNotSupported { span, .. } => span.clone(),
+            ArrayToSliceElementMismatch { span, .. } => span.clone(),
+            DeniedLint { span, .. } => span.clone(),
             InitializedRegisterReassignment { span, .. } => span.clone(),
+            DuplicateAsmRegister { span, .. } => span.clone(),
+            ReservedAsmRegister { span, .. } => span.clone(),
             DisallowedControlFlowInstruction { span, .. } => span.clone(),
             CallingPrivateLibraryMethod { span, .. } => span.clone(),
             DisallowedIntrinsicInPredicate { span, .. } => span.clone(),
             CoinsPassedToNonPayableMethod { span, .. } => span.clone(),
             TraitImplPayabilityMismatch { span, .. } => span.clone(),
             ConfigurableInLibrary { span } => span.clone(),
+            ConfigurableInConstantContext { span, .. } => span.clone(),
             MultipleApplicableItemsInScope { span, .. } => span.clone(),
             CannotBeEvaluatedToConst { span } => span.clone(),
             ContractCallsItsOwnMethod { span } => span.clone(),
@@ -857,11 +1420,10 @@ impl Spanned for CompileError {
 
 impl ToDiagnostic for CompileError {
     fn to_diagnostic(&self, source_engine: &SourceEngine) -> Diagnostic {
-        let code = Code::semantic_analysis;
         use CompileError::*;
         match self {
             ConstantsCannotBeShadowed { variable_or_constant, name, constant_span, constant_decl, is_alias } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Constants cannot be shadowed".to_string())),
+                reason: Some(Reason::new(self.code(), "Constants cannot be shadowed".to_string())),
                 // NOTE: Issue level should actually be the part of the reason. But it would complicate handling of labels in the transitional
                 //       period when we still have "old-style" diagnostics.
                 //       Let's leave it like this, refactoring at the moment does not pay of.
@@ -919,9 +1481,10 @@ impl ToDiagnostic for CompileError {
                         _ => unreachable!("We can have only the listed combinations: variable/constant shadows a non imported/imported constant.")
                     }
                 ],
+                ..Default::default()
             },
             ConstantShadowsVariable { name , variable_span } => Diagnostic {
-                reason: Some(Reason::new(code(2), "Constants cannot shadow variables".to_string())),
+                reason: Some(Reason::new(self.code(), "Constants cannot shadow variables".to_string())),
                 issue: Issue::error(
                     source_engine,
                     name.span(),
@@ -943,6 +1506,749 @@ impl ToDiagnostic for CompileError {
                     "Variables can shadow other variables, but constants cannot.".to_string(),
                     "Consider renaming either the variable or the constant.".to_string(),
                 ],
+                ..Default::default()
+            },
+            AmbiguousGlobImport { name, span, sources } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Ambiguous glob import".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("\"{name}\" is ambiguous, because it is glob-imported from more than one module")
+                ),
+                hints: sources.iter().map(|(path, use_span)|
+                    Hint::info(
+                        source_engine,
+                        use_span.clone(),
+                        format!("\"{name}\" is glob-imported here from \"{path}\".")
+                    )
+                ).collect(),
+                help: vec![
+                    format!("Consider importing \"{name}\" explicitly from the module you want to use, e.g. `use {}::{name};`.",
+                        sources.first().map(|(path, _)| path.as_str()).unwrap_or_default()
+                    ),
+                ],
+                ..Default::default()
+            },
+            ModuleDependencyCycle { modules, use_spans } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Module dependency cycle".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    use_spans[0].clone(),
+                    format!("Module \"{}\" depends on itself through the cycle \"{}\"",
+                        modules[0],
+                        modules.iter().chain(modules.first()).map(String::as_str).collect::<Vec<_>>().join(" -> ")
+                    )
+                ),
+                hints: modules.iter().zip(use_spans).zip(modules.iter().cycle().skip(1)).skip(1).map(|((module, use_span), dependency)|
+                    Hint::info(
+                        source_engine,
+                        use_span.clone(),
+                        format!("Module \"{module}\" depends on module \"{dependency}\" here.")
+                    )
+                ).collect(),
+                help: vec![
+                    "A module can only use items from modules that do not depend on it.".to_string(),
+                    "Consider moving the items these modules share into a separate module that they can all depend on.".to_string(),
+                ],
+                ..Default::default()
+            },
+            ImmutableArgumentToMutableParameter { span, immutable_variable } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Cannot pass immutable argument to mutable parameter".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    match immutable_variable {
+                        Some(name) => format!("This argument is immutable, because variable \"{name}\" is not declared as mutable"),
+                        None => "This argument is not a mutable variable, nor a field or an element of one".to_string(),
+                    }
+                ),
+                hints: immutable_variable.iter().map(|name|
+                    Hint::info(
+                        source_engine,
+                        name.span(),
+                        format!("Variable \"{name}\" is declared here as immutable.")
+                    )
+                ).collect(),
+                help: vec![
+                    "Only mutable variables, and fields and elements of mutable variables, can be passed to mutable parameters.".to_string(),
+                    match immutable_variable {
+                        Some(name) => format!("Consider declaring \"{name}\" as mutable."),
+                        None => "Consider storing the argument in a mutable variable first.".to_string(),
+                    },
+                ],
+                ..Default::default()
+            },
+            ConstantDependencyCycle { name, references } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Constant dependency cycle".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    name.span(),
+                    format!("Constant \"{name}\" depends on itself through the cycle \"{}\"",
+                        std::iter::once(name).chain(references).map(Ident::as_str).collect::<Vec<_>>().join(" -> ")
+                    )
+                ),
+                hints: std::iter::once(name).chain(references).zip(references).map(|(constant, reference)|
+                    Hint::info(
+                        source_engine,
+                        reference.span(),
+                        format!("Constant \"{constant}\" depends on constant \"{reference}\" here.")
+                    )
+                ).collect(),
+                help: vec![
+                    "The value of a constant cannot depend on the constant itself.".to_string(),
+                ],
+                ..Default::default()
+            },
+            ParameterMutabilityMismatch { interface_name, interface_param_name, is_mutable_in_interface, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Parameter mutability mismatch".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Mutability of parameter \"{interface_param_name}\" differs from the one in the {interface_name} declaration")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        interface_param_name.span(),
+                        format!("Parameter \"{interface_param_name}\" is declared here as {}.",
+                            if *is_mutable_in_interface { "mutable" } else { "immutable" }
+                        )
+                    ),
+                ],
+                help: vec![
+                    format!("The definition of this function must match the one in the {interface_name} declaration."),
+                ],
+                ..Default::default()
+            },
+            TraitImplPayabilityMismatch { fn_name, interface_name, missing_impl_attribute, interface_fn_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Payable attribute mismatch".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Payable attribute mismatch. The \"{fn_name}\" method implementation {} in its signature in {interface_name}.",
+                        if *missing_impl_attribute {
+                            "is missing #[payable] attribute specified"
+                        } else {
+                            "has extra #[payable] attribute not mentioned"
+                        }
+                    )
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        interface_fn_span.clone(),
+                        format!("Method \"{fn_name}\" is declared here {} the #[payable] attribute.",
+                            if *missing_impl_attribute { "with" } else { "without" }
+                        )
+                    ),
+                ],
+                help: vec![
+                    format!("The definition of this function must match the one in the {interface_name} declaration."),
+                ],
+                ..Default::default()
+            },
+            InterfaceParametersReordered { fn_name, interface_name, interface_parameters_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Function parameters are reordered".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("The parameters of the function \"{fn_name}\" appear reordered compared to the {interface_name} declaration.")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        interface_parameters_span.clone(),
+                        format!("The parameters are declared in this order in the {interface_name} declaration.")
+                    ),
+                ],
+                help: vec![
+                    "Parameters are matched by their position, so they must be declared in the same order.".to_string(),
+                ],
+                ..Default::default()
+            },
+            ImpureInPureContext { storage_op, attrs, read_chain, write_chain, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Storage access mismatch".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("This function performs a storage {storage_op} but does not have the required attribute(s).  Try adding \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]\" to the function declaration.")
+                ),
+                hints: [(read_chain, "read"), (write_chain, "written")].into_iter().flat_map(|(chain, access)|
+                    chain.iter().enumerate().map(move |(i, access_span)|
+                        Hint::info(
+                            source_engine,
+                            access_span.clone(),
+                            if i + 1 == chain.len() {
+                                format!("Storage is {access} here.")
+                            } else {
+                                format!("Storage is {access} through this call.")
+                            }
+                        )
+                    )
+                ).collect(),
+                help: vec![
+                    "The storage access of a function without a storage attribute is inferred, unless the function is an entry point, or a trait or an ABI method.".to_string(),
+                ],
+                ..Default::default()
+            },
+            StateAccessInPredicate { access, chain, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Predicates cannot access the blockchain state".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("This predicate performs a {access}, which is not allowed in predicates.")
+                ),
+                hints: chain.iter().enumerate().map(|(i, access_span)|
+                    Hint::info(
+                        source_engine,
+                        access_span.clone(),
+                        if i + 1 == chain.len() {
+                            format!("The {access} is performed here.")
+                        } else {
+                            format!("The {access} is performed through this call.")
+                        }
+                    )
+                ).collect(),
+                help: vec![
+                    "Predicates are evaluated without access to contract storage, so they cannot read or write storage, call contracts, or log.".to_string(),
+                ],
+                ..Default::default()
+            },
+            PrivateTypeInPublicInterface { fn_name, param_name, type_name, decl_span, type_span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Private type in public interface".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    type_span.clone(),
+                    format!("Parameter \"{param_name}\" of the public function \"{fn_name}\" has the private type \"{type_name}\".")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        decl_span.clone(),
+                        format!("\"{type_name}\" is declared here without \"pub\".")
+                    ),
+                ],
+                help: vec![
+                    format!("Callers from other modules cannot name \"{type_name}\", so they cannot call \"{fn_name}\"."),
+                    format!("Consider declaring \"{type_name}\" as \"pub\", or making \"{fn_name}\" private."),
+                ],
+                ..Default::default()
+            },
+            DuplicateAsmRegister { name, previous_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Register is declared multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Register \"{name}\" is declared multiple times in this asm block.")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{name}\" is first declared here.")
+                    ),
+                ],
+                help: vec![
+                    "Later declarations of a register silently overwrite the earlier ones.".to_string(),
+                    "Consider renaming one of the registers.".to_string(),
+                ],
+                ..Default::default()
+            },
+            SpreadArgumentMismatch { name, fn_name, parameters_span, expected, received, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Spread argument does not match function parameters".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("\"..{name}\" expands to arguments of type \"{received}\", but \"{fn_name}\" expects \"{expected}\"")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        parameters_span.clone(),
+                        format!("These are the parameters of \"{fn_name}\" that \"..{name}\" is expanded into.")
+                    ),
+                ],
+                help: vec![
+                    "A spread argument must expand into exactly the remaining parameters of the called function.".to_string(),
+                ],
+                ..Default::default()
+            },
+            MultipleDefinitionsOfFunction { name, previous_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Function is defined multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Function \"{name}\" was already defined in scope")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{name}\" is previously defined here.")
+                    ),
+                ],
+                help: vec![
+                    "Consider removing or renaming one of the functions.".to_string(),
+                ],
+                ..Default::default()
+            },
+            MultipleDefinitionsOfName { name, previous_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Name is defined multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Name \"{name}\" is defined multiple times")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{name}\" is previously defined here.")
+                    ),
+                ],
+                help: vec![
+                    "Types, traits and ABIs share the same namespace, so their names must be unique within a module.".to_string(),
+                ],
+                ..Default::default()
+            },
+            MultipleDefinitionsOfConstant { name, previous_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Constant is defined multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Constant \"{name}\" was already defined in scope")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{name}\" is previously defined here.")
+                    ),
+                ],
+                help: vec![
+                    "Consider removing or renaming one of the constants.".to_string(),
+                ],
+                ..Default::default()
+            },
+            MultipleStorageDeclarations { previous_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Storage is declared multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    "Multiple storage declarations were found".to_string()
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        "Storage is first declared here.".to_string()
+                    ),
+                ],
+                help: vec![
+                    "A contract can have only one storage declaration.".to_string(),
+                    "Consider moving all the storage fields into a single declaration.".to_string(),
+                ],
+                ..Default::default()
+            },
+            DuplicateParameterName { param_name, previous_span, span, .. } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Parameter is declared multiple times".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Parameter \"{param_name}\" is declared multiple times")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{param_name}\" is first declared here.")
+                    ),
+                ],
+                help: vec![
+                    "Consider renaming one of the parameters.".to_string(),
+                ],
+                ..Default::default()
+            },
+            AssignmentToNonMutable { name, variable_span, is_parameter: false, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Assignment to immutable variable".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Variable {name} is not declared as mutable")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        variable_span.clone(),
+                        format!("Variable \"{name}\" is declared here as immutable.")
+                    ),
+                ],
+                help: vec![
+                    "Only variables declared as mutable can be reassigned.".to_string(),
+                    format!("Consider declaring \"{name}\" as mutable: `let mut {name}`."),
+                ],
+                suggestion: self.suggestion(source_engine),
+                ..Default::default()
+            },
+            AssignmentToNonMutable { name, variable_span, is_parameter: true, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Assignment to immutable parameter".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Parameter {name} is not declared as mutable")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        variable_span.clone(),
+                        format!("Parameter \"{name}\" is declared here as immutable.")
+                    ),
+                ],
+                help: vec![
+                    "Only parameters declared as \"ref mut\" can be reassigned.".to_string(),
+                    format!("Consider declaring \"{name}\" as mutable: `ref mut {name}`."),
+                ],
+                suggestion: self.suggestion(source_engine),
+                ..Default::default()
+            },
+            MethodRequiresMutableSelf { method_name, variable_name, variable_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Method requires a mutable variable".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Cannot call method \"{method_name}\" on variable \"{variable_name}\" because \"{variable_name}\" is not declared as mutable")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        variable_span.clone(),
+                        format!("\"{variable_name}\" is declared here as immutable.")
+                    ),
+                ],
+                help: vec![
+                    format!("Method \"{method_name}\" takes \"ref mut self\", so it can only be called on mutable variables."),
+                ],
+                ..Default::default()
+            },
+            TooManyArgumentsForFunction { method_name, dot_syntax_used, expected, received, span }
+            | TooFewArgumentsForFunction { method_name, dot_syntax_used, expected, received, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), if matches!(self, TooManyArgumentsForFunction { .. }) {
+                    "Too many arguments".to_string()
+                } else {
+                    "Too few arguments".to_string()
+                })),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("{} \"{method_name}\" expects {expected} {} but you provided {received}",
+                        if *dot_syntax_used { "Method" } else { "Function" },
+                        if *expected == 1usize { "argument" } else { "arguments" },
+                    )
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        method_name.span(),
+                        format!("{} \"{method_name}\" is declared here.",
+                            if *dot_syntax_used { "Method" } else { "Function" },
+                        )
+                    ),
+                ],
+                help: vec![],
+                ..Default::default()
+            },
+            StructMissingField { field_name, struct_name, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Struct field is missing".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Initialization of struct \"{struct_name}\" is missing field \"{field_name}\"")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        field_name.span(),
+                        format!("Field \"{field_name}\" is declared here.")
+                    ),
+                ],
+                help: vec![
+                    "All the fields of a struct must be initialized.".to_string(),
+                ],
+                ..Default::default()
+            },
+            StructDoesNotHaveField { field_name, struct_name, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Struct field does not exist".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Struct \"{struct_name}\" does not have field \"{field_name}\"")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        struct_name.span(),
+                        format!("Struct \"{struct_name}\" is declared here.")
+                    ),
+                ],
+                help: vec![],
+                ..Default::default()
+            },
+            TraitImplSignatureMismatch { fn_name, interface_name, difference, interface_fn_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Function signature mismatch".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    match &difference.mismatch {
+                        SignatureMismatch::ParameterType { position, name } => format!(
+                            "The type of the parameter \"{name}\" at position {} differs from the one in the {interface_name} declaration",
+                            position + 1
+                        ),
+                        SignatureMismatch::MissingParameter { position, name } => format!(
+                            "The parameter \"{name}\" at position {} in the {interface_name} declaration is missing",
+                            position + 1
+                        ),
+                        SignatureMismatch::ExtraParameter { position, name } => format!(
+                            "The parameter \"{name}\" at position {} is not declared in the {interface_name} declaration",
+                            position + 1
+                        ),
+                        SignatureMismatch::SelfParameter { is_method_in_interface: true } => format!(
+                            "The {interface_name} declares \"{fn_name}\" as a method taking \"self\", but it is implemented without \"self\""
+                        ),
+                        SignatureMismatch::SelfParameter { is_method_in_interface: false } => format!(
+                            "The {interface_name} declares \"{fn_name}\" without \"self\", but it is implemented as a method taking \"self\""
+                        ),
+                        SignatureMismatch::ReturnType => format!("The return type differs from the one in the {interface_name} declaration"),
+                    }
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        interface_fn_span.clone(),
+                        format!("The {interface_name} declares \"{fn_name}\" here.")
+                    ),
+                ],
+                help: vec![
+                    format!("expected: {}", difference.expected_signature),
+                    format!("found:    {}", difference.provided_signature),
+                    format!("The signature of \"{fn_name}\" must match the one in the {interface_name} declaration."),
+                ],
+                ..Default::default()
+            },
+            ConflictingInlineAttributes { fn_name, first_value, first_span, value, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Conflicting inline attributes".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("\"{fn_name}\" cannot also be attributed with #[inline({value})]")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        first_span.clone(),
+                        format!("\"{fn_name}\" is already attributed with #[inline({first_value})] here.")
+                    ),
+                ],
+                help: vec![
+                    "A function can either always be inlined or never be inlined. Keep only one of the attributes.".to_string(),
+                ],
+                ..Default::default()
+            },
+            ArrayPatternArityMismatch { pattern_len, has_rest, array_len, type_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Array pattern does not match the array length".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("This pattern binds {}{pattern_len} elements", if *has_rest { "at least " } else { "" })
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        type_span.clone(),
+                        format!("The parameter is declared as an array of length {array_len} here.")
+                    ),
+                ],
+                help: vec![
+                    if *has_rest {
+                        "The elements before the rest, like `first` in `[first, ..rest]`, cannot outnumber the elements of the array.".to_string()
+                    } else {
+                        "To bind only the leading elements, end the pattern with a rest, like `[first, ..rest]`.".to_string()
+                    },
+                ],
+                ..Default::default()
+            },
+            MonomorphizationRecursionLimit { name, ty, depth, limit, type_span, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Monomorphization depth limit exceeded".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Parameter \"{name}\" is instantiated with type \"{ty}\", whose type arguments are nested {depth} levels deep")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        type_span.clone(),
+                        format!("The type of \"{name}\" is declared here.")
+                    ),
+                ],
+                help: vec![
+                    format!("The monomorphization depth limit is {limit}."),
+                    "The limit can be raised with the \"monomorphization-depth-limit\" build profile option or the \"--monomorphization-depth-limit\" flag.".to_string(),
+                ],
+                ..Default::default()
+            },
+            TypeError(crate::type_error::TypeError::MismatchedType { expected_origin: Some(expected_origin), .. }) => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self).trim_end().to_string()
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        expected_origin.span(),
+                        expected_origin.to_string()
+                    ),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            MutableParameterNotSupported { param_name, mutability_span, .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        mutability_span.clone(),
+                        format!("Parameter \"{param_name}\" is declared as mutable here.")
+                    ),
+                ],
+                help: vec![
+                    format!("To mutate the caller's value use `ref mut {param_name}`; to mutate a local copy `mut` will be supported in a future release."),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            ArgumentDoesNotSatisfyParameterBound { ty, trait_name, param_name, type_param, type_span, .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("`{ty}` does not implement `{trait_name}`, required by parameter `{param_name}`")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        type_span.clone(),
+                        format!("Parameter \"{param_name}\" is of type \"{type_param}\", which is bound by \"{type_param}: {trait_name}\".")
+                    ),
+                ],
+                help: vec![
+                    format!("Consider implementing \"{trait_name}\" for \"{ty}\", or passing an argument of a type that implements it."),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            FieldsAttributeOnNonStructParameter { param_name, ty, type_span, .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        type_span.clone(),
+                        format!("Parameter \"{param_name}\" is of type \"{ty}\".")
+                    ),
+                ],
+                help: vec![
+                    "Only the fields of parameters of struct types can be bound by \"#[fields]\".".to_string(),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            FieldBindingCollision { field_name, param_name, previous_span, .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        previous_span.clone(),
+                        format!("\"{field_name}\" is already declared here.")
+                    ),
+                ],
+                help: vec![
+                    format!("Consider renaming \"{field_name}\", or accessing the field as \"{param_name}.{field_name}\" without \"#[fields]\"."),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            TypeofRefersToUndeclaredParameter { .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                help: vec![
+                    "The expression of \"typeof\" can only refer to the parameters declared before the parameter it types.".to_string(),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            UnknownLintOnParameter { .. } => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                help: vec![
+                    format!("The known lints are: {}.", VALID_LINT_NAMES.iter().map(|lint| format!("\"{lint}\"")).collect::<Vec<_>>().join(", ")),
+                ],
+                code: Some(self.code()),
+                ..Default::default()
+            },
+            ExternAbiParameterNotSupported { abi, param_name, reason, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Parameter cannot be passed with the ABI".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("\"{param_name}\" cannot be passed with the \"{abi}\" ABI, because {reason}.")
+                ),
+                help: vec![
+                    format!("The \"{EXTERN_ABI_REGISTER_NAME}\" ABI passes the arguments by value, in at most six registers, so the parameters must be integers of at most 64 bits, `bool`s or pointers."),
+                    format!("The \"{EXTERN_ABI_MEMORY_NAME}\" ABI passes the arguments by reference, so the parameters can be of any type which is not generic."),
+                ],
+                ..Default::default()
+            },
+            ArrayToSliceElementMismatch { array_elem, slice_elem, param_name, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Array cannot be coerced to a slice".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("This array of \"{array_elem}\" is passed to \"{param_name}\", which is a slice of \"{slice_elem}\".")
+                ),
+                help: vec![
+                    "An array can be passed where a slice is expected only if the elements of both have the same type.".to_string(),
+                ],
+                ..Default::default()
+            },
+            MethodNotFound { method_name, importable_traits, .. } if !importable_traits.is_empty() => Diagnostic {
+                issue: Issue::error(
+                    source_engine,
+                    self.span(),
+                    format!("{}", self)
+                ),
+                help: importable_traits.iter().map(|path|
+                    format!("Trait \"{path}\" provides the method \"{method_name}\". Consider importing it: `use {path};`.")
+                ).collect(),
+                code: Some(self.code()),
+                suggestion: self.suggestion(source_engine),
+                ..Default::default()
             },
            _ => Diagnostic {
                     // TODO: Temporary we use self here to achieve backward compatibility.
@@ -950,6 +2256,8 @@ impl ToDiagnostic for CompileError {
                     //       switch to our own #[error] macro. All the values for the formating
                     //       of a diagnostic must come from the enum variant parameters.
                     issue: Issue::error(source_engine, self.span(), format!("{}", self)),
+                    code: Some(self.code()),
+                    suggestion: self.suggestion(source_engine),
                     ..Default::default()
                 }
         }
@@ -0,0 +1,531 @@
+//! Stable codes of the [CompileError]s and their extended explanations.
+//!
+//! Every variant of [CompileError] has a code, like `E4019`, that identifies it independently of
+//! the wording of its message. A new variant must be given the next free code in its area below,
+//! otherwise the crate does not compile. The codes of removed variants are listed in
+//! [RETIRED_CODES] and are never assigned again.
+
+use crate::{diagnostic::Code, error::CompileError};
+
+macro_rules! compile_error_codes {
+    ($($variant:ident => $area:ident($number:literal),)*) => {
+        impl CompileError {
+            /// The stable code of the error, e.g., `E4019`.
+            pub fn code(&self) -> Code {
+                match self {
+                    $(CompileError::$variant { .. } => Code::$area($number),)*
+                }
+            }
+        }
+
+        /// The codes of all the variants of [CompileError], together with the variant names.
+        fn assigned_codes() -> Vec<(&'static str, Code)> {
+            vec![$((stringify!($variant), Code::$area($number)),)*]
+        }
+    };
+}
+
+compile_error_codes! {
+    UnknownVariable => semantic_analysis(30),
+    NotAVariable => semantic_analysis(31),
+    Unimplemented => semantic_analysis(32),
+    UnimplementedWithHelp => semantic_analysis(33),
+    TypeError => type_checking(1),
+    ParseError => semantic_analysis(34),
+    Internal => semantic_analysis(35),
+    InternalOwned => semantic_analysis(36),
+    NoPredicateMainFunction => semantic_analysis(37),
+    PredicateMainDoesNotReturnBool => semantic_analysis(38),
+    NoScriptMainFunction => semantic_analysis(39),
+    MultipleDefinitionsOfFunction => semantic_analysis(18),
+    MultipleDefinitionsOfName => semantic_analysis(19),
+    MultipleDefinitionsOfConstant => semantic_analysis(20),
+    AssignmentToNonMutable => semantic_analysis(22),
+    MethodRequiresMutableSelf => semantic_analysis(23),
+    MutableParameterNotSupported => semantic_analysis(13),
+    DuplicateParameterName => semantic_analysis(14),
+    UnknownParameterType => semantic_analysis(15),
+    PrivateTypeInPublicInterface => semantic_analysis(17),
+    InvalidSelfParameterType => semantic_analysis(40),
+    ImmutableArgumentToMutableParameter => semantic_analysis(5),
+    RefMutableNotAllowedInContractAbi => semantic_analysis(41),
+    AssociatedFunctionCalledAsMethod => semantic_analysis(42),
+    TypeParameterNotInTypeScope => semantic_analysis(43),
+    MismatchedTypeInInterfaceSurface => semantic_analysis(44),
+    UnknownTrait => semantic_analysis(45),
+    FunctionNotAPartOfInterfaceSurface => semantic_analysis(46),
+    ConstantNotAPartOfInterfaceSurface => semantic_analysis(47),
+    MissingInterfaceSurfaceConstants => semantic_analysis(48),
+    MissingInterfaceSurfaceMethods => semantic_analysis(49),
+    IncorrectNumberOfTypeArguments => semantic_analysis(50),
+    DoesNotTakeTypeArguments => semantic_analysis(51),
+    DoesNotTakeTypeArgumentsAsPrefix => semantic_analysis(52),
+    TypeArgumentsNotAllowed => semantic_analysis(53),
+    NeedsTypeArguments => semantic_analysis(54),
+    EnumNotFound => semantic_analysis(55),
+    StructMissingField => semantic_analysis(26),
+    StructDoesNotHaveField => semantic_analysis(27),
+    MethodNotFound => semantic_analysis(56),
+    AbiMethodNotFound => semantic_analysis(57),
+    ModuleNotFound => semantic_analysis(58),
+    FieldAccessOnNonStruct => semantic_analysis(59),
+    NotATuple => semantic_analysis(60),
+    NotIndexable => semantic_analysis(61),
+    NotAnEnum => semantic_analysis(62),
+    NotAStruct => semantic_analysis(63),
+    DeclIsNotAnEnum => semantic_analysis(64),
+    DeclIsNotAStruct => semantic_analysis(65),
+    DeclIsNotAFunction => semantic_analysis(66),
+    DeclIsNotAVariable => semantic_analysis(67),
+    DeclIsNotAnAbi => semantic_analysis(68),
+    DeclIsNotATrait => semantic_analysis(69),
+    DeclIsNotAnImplTrait => semantic_analysis(70),
+    DeclIsNotATraitFn => semantic_analysis(71),
+    DeclIsNotStorage => semantic_analysis(72),
+    DeclIsNotAConstant => semantic_analysis(73),
+    DeclIsNotATypeAlias => semantic_analysis(74),
+    FieldNotFound => semantic_analysis(75),
+    SymbolNotFound => semantic_analysis(76),
+    ImportPrivateSymbol => semantic_analysis(77),
+    ImportPrivateModule => semantic_analysis(78),
+    AmbiguousGlobImport => semantic_analysis(3),
+    ModuleDependencyCycle => semantic_analysis(4),
+    NoElseBranch => semantic_analysis(79),
+    NotAType => semantic_analysis(80),
+    MissingEnumInstantiator => semantic_analysis(81),
+    PathDoesNotReturn => semantic_analysis(82),
+    ExpectedModuleDocComment => semantic_analysis(83),
+    UnknownRegister => semantic_analysis(84),
+    MissingImmediate => semantic_analysis(85),
+    InvalidImmediateValue => semantic_analysis(86),
+    UnknownEnumVariant => semantic_analysis(87),
+    UnrecognizedOp => semantic_analysis(88),
+    UnableToInferGeneric => semantic_analysis(89),
+    UnconstrainedGenericParameter => semantic_analysis(90),
+    TraitConstraintNotSatisfied => semantic_analysis(91),
+    TraitConstraintMissing => semantic_analysis(92),
+    Immediate06TooLarge => semantic_analysis(93),
+    Immediate12TooLarge => semantic_analysis(94),
+    Immediate18TooLarge => semantic_analysis(95),
+    Immediate24TooLarge => semantic_analysis(96),
+    IncorrectNumberOfAsmRegisters => semantic_analysis(97),
+    UnnecessaryImmediate => semantic_analysis(98),
+    AmbiguousPath => semantic_analysis(99),
+    UnknownType => semantic_analysis(100),
+    UnknownTypeName => semantic_analysis(101),
+    FileCouldNotBeRead => semantic_analysis(102),
+    ImportMustBeLibrary => semantic_analysis(103),
+    MoreThanOneEnumInstantiator => semantic_analysis(104),
+    UnnecessaryEnumInstantiator => semantic_analysis(105),
+    UnitVariantWithParenthesesEnumInstantiator => semantic_analysis(106),
+    TraitNotFound => semantic_analysis(107),
+    InvalidExpressionOnLhs => semantic_analysis(108),
+    CannotBeEvaluatedToConst => semantic_analysis(109),
+    TooManyArgumentsForFunction => semantic_analysis(24),
+    TooFewArgumentsForFunction => semantic_analysis(25),
+    MissingParenthesesForFunction => semantic_analysis(110),
+    InvalidAbiType => semantic_analysis(111),
+    NotAnAbi => semantic_analysis(112),
+    ImplAbiForNonContract => semantic_analysis(113),
+    ConflictingImplsForTraitAndType => semantic_analysis(114),
+    DuplicateDeclDefinedForType => semantic_analysis(115),
+    TraitImplSignatureMismatch => semantic_analysis(189),
+    InterfaceParametersReordered => semantic_analysis(11),
+    ArgumentParameterTypeMismatch => semantic_analysis(116),
+    RecursiveCall => semantic_analysis(117),
+    RecursiveCallChain => semantic_analysis(118),
+    RecursiveType => semantic_analysis(119),
+    RecursiveTypeChain => semantic_analysis(120),
+    MonomorphizationRecursionLimit => semantic_analysis(29),
+    ConstantDependencyCycle => semantic_analysis(6),
+    NonConstantArrayLength => semantic_analysis(121),
+    GMFromExternalContext => semantic_analysis(122),
+    MintFromExternalContext => semantic_analysis(123),
+    BurnFromExternalContext => semantic_analysis(124),
+    ContractStorageFromExternalContext => semantic_analysis(125),
+    InvalidOpcodeFromPredicate => semantic_analysis(126),
+    ArrayOutOfBounds => semantic_analysis(127),
+    TupleIndexOutOfBounds => semantic_analysis(128),
+    ConstantsCannotBeShadowed => semantic_analysis(1),
+    ConstantShadowsVariable => semantic_analysis(2),
+    ShadowsOtherSymbol => semantic_analysis(129),
+    GenericShadowsGeneric => semantic_analysis(130),
+    MatchExpressionNonExhaustive => semantic_analysis(131),
+    MatchStructPatternMissingFields => semantic_analysis(132),
+    MatchVariableNotBoundInAllPatterns => semantic_analysis(133),
+    StorageAccessMismatch => semantic_analysis(134),
+    TraitDeclPureImplImpure => semantic_analysis(135),
+    TraitImplPurityMismatch => semantic_analysis(136),
+    ImpureInNonContract => semantic_analysis(137),
+    StateAccessInPredicate => semantic_analysis(12),
+    ImpureInPureContext => semantic_analysis(9),
+    ParameterRefMutabilityMismatch => semantic_analysis(138),
+    ParameterMutabilityMismatch => semantic_analysis(7),
+    RestParameterNotAllowed => semantic_analysis(139),
+    RestParameterNotForwarded => semantic_analysis(140),
+    SpreadNotAllowedHere => semantic_analysis(141),
+    SpreadIntoGenericFunction => semantic_analysis(142),
+    SpreadArgumentMismatch => semantic_analysis(8),
+    OptionalParameterNotAllowed => semantic_analysis(186),
+    OptionalParameterNotLast => semantic_analysis(187),
+    RefMutableOptionalParameter => semantic_analysis(188),
+    ArrayPatternParameterNotAllowed => semantic_analysis(190),
+    ArrayPatternParameterNotArray => semantic_analysis(191),
+    ArrayPatternArityMismatch => semantic_analysis(192),
+    ConflictingInlineAttributes => semantic_analysis(193),
+    ConstantArithmeticOverflow => semantic_analysis(194),
+    ArgumentDoesNotSatisfyParameterBound => semantic_analysis(195),
+    FieldsAttributeOnNonStructParameter => semantic_analysis(196),
+    FieldBindingCollision => semantic_analysis(197),
+    TypeofParameterNotAllowed => semantic_analysis(198),
+    TypeofRefersToUndeclaredParameter => semantic_analysis(199),
+    UnknownLintOnParameter => semantic_analysis(200),
+    UnknownExternAbi => semantic_analysis(201),
+    ExternAbiParameterNotSupported => semantic_analysis(202),
+    ArrayToSliceElementMismatch => semantic_analysis(203),
+    IntegerTooLarge => semantic_analysis(143),
+    IntegerTooSmall => semantic_analysis(144),
+    IntegerContainsInvalidDigit => semantic_analysis(145),
+    AbiAsSupertrait => semantic_analysis(146),
+    SupertraitImplRequired => semantic_analysis(147),
+    ContractCallParamRepeated => semantic_analysis(148),
+    UnrecognizedContractParam => semantic_analysis(149),
+    CallParamForNonContractCallMethod => semantic_analysis(150),
+    StorageFieldDoesNotExist => semantic_analysis(151),
+    NoDeclaredStorage => semantic_analysis(152),
+    MultipleStorageDeclarations => semantic_analysis(21),
+    InvalidStorageOnlyTypeDecl => semantic_analysis(153),
+    UnexpectedDeclaration => semantic_analysis(154),
+    ContractAddressMustBeKnown => semantic_analysis(155),
+    ConvertParseTree => parse_tree_conversion(1),
+    Lex => lexical_analysis(1),
+    Parse => parsing(1),
+    NonConstantDeclValue => semantic_analysis(156),
+    StorageDeclarationInNonContract => semantic_analysis(157),
+    IntrinsicUnsupportedArgType => semantic_analysis(158),
+    IntrinsicArgIsTemporary => semantic_analysis(159),
+    IntrinsicIncorrectNumArgs => semantic_analysis(160),
+    IntrinsicIncorrectNumTArgs => semantic_analysis(161),
+    BreakOutsideLoop => semantic_analysis(162),
+    ContinueOutsideLoop => semantic_analysis(163),
+    ContractIdConstantNotAConstDecl => semantic_analysis(164),
+    ContractIdValueNotALiteral => semantic_analysis(165),
+    TypeNotAllowedInContractStorage => semantic_analysis(166),
+    RefMutableNotAllowedInMain => semantic_analysis(167),
+    MainParameterTypeNotEncodable => semantic_analysis(168),
+    NestedSliceReturnNotAllowedInMain => semantic_analysis(169),
+    MainReturnTypeNotEncodable => semantic_analysis(204),
+    TestFunctionWithParameters => semantic_analysis(170),
+    InvalidTestFunctionReturnType => semantic_analysis(171),
+    DeniedLint => semantic_analysis(172),
+    InitializedRegisterReassignment => semantic_analysis(173),
+    DuplicateAsmRegister => semantic_analysis(16),
+    ReservedAsmRegister => semantic_analysis(174),
+    DisallowedControlFlowInstruction => semantic_analysis(175),
+    CallingPrivateLibraryMethod => semantic_analysis(176),
+    DisallowedIntrinsicInPredicate => semantic_analysis(177),
+    CoinsPassedToNonPayableMethod => semantic_analysis(178),
+    TraitImplPayabilityMismatch => semantic_analysis(10),
+    ConfigurableInLibrary => semantic_analysis(179),
+    ConfigurableInConstantContext => semantic_analysis(180),
+    MultipleApplicableItemsInScope => semantic_analysis(181),
+    ContractCallsItsOwnMethod => semantic_analysis(182),
+    AbiShadowsSuperAbiMethod => semantic_analysis(183),
+    ConflictingSuperAbiMethods => semantic_analysis(184),
+    AbiSupertraitMethodCallAsContractCall => semantic_analysis(185),
+}
+
+/// The codes of the variants that have been removed from [CompileError]. They must not be
+/// assigned to new variants, so that a code never changes its meaning.
+pub const RETIRED_CODES: &[&str] = &["E4028"];
+
+/// The codes of the errors that have no extended explanation, because their message is all there
+/// is to say about them: the errors for unimplemented features and unparsable inputs, which only
+/// carry the message of the underlying problem, and the errors that report a bug in the compiler.
+pub const UNEXPLAINED_CODES: &[&str] = &[
+    "E4032", "E4033", "E4034", "E4035", "E4036", "E4070", "E4071", "E4072", "E4074", "E4093",
+    "E4154", "E4164",
+];
+
+/// The extended explanations of the errors, written in Markdown.
+const EXPLANATIONS: &[(&str, &str)] = &[
+    ("E0001", include_str!("error_codes/E0001.md")),
+    ("E1001", include_str!("error_codes/E1001.md")),
+    ("E2001", include_str!("error_codes/E2001.md")),
+    ("E3001", include_str!("error_codes/E3001.md")),
+    ("E4001", include_str!("error_codes/E4001.md")),
+    ("E4002", include_str!("error_codes/E4002.md")),
+    ("E4003", include_str!("error_codes/E4003.md")),
+    ("E4004", include_str!("error_codes/E4004.md")),
+    ("E4005", include_str!("error_codes/E4005.md")),
+    ("E4006", include_str!("error_codes/E4006.md")),
+    ("E4007", include_str!("error_codes/E4007.md")),
+    ("E4008", include_str!("error_codes/E4008.md")),
+    ("E4009", include_str!("error_codes/E4009.md")),
+    ("E4010", include_str!("error_codes/E4010.md")),
+    ("E4011", include_str!("error_codes/E4011.md")),
+    ("E4012", include_str!("error_codes/E4012.md")),
+    ("E4013", include_str!("error_codes/E4013.md")),
+    ("E4014", include_str!("error_codes/E4014.md")),
+    ("E4015", include_str!("error_codes/E4015.md")),
+    ("E4016", include_str!("error_codes/E4016.md")),
+    ("E4017", include_str!("error_codes/E4017.md")),
+    ("E4018", include_str!("error_codes/E4018.md")),
+    ("E4019", include_str!("error_codes/E4019.md")),
+    ("E4020", include_str!("error_codes/E4020.md")),
+    ("E4021", include_str!("error_codes/E4021.md")),
+    ("E4022", include_str!("error_codes/E4022.md")),
+    ("E4023", include_str!("error_codes/E4023.md")),
+    ("E4024", include_str!("error_codes/E4024.md")),
+    ("E4025", include_str!("error_codes/E4025.md")),
+    ("E4026", include_str!("error_codes/E4026.md")),
+    ("E4027", include_str!("error_codes/E4027.md")),
+    ("E4029", include_str!("error_codes/E4029.md")),
+    ("E4030", include_str!("error_codes/E4030.md")),
+    ("E4031", include_str!("error_codes/E4031.md")),
+    ("E4037", include_str!("error_codes/E4037.md")),
+    ("E4038", include_str!("error_codes/E4038.md")),
+    ("E4039", include_str!("error_codes/E4039.md")),
+    ("E4040", include_str!("error_codes/E4040.md")),
+    ("E4041", include_str!("error_codes/E4041.md")),
+    ("E4042", include_str!("error_codes/E4042.md")),
+    ("E4043", include_str!("error_codes/E4043.md")),
+    ("E4044", include_str!("error_codes/E4044.md")),
+    ("E4045", include_str!("error_codes/E4045.md")),
+    ("E4046", include_str!("error_codes/E4046.md")),
+    ("E4047", include_str!("error_codes/E4047.md")),
+    ("E4048", include_str!("error_codes/E4048.md")),
+    ("E4049", include_str!("error_codes/E4049.md")),
+    ("E4050", include_str!("error_codes/E4050.md")),
+    ("E4051", include_str!("error_codes/E4051.md")),
+    ("E4052", include_str!("error_codes/E4052.md")),
+    ("E4053", include_str!("error_codes/E4053.md")),
+    ("E4054", include_str!("error_codes/E4054.md")),
+    ("E4055", include_str!("error_codes/E4055.md")),
+    ("E4056", include_str!("error_codes/E4056.md")),
+    ("E4057", include_str!("error_codes/E4057.md")),
+    ("E4058", include_str!("error_codes/E4058.md")),
+    ("E4059", include_str!("error_codes/E4059.md")),
+    ("E4060", include_str!("error_codes/E4060.md")),
+    ("E4061", include_str!("error_codes/E4061.md")),
+    ("E4062", include_str!("error_codes/E4062.md")),
+    ("E4063", include_str!("error_codes/E4063.md")),
+    ("E4064", include_str!("error_codes/E4064.md")),
+    ("E4065", include_str!("error_codes/E4065.md")),
+    ("E4066", include_str!("error_codes/E4066.md")),
+    ("E4067", include_str!("error_codes/E4067.md")),
+    ("E4068", include_str!("error_codes/E4068.md")),
+    ("E4069", include_str!("error_codes/E4069.md")),
+    ("E4073", include_str!("error_codes/E4073.md")),
+    ("E4075", include_str!("error_codes/E4075.md")),
+    ("E4076", include_str!("error_codes/E4076.md")),
+    ("E4077", include_str!("error_codes/E4077.md")),
+    ("E4078", include_str!("error_codes/E4078.md")),
+    ("E4079", include_str!("error_codes/E4079.md")),
+    ("E4080", include_str!("error_codes/E4080.md")),
+    ("E4081", include_str!("error_codes/E4081.md")),
+    ("E4082", include_str!("error_codes/E4082.md")),
+    ("E4083", include_str!("error_codes/E4083.md")),
+    ("E4084", include_str!("error_codes/E4084.md")),
+    ("E4085", include_str!("error_codes/E4085.md")),
+    ("E4086", include_str!("error_codes/E4086.md")),
+    ("E4087", include_str!("error_codes/E4087.md")),
+    ("E4088", include_str!("error_codes/E4088.md")),
+    ("E4089", include_str!("error_codes/E4089.md")),
+    ("E4090", include_str!("error_codes/E4090.md")),
+    ("E4091", include_str!("error_codes/E4091.md")),
+    ("E4092", include_str!("error_codes/E4092.md")),
+    ("E4094", include_str!("error_codes/E4094.md")),
+    ("E4095", include_str!("error_codes/E4095.md")),
+    ("E4096", include_str!("error_codes/E4096.md")),
+    ("E4097", include_str!("error_codes/E4097.md")),
+    ("E4098", include_str!("error_codes/E4098.md")),
+    ("E4099", include_str!("error_codes/E4099.md")),
+    ("E4100", include_str!("error_codes/E4100.md")),
+    ("E4101", include_str!("error_codes/E4101.md")),
+    ("E4102", include_str!("error_codes/E4102.md")),
+    ("E4103", include_str!("error_codes/E4103.md")),
+    ("E4104", include_str!("error_codes/E4104.md")),
+    ("E4105", include_str!("error_codes/E4105.md")),
+    ("E4106", include_str!("error_codes/E4106.md")),
+    ("E4107", include_str!("error_codes/E4107.md")),
+    ("E4108", include_str!("error_codes/E4108.md")),
+    ("E4109", include_str!("error_codes/E4109.md")),
+    ("E4110", include_str!("error_codes/E4110.md")),
+    ("E4111", include_str!("error_codes/E4111.md")),
+    ("E4112", include_str!("error_codes/E4112.md")),
+    ("E4113", include_str!("error_codes/E4113.md")),
+    ("E4114", include_str!("error_codes/E4114.md")),
+    ("E4115", include_str!("error_codes/E4115.md")),
+    ("E4116", include_str!("error_codes/E4116.md")),
+    ("E4117", include_str!("error_codes/E4117.md")),
+    ("E4118", include_str!("error_codes/E4118.md")),
+    ("E4119", include_str!("error_codes/E4119.md")),
+    ("E4120", include_str!("error_codes/E4120.md")),
+    ("E4121", include_str!("error_codes/E4121.md")),
+    ("E4122", include_str!("error_codes/E4122.md")),
+    ("E4123", include_str!("error_codes/E4123.md")),
+    ("E4124", include_str!("error_codes/E4124.md")),
+    ("E4125", include_str!("error_codes/E4125.md")),
+    ("E4126", include_str!("error_codes/E4126.md")),
+    ("E4127", include_str!("error_codes/E4127.md")),
+    ("E4128", include_str!("error_codes/E4128.md")),
+    ("E4129", include_str!("error_codes/E4129.md")),
+    ("E4130", include_str!("error_codes/E4130.md")),
+    ("E4131", include_str!("error_codes/E4131.md")),
+    ("E4132", include_str!("error_codes/E4132.md")),
+    ("E4133", include_str!("error_codes/E4133.md")),
+    ("E4134", include_str!("error_codes/E4134.md")),
+    ("E4135", include_str!("error_codes/E4135.md")),
+    ("E4136", include_str!("error_codes/E4136.md")),
+    ("E4137", include_str!("error_codes/E4137.md")),
+    ("E4138", include_str!("error_codes/E4138.md")),
+    ("E4139", include_str!("error_codes/E4139.md")),
+    ("E4140", include_str!("error_codes/E4140.md")),
+    ("E4141", include_str!("error_codes/E4141.md")),
+    ("E4142", include_str!("error_codes/E4142.md")),
+    ("E4143", include_str!("error_codes/E4143.md")),
+    ("E4144", include_str!("error_codes/E4144.md")),
+    ("E4145", include_str!("error_codes/E4145.md")),
+    ("E4146", include_str!("error_codes/E4146.md")),
+    ("E4147", include_str!("error_codes/E4147.md")),
+    ("E4148", include_str!("error_codes/E4148.md")),
+    ("E4149", include_str!("error_codes/E4149.md")),
+    ("E4150", include_str!("error_codes/E4150.md")),
+    ("E4151", include_str!("error_codes/E4151.md")),
+    ("E4152", include_str!("error_codes/E4152.md")),
+    ("E4153", include_str!("error_codes/E4153.md")),
+    ("E4155", include_str!("error_codes/E4155.md")),
+    ("E4156", include_str!("error_codes/E4156.md")),
+    ("E4157", include_str!("error_codes/E4157.md")),
+    ("E4158", include_str!("error_codes/E4158.md")),
+    ("E4159", include_str!("error_codes/E4159.md")),
+    ("E4160", include_str!("error_codes/E4160.md")),
+    ("E4161", include_str!("error_codes/E4161.md")),
+    ("E4162", include_str!("error_codes/E4162.md")),
+    ("E4163", include_str!("error_codes/E4163.md")),
+    ("E4165", include_str!("error_codes/E4165.md")),
+    ("E4166", include_str!("error_codes/E4166.md")),
+    ("E4167", include_str!("error_codes/E4167.md")),
+    ("E4168", include_str!("error_codes/E4168.md")),
+    ("E4169", include_str!("error_codes/E4169.md")),
+    ("E4170", include_str!("error_codes/E4170.md")),
+    ("E4171", include_str!("error_codes/E4171.md")),
+    ("E4172", include_str!("error_codes/E4172.md")),
+    ("E4173", include_str!("error_codes/E4173.md")),
+    ("E4174", include_str!("error_codes/E4174.md")),
+    ("E4175", include_str!("error_codes/E4175.md")),
+    ("E4176", include_str!("error_codes/E4176.md")),
+    ("E4177", include_str!("error_codes/E4177.md")),
+    ("E4178", include_str!("error_codes/E4178.md")),
+    ("E4179", include_str!("error_codes/E4179.md")),
+    ("E4180", include_str!("error_codes/E4180.md")),
+    ("E4181", include_str!("error_codes/E4181.md")),
+    ("E4182", include_str!("error_codes/E4182.md")),
+    ("E4183", include_str!("error_codes/E4183.md")),
+    ("E4184", include_str!("error_codes/E4184.md")),
+    ("E4185", include_str!("error_codes/E4185.md")),
+    ("E4186", include_str!("error_codes/E4186.md")),
+    ("E4187", include_str!("error_codes/E4187.md")),
+    ("E4188", include_str!("error_codes/E4188.md")),
+    ("E4189", include_str!("error_codes/E4189.md")),
+    ("E4190", include_str!("error_codes/E4190.md")),
+    ("E4191", include_str!("error_codes/E4191.md")),
+    ("E4192", include_str!("error_codes/E4192.md")),
+    ("E4193", include_str!("error_codes/E4193.md")),
+    ("E4194", include_str!("error_codes/E4194.md")),
+    ("E4195", include_str!("error_codes/E4195.md")),
+    ("E4196", include_str!("error_codes/E4196.md")),
+    ("E4197", include_str!("error_codes/E4197.md")),
+    ("E4198", include_str!("error_codes/E4198.md")),
+    ("E4199", include_str!("error_codes/E4199.md")),
+    ("E4200", include_str!("error_codes/E4200.md")),
+    ("E4201", include_str!("error_codes/E4201.md")),
+    ("E4202", include_str!("error_codes/E4202.md")),
+    ("E4203", include_str!("error_codes/E4203.md")),
+    ("E4204", include_str!("error_codes/E4204.md")),
+];
+
+/// True if `code`, like `E4019`, is the code of a [CompileError].
+pub fn is_error_code(code: &str) -> bool {
+    assigned_codes()
+        .iter()
+        .any(|(_, assigned)| assigned.as_str() == code)
+}
+
+/// Returns the extended explanation of the error with the given `code`, like `E4019`, if it has
+/// one. The explanation describes the error in more detail and shows a minimal example that
+/// causes it.
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(explained, _)| *explained == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut variants_by_code = HashMap::new();
+        for (variant, code) in assigned_codes() {
+            if let Some(other) = variants_by_code.insert(code.as_str().to_string(), variant) {
+                panic!(
+                    "{variant} and {other} have the same code {}.",
+                    code.as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn retired_codes_are_not_reused() {
+        for (variant, code) in assigned_codes() {
+            assert!(
+                !RETIRED_CODES.contains(&code.as_str()),
+                "{variant} reuses the retired code {}.",
+                code.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn explanations_are_for_assigned_codes() {
+        for (code, explanation) in EXPLANATIONS {
+            assert!(is_error_code(code), "{code} is not assigned to any error.");
+            assert!(
+                explanation.starts_with(&format!("{code}:")),
+                "The explanation of {code} does not start with its code."
+            );
+        }
+    }
+
+    #[test]
+    fn every_error_has_an_explanation() {
+        for (variant, code) in assigned_codes() {
+            if UNEXPLAINED_CODES.contains(&code.as_str()) {
+                assert!(
+                    explain(code.as_str()).is_none(),
+                    "{variant} has the code {}, which is listed as unexplained, but it has an explanation.",
+                    code.as_str()
+                );
+            } else {
+                assert!(
+                    explain(code.as_str()).is_some(),
+                    "{variant} has the code {}, but it has no explanation.",
+                    code.as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unexplained_codes_are_assigned() {
+        for code in UNEXPLAINED_CODES {
+            assert!(is_error_code(code), "{code} is not assigned to any error.");
+        }
+    }
+}
@@ -1,5 +1,13 @@
-use crate::{error::CompileError, warning::CompileWarning};
-use std::collections::HashMap;
+use crate::{
+    error::CompileError,
+    warning::{CompileWarning, Warning},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    mem::Discriminant,
+};
+use sway_types::{Span, Spanned};
 
 use core::cell::RefCell;
 
@@ -19,12 +27,18 @@ struct HandlerInner {
     errors: Vec<CompileError>,
     /// The sink through which warnings will be emitted.
     warnings: Vec<CompileWarning>,
+    /// The number of duplicates removed by [Handler::dedup].
+    duplicates: Duplicates,
 }
 
 impl Handler {
     pub fn from_parts(errors: Vec<CompileError>, warnings: Vec<CompileWarning>) -> Self {
         Self {
-            inner: RefCell::new(HandlerInner { errors, warnings }),
+            inner: RefCell::new(HandlerInner {
+                errors,
+                warnings,
+                duplicates: Duplicates::default(),
+            }),
         }
     }
 
@@ -39,6 +53,11 @@ impl Handler {
         self.inner.borrow_mut().warnings.push(warn);
     }
 
+    /// Retain only the warnings for which `f` returns `true`.
+    pub fn retain_warnings(&self, f: impl FnMut(&CompileWarning) -> bool) {
+        self.inner.borrow_mut().warnings.retain(f);
+    }
+
     pub fn has_errors(&self) -> bool {
         !self.inner.borrow().errors.is_empty()
     }
@@ -70,20 +89,103 @@ impl Handler {
         (inner.errors, inner.warnings)
     }
 
+    /// The number of duplicates that [Handler::dedup] removed of each remaining error and warning.
+    pub fn duplicates(&self) -> Duplicates {
+        self.inner.borrow().duplicates.clone()
+    }
+
     pub fn append(&self, other: Handler) {
-        let (errors, warnings) = other.consume();
+        let HandlerInner {
+            errors,
+            warnings,
+            duplicates,
+        } = other.inner.into_inner();
         for warn in warnings {
             self.emit_warn(warn);
         }
         for err in errors {
             self.emit_err(err);
         }
+        self.inner.borrow_mut().duplicates.merge(duplicates);
     }
 
+    /// Removes the duplicated errors and warnings, e.g., the ones emitted once for every
+    /// instantiation of a generic function. Two diagnostics are duplicates if they are of the
+    /// same kind, point to the same span, and have the same message, so the diagnostics that
+    /// genuinely differ per instantiation, like the ones naming the concrete type, are kept.
+    /// The first instance of the diagnostics is kept and the number of the removed ones is
+    /// available in [Handler::duplicates].
     pub fn dedup(&self) {
         let mut inner = self.inner.borrow_mut();
-        inner.errors = dedup_unsorted(inner.errors.clone());
-        inner.warnings = dedup_unsorted(inner.warnings.clone());
+        let HandlerInner {
+            errors,
+            warnings,
+            duplicates,
+        } = &mut *inner;
+        dedup_counting(errors, DuplicateKey::of_error, &mut duplicates.errors);
+        dedup_counting(warnings, DuplicateKey::of_warning, &mut duplicates.warnings);
+    }
+}
+
+/// The number of duplicates that [Handler::dedup] removed of errors and warnings.
+#[derive(Default, Debug, Clone)]
+pub struct Duplicates {
+    errors: HashMap<DuplicateKey<CompileError>, usize>,
+    warnings: HashMap<DuplicateKey<Warning>, usize>,
+}
+
+impl Duplicates {
+    /// The number of the removed duplicates of `err`.
+    pub fn of_error(&self, err: &CompileError) -> usize {
+        self.errors
+            .get(&DuplicateKey::of_error(err))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The number of the removed duplicates of `warn`.
+    pub fn of_warning(&self, warn: &CompileWarning) -> usize {
+        self.warnings
+            .get(&DuplicateKey::of_warning(warn))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn merge(&mut self, other: Duplicates) {
+        for (key, count) in other.errors {
+            *self.errors.entry(key).or_default() += count;
+        }
+        for (key, count) in other.warnings {
+            *self.warnings.entry(key).or_default() += count;
+        }
+    }
+}
+
+/// Identifies the duplicates of a diagnostic: its kind, its primary span and its message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DuplicateKey<T> {
+    kind: Discriminant<T>,
+    span: Span,
+    message: String,
+}
+
+impl DuplicateKey<CompileError> {
+    fn of_error(err: &CompileError) -> Self {
+        Self {
+            kind: std::mem::discriminant(err),
+            span: err.span(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl DuplicateKey<Warning> {
+    fn of_warning(warn: &CompileWarning) -> Self {
+        Self {
+            kind: std::mem::discriminant(&warn.warning_content),
+            span: warn.span.clone(),
+            message: warn.to_friendly_warning_string(),
+        }
     }
 }
 
@@ -95,40 +197,104 @@ pub struct ErrorEmitted {
 
 /// We want compile errors and warnings to retain their ordering, since typically
 /// they are grouped by relevance. However, we want to deduplicate them.
-/// Stdlib dedup in Rust assumes sorted data for efficiency, but we don't want that.
-/// A hash set would also mess up the order, so this is just a brute force way of doing it
-/// with a vector.
-fn dedup_unsorted<T: PartialEq + std::hash::Hash>(mut data: Vec<T>) -> Vec<T> {
-    // TODO(Centril): Consider using `IndexSet` instead for readability.
-    use smallvec::SmallVec;
-    use std::collections::hash_map::{DefaultHasher, Entry};
-    use std::hash::Hasher;
-
-    let mut write_index = 0;
-    let mut indexes: HashMap<u64, SmallVec<[usize; 1]>> = HashMap::with_capacity(data.len());
-    for read_index in 0..data.len() {
-        let hash = {
-            let mut hasher = DefaultHasher::new();
-            data[read_index].hash(&mut hasher);
-            hasher.finish()
-        };
-        let index_vec = match indexes.entry(hash) {
-            Entry::Occupied(oe) => {
-                if oe
-                    .get()
-                    .iter()
-                    .any(|index| data[*index] == data[read_index])
-                {
-                    continue;
-                }
-                oe.into_mut()
-            }
-            Entry::Vacant(ve) => ve.insert(SmallVec::new()),
-        };
-        data.swap(write_index, read_index);
-        index_vec.push(write_index);
-        write_index += 1;
-    }
-    data.truncate(write_index);
-    data
+/// Stdlib dedup in Rust assumes sorted data for efficiency, but we don't want that,
+/// so this keeps the first item with a particular `key` and counts the removed ones
+/// in `counts`.
+fn dedup_counting<T, K: Eq + Hash>(
+    data: &mut Vec<T>,
+    key: impl Fn(&T) -> K,
+    counts: &mut HashMap<K, usize>,
+) {
+    let mut seen = HashSet::with_capacity(data.len());
+    data.retain(|item| {
+        let key = key(item);
+        if seen.contains(&key) {
+            *counts.entry(key).or_default() += 1;
+            false
+        } else {
+            seen.insert(key);
+            true
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    fn span(src: &Arc<str>, start: usize, end: usize) -> Span {
+        Span::new(src.clone(), start, end, None).unwrap()
+    }
+
+    fn unused_return_value(span: Span, ty: &str) -> CompileWarning {
+        CompileWarning {
+            span,
+            warning_content: Warning::UnusedReturnValue {
+                r#type: ty.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn dedup_counts_removed_duplicates() {
+        let src: Arc<str> = "push(x); push(y);".into();
+        let handler = Handler::default();
+        for _ in 0..3 {
+            handler.emit_warn(unused_return_value(span(&src, 0, 7), "u64"));
+        }
+        handler.emit_warn(unused_return_value(span(&src, 9, 16), "u64"));
+        for _ in 0..2 {
+            handler.emit_err(CompileError::InternalOwned(
+                "error".to_string(),
+                span(&src, 0, 7),
+            ));
+        }
+
+        handler.dedup();
+
+        let duplicates = handler.duplicates();
+        let (errors, warnings) = handler.consume();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(duplicates.of_error(&errors[0]), 1);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(duplicates.of_warning(&warnings[0]), 2);
+        assert_eq!(duplicates.of_warning(&warnings[1]), 0);
+    }
+
+    #[test]
+    fn dedup_keeps_diagnostics_with_different_messages() {
+        let src: Arc<str> = "push(x);".into();
+        let handler = Handler::default();
+        handler.emit_warn(unused_return_value(span(&src, 0, 7), "u64"));
+        handler.emit_warn(unused_return_value(span(&src, 0, 7), "bool"));
+
+        handler.dedup();
+
+        let duplicates = handler.duplicates();
+        let (_, warnings) = handler.consume();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .all(|warning| duplicates.of_warning(warning) == 0));
+    }
+
+    #[test]
+    fn append_keeps_duplicate_counts() {
+        let src: Arc<str> = "push(x);".into();
+        let scoped = Handler::default();
+        scoped.emit_warn(unused_return_value(span(&src, 0, 7), "u64"));
+        scoped.emit_warn(unused_return_value(span(&src, 0, 7), "u64"));
+        scoped.dedup();
+
+        let handler = Handler::default();
+        handler.emit_warn(unused_return_value(span(&src, 0, 7), "u64"));
+        handler.append(scoped);
+        handler.dedup();
+
+        let duplicates = handler.duplicates();
+        let (_, warnings) = handler.consume();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(duplicates.of_warning(&warnings[0]), 2);
+    }
 }
@@ -1,6 +1,7 @@
 pub mod convert_parse_tree_error;
 pub mod diagnostic;
 pub mod error;
+pub mod error_codes;
 pub mod handler;
 pub mod lex_error;
 pub mod parser_error;
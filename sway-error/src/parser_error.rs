@@ -10,6 +10,10 @@ pub enum ParseErrorKind {
     ExpectedAnItem,
     #[error("Expected a comma or closing parenthesis in function arguments.")]
     ExpectedCommaOrCloseParenInFnArgs,
+    #[error("The rest parameter must be the last parameter.")]
+    RestParameterMustBeLast,
+    #[error("Spread arguments are only allowed as the last argument of a function call.")]
+    UnexpectedSpread,
     #[error("Unrecognized op code.")]
     UnrecognizedOpCode,
     #[error("Unexpected token in statement.")]
@@ -84,6 +88,8 @@ pub enum ParseErrorKind {
     UnexpectedTokenAfterPtrType,
     #[error("Unexpected token after __slice type.")]
     UnexpectedTokenAfterSliceType,
+    #[error("Unexpected token after the expression of a typeof type.")]
+    UnexpectedTokenAfterTypeofExpression,
     #[error("Expected a path type.")]
     ExpectedPathType,
 }
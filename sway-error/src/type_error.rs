@@ -1,4 +1,5 @@
-use sway_types::{Span, Spanned};
+use core::fmt;
+use sway_types::{Ident, Span, Spanned};
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
@@ -15,9 +16,19 @@ pub enum TypeError {
         received: String,
         help_text: String,
         span: Span,
+        /// The place in source code that requires the `expected` type, if known.
+        expected_origin: Option<ExpectedTypeOrigin>,
     },
     #[error("This type is not known. Try annotating it with a type annotation.")]
     UnknownType { span: Span },
+    /// Unifying the type being inferred, `variable`, with `ty` would require `variable` to
+    /// contain itself, because it occurs in `ty`.
+    #[error("Cannot construct infinite type \"{variable} = {ty}\".")]
+    InfiniteType {
+        variable: String,
+        ty: String,
+        span: Span,
+    },
     #[error(
         "The pattern for this match expression arm has a mismatched type.\n\
          expected: {expected}\n\
@@ -37,7 +48,68 @@ impl Spanned for TypeError {
         match self {
             MismatchedType { span, .. } => span.clone(),
             UnknownType { span } => span.clone(),
+            InfiniteType { span, .. } => span.clone(),
             MatchArmScrutineeWrongType { span, .. } => span.clone(),
         }
     }
 }
+
+/// The place in source code that requires the expected type of a [TypeError::MismatchedType].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExpectedTypeOrigin {
+    /// The declared return type of a function.
+    ReturnType { span: Span },
+    /// The type ascription of a variable, constant or storage field.
+    TypeAscription { span: Span },
+    /// The declared type of a function parameter.
+    Parameter { name: Ident, span: Span },
+    /// An explicit type argument, like `u64` in `f::<u64>(..)`, given for the type parameter
+    /// `name` that the declared type of a function parameter refers to.
+    TypeArgument { name: Ident, span: Span },
+    /// An earlier branch of an `if` expression.
+    IfBranch { span: Span },
+    /// An earlier arm of a `match` expression.
+    MatchArm { span: Span },
+}
+
+impl fmt::Display for ExpectedTypeOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ExpectedTypeOrigin::*;
+        match self {
+            ReturnType { .. } => write!(f, "Expected because of the return type declared here."),
+            TypeAscription { .. } => {
+                write!(f, "Expected because of the type annotation here.")
+            }
+            Parameter { name, .. } => write!(
+                f,
+                "Expected because of the type of the parameter \"{name}\" declared here."
+            ),
+            TypeArgument { name, .. } => write!(
+                f,
+                "Expected because the type parameter \"{name}\" is given explicitly here."
+            ),
+            IfBranch { .. } => write!(
+                f,
+                "Expected because the earlier branch of the \"if\" expression has this type."
+            ),
+            MatchArm { .. } => write!(
+                f,
+                "Expected because the earlier arm of the \"match\" expression has this type."
+            ),
+        }
+    }
+}
+
+impl Spanned for ExpectedTypeOrigin {
+    fn span(&self) -> Span {
+        use ExpectedTypeOrigin::*;
+        match self {
+            ReturnType { span }
+            | TypeAscription { span }
+            | Parameter { span, .. }
+            | TypeArgument { span, .. }
+            | IfBranch { span }
+            | MatchArm { span } => span.clone(),
+        }
+    }
+}
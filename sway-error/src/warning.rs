@@ -2,7 +2,14 @@ use crate::diagnostic::{Code, Diagnostic, Hint, Issue, Reason, ToDiagnostic};
 
 use core::fmt;
 
-use sway_types::{Ident, SourceId, Span, Spanned};
+use sway_types::{
+    constants::{
+        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, ALLOW_NON_CAMEL_CASE_TYPES_NAME,
+        ALLOW_NON_SNAKE_CASE_NAME, ALLOW_NON_UPPER_CASE_GLOBALS_NAME, ALLOW_UNREACHABLE_CODE_NAME,
+        ALLOW_UNUSED_RESULTS_NAME, ALLOW_UNUSED_VARIABLES_NAME,
+    },
+    Ident, SourceId, Span, Spanned,
+};
 
 // TODO: since moving to using Idents instead of strings,
 // the warning_content will usually contain a duplicate of the span.
@@ -23,6 +30,37 @@ impl CompileWarning {
         self.warning_content.to_string()
     }
 
+    /// Returns the name of the lint that this warning belongs to, if the warning can be allowed
+    /// or denied with the `allow` and `deny` attributes.
+    pub fn lint_name(&self) -> Option<&'static str> {
+        use Warning::*;
+        Some(match &self.warning_content {
+            DeadDeclaration
+            | DeadEnumDeclaration
+            | DeadFunctionDeclaration
+            | DeadStructDeclaration
+            | DeadTrait
+            | DeadEnumVariant { .. }
+            | DeadMethod
+            | StructFieldNeverRead
+            | DeadStorageDeclaration => ALLOW_DEAD_CODE_NAME,
+            UnusedVariable => ALLOW_UNUSED_VARIABLES_NAME,
+            UnreachableCode | MatchExpressionUnreachableArm => ALLOW_UNREACHABLE_CODE_NAME,
+            UnusedReturnValue { .. } => ALLOW_UNUSED_RESULTS_NAME,
+            UsingDeprecated { .. } => ALLOW_DEPRECATED_NAME,
+            NonSnakeCaseStructFieldName { .. } | NonSnakeCaseFunctionName { .. } => {
+                ALLOW_NON_SNAKE_CASE_NAME
+            }
+            NonClassCaseStructName { .. }
+            | NonClassCaseTypeParameter { .. }
+            | NonClassCaseTraitName { .. }
+            | NonClassCaseEnumName { .. }
+            | NonClassCaseEnumVariantName { .. } => ALLOW_NON_CAMEL_CASE_TYPES_NAME,
+            NonScreamingSnakeCaseConstName { .. } => ALLOW_NON_UPPER_CASE_GLOBALS_NAME,
+            _ => return None,
+        })
+    }
+
     pub fn source_id(&self) -> Option<SourceId> {
         self.span.source_id().cloned()
     }
@@ -57,6 +95,33 @@ pub enum Warning {
     UnusedReturnValue {
         r#type: String,
     },
+    UnitParameter {
+        name: Ident,
+    },
+    ParameterShadowsPreludeItem {
+        name: Ident,
+    },
+    MutRefKeywordOrder,
+    UnusedAsmRegister {
+        name: Ident,
+    },
+    AsmBlockMissingReturn {
+        register: String,
+    },
+    InlineAlwaysWithAsmBlock,
+    StackFrameExceedsBudget {
+        frame_size: u64,
+        budget: u64,
+        /// The names and sizes in bytes of the largest parameters passed by value.
+        largest_parameters: Vec<(String, u64)>,
+    },
+    UsingDeprecated {
+        /// The kind of the deprecated item, e.g., "function" or "struct".
+        kind: &'static str,
+        name: String,
+        note: Option<String>,
+        decl_span: Span,
+    },
     SimilarMethodFound {
         lib: Ident,
         module: Ident,
@@ -67,6 +132,7 @@ pub enum Warning {
     },
     OverridingTraitImplementation,
     DeadDeclaration,
+    UnusedVariable,
     DeadEnumDeclaration,
     DeadFunctionDeclaration,
     DeadStructDeclaration,
@@ -180,6 +246,69 @@ impl fmt::Display for Warning {
                 "This returns a value of type {type}, which is not assigned to anything and is \
                  ignored."
             ),
+            UnitParameter { name } => write!(
+                f,
+                "Parameter \"{name}\" is of the unit type \"()\", which carries no information. \
+                 Consider removing the parameter."
+            ),
+            ParameterShadowsPreludeItem { name } => write!(
+                f,
+                "Parameter \"{name}\" shadows the prelude item \"{name}\", which cannot be used \
+                 in the function body. Consider renaming the parameter."
+            ),
+            InlineAlwaysWithAsmBlock => write!(
+                f,
+                "This asm block prevents its function from always being inlined, although the \
+                 function is attributed with #[inline(always)]."
+            ),
+            StackFrameExceedsBudget {
+                frame_size,
+                budget,
+                largest_parameters,
+            } => {
+                write!(
+                    f,
+                    "The parameters and locals of this function take {frame_size} bytes of stack, \
+                     which exceeds the budget of {budget} bytes."
+                )?;
+                if !largest_parameters.is_empty() {
+                    write!(
+                        f,
+                        " The largest parameters are {}. Consider passing them as \"ref mut\" \
+                         parameters.",
+                        largest_parameters
+                            .iter()
+                            .map(|(name, size)| format!("\"{name}\" ({size} bytes)"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            UsingDeprecated {
+                kind, name, note, ..
+            } => {
+                write!(f, "The {kind} \"{name}\" is deprecated.")?;
+                if let Some(note) = note {
+                    write!(f, " {note}")?;
+                }
+                Ok(())
+            }
+            UnusedAsmRegister { name } => write!(
+                f,
+                "Register \"{name}\" is initialized but never used in this asm block."
+            ),
+            AsmBlockMissingReturn { register } => write!(
+                f,
+                "The last instruction of this asm block writes to register \"{register}\", but \
+                 the block does not return a register and evaluates to \"()\". Consider ending \
+                 the block with \"{register}: <type>\"."
+            ),
+            MutRefKeywordOrder => write!(
+                f,
+                "\"mut ref\" is conventionally written as \"ref mut\". Consider swapping the \
+                 keywords."
+            ),
             SimilarMethodFound { lib, module, name } => write!(
                 f,
                 "A method with the same name was found for type {name} in dependency \"{lib}::{module}\". \
@@ -194,6 +323,7 @@ impl fmt::Display for Warning {
                 "This trait implementation overrides another one that was previously defined."
             ),
             DeadDeclaration => write!(f, "This declaration is never used."),
+            UnusedVariable => write!(f, "This declaration is never used."),
             DeadEnumDeclaration => write!(f, "This enum is never used."),
             DeadStructDeclaration => write!(f, "This struct is never used."),
             DeadFunctionDeclaration => write!(f, "This function is never called."),
@@ -272,6 +402,29 @@ impl ToDiagnostic for CompileWarning {
                     "Modules, variables, and functions are snake_case, while constants are SCREAMING_SNAKE_CASE.".to_string(),
                     format!("Consider renaming the constant to, e.g., \"{}\".", to_screaming_snake_case(name.as_str())),
                 ],
+                ..Default::default()
+            },
+            UsingDeprecated {
+                kind,
+                name,
+                note,
+                decl_span,
+            } => Diagnostic {
+                reason: Some(Reason::new(code(2), format!("Use of deprecated {kind}"))),
+                issue: Issue::warning(
+                    source_engine,
+                    self.span(),
+                    format!("The {kind} \"{name}\" is deprecated")
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        decl_span.clone(),
+                        format!("\"{name}\" is marked as #[deprecated] here.")
+                    ),
+                ],
+                help: note.iter().cloned().collect(),
+                ..Default::default()
             },
            _ => Diagnostic {
                     // TODO: Temporary we use self here to achieve backward compatibility.
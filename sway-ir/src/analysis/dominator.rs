@@ -103,17 +103,22 @@ pub fn create_dominators_pass() -> Pass {
         name: DOMINATORS_NAME,
         descr: "Dominator tree computation",
         deps: vec![POSTORDER_NAME],
-        runner: ScopedPass::FunctionPass(PassMutability::Analysis(compute_dom_tree)),
+        runner: ScopedPass::FunctionPass(PassMutability::Analysis(compute_dom_tree_pass)),
     }
 }
 
-/// Compute the dominator tree for the CFG.
-fn compute_dom_tree(
+fn compute_dom_tree_pass(
     context: &Context,
     analyses: &AnalysisResults,
     function: Function,
 ) -> Result<AnalysisResult, IrError> {
     let po: &PostOrder = analyses.get_analysis_result(function);
+    Ok(Box::new(compute_dom_tree(context, &function, po)))
+}
+
+/// Compute the dominator tree for the CFG, given its post-order traversal `po`.
+/// Beware: Unreachable blocks aren't part of the result.
+pub fn compute_dom_tree(context: &Context, function: &Function, po: &PostOrder) -> DomTree {
     let mut dom_tree = DomTree::default();
     let entry = function.get_entry_block(context);
 
@@ -193,7 +198,23 @@ fn compute_dom_tree(
         dom_tree.get_mut(&parent).unwrap().children.push(child);
     }
 
-    Ok(Box::new(dom_tree))
+    dom_tree
+}
+
+/// Returns true if the block `a` dominates the block `b`. Unreachable blocks neither dominate nor
+/// are dominated by any block.
+pub fn dominates(dom_tree: &DomTree, a: Block, b: Block) -> bool {
+    if !dom_tree.contains_key(&a) {
+        return false;
+    }
+    let mut node = Some(b);
+    while let Some(block) = node {
+        if block == a {
+            return true;
+        }
+        node = dom_tree.get(&block).and_then(|node| node.parent);
+    }
+    false
 }
 
 pub const DOMFRONTS_NAME: &str = "dominance_frontiers";
@@ -0,0 +1,407 @@
+//! Summaries of the effects a function may have when called, like reading storage or writing
+//! memory, including the effects of the functions it calls.
+//!
+//! The summaries are computed bottom-up over the call graph and attached to the functions as
+//! `effects` metadata, listing the effects by name, e.g., `effects "reads_storage" "may_revert"`.
+//! The optimizations use them to tell which calls they may reuse, move or remove. A function
+//! without a summary is assumed to have every effect.
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    build_call_graph, callee_first_order, get_symbols, AnalysisResults, BinaryOpKind, Block,
+    BranchToWithArgs, CallGraph, Context, FuelVmInstruction, Function, Instruction, IrError,
+    MetadataIndex, Metadatum, Module, Pass, PassMutability, ScopedPass, Symbol, Value,
+};
+
+pub const EFFECTS_NAME: &str = "effects";
+
+pub fn create_effects_pass() -> Pass {
+    Pass {
+        name: EFFECTS_NAME,
+        descr: "Function effect summaries.",
+        deps: vec![],
+        runner: ScopedPass::ModulePass(PassMutability::Transform(compute_effects)),
+    }
+}
+
+const EFFECTS_MD_TAG: &str = "effects";
+
+/// The effects of a call. The default is a call without any effects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Effects {
+    pub reads_storage: bool,
+    pub writes_storage: bool,
+    pub logs: bool,
+    /// Calls other contracts, or sends messages with `smo`.
+    pub calls_contracts: bool,
+    /// Reads memory the function did not allocate, e.g., through a pointer argument.
+    pub reads_memory: bool,
+    /// Writes memory the function did not allocate.
+    pub writes_memory: bool,
+    /// Reverts explicitly, or on a failing arithmetic operation. A function which may not return
+    /// at all, because it loops or recurses, is assumed to revert too, so that its calls are kept.
+    pub may_revert: bool,
+    /// Reads registers, or runs `asm` blocks, whose effects are not tracked.
+    pub other: bool,
+}
+
+impl Effects {
+    /// The effects assumed for a function without a summary.
+    pub const ALL: Effects = Effects {
+        reads_storage: true,
+        writes_storage: true,
+        logs: true,
+        calls_contracts: true,
+        reads_memory: true,
+        writes_memory: true,
+        may_revert: true,
+        other: true,
+    };
+
+    /// Returns true if the effects change any state which outlives the call.
+    pub fn has_side_effects(&self) -> bool {
+        self.writes_storage || self.logs || self.calls_contracts || self.writes_memory || self.other
+    }
+
+    /// Returns true if the result may depend on more than the arguments, i.e., on a state which
+    /// may change between calls.
+    pub fn reads_state(&self) -> bool {
+        self.reads_storage || self.reads_memory || self.other
+    }
+
+    fn union(self, other: Effects) -> Effects {
+        Effects {
+            reads_storage: self.reads_storage || other.reads_storage,
+            writes_storage: self.writes_storage || other.writes_storage,
+            logs: self.logs || other.logs,
+            calls_contracts: self.calls_contracts || other.calls_contracts,
+            reads_memory: self.reads_memory || other.reads_memory,
+            writes_memory: self.writes_memory || other.writes_memory,
+            may_revert: self.may_revert || other.may_revert,
+            other: self.other || other.other,
+        }
+    }
+
+    fn names(&self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.reads_storage, "reads_storage"),
+            (self.writes_storage, "writes_storage"),
+            (self.logs, "logs"),
+            (self.calls_contracts, "calls_contracts"),
+            (self.reads_memory, "reads_memory"),
+            (self.writes_memory, "writes_memory"),
+            (self.may_revert, "may_revert"),
+            (self.other, "other"),
+        ]
+        .into_iter()
+        .filter_map(|(has, name)| has.then_some(name))
+    }
+
+    fn from_names<'a>(names: impl Iterator<Item = &'a str>) -> Option<Effects> {
+        let mut effects = Effects::default();
+        for name in names {
+            let flag = match name {
+                "reads_storage" => &mut effects.reads_storage,
+                "writes_storage" => &mut effects.writes_storage,
+                "logs" => &mut effects.logs,
+                "calls_contracts" => &mut effects.calls_contracts,
+                "reads_memory" => &mut effects.reads_memory,
+                "writes_memory" => &mut effects.writes_memory,
+                "may_revert" => &mut effects.may_revert,
+                "other" => &mut effects.other,
+                _ => return None,
+            };
+            *flag = true;
+        }
+        Some(effects)
+    }
+}
+
+/// Returns the effects summary attached to the `function`, or [Effects::ALL] if it has none.
+pub fn get_effects(context: &Context, function: Function) -> Effects {
+    find_effects_md(context, function.get_metadata(context))
+        .and_then(|md_idx| match md_idx.get_content(context) {
+            Metadatum::Struct(_, els) => Effects::from_names(
+                els.iter()
+                    .map(|el| el.unwrap_string())
+                    .collect::<Option<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            _ => None,
+        })
+        .unwrap_or(Effects::ALL)
+}
+
+fn find_effects_md(context: &Context, md_idx: Option<MetadataIndex>) -> Option<MetadataIndex> {
+    let md_idx = md_idx?;
+    let is_effects = |md_idx: &MetadataIndex| match md_idx.get_content(context) {
+        Metadatum::Struct(tag, _) => tag == EFFECTS_MD_TAG,
+        _ => false,
+    };
+    match md_idx.get_content(context).unwrap_list() {
+        Some(md_idcs) => md_idcs.iter().copied().find(is_effects),
+        None => is_effects(&md_idx).then_some(md_idx),
+    }
+}
+
+/// Computes the effects of every function in the `module`, and attaches them to the functions,
+/// replacing the summaries computed earlier.
+pub fn compute_effects(
+    context: &mut Context,
+    _: &AnalysisResults,
+    module: Module,
+) -> Result<bool, IrError> {
+    let functions = module.function_iter(context).collect::<Vec<_>>();
+    let cg = build_call_graph(context, &functions);
+    let order = callee_first_order(&cg);
+
+    // Nothing is known about whether a loop or a recursion ends, so the functions with either may
+    // never return, and their calls must not be removed.
+    let may_not_return = functions
+        .iter()
+        .copied()
+        .filter(|function| has_loop(context, *function) || is_recursive(&cg, *function))
+        .collect::<FxHashSet<_>>();
+
+    // The callees come first, so a single sweep is enough unless there is recursion. The
+    // summaries of recursive functions grow with each sweep, until they include the effects of
+    // the whole cycle.
+    let mut summaries = FxHashMap::<Function, Effects>::default();
+    loop {
+        let mut changed = false;
+        for function in order.iter() {
+            let mut effects = function_effects(context, *function, &summaries);
+            effects.may_revert |= may_not_return.contains(function);
+            if summaries.insert(*function, effects) != Some(effects) {
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut modified = false;
+    for function in functions {
+        let effects = summaries[&function];
+        let old_md = function.get_metadata(context);
+        let old_effects_md = find_effects_md(context, old_md);
+        if old_effects_md.is_some() && get_effects(context, function) == effects {
+            continue;
+        }
+
+        let mut md_idcs = match old_md {
+            None => Vec::new(),
+            Some(md_idx) => match md_idx.get_content(context).unwrap_list() {
+                Some(md_idcs) => md_idcs.to_vec(),
+                None => vec![md_idx],
+            },
+        };
+        md_idcs.retain(|md_idx| Some(*md_idx) != old_effects_md);
+        md_idcs.push(MetadataIndex::new_struct(
+            context,
+            EFFECTS_MD_TAG,
+            effects
+                .names()
+                .map(|name| Metadatum::String(name.to_owned()))
+                .collect(),
+        ));
+        let new_md = match md_idcs[..] {
+            [md_idx] => md_idx,
+            _ => MetadataIndex::new_list(context, md_idcs),
+        };
+        function.set_metadata(context, Some(new_md));
+        modified = true;
+    }
+
+    Ok(modified)
+}
+
+/// Returns the effects of the instructions in the `function`, taking the effects of the callees
+/// from the `summaries`. The callees without a summary yet are assumed to have none.
+fn function_effects(
+    context: &Context,
+    function: Function,
+    summaries: &FxHashMap<Function, Effects>,
+) -> Effects {
+    let reads = |ptr: &Value| Effects {
+        reads_memory: !is_local(context, *ptr),
+        ..Effects::default()
+    };
+    let writes = |ptr: &Value| Effects {
+        writes_memory: !is_local(context, *ptr),
+        ..Effects::default()
+    };
+    let reverts = Effects {
+        may_revert: true,
+        ..Effects::default()
+    };
+
+    function
+        .instruction_iter(context)
+        .filter_map(|(_block, ins)| ins.get_instruction(context))
+        .map(|inst| match inst {
+            Instruction::AsmBlock(..) => Effects::ALL,
+            Instruction::BinaryOp { op, .. } => match op {
+                BinaryOpKind::Add
+                | BinaryOpKind::Sub
+                | BinaryOpKind::Mul
+                | BinaryOpKind::Div
+                | BinaryOpKind::Mod => reverts,
+                _ => Effects::default(),
+            },
+            Instruction::Call(callee, _) => summaries.get(callee).copied().unwrap_or_default(),
+            Instruction::ContractCall {
+                params,
+                coins,
+                asset_id,
+                ..
+            } => Effects {
+                calls_contracts: true,
+                may_revert: true,
+                ..reads(params).union(reads(coins)).union(reads(asset_id))
+            },
+            Instruction::FuelVm(fuel_vm_inst) => match fuel_vm_inst {
+                FuelVmInstruction::Gtf { .. } => reverts,
+                FuelVmInstruction::Log { .. } => Effects {
+                    logs: true,
+                    ..Effects::default()
+                },
+                FuelVmInstruction::ReadRegister(_) => Effects {
+                    other: true,
+                    ..Effects::default()
+                },
+                FuelVmInstruction::Revert(_) => reverts,
+                FuelVmInstruction::Smo {
+                    recipient, message, ..
+                } => Effects {
+                    calls_contracts: true,
+                    may_revert: true,
+                    ..reads(recipient).union(reads(message))
+                },
+                FuelVmInstruction::StateClear { key, .. } => Effects {
+                    writes_storage: true,
+                    ..reads(key)
+                },
+                FuelVmInstruction::StateLoadQuadWord { load_val, key, .. } => Effects {
+                    reads_storage: true,
+                    ..reads(key).union(writes(load_val))
+                },
+                FuelVmInstruction::StateLoadWord(key) => Effects {
+                    reads_storage: true,
+                    ..reads(key)
+                },
+                FuelVmInstruction::StateStoreQuadWord {
+                    stored_val, key, ..
+                } => Effects {
+                    writes_storage: true,
+                    ..reads(key).union(reads(stored_val))
+                },
+                FuelVmInstruction::StateStoreWord { key, .. } => Effects {
+                    writes_storage: true,
+                    ..reads(key)
+                },
+                FuelVmInstruction::WideUnaryOp { result, arg, .. } => {
+                    writes(result).union(reads(arg))
+                }
+                FuelVmInstruction::WideBinaryOp {
+                    result, arg1, arg2, ..
+                } => reverts
+                    .union(writes(result))
+                    .union(reads(arg1))
+                    .union(reads(arg2)),
+                FuelVmInstruction::WideModularOp {
+                    result,
+                    arg1,
+                    arg2,
+                    arg3,
+                    ..
+                } => reverts
+                    .union(writes(result))
+                    .union(reads(arg1))
+                    .union(reads(arg2))
+                    .union(reads(arg3)),
+                FuelVmInstruction::WideCmpOp { arg1, arg2, .. } => reads(arg1).union(reads(arg2)),
+            },
+            Instruction::Load(ptr) => reads(ptr),
+            Instruction::MemCopyBytes {
+                dst_val_ptr,
+                src_val_ptr,
+                ..
+            }
+            | Instruction::MemCopyVal {
+                dst_val_ptr,
+                src_val_ptr,
+            } => writes(dst_val_ptr).union(reads(src_val_ptr)),
+            Instruction::Store { dst_val_ptr, .. } => writes(dst_val_ptr),
+            Instruction::UnaryOp { .. }
+            | Instruction::BitCast(..)
+            | Instruction::Branch(_)
+            | Instruction::CastPtr(..)
+            | Instruction::Cmp(..)
+            | Instruction::ConditionalBranch { .. }
+            | Instruction::GetElemPtr { .. }
+            | Instruction::GetLocal(_)
+            | Instruction::IntToPtr(..)
+            | Instruction::Nop
+            | Instruction::PtrToInt(..)
+            | Instruction::Ret(..) => Effects::default(),
+        })
+        .fold(Effects::default(), Effects::union)
+}
+
+/// Returns true if the CFG of the `function` has a back edge, i.e., a branch to a block which is
+/// still being visited by a depth-first walk from the entry block.
+fn has_loop(context: &Context, function: Function) -> bool {
+    fn visit(
+        context: &Context,
+        block: Block,
+        visited: &mut FxHashSet<Block>,
+        on_path: &mut FxHashSet<Block>,
+    ) -> bool {
+        if on_path.contains(&block) {
+            return true;
+        }
+        if !visited.insert(block) {
+            return false;
+        }
+        on_path.insert(block);
+        let found = block
+            .successors(context)
+            .into_iter()
+            .any(|BranchToWithArgs { block: succ, .. }| visit(context, succ, visited, on_path));
+        on_path.remove(&block);
+        found
+    }
+
+    visit(
+        context,
+        function.get_entry_block(context),
+        &mut FxHashSet::default(),
+        &mut FxHashSet::default(),
+    )
+}
+
+/// Returns true if the `function` may call itself, directly or through other functions.
+fn is_recursive(cg: &CallGraph, function: Function) -> bool {
+    let mut visited = FxHashSet::default();
+    let mut worklist = cg[&function].iter().copied().collect::<Vec<_>>();
+    while let Some(callee) = worklist.pop() {
+        if callee == function {
+            return true;
+        }
+        if visited.insert(callee) {
+            worklist.extend(cg.get(&callee).into_iter().flatten().copied());
+        }
+    }
+    false
+}
+
+/// Returns true if the pointer `ptr` is known to point into the locals of its function.
+fn is_local(context: &Context, ptr: Value) -> bool {
+    let symbols = get_symbols(context, ptr);
+    !symbols.is_empty()
+        && symbols
+            .iter()
+            .all(|symbol| matches!(symbol, Symbol::Local(_)))
+}
@@ -6,7 +6,6 @@ use std::{
 use anyhow::anyhow;
 use sway_ir::{
     insert_after_each, register_known_passes, PassGroup, PassManager, MODULEPRINTER_NAME,
-    MODULEVERIFIER_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -36,9 +35,7 @@ fn main() -> Result<(), anyhow::Error> {
     if config.print_after_each {
         passes = insert_after_each(passes, MODULEPRINTER_NAME);
     }
-    if config.verify_after_each {
-        passes = insert_after_each(passes, MODULEVERIFIER_NAME);
-    }
+    pass_mgr.verify_after_each(config.verify_after_each);
     pass_mgr.run(&mut ir, &passes)?;
 
     // Write the output file or standard out.
@@ -163,6 +163,43 @@ impl Block {
         }
     }
 
+    /// Remove the i'th block arg, along with the parameters the predecessors pass to it.
+    ///
+    /// > Care must be taken to ensure the argument has no uses, otherwise the function will be
+    /// > made invalid.
+    pub fn remove_arg(&self, context: &mut Context, index: usize) {
+        context.blocks[self.0].args.remove(index);
+        for arg in context.blocks[self.0].args.clone().into_iter().skip(index) {
+            if let ValueDatum::Argument(BlockArgument { idx, .. }) =
+                &mut context.values[arg.0].value
+            {
+                *idx -= 1;
+            }
+        }
+
+        let preds = self.pred_iter(context).copied().collect::<Vec<_>>();
+        for pred in preds {
+            match pred.get_terminator_mut(context) {
+                Some(Instruction::ConditionalBranch {
+                    true_block,
+                    false_block,
+                    ..
+                }) => {
+                    // Both edges of a conditional branch may lead to this block.
+                    for branch in [true_block, false_block] {
+                        if branch.block == *self {
+                            branch.args.remove(index);
+                        }
+                    }
+                }
+                Some(Instruction::Branch(branch)) if branch.block == *self => {
+                    branch.args.remove(index);
+                }
+                _ => (),
+            }
+        }
+    }
+
     /// Get an iterator over this block's args.
     pub fn arg_iter<'a>(&'a self, context: &'a Context) -> impl Iterator<Item = &Value> {
         context.blocks[self.0].args.iter()
@@ -13,7 +13,7 @@ use sway_types::SourceEngine;
 use crate::{
     asm::AsmBlockContent, block::BlockContent, function::FunctionContent,
     local_var::LocalVarContent, metadata::Metadatum, module::Kind, module::ModuleContent,
-    module::ModuleIterator, value::ValueContent, Type, TypeContent,
+    module::ModuleIterator, value::ValueContent, Type, TypeContent, DEFAULT_INLINE_THRESHOLD,
 };
 
 /// The main IR context handle.
@@ -35,6 +35,10 @@ pub struct Context<'eng> {
 
     pub program_kind: Kind,
 
+    /// The estimated size, in instructions, above which the inliner leaves the calls to a
+    /// function with more than one call site in place.
+    pub inline_threshold: usize,
+
     next_unique_sym_tag: u64,
 }
 
@@ -53,6 +57,7 @@ impl<'eng> Context<'eng> {
             metadata: Default::default(),
             next_unique_sym_tag: Default::default(),
             program_kind: Kind::Contract,
+            inline_threshold: DEFAULT_INLINE_THRESHOLD,
         };
         Type::create_basic_types(&mut def);
         def
@@ -21,6 +21,7 @@ pub enum IrError {
     VerifyBitcastBetweenInvalidTypes(String, String),
     VerifyBitcastUnknownSourceType,
     VerifyBlockArgMalformed,
+    VerifyBlockPredecessorMissing(String, String),
     VerifyBranchParamsMismatch,
     VerifyBranchToMissingBlock(String),
     VerifyCallArgTypeMismatch(String, String, String),
@@ -30,6 +31,10 @@ pub enum IrError {
     VerifyCmpUnknownTypes,
     VerifyConditionExprNotABool,
     VerifyContractCallBadTypes(String),
+    VerifyFailedAfterPass(String, Box<IrError>),
+    VerifyFailedAtInstruction(String, Box<IrError>),
+    VerifyFailedInBlock(String, Box<IrError>),
+    VerifyFailedInFunction(String, Box<IrError>),
     VerifyGepElementTypeNonPointer,
     VerifyGepFromNonPointer(String),
     VerifyGepInconsistentTypes,
@@ -65,6 +70,8 @@ pub enum IrError {
     VerifyStoreMismatchedTypes,
     VerifyStoreToNonPointer(String),
     VerifyUntypedValuePassedToFunction,
+    VerifyUseNotDominated(String),
+    VerifyUseOfValueNotInFunction,
 }
 
 impl std::error::Error for IrError {}
@@ -180,6 +187,18 @@ impl fmt::Display for IrError {
                     Argument {arg_name} passed to contract call has the incorrect type."
                 )
             }
+            IrError::VerifyFailedAfterPass(pass, error) => {
+                write!(f, "The IR is invalid after running pass '{pass}'. {error}")
+            }
+            IrError::VerifyFailedAtInstruction(ins, error) => {
+                write!(f, "At instruction `{ins}`: {error}")
+            }
+            IrError::VerifyFailedInBlock(label, error) => {
+                write!(f, "In block '{label}': {error}")
+            }
+            IrError::VerifyFailedInFunction(name, error) => {
+                write!(f, "In function '{name}': {error}")
+            }
             IrError::VerifyGepElementTypeNonPointer => {
                 write!(f, "Verification failed: GEP on a non-pointer.")
             }
@@ -256,6 +275,14 @@ impl fmt::Display for IrError {
             IrError::VerifyBlockArgMalformed => {
                 write!(f, "Verification failed: Block argument is malformed")
             }
+            IrError::VerifyBlockPredecessorMissing(block, pred) => {
+                write!(
+                    f,
+                    "Verification failed: \
+                    Block '{pred}' branches to block '{block}', which does not list it as a \
+                    predecessor."
+                )
+            }
             IrError::VerifyBranchParamsMismatch => {
                 write!(
                     f,
@@ -318,6 +345,16 @@ impl fmt::Display for IrError {
                 f,
                 "Verification failed: An untyped/void value has been passed to a function call."
             ),
+            IrError::VerifyUseNotDominated(def) => write!(
+                f,
+                "Verification failed: \
+                The value defined by `{def}` is used where its definition does not dominate."
+            ),
+            IrError::VerifyUseOfValueNotInFunction => write!(
+                f,
+                "Verification failed: \
+                An instruction uses a value which is not defined in the current function."
+            ),
             IrError::VerifyInvalidGtfIndexType => write!(
                 f,
                 "Verification failed: An non-integer value has been passed to a 'gtf' instruction."
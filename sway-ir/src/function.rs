@@ -254,6 +254,11 @@ impl Function {
         context.functions[self.0].metadata
     }
 
+    /// Replace the attached metadata.
+    pub fn set_metadata(&self, context: &mut Context, metadata: Option<MetadataIndex>) {
+        context.functions[self.0].metadata = metadata;
+    }
+
     /// Whether this function has a valid selector.
     pub fn has_selector(&self, context: &Context) -> bool {
         context.functions[self.0].selector.is_some()
@@ -1,10 +1,11 @@
 /// Function argument demotion.
 ///
 /// This pass demotes 'by-value' function arg types to 'by-reference` pointer types, based on target
-/// specific parameters.
+/// specific parameters.  The functions with `extern_abi "memory"` metadata have all of their args
+/// demoted, except for those which are pointers already.
 use crate::{
-    AnalysisResults, Block, BlockArgument, Context, Function, Instruction, IrError, Pass,
-    PassMutability, ScopedPass, Type, Value, ValueDatum,
+    AnalysisResults, Block, BlockArgument, Context, Function, Instruction, IrError, MetadataIndex,
+    Pass, PassMutability, ScopedPass, Type, Value, ValueDatum,
 };
 
 use rustc_hash::FxHashMap;
@@ -40,12 +41,15 @@ fn fn_arg_demotion(context: &mut Context, function: Function) -> Result<bool, Ir
     // aggregate.  This info should be instead determined by a target info analysis pass.
 
     // Find candidate argument indices.
+    let in_memory = passes_args_in_memory(context, function.get_metadata(context));
     let candidate_args = function
         .args_iter(context)
         .enumerate()
         .filter_map(|(idx, (_name, arg_val))| {
             arg_val.get_type(context).and_then(|ty| {
-                super::target_fuel::is_demotable_type(context, &ty).then_some((idx, ty))
+                ((in_memory && !ty.is_ptr(context))
+                    || super::target_fuel::is_demotable_type(context, &ty))
+                .then_some((idx, ty))
             })
         })
         .collect::<Vec<(usize, Type)>>();
@@ -89,6 +93,24 @@ fn fn_arg_demotion(context: &mut Context, function: Function) -> Result<bool, Ir
     Ok(true)
 }
 
+/// Returns true if the metadata of a function asks for its args to be passed in memory, with
+/// `extern_abi "memory"`.
+fn passes_args_in_memory(context: &Context, md_idx: Option<MetadataIndex>) -> bool {
+    let is_memory_abi = |md_idx: &MetadataIndex| {
+        md_idx
+            .get_content(context)
+            .unwrap_struct("extern_abi", 1)
+            .and_then(|fields| fields[0].unwrap_string())
+            == Some("memory")
+    };
+    md_idx.map_or(false, |md_idx| {
+        match md_idx.get_content(context).unwrap_list() {
+            Some(md_idcs) => md_idcs.iter().any(is_memory_abi),
+            None => is_memory_abi(&md_idx),
+        }
+    })
+}
+
 fn demote_fn_signature(context: &mut Context, function: &Function, arg_idcs: &[(usize, Type)]) {
     // Change the types of the arg values in place to their pointer counterparts.
     let entry_block = function.get_entry_block(context);
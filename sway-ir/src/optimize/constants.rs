@@ -3,6 +3,12 @@
 //! - combining - compile time evaluation of constant expressions.
 //!   - combine insert_values - reduce expressions which insert a constant value into a constant
 //!     struct.
+//! - propagation - replace block arguments which receive the same constant from every predecessor
+//!   with that constant.
+//!
+//! Operations which would overflow at runtime are never folded, so that they still revert when
+//! executed. This includes the additions and multiplications of `u8`, `u16` and `u32` operands
+//! whose result exceeds their width.
 
 use crate::{
     constant::{Constant, ConstantValue},
@@ -10,7 +16,8 @@ use crate::{
     error::IrError,
     function::Function,
     instruction::Instruction,
-    value::ValueDatum,
+    metadata::MetadataIndex,
+    value::{Value, ValueDatum},
     AnalysisResults, BranchToWithArgs, Pass, PassMutability, Predicate, ScopedPass,
 };
 
@@ -53,6 +60,11 @@ pub fn combine_constants(
             continue;
         }
 
+        if combine_block_args(context, &function) {
+            modified = true;
+            continue;
+        }
+
         // Other passes here... always continue to the top if pass returns true.
         break;
     }
@@ -196,7 +208,7 @@ fn combine_binary_op(context: &mut Context, function: &Function) -> bool {
                             _ => None,
                         },
                         crate::BinaryOpKind::Mod => match (&val1.value, &val2.value) {
-                            (ConstantValue::Uint(l), ConstantValue::Uint(r)) => Some(l % r),
+                            (ConstantValue::Uint(l), ConstantValue::Uint(r)) => l.checked_rem(*r),
                             _ => None,
                         },
                         crate::BinaryOpKind::Rsh => match (&val1.value, &val2.value) {
@@ -213,16 +225,17 @@ fn combine_binary_op(context: &mut Context, function: &Function) -> bool {
                         },
                     };
 
-                    v.map(|v| {
-                        (
-                            inst_val,
-                            block,
-                            Constant {
-                                ty: val1.ty,
-                                value: ConstantValue::Uint(v),
-                            },
-                        )
-                    })
+                    v.filter(|v| *v <= uint_width_max(context, inst_val))
+                        .map(|v| {
+                            (
+                                inst_val,
+                                block,
+                                Constant {
+                                    ty: val1.ty,
+                                    value: ConstantValue::Uint(v),
+                                },
+                            )
+                        })
                 }
                 _ => None,
             },
@@ -236,6 +249,26 @@ fn combine_binary_op(context: &mut Context, function: &Function) -> bool {
     })
 }
 
+/// The largest result of the arithmetic `inst_val` that fits the width of its operands.
+///
+/// Sway compiles `u8`, `u16` and `u32` to `u64`, and marks the arithmetic on those with a
+/// `uint_width` metadatum. A result which exceeds that width reverts at runtime.
+fn uint_width_max(context: &Context, inst_val: Value) -> u64 {
+    let uint_width = |md_idx: MetadataIndex| {
+        md_idx
+            .get_content(context)
+            .unwrap_struct("uint_width", 1)
+            .and_then(|fields| fields[0].unwrap_integer())
+    };
+    inst_val
+        .get_metadata(context)
+        .and_then(|md_idx| match md_idx.get_content(context).unwrap_list() {
+            Some(md_idcs) => md_idcs.iter().find_map(|md_idx| uint_width(*md_idx)),
+            None => uint_width(md_idx),
+        })
+        .map_or(u64::MAX, |bits| u64::MAX >> (64 - bits))
+}
+
 fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
     let candidate = function
         .instruction_iter(context)
@@ -282,6 +315,48 @@ fn combine_unary_op(context: &mut Context, function: &Function) -> bool {
     })
 }
 
+/// Replace the uses of a block argument with a constant if every predecessor passes that same
+/// constant to it.
+fn combine_block_args(context: &mut Context, function: &Function) -> bool {
+    let candidate = function
+        .block_iter(context)
+        .skip(1)
+        .flat_map(|block| {
+            block
+                .arg_iter(context)
+                .copied()
+                .enumerate()
+                .map(move |(idx, arg)| (block, idx, arg))
+                .collect::<Vec<_>>()
+        })
+        .find_map(|(block, idx, arg)| {
+            let mut incoming = block.pred_iter(context).flat_map(|pred| {
+                pred.successors(context)
+                    .into_iter()
+                    .filter(|succ| succ.block == block)
+                    .map(|succ| succ.args[idx])
+            });
+            let first = incoming.next()?;
+            let constant = first.get_constant(context)?;
+            let is_propagatable = !matches!(constant.value, ConstantValue::Undef)
+                && incoming.all(|val| {
+                    val.get_constant(context)
+                        .map_or(false, |other| other.eq(context, constant))
+                });
+            let is_used = function.instruction_iter(context).any(|(_, inst_val)| {
+                inst_val
+                    .get_instruction(context)
+                    .map_or(false, |inst| inst.get_operands().contains(&arg))
+            });
+            (is_propagatable && is_used).then_some((arg, first))
+        });
+
+    candidate.map_or(false, |(arg, constant)| {
+        function.replace_value(context, arg, constant, None);
+        true
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::optimize::tests::*;
@@ -347,6 +422,7 @@ mod tests {
         assert_operator("sub", "0", Some("1"), None);
         assert_operator("mul", &u64::MAX.to_string(), Some("2"), None);
         assert_operator("div", "1", Some("0"), None);
+        assert_operator("mod", "1", Some("0"), None);
 
         assert_operator("rsh", "1", Some("64"), None);
         assert_operator("lsh", "1", Some("64"), None);
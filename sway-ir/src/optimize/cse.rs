@@ -0,0 +1,239 @@
+//! ## Common Subexpression Elimination
+//!
+//! This optimization reuses the result of a pure instruction, instead of computing it again, when
+//! an instruction with the same operands is found later on. E.g., the address of a struct field,
+//! computed with a `get_elem_ptr` each time the field is accessed.
+//!
+//!   1. The pure instructions are reused across blocks, if the earlier one is in a block which
+//!      dominates the block of the later one. The blocks are visited in the order of the
+//!      dominator tree, so the available instructions are the ones of the dominating blocks.
+//!   2. Loads are reused only within a block, and only until an instruction which may write to
+//!      memory, like a store or a call, is found in between.
+//!   3. Calls are reused like the pure instructions if, according to the effects summary of the
+//!      callee, their result depends only on their arguments, and they change no state. Such
+//!      calls don't stop the reuse of loads either.
+//!   4. Storage and `asm` instructions, and any other instructions with side effects, are never
+//!      touched.
+//!
+//! The operands which are constants are compared by their values, as each constant is a separate
+//! value in the IR.
+
+use std::mem::discriminant;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    get_effects, AnalysisResults, Block, Context, DomTree, Function, Instruction, IrError, Pass,
+    PassMutability, ScopedPass, Value, DOMINATORS_NAME, POSTORDER_NAME,
+};
+
+pub const CSE_NAME: &str = "cse";
+
+pub fn create_cse_pass() -> Pass {
+    Pass {
+        name: CSE_NAME,
+        descr: "Common subexpression elimination.",
+        deps: vec![POSTORDER_NAME, DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(cse)),
+    }
+}
+
+pub fn cse(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+
+    // Map each redundant instruction to the earlier one computing the same result.
+    let mut replacements = FxHashMap::<Value, Value>::default();
+    let mut available = Vec::new();
+    visit_block(
+        context,
+        dom_tree,
+        function.get_entry_block(context),
+        &mut available,
+        &mut replacements,
+    );
+
+    if replacements.is_empty() {
+        return Ok(false);
+    }
+
+    function.replace_values(context, &replacements, None);
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |ins| replacements.contains_key(&ins));
+    }
+
+    Ok(true)
+}
+
+/// Finds the redundant instructions in `block`, given the pure instructions `available` from the
+/// dominating blocks, and then in the blocks it immediately dominates.
+fn visit_block(
+    context: &Context,
+    dom_tree: &DomTree,
+    block: Block,
+    available: &mut Vec<Value>,
+    replacements: &mut FxHashMap<Value, Value>,
+) {
+    let num_available_in_dominators = available.len();
+    let mut available_loads = Vec::new();
+
+    for ins in block.instruction_iter(context) {
+        let Some(inst) = ins.get_instruction(context) else {
+            continue;
+        };
+        let candidates = match inst {
+            Instruction::Load(_) => &mut available_loads,
+            _ if is_pure(context, inst) => &mut *available,
+            _ => {
+                if may_write_memory(context, inst) {
+                    available_loads.clear();
+                }
+                continue;
+            }
+        };
+        match candidates
+            .iter()
+            .find(|candidate| computes_same(context, replacements, **candidate, ins))
+            .copied()
+        {
+            Some(earlier) => {
+                replacements.insert(ins, earlier);
+            }
+            None => candidates.push(ins),
+        }
+    }
+
+    for child in dom_tree
+        .get(&block)
+        .map(|node| node.children.clone())
+        .unwrap_or_default()
+    {
+        visit_block(context, dom_tree, child, available, replacements);
+    }
+
+    available.truncate(num_available_in_dominators);
+}
+
+/// Returns true for the instructions whose result depends only on their operands.
+fn is_pure(context: &Context, inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            !effects.has_side_effects() && !effects.reads_state()
+        }
+        Instruction::UnaryOp { .. }
+        | Instruction::BinaryOp { .. }
+        | Instruction::BitCast(..)
+        | Instruction::CastPtr(..)
+        | Instruction::Cmp(..)
+        | Instruction::GetElemPtr { .. }
+        | Instruction::GetLocal(_)
+        | Instruction::IntToPtr(..)
+        | Instruction::PtrToInt(..) => true,
+        _ => false,
+    }
+}
+
+/// Returns true for the instructions which may change the memory read by the available loads.
+fn may_write_memory(context: &Context, inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            effects.writes_memory || effects.other
+        }
+        _ => inst.may_have_side_effect(),
+    }
+}
+
+/// Returns true if the instructions `a` and `b` are of the same kind and have the same operands,
+/// taking the `replacements` found so far into account.
+fn computes_same(
+    context: &Context,
+    replacements: &FxHashMap<Value, Value>,
+    a: Value,
+    b: Value,
+) -> bool {
+    let same = |a: &Value, b: &Value| same_operand(context, replacements, *a, *b);
+    match (a.get_instruction(context), b.get_instruction(context)) {
+        (
+            Some(Instruction::UnaryOp {
+                op: op_a,
+                arg: arg_a,
+            }),
+            Some(Instruction::UnaryOp {
+                op: op_b,
+                arg: arg_b,
+            }),
+        ) => discriminant(op_a) == discriminant(op_b) && same(arg_a, arg_b),
+        (
+            Some(Instruction::BinaryOp {
+                op: op_a,
+                arg1: lhs_a,
+                arg2: rhs_a,
+            }),
+            Some(Instruction::BinaryOp {
+                op: op_b,
+                arg1: lhs_b,
+                arg2: rhs_b,
+            }),
+        ) => discriminant(op_a) == discriminant(op_b) && same(lhs_a, lhs_b) && same(rhs_a, rhs_b),
+        (Some(Instruction::BitCast(val_a, ty_a)), Some(Instruction::BitCast(val_b, ty_b)))
+        | (Some(Instruction::CastPtr(val_a, ty_a)), Some(Instruction::CastPtr(val_b, ty_b)))
+        | (Some(Instruction::IntToPtr(val_a, ty_a)), Some(Instruction::IntToPtr(val_b, ty_b)))
+        | (Some(Instruction::PtrToInt(val_a, ty_a)), Some(Instruction::PtrToInt(val_b, ty_b))) => {
+            ty_a == ty_b && same(val_a, val_b)
+        }
+        (
+            Some(Instruction::Cmp(pred_a, lhs_a, rhs_a)),
+            Some(Instruction::Cmp(pred_b, lhs_b, rhs_b)),
+        ) => {
+            discriminant(pred_a) == discriminant(pred_b) && same(lhs_a, lhs_b) && same(rhs_a, rhs_b)
+        }
+        (
+            Some(Instruction::GetElemPtr {
+                base: base_a,
+                elem_ptr_ty: ty_a,
+                indices: indices_a,
+            }),
+            Some(Instruction::GetElemPtr {
+                base: base_b,
+                elem_ptr_ty: ty_b,
+                indices: indices_b,
+            }),
+        ) => {
+            ty_a == ty_b
+                && same(base_a, base_b)
+                && indices_a.len() == indices_b.len()
+                && indices_a.iter().zip(indices_b).all(|(a, b)| same(a, b))
+        }
+        (Some(Instruction::GetLocal(local_a)), Some(Instruction::GetLocal(local_b))) => {
+            local_a == local_b
+        }
+        (Some(Instruction::Load(ptr_a)), Some(Instruction::Load(ptr_b))) => same(ptr_a, ptr_b),
+        (Some(Instruction::Call(callee_a, args_a)), Some(Instruction::Call(callee_b, args_b))) => {
+            callee_a == callee_b
+                && args_a.len() == args_b.len()
+                && args_a.iter().zip(args_b).all(|(a, b)| same(a, b))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if the operands `a` and `b` are the same value, or constants with the same value.
+fn same_operand(
+    context: &Context,
+    replacements: &FxHashMap<Value, Value>,
+    a: Value,
+    b: Value,
+) -> bool {
+    let a = replacements.get(&a).copied().unwrap_or(a);
+    let b = replacements.get(&b).copied().unwrap_or(b);
+    a == b
+        || match (a.get_constant(context), b.get_constant(context)) {
+            (Some(const_a), Some(const_b)) => const_a.eq(context, const_b),
+            _ => false,
+        }
+}
@@ -3,14 +3,16 @@
 //! This optimization removes unused definitions. The pass is a combination of
 //!   1. A liveness analysis that keeps track of the uses of a definition,
 //!   2. At the time of inspecting a definition, if it has no uses, it is removed.
+//!   3. Block arguments which are never used are removed too, so that the values passed to them
+//!      can be removed in turn.
 //! This pass does not do CFG transformations. That is handled by simplify_cfg.
 
 use rustc_hash::FxHashSet;
 
 use crate::{
-    get_symbols, AnalysisResults, Context, EscapedSymbols, FuelVmInstruction, Function,
-    Instruction, IrError, LocalVar, Module, Pass, PassMutability, ScopedPass, Symbol, Value,
-    ValueDatum, ESCAPED_SYMBOLS_NAME,
+    get_effects, get_symbols, AnalysisResults, Context, EscapedSymbols, FuelVmInstruction,
+    Function, Instruction, IrError, LocalVar, Module, Pass, PassMutability, ScopedPass, Symbol,
+    Value, ValueDatum, ESCAPED_SYMBOLS_NAME,
 };
 
 use std::collections::{HashMap, HashSet};
@@ -44,7 +46,17 @@ fn can_eliminate_instruction(
     escaped_symbols: &EscapedSymbols,
 ) -> bool {
     let inst = val.get_instruction(context).unwrap();
-    (!inst.is_terminator() && !inst.may_have_side_effect())
+    let may_have_side_effect = match inst {
+        // An unused call is removed if, according to the effects summary, the callee neither
+        // changes any state nor reverts. The callees which loop or recurse may never return, and
+        // are summarized as reverting, so the calls to them are kept.
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            effects.has_side_effects() || effects.may_revert
+        }
+        _ => inst.may_have_side_effect(),
+    };
+    (!inst.is_terminator() && !may_have_side_effect)
         || is_removable_store(context, val, num_symbol_uses, escaped_symbols)
 }
 
@@ -227,6 +239,9 @@ pub fn dce(
 ) -> Result<bool, IrError> {
     let escaped_symbols: &EscapedSymbols = analyses.get_analysis_result(function);
 
+    // Removing the unused block arguments first leaves the values passed to them without uses.
+    let mut modified = remove_dead_block_args(context, function);
+
     // Number of uses that an instruction has.
     let mut num_uses: HashMap<Value, u32> = HashMap::new();
     let mut num_local_uses: HashMap<LocalVar, u32> = HashMap::new();
@@ -292,7 +307,6 @@ pub fn dce(
         })
         .collect::<Vec<_>>();
 
-    let mut modified = false;
     let mut cemetery = FxHashSet::default();
     while let Some(dead) = worklist.pop() {
         if !can_eliminate_instruction(context, dead, &num_symbol_uses, escaped_symbols)
@@ -354,6 +368,29 @@ pub fn dce(
     Ok(modified)
 }
 
+/// Remove the arguments of the blocks which are never used, along with the parameters passed to
+/// them by the predecessors.  The arguments of the entry block are the function arguments, and are
+/// always kept.
+fn remove_dead_block_args(context: &mut Context, function: Function) -> bool {
+    let used_values: FxHashSet<Value> = function
+        .instruction_iter(context)
+        .flat_map(|(_block, inst)| inst.get_instruction(context).unwrap().get_operands())
+        .collect();
+
+    let mut modified = false;
+    for block in function.block_iter(context).skip(1) {
+        // Go backwards, so that removing an argument doesn't shift the ones still to be visited.
+        for idx in (0..block.num_args(context)).rev() {
+            let arg = block.get_arg(context, idx).unwrap();
+            if !used_values.contains(&arg) {
+                block.remove_arg(context, idx);
+                modified = true;
+            }
+        }
+    }
+    modified
+}
+
 /// Remove entire functions from a module based on whether they are called or not, using a list of
 /// root 'entry' functions to perform a search.
 ///
@@ -1,8 +1,16 @@
 //! Function inlining.
 //!
 //! Function inlining is pretty hairy so these passes must be maintained with care.
-
-use std::{cell::RefCell, collections::HashMap};
+//!
+//! The module inliner decides on each call site using a cost model: the size of the callee is
+//! estimated in instructions, tiny callees and the ones with a single call site are always
+//! inlined, and callees above [Context::inline_threshold] are never duplicated into their
+//! call sites.  `#[inline(always)]` and `#[inline(never)]` override the model.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
 
 use rustc_hash::FxHashMap;
 
@@ -34,6 +42,13 @@ pub fn create_inline_in_main_pass() -> Pass {
 
 pub const INLINE_MODULE_NAME: &str = "inline_module";
 
+/// The default of [Context::inline_threshold].
+pub const DEFAULT_INLINE_THRESHOLD: usize = 64;
+
+/// The estimated size, in instructions, of the functions which are always inlined, because a
+/// call to them is about as large as their body.
+const TINY_FUNCTION_SIZE: usize = 4;
+
 pub fn create_inline_in_module_pass() -> Pass {
     Pass {
         name: INLINE_MODULE_NAME,
@@ -109,13 +124,25 @@ pub fn inline_in_module(
                 counts
             });
 
-    let inline_heuristic = |ctx: &Context, func: &Function, _call_site: &Value| {
+    // The arguments which only decide branches, per function.
+    let branch_args: HashMap<Function, Vec<usize>> = module
+        .function_iter(context)
+        .map(|func| (func, branch_only_args(context, &func)))
+        .filter(|(_func, args)| !args.is_empty())
+        .collect();
+
+    let cg =
+        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
+
+    let inline_heuristic = |ctx: &Context, func: &Function, call_site: &Value| {
         let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
         match attributed_inline {
-            Some(Inline::Always) => {
-                // TODO: check if inlining of function is possible
-                // return true;
+            // Inlining a recursive function would never end, and functions with `asm` blocks are
+            // left to the heuristic below.
+            Some(Inline::Always) if !is_recursive(&cg, func) && !contains_asm_block(ctx, func) => {
+                return true;
             }
+            Some(Inline::Always) => {}
             Some(Inline::Never) => {
                 return false;
             }
@@ -140,14 +167,28 @@ pub fn inline_in_module(
             return true;
         }
 
-        // If the function is called only once then definitely inline it.
+        // If the function is (still) tiny then the call would be as large as the body.
+        let size = estimated_size(ctx, func);
+        if size <= TINY_FUNCTION_SIZE {
+            return true;
+        }
+
+        // If the function is called only once then definitely inline it, nothing is duplicated.
         if call_counts.get(func).copied().unwrap_or(0) == 1 {
             return true;
         }
 
-        // If the function is (still) small then also inline it.
-        const MAX_INLINE_INSTRS_COUNT: usize = 4;
-        if func.num_instructions(ctx) <= MAX_INLINE_INSTRS_COUNT {
+        // Otherwise the body is duplicated into every call site, so don't for large functions.
+        if size > ctx.inline_threshold {
+            return false;
+        }
+
+        // If the call passes constants to arguments which only decide branches then inline it,
+        // so that the branches which are never taken can be folded away.
+        if branch_args
+            .get(func)
+            .map_or(false, |args| passes_constant_to(ctx, call_site, args))
+        {
             return true;
         }
 
@@ -163,8 +204,6 @@ pub fn inline_in_module(
         false
     };
 
-    let cg =
-        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
     let functions = call_graph::callee_first_order(&cg);
     let mut modified = false;
 
@@ -188,7 +227,49 @@ pub fn inline_in_main(
     Ok(false)
 }
 
-/// Inline all calls made from a specific function, effectively removing all `Call` instructions.
+/// Estimates the size of the code generated for `function` in instructions.  Each instruction
+/// of an `asm` block is counted, rather than the block as a whole.
+pub fn estimated_size(context: &Context, function: &Function) -> usize {
+    function
+        .instruction_iter(context)
+        .map(|(_block, ins)| match ins.get_instruction(context) {
+            Some(Instruction::AsmBlock(asm_block, _args)) => {
+                asm_block.get_content(context).body.len().max(1)
+            }
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Returns true if `function` calls itself, directly or through other functions.
+fn is_recursive(cg: &call_graph::CallGraph, function: &Function) -> bool {
+    let mut visited = HashSet::new();
+    let mut to_visit = vec![*function];
+    while let Some(caller) = to_visit.pop() {
+        for callee in cg.get(&caller).into_iter().flatten() {
+            if callee == function {
+                return true;
+            }
+            if visited.insert(*callee) {
+                to_visit.push(*callee);
+            }
+        }
+    }
+    false
+}
+
+/// Returns true if `function` contains an `asm` block.
+fn contains_asm_block(context: &Context, function: &Function) -> bool {
+    function.instruction_iter(context).any(|(_block, ins)| {
+        matches!(
+            ins.get_instruction(context),
+            Some(Instruction::AsmBlock(..))
+        )
+    })
+}
+
+/// Inline all calls made from a specific function, effectively removing all `Call` instructions,
+/// except for the calls to functions attributed with `#[inline(never)]`.
 ///
 /// e.g., If this is applied to main() then all calls in the program are removed.  This is
 /// obviously dangerous for recursive functions, in which case this pass would inline forever.
@@ -197,7 +278,12 @@ pub fn inline_all_function_calls(
     context: &mut Context,
     function: &Function,
 ) -> Result<bool, IrError> {
-    inline_some_function_calls(context, function, |_, _, _| true)
+    inline_some_function_calls(context, function, |ctx, func, _| {
+        !matches!(
+            metadata_to_inline(ctx, func.get_metadata(ctx)),
+            Some(Inline::Never)
+        )
+    })
 }
 
 /// Inline function calls based on a provided heuristic predicate.
@@ -291,6 +377,62 @@ pub fn is_small_fn(
     }
 }
 
+/// A predicate for [`inline_some_function_calls`] which accepts the call sites passing a constant
+/// to a parameter of the callee which is only used to decide branches.
+///
+/// Once such a call is inlined constant combining folds the branch conditions and the blocks
+/// which are never taken can be removed, e.g., for config-like flags passed to a function.
+
+pub fn passes_constant_branch_args(
+    context: &Context,
+    function: &Function,
+    call_site: &Value,
+) -> bool {
+    passes_constant_to(context, call_site, &branch_only_args(context, function))
+}
+
+/// Returns the positions of the arguments of `function` which are only used as branch
+/// conditions, either directly or compared with another value.
+fn branch_only_args(context: &Context, function: &Function) -> Vec<usize> {
+    let uses = move |value: Value| {
+        function
+            .instruction_iter(context)
+            .filter_map(move |(_block, ins)| ins.get_instruction(context).map(|inst| (ins, inst)))
+            .filter(move |(_ins, inst)| inst.get_operands().contains(&value))
+    };
+    let is_branch_condition = |value: Value| {
+        uses(value).count() > 0
+            && uses(value).all(|(_ins, inst)| match inst {
+                Instruction::ConditionalBranch { cond_value, .. } => *cond_value == value,
+                _ => false,
+            })
+    };
+
+    function
+        .args_iter(context)
+        .enumerate()
+        .filter(|(_idx, (_name, arg_val))| {
+            uses(*arg_val).count() > 0
+                && uses(*arg_val).all(|(ins, inst)| match inst {
+                    Instruction::ConditionalBranch { cond_value, .. } => cond_value == arg_val,
+                    Instruction::Cmp(..) => is_branch_condition(ins),
+                    _ => false,
+                })
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Returns true if `call_site` passes a constant to any of the arguments at `arg_positions`.
+fn passes_constant_to(context: &Context, call_site: &Value, arg_positions: &[usize]) -> bool {
+    match call_site.get_instruction(context) {
+        Some(Instruction::Call(_callee, args)) => arg_positions
+            .iter()
+            .any(|idx| args.get(*idx).map_or(false, |arg| arg.is_constant(context))),
+        _ => false,
+    }
+}
+
 /// Inline a function to a specific call site within another function.
 ///
 /// The destination function, block and call site must be specified along with the function to
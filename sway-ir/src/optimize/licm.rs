@@ -0,0 +1,320 @@
+//! ## Loop-Invariant Code Motion
+//!
+//! This optimization moves the instructions which compute the same result in every iteration of a
+//! loop, like the address of a struct field, or arithmetic on values defined before the loop, out
+//! of the loop and into its _preheader_, a block which is executed once, right before the loop.
+//!
+//!   1. The loops are found from the back edges of the CFG, i.e., the branches to a block which
+//!      dominates the branching block. That block is the _header_ of the loop.
+//!   2. If the header has a single predecessor outside the loop, which branches only to the
+//!      header, that predecessor is the preheader. Otherwise a new preheader is created, and the
+//!      branches which enter the loop are redirected to it.
+//!   3. An instruction is hoisted if all of its operands are defined outside the loop, or are
+//!      hoisted themselves, and if executing it before the loop is not observable:
+//!      - Instructions with side effects are never hoisted, and neither are loads, as the loop
+//!        may write to the memory they read.
+//!      - Instructions which cannot fail, like `get_elem_ptr` or `cmp`, are hoisted from any block
+//!        of the loop, even if they are executed only conditionally, or not at all.
+//!      - Arithmetic which reverts on an overflow or a division by zero is hoisted only from the
+//!        header, where it is executed at the start of the first iteration anyway, and only if
+//!        nothing left before it in the header has side effects or may revert.
+//!      - Calls are hoisted like the instructions if, according to the effects summary of the
+//!        callee, their result depends only on their arguments, and they change no state. The
+//!        calls to such functions don't stop the hoisting of arithmetic from the header either.
+//!
+//! Inner loops are processed before the loops containing them, so an instruction may be hoisted
+//! out of several loops at once.
+
+use std::cmp::Reverse;
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    dominates, get_effects, AnalysisResults, BinaryOpKind, Block, BranchToWithArgs, Context,
+    DomTree, Effects, Function, Instruction, IrError, Pass, PassMutability, PostOrder, ScopedPass,
+    DOMINATORS_NAME, POSTORDER_NAME,
+};
+
+pub const LICM_NAME: &str = "licm";
+
+pub fn create_licm_pass() -> Pass {
+    Pass {
+        name: LICM_NAME,
+        descr: "Loop-invariant code motion.",
+        deps: vec![POSTORDER_NAME, DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(licm)),
+    }
+}
+
+/// A natural loop, i.e., the `header` and the blocks which reach a back edge to it without
+/// passing through it.
+struct Loop {
+    header: Block,
+    blocks: FxHashSet<Block>,
+}
+
+pub fn licm(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    let po: &PostOrder = analyses.get_analysis_result(function);
+
+    let mut loops = find_loops(context, function, dom_tree);
+    // An inner loop has fewer blocks than the loops containing it.
+    loops.sort_by_key(|lp| lp.blocks.len());
+
+    let mut modified = false;
+    for idx in 0..loops.len() {
+        let Some(preheader) = hoist_invariants(context, function, po, &loops[idx])? else {
+            continue;
+        };
+        modified = true;
+
+        // The preheader of an inner loop is a part of the loops containing it.
+        let header = loops[idx].header;
+        for outer in loops[idx + 1..].iter_mut() {
+            if outer.blocks.contains(&header) {
+                outer.blocks.insert(preheader);
+            }
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Finds the natural loops of the reachable blocks of the `function`. The loops with the same
+/// header are merged into one.
+fn find_loops(context: &Context, function: Function, dom_tree: &DomTree) -> Vec<Loop> {
+    let mut loops: Vec<Loop> = Vec::new();
+    for block in function.block_iter(context) {
+        if !dom_tree.contains_key(&block) {
+            continue;
+        }
+        for BranchToWithArgs { block: header, .. } in block.successors(context) {
+            if !dominates(dom_tree, header, block) {
+                continue;
+            }
+            let lp = match loops.iter().position(|lp| lp.header == header) {
+                Some(idx) => &mut loops[idx],
+                None => {
+                    loops.push(Loop {
+                        header,
+                        blocks: FxHashSet::from_iter([header]),
+                    });
+                    loops.last_mut().unwrap()
+                }
+            };
+            let mut worklist = vec![block];
+            while let Some(block) = worklist.pop() {
+                if lp.blocks.insert(block) {
+                    worklist.extend(
+                        block
+                            .pred_iter(context)
+                            .filter(|pred| dom_tree.contains_key(pred)),
+                    );
+                }
+            }
+        }
+    }
+    loops
+}
+
+/// Moves the invariant instructions of the loop `lp` to its preheader. Returns the preheader, if
+/// any instruction was moved.
+fn hoist_invariants(
+    context: &mut Context,
+    function: Function,
+    po: &PostOrder,
+    lp: &Loop,
+) -> Result<Option<Block>, IrError> {
+    let outer_preds = lp
+        .header
+        .pred_iter(context)
+        .filter(|pred| !lp.blocks.contains(pred))
+        .copied()
+        .collect::<Vec<_>>();
+    // A loop entered only from a conditional branch, which leads to the header on both edges,
+    // possibly with different arguments, cannot get a single preheader.
+    let entered_on_both_edges = outer_preds.iter().any(|pred| {
+        matches!(
+            pred.get_terminator(context),
+            Some(Instruction::ConditionalBranch { true_block, false_block, .. })
+                if true_block.block == lp.header && false_block.block == lp.header
+        )
+    });
+    if outer_preds.is_empty() || entered_on_both_edges {
+        return Ok(None);
+    }
+
+    // The instructions and the arguments of the blocks in the loop.
+    let defined_in_loop = lp
+        .blocks
+        .iter()
+        .flat_map(|block| {
+            block
+                .arg_iter(context)
+                .copied()
+                .chain(block.instruction_iter(context))
+        })
+        .collect::<FxHashSet<_>>();
+
+    // The blocks are visited in reverse postorder, so that the operands are usually visited
+    // before their uses. The preheaders created for the inner loops are not in the postorder,
+    // and are visited first, as they precede the headers of the inner loops.
+    let mut blocks = lp.blocks.iter().copied().collect::<Vec<_>>();
+    blocks.sort_by_key(|block| Reverse(po.block_to_po.get(block).copied().unwrap_or(usize::MAX)));
+
+    let mut hoisted = Vec::new();
+    let mut hoisted_set = FxHashSet::default();
+    loop {
+        let num_hoisted = hoisted.len();
+        for block in blocks.iter() {
+            let is_header = *block == lp.header;
+            let mut past_effects = false;
+            for ins in block.instruction_iter(context) {
+                if hoisted_set.contains(&ins) {
+                    continue;
+                }
+                let Some(inst) = ins.get_instruction(context) else {
+                    continue;
+                };
+                let is_invariant = inst
+                    .get_operands()
+                    .iter()
+                    .all(|op| !defined_in_loop.contains(op) || hoisted_set.contains(op));
+                let may_revert = may_revert(context, inst);
+                if is_invariant
+                    && (cannot_fail(context, inst) || (is_header && may_revert && !past_effects))
+                {
+                    hoisted.push(ins);
+                    hoisted_set.insert(ins);
+                } else if may_revert || may_have_side_effect(context, inst) {
+                    past_effects = true;
+                }
+            }
+        }
+        if hoisted.len() == num_hoisted {
+            break;
+        }
+    }
+
+    if hoisted.is_empty() {
+        return Ok(None);
+    }
+
+    let preheader = match outer_preds[..] {
+        [pred] if matches!(pred.get_terminator(context), Some(Instruction::Branch(_))) => pred,
+        _ => create_preheader(context, function, lp.header, &outer_preds)?,
+    };
+
+    for block in blocks {
+        block.remove_instructions(context, |ins| hoisted_set.contains(&ins));
+    }
+    // Insert the hoisted instructions before the terminator of the preheader.
+    let instructions = &mut context.blocks[preheader.0].instructions;
+    let terminator_idx = instructions.len() - 1;
+    instructions.splice(terminator_idx..terminator_idx, hoisted);
+
+    Ok(Some(preheader))
+}
+
+/// Creates a new block before the `header`, which takes the same arguments and passes them on to
+/// the `header`, and redirects the branches of the `outer_preds` to it.
+fn create_preheader(
+    context: &mut Context,
+    function: Function,
+    header: Block,
+    outer_preds: &[Block],
+) -> Result<Block, IrError> {
+    let preheader = function.create_block_before(context, &header, Some("preheader".into()))?;
+
+    let arg_types = header
+        .arg_iter(context)
+        .map(|arg| arg.get_type(context).expect("Block arguments have types."))
+        .collect::<Vec<_>>();
+    let args = arg_types
+        .into_iter()
+        .map(|ty| {
+            let idx = preheader.new_arg(context, ty);
+            preheader.get_arg(context, idx).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for pred in outer_preds {
+        let params = pred.get_succ_params(context, &header);
+        pred.replace_successor(context, header, preheader, params);
+    }
+    preheader.ins(context).branch(header, args);
+
+    Ok(preheader)
+}
+
+/// Returns true for the pure instructions which never fail, and so can be executed even if they
+/// would not have been executed in the loop.
+fn cannot_fail(context: &Context, inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            is_pure_call(&effects) && !effects.may_revert
+        }
+        Instruction::BinaryOp { op, .. } => matches!(
+            op,
+            BinaryOpKind::And
+                | BinaryOpKind::Or
+                | BinaryOpKind::Xor
+                | BinaryOpKind::Lsh
+                | BinaryOpKind::Rsh
+        ),
+        Instruction::UnaryOp { .. }
+        | Instruction::BitCast(..)
+        | Instruction::CastPtr(..)
+        | Instruction::Cmp(..)
+        | Instruction::GetElemPtr { .. }
+        | Instruction::GetLocal(_)
+        | Instruction::IntToPtr(..)
+        | Instruction::PtrToInt(..) => true,
+        _ => false,
+    }
+}
+
+/// Returns true for the arithmetic which reverts on an overflow or a division by zero, and for
+/// the pure calls which may revert.
+fn may_revert(context: &Context, inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            is_pure_call(&effects) && effects.may_revert
+        }
+        _ => matches!(
+            inst,
+            Instruction::BinaryOp {
+                op: BinaryOpKind::Add
+                    | BinaryOpKind::Sub
+                    | BinaryOpKind::Mul
+                    | BinaryOpKind::Div
+                    | BinaryOpKind::Mod,
+                ..
+            }
+        ),
+    }
+}
+
+/// Returns true if the result of a call with the `effects` depends only on its arguments, and
+/// the call changes no state.
+fn is_pure_call(effects: &Effects) -> bool {
+    !effects.has_side_effects() && !effects.reads_state()
+}
+
+/// Returns true for the instructions which may have side effects, taking the effects summaries
+/// of the callees into account.
+fn may_have_side_effect(context: &Context, inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Call(callee, _) => {
+            let effects = get_effects(context, *callee);
+            effects.has_side_effects() || effects.may_revert
+        }
+        _ => inst.may_have_side_effect(),
+    }
+}
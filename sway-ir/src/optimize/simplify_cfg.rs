@@ -66,16 +66,14 @@ fn unlink_empty_blocks(context: &mut Context, function: &Function) -> Result<boo
     ) in candidates
     {
         // If `to_block`'s predecessors and `block`'s predecessors intersect,
-        // AND `to_block` has an arg, then we have that pred branching to to_block
-        // with different args. While that's valid IR, it's harder to generate
-        // ASM for it, so let's just skip that for now.
-        if to_block.num_args(context) > 0
-            && to_block.pred_iter(context).any(|to_block_pred| {
-                block
-                    .pred_iter(context)
-                    .any(|block_pred| block_pred == to_block_pred)
-            })
-        {
+        // then we'd have that pred branching to to_block twice, with different
+        // args if `to_block` has any. While that's valid IR, it's harder to
+        // generate ASM for it, so let's just skip that for now.
+        if to_block.pred_iter(context).any(|to_block_pred| {
+            block
+                .pred_iter(context)
+                .any(|block_pred| block_pred == to_block_pred)
+        }) {
             // We cannot filter this out in candidates itself because this condition
             // may get updated *during* this optimization (i.e., inside this loop).
             continue;
@@ -0,0 +1,294 @@
+//! ## Storage Access Optimization
+//!
+//! This optimization removes the storage accesses whose effect is already known, within a block.
+//!
+//!   1. A `state_load_word` of a slot which was loaded from, or stored to, earlier is replaced by
+//!      the value loaded or stored then, if there is no write to storage which may change the slot
+//!      in between.
+//!   2. A `state_store_word` is removed if the same slot is stored to again later, if there is no
+//!      read of storage which may see the slot in between. The results of both stores, telling
+//!      whether the slot was set before, must be unused.
+//!   3. Calls to other contracts and `asm` blocks may read and write any slot. Calls to functions
+//!      of the same module are treated according to the effects summary of the callee.
+//!
+//! The slots are compared by their key expressions, i.e., by the `b256` value which was stored to
+//! the key in the same block, constants being compared by their values. When the stored value is
+//! not known the slots are compared by the key pointer, until the memory is written to. Two keys
+//! which aren't provably equal are assumed to refer to the same slot, unless both are different
+//! constants.
+//!
+//! The accesses of more than one slot, like `state_load_quad_word` or `state_clear`, are never
+//! removed, and are treated as reading, and writing if they do, any slot.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    get_effects, get_symbols, AnalysisResults, Block, ConstantValue, Context, FuelVmInstruction,
+    Function, Instruction, IrError, LocalVar, Pass, PassMutability, ScopedPass, Symbol, Type,
+    TypeOption, Value,
+};
+
+pub const STORAGEOPT_NAME: &str = "storageopt";
+
+pub fn create_storageopt_pass() -> Pass {
+    Pass {
+        name: STORAGEOPT_NAME,
+        descr: "Redundant storage load and dead storage store elimination.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(storage_opt)),
+    }
+}
+
+pub fn storage_opt(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let used = function
+        .instruction_iter(context)
+        .filter_map(|(_block, ins)| ins.get_instruction(context))
+        .flat_map(|inst| inst.get_operands())
+        .collect::<FxHashSet<_>>();
+
+    // Map each redundant load to the value it would load, and collect the dead stores.
+    let mut replacements = FxHashMap::<Value, Value>::default();
+    let mut dead_stores = FxHashSet::<Value>::default();
+    for block in function.block_iter(context) {
+        visit_block(context, block, &used, &mut replacements, &mut dead_stores);
+    }
+
+    if replacements.is_empty() && dead_stores.is_empty() {
+        return Ok(false);
+    }
+
+    function.replace_values(context, &replacements, None);
+    for block in function.block_iter(context).collect::<Vec<_>>() {
+        block.remove_instructions(context, |ins| {
+            replacements.contains_key(&ins) || dead_stores.contains(&ins)
+        });
+    }
+
+    Ok(true)
+}
+
+/// The expression identifying a storage slot.
+#[derive(Clone, Copy)]
+enum SlotKey {
+    /// The `b256` value stored to the key.
+    Value(Value),
+    /// The key pointer, whose content is unknown.
+    Pointer(Value),
+}
+
+/// A memory location within a local, given by the constant indices into it.
+type Location = (LocalVar, Vec<u64>);
+
+/// The state of storage and of the keys while going through a block.
+#[derive(Default)]
+struct BlockState {
+    /// The slots whose values are known, with the value.
+    available: Vec<(SlotKey, Value)>,
+    /// The stores which haven't been read from yet.
+    pending_stores: Vec<(SlotKey, Value)>,
+    /// The `b256` values last stored to the locations.
+    key_values: FxHashMap<Location, Value>,
+}
+
+fn visit_block(
+    context: &Context,
+    block: Block,
+    used: &FxHashSet<Value>,
+    replacements: &mut FxHashMap<Value, Value>,
+    dead_stores: &mut FxHashSet<Value>,
+) {
+    let mut state = BlockState::default();
+
+    for ins in block.instruction_iter(context) {
+        let Some(inst) = ins.get_instruction(context) else {
+            continue;
+        };
+        match inst {
+            Instruction::FuelVm(FuelVmInstruction::StateLoadWord(key)) => {
+                let slot = state.slot_key(context, *key);
+                state.read_slot(context, slot);
+                match state
+                    .available
+                    .iter()
+                    .find(|(known, _)| same_slot(context, *known, slot))
+                {
+                    Some((_, value)) => {
+                        replacements.insert(ins, *value);
+                    }
+                    None => state.available.push((slot, ins)),
+                }
+            }
+            Instruction::FuelVm(FuelVmInstruction::StateStoreWord { stored_val, key }) => {
+                let slot = state.slot_key(context, *key);
+                // A store whose result is used sees if the slot was set, so it reads the slot,
+                // and it can't be removed itself.
+                let result_used = used.contains(&ins);
+                if result_used {
+                    state.read_slot(context, slot);
+                } else {
+                    state.pending_stores.retain(|(pending, store)| {
+                        let overwritten = same_slot(context, *pending, slot);
+                        if overwritten {
+                            dead_stores.insert(*store);
+                        }
+                        !overwritten
+                    });
+                }
+                state
+                    .available
+                    .retain(|(known, _)| !may_be_same_slot(context, *known, slot));
+                state.available.push((slot, *stored_val));
+                if !result_used {
+                    state.pending_stores.push((slot, ins));
+                }
+            }
+            Instruction::FuelVm(FuelVmInstruction::StateLoadQuadWord { load_val, .. }) => {
+                state.pending_stores.clear();
+                state.write_memory(context, Some(*load_val));
+            }
+            Instruction::FuelVm(
+                FuelVmInstruction::StateStoreQuadWord { .. } | FuelVmInstruction::StateClear { .. },
+            ) => {
+                // These tell whether the slots were set, so they read them as well.
+                state.pending_stores.clear();
+                state.available.clear();
+            }
+            Instruction::Call(callee, _) => {
+                let effects = get_effects(context, *callee);
+                if effects.reads_storage || effects.calls_contracts || effects.other {
+                    state.pending_stores.clear();
+                }
+                if effects.writes_storage || effects.calls_contracts || effects.other {
+                    state.available.clear();
+                }
+                if effects.writes_memory || effects.other {
+                    state.write_memory(context, None);
+                }
+            }
+            Instruction::AsmBlock(..)
+            | Instruction::ContractCall { .. }
+            | Instruction::FuelVm(FuelVmInstruction::Smo { .. }) => {
+                state.pending_stores.clear();
+                state.available.clear();
+                state.write_memory(context, None);
+            }
+            Instruction::Store {
+                dst_val_ptr,
+                stored_val,
+            } => {
+                state.write_memory(context, Some(*dst_val_ptr));
+                if stored_val.get_type(context).is(Type::is_b256, context) {
+                    if let Some(location) = get_location(context, *dst_val_ptr) {
+                        state.key_values.insert(location, *stored_val);
+                    }
+                }
+            }
+            Instruction::MemCopyBytes { dst_val_ptr, .. }
+            | Instruction::MemCopyVal { dst_val_ptr, .. }
+            | Instruction::FuelVm(
+                FuelVmInstruction::WideUnaryOp {
+                    result: dst_val_ptr,
+                    ..
+                }
+                | FuelVmInstruction::WideBinaryOp {
+                    result: dst_val_ptr,
+                    ..
+                }
+                | FuelVmInstruction::WideModularOp {
+                    result: dst_val_ptr,
+                    ..
+                },
+            ) => state.write_memory(context, Some(*dst_val_ptr)),
+            _ => {
+                if inst.may_have_side_effect() {
+                    state.write_memory(context, None);
+                }
+            }
+        }
+    }
+}
+
+impl BlockState {
+    /// Returns the key expression for the slot the `key` pointer points to.
+    fn slot_key(&self, context: &Context, key: Value) -> SlotKey {
+        get_location(context, key)
+            .and_then(|location| self.key_values.get(&location))
+            .map_or(SlotKey::Pointer(key), |value| SlotKey::Value(*value))
+    }
+
+    /// Stops tracking the stores which may be read by a load of the `slot`.
+    fn read_slot(&mut self, context: &Context, slot: SlotKey) {
+        self.pending_stores
+            .retain(|(pending, _)| !may_be_same_slot(context, *pending, slot));
+    }
+
+    /// Forgets the keys which may be changed by a write to the memory pointed to by `ptr`, or by
+    /// a write to any memory if `ptr` isn't given.
+    fn write_memory(&mut self, context: &Context, ptr: Option<Value>) {
+        // The key pointers may point anywhere, so a write to any memory may change them.
+        let is_value = |slot: &SlotKey| matches!(slot, SlotKey::Value(_));
+        self.available.retain(|(slot, _)| is_value(slot));
+        self.pending_stores.retain(|(slot, _)| is_value(slot));
+
+        let symbols = ptr.map(|ptr| get_symbols(context, ptr)).unwrap_or_default();
+        if symbols.is_empty() {
+            self.key_values.clear();
+        } else {
+            self.key_values.retain(|(local, _), _| {
+                !symbols
+                    .iter()
+                    .any(|symbol| *symbol == Symbol::Local(*local))
+            });
+        }
+    }
+}
+
+/// Returns the location within a local the `ptr` points to, if it is known.
+fn get_location(context: &Context, ptr: Value) -> Option<Location> {
+    match ptr.get_instruction(context)? {
+        Instruction::GetLocal(local) => Some((*local, Vec::new())),
+        Instruction::GetElemPtr { base, indices, .. } => {
+            let (local, mut base_indices) = get_location(context, *base)?;
+            for idx in indices {
+                match idx.get_constant(context)?.value {
+                    ConstantValue::Uint(idx) => base_indices.push(idx),
+                    _ => return None,
+                }
+            }
+            Some((local, base_indices))
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the keys `a` and `b` are provably the same slot.
+fn same_slot(context: &Context, a: SlotKey, b: SlotKey) -> bool {
+    match (a, b) {
+        (SlotKey::Value(a), SlotKey::Value(b)) => {
+            a == b
+                || match (a.get_constant(context), b.get_constant(context)) {
+                    (Some(const_a), Some(const_b)) => const_a.eq(context, const_b),
+                    _ => false,
+                }
+        }
+        (SlotKey::Pointer(a), SlotKey::Pointer(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Returns false only if the keys `a` and `b` are provably different slots.
+fn may_be_same_slot(context: &Context, a: SlotKey, b: SlotKey) -> bool {
+    match (a, b) {
+        (SlotKey::Value(a), SlotKey::Value(b)) => {
+            match (a.get_constant(context), b.get_constant(context)) {
+                (Some(const_a), Some(const_b)) => const_a.eq(context, const_b),
+                _ => true,
+            }
+        }
+        _ => true,
+    }
+}
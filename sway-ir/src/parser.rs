@@ -52,6 +52,7 @@ mod ir_builder {
             rule module_kind() -> Kind
                 = "script" _ { Kind::Script }
                 / "predicate" _ { Kind::Predicate }
+                / "library" _ { Kind::Library }
 
             rule contract() -> IrAstModule
                 = "contract" _ "{" _
@@ -177,6 +178,7 @@ mod ir_builder {
             rule operation() -> IrAstOperation
                 = op_asm()
                 / op_wide_unary()
+                / op_wide_modular_operation()
                 / op_wide_binary()
                 / op_wide_cmp()
                 / op_branch()
@@ -203,6 +205,7 @@ mod ir_builder {
                 / op_ret()
                 / op_revert()
                 / op_smo()
+                / op_state_clear()
                 / op_state_load_quad_word()
                 / op_state_load_word()
                 / op_state_store_quad_word()
@@ -288,6 +291,12 @@ mod ir_builder {
                 = "const" _ val_ty:ast_ty() cv:constant() {
                     IrAstOperation::Const(val_ty, cv)
                 }
+                / "const" _ val_ty:ast_ty() "undef" _ {
+                    IrAstOperation::Const(
+                        val_ty.clone(),
+                        IrAstConst { value: IrAstConstValue::Undef(val_ty), meta_idx: None }
+                    )
+                }
 
             rule op_contract_call() -> IrAstOperation
                 = "contract_call" _
@@ -494,10 +503,8 @@ mod ir_builder {
                 }
 
             rule array_const() -> IrAstConstValue
-                = "[" _ els:(field_or_element_const() ++ comma()) "]" _ {
-                    let el_ty = els[0].0.clone();
-                    let els = els.into_iter().map(|(_, cv)| cv).collect::<Vec<_>>();
-                    IrAstConstValue::Array(el_ty, els)
+                = "[" _ els:(field_or_element_const() ** comma()) "]" _ {
+                    IrAstConstValue::Array(els)
                 }
 
             rule struct_const() -> IrAstConstValue
@@ -513,17 +520,21 @@ mod ir_builder {
                     (ty.clone(), IrAstConst { value: IrAstConstValue::Undef(ty), meta_idx: None })
                 }
 
+            // The names of the types are keywords, which only match whole words, so that names like
+            // `bool_flag` or `slice_len` are not taken for types.
             rule ast_ty() -> IrAstTy
-                = ("unit" / "()") _ { IrAstTy::Unit }
-                / "bool" _ { IrAstTy::Bool }
-                / "u64" _ { IrAstTy::U64 }
-                / "u256" _ { IrAstTy::U256 }
-                / "b256" _ { IrAstTy::B256 }
-                / "string" _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
+                = ("unit" !id_char() / "()") _ { IrAstTy::Unit }
+                / "bool" !id_char() _ { IrAstTy::Bool }
+                / "u8" !id_char() _ { IrAstTy::U8 }
+                / "u64" !id_char() _ { IrAstTy::U64 }
+                / "u256" !id_char() _ { IrAstTy::U256 }
+                / "b256" !id_char() _ { IrAstTy::B256 }
+                / "slice" !id_char() _ { IrAstTy::Slice }
+                / "string" !id_char() _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
                 / array_ty()
                 / struct_ty()
                 / union_ty()
-                / "ptr" _ ty:ast_ty() { IrAstTy::Ptr(Box::new(ty)) }
+                / "ptr" !id_char() _ ty:ast_ty() { IrAstTy::Ptr(Box::new(ty)) }
 
             rule array_ty() -> IrAstTy
                 = "[" _ ty:ast_ty() ";" _ c:decimal() "]" _ {
@@ -581,9 +592,9 @@ mod ir_builder {
                 / "!" idx:dec_digits() __ {
                     IrMetadatum::Index(idx)
                 }
-                / ['"'] s:$(([^ '"' | '\\'] / ['\\'] ['\\' | '"' ])+) ['"'] __ {
-                    // Metadata strings are printed with '\\' escaped on parsing we unescape it.
-                    IrMetadatum::String(s.to_owned().replace("\\\\", "\\"))
+                / ['"'] s:$(([^ '"' | '\\'] / ['\\'] ['\\' | '"' | 'n' | 'r' | 't' | '0'])*) ['"'] __ {
+                    // Metadata strings are printed escaped, the way Rust debug prints strings.
+                    IrMetadatum::String(unescape_md_string(s))
                 }
                 / tag:$(id_char0() id_char()*) __ els:metadata_item()* {
                     IrMetadatum::Struct(tag.to_owned(), els)
@@ -754,7 +765,7 @@ mod ir_builder {
         Hex256([u8; 32]),
         Number(u64),
         String(Vec<u8>),
-        Array(IrAstTy, Vec<IrAstConst>),
+        Array(Vec<(IrAstTy, IrAstConst)>),
         Struct(Vec<(IrAstTy, IrAstConst)>),
     }
 
@@ -788,10 +799,10 @@ mod ir_builder {
                 },
                 IrAstConstValue::Number(n) => ConstantValue::Uint(*n),
                 IrAstConstValue::String(bs) => ConstantValue::String(bs.clone()),
-                IrAstConstValue::Array(el_ty, els) => {
+                IrAstConstValue::Array(els) => {
                     let els: Vec<_> = els
                         .iter()
-                        .map(|cv| cv.value.as_constant(context, el_ty.clone()))
+                        .map(|(ty, cv)| cv.value.as_constant(context, ty.clone()))
                         .collect();
                     ConstantValue::Array(els)
                 }
@@ -814,7 +825,10 @@ mod ir_builder {
 
         fn as_value(&self, context: &mut Context, val_ty: IrAstTy) -> Value {
             match self {
-                IrAstConstValue::Undef(_) => unreachable!("Can't convert 'undef' to a value."),
+                IrAstConstValue::Undef(_) => {
+                    let undef_const = self.as_constant(context, val_ty);
+                    Value::new_constant(context, undef_const)
+                }
                 IrAstConstValue::Unit => Constant::get_unit(context),
                 IrAstConstValue::Bool(b) => Constant::get_bool(context, *b),
                 IrAstConstValue::Hex256(bs) => match val_ty {
@@ -825,7 +839,10 @@ mod ir_builder {
                     IrAstTy::B256 => Constant::get_b256(context, *bs),
                     _ => unreachable!("invalid type for hex number"),
                 },
-                IrAstConstValue::Number(n) => Constant::get_uint(context, 64, *n),
+                IrAstConstValue::Number(n) => match val_ty {
+                    IrAstTy::U8 => Constant::get_uint(context, 8, *n),
+                    _ => Constant::get_uint(context, 64, *n),
+                },
                 IrAstConstValue::String(s) => Constant::get_string(context, s.clone()),
                 IrAstConstValue::Array(..) => {
                     let array_const = self.as_constant(context, val_ty);
@@ -843,9 +860,11 @@ mod ir_builder {
     enum IrAstTy {
         Unit,
         Bool,
+        U8,
         U64,
         U256,
         B256,
+        Slice,
         String(u64),
         Array(Box<IrAstTy>, u64),
         Union(Vec<IrAstTy>),
@@ -858,9 +877,11 @@ mod ir_builder {
             match self {
                 IrAstTy::Unit => Type::get_unit(context),
                 IrAstTy::Bool => Type::get_bool(context),
+                IrAstTy::U8 => Type::get_uint8(context),
                 IrAstTy::U64 => Type::get_uint64(context),
                 IrAstTy::U256 => Type::get_uint256(context),
                 IrAstTy::B256 => Type::get_b256(context),
+                IrAstTy::Slice => Type::get_slice(context),
                 IrAstTy::String(n) => Type::new_string(context, *n),
                 IrAstTy::Array(el_ty, count) => {
                     let el_ty = el_ty.to_ir_type(context);
@@ -1052,7 +1073,10 @@ mod ir_builder {
                                     IrAstAsmArgInit::Imm(cv) => {
                                         cv.value.as_value(context, IrAstTy::U64).add_metadatum(
                                             context,
-                                            self.md_map.get(cv.meta_idx.as_ref().unwrap()).copied(),
+                                            cv.meta_idx
+                                                .as_ref()
+                                                .and_then(|mdi| self.md_map.get(mdi))
+                                                .copied(),
                                         )
                                     }
                                 }),
@@ -1484,6 +1508,28 @@ mod ir_builder {
         md_map
     }
 
+    /// Undoes the escaping of a metadata string, which is printed the way Rust debug prints
+    /// strings.
+    fn unescape_md_string(s: &str) -> String {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(ch) = chars.next() {
+            if ch != '\\' {
+                unescaped.push(ch);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('t') => unescaped.push('\t'),
+                Some('0') => unescaped.push('\0'),
+                Some(escaped) => unescaped.push(escaped),
+                None => unescaped.push('\\'),
+            }
+        }
+        unescaped
+    }
+
     fn string_to_hex<const N: usize>(s: &str) -> [u8; N] {
         let mut bytes: [u8; N] = [0; N];
         let mut cur_byte: u8 = 0;
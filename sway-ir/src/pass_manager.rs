@@ -1,11 +1,13 @@
 use crate::{
     create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_func_dce_pass, create_inline_in_main_pass, create_inline_in_module_pass,
+    create_cse_pass, create_dce_pass, create_dom_fronts_pass, create_dominators_pass,
+    create_effects_pass, create_escaped_symbols_pass, create_func_dce_pass,
+    create_inline_in_main_pass, create_inline_in_module_pass, create_licm_pass,
     create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass,
     create_module_printer_pass, create_module_verifier_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, Context, Function, IrError, Module,
-    CONSTCOMBINE_NAME, DCE_NAME, FUNC_DCE_NAME, INLINE_MODULE_NAME, MEM2REG_NAME, SIMPLIFYCFG_NAME,
+    create_ret_demotion_pass, create_simplify_cfg_pass, create_storageopt_pass, Context, Function,
+    IrError, Module, CONSTCOMBINE_NAME, CSE_NAME, DCE_NAME, EFFECTS_NAME, FUNC_DCE_NAME,
+    INLINE_MODULE_NAME, LICM_NAME, MEM2REG_NAME, SIMPLIFYCFG_NAME, STORAGEOPT_NAME,
 };
 use downcast_rs::{impl_downcast, Downcast};
 use rustc_hash::FxHashMap;
@@ -144,6 +146,7 @@ impl AnalysisResults {
 pub struct PassManager {
     passes: FxHashMap<&'static str, Pass>,
     analyses: AnalysisResults,
+    verify_after_each: bool,
 }
 
 impl PassManager {
@@ -176,6 +179,12 @@ impl PassManager {
         pass_name
     }
 
+    /// Verify the IR each time a transformation pass modifies it. The errors name the pass, so
+    /// that a pass which leaves the IR invalid is caught right away, rather than in codegen.
+    pub fn verify_after_each(&mut self, verify: bool) {
+        self.verify_after_each = verify;
+    }
+
     fn actually_run(&mut self, ir: &mut Context, pass: &'static str) -> Result<bool, IrError> {
         let mut modified = false;
         let pass_t = self.passes.get(pass).expect("Unregistered pass");
@@ -203,6 +212,9 @@ impl PassManager {
                             for f in m.function_iter(ir) {
                                 self.analyses.invalidate_all_results_at_scope(f);
                             }
+                            if self.verify_after_each {
+                                ir.verify_module_after_pass(pass_t.name, m)?;
+                            }
                             modified = true;
                         }
                     }
@@ -220,6 +232,9 @@ impl PassManager {
                                 if transform(ir, &self.analyses, f)? {
                                     self.analyses.invalidate_all_results_at_scope(f);
                                     self.analyses.invalidate_all_results_at_scope(m);
+                                    if self.verify_after_each {
+                                        ir.verify_function_after_pass(pass_t.name, m, f)?;
+                                    }
                                     modified = true;
                                 }
                             }
@@ -234,8 +249,24 @@ impl PassManager {
     /// Run the passes specified in `config`.
     pub fn run(&mut self, ir: &mut Context, passes: &PassGroup) -> Result<bool, IrError> {
         let mut modified = false;
-        for pass in passes.flatten_pass_group() {
-            modified |= self.actually_run(ir, pass)?;
+        for pass_or_group in &passes.0 {
+            modified |= match pass_or_group {
+                PassOrGroup::Pass(pass) => self.actually_run(ir, pass)?,
+                PassOrGroup::Group(pg) => self.run(ir, pg)?,
+                PassOrGroup::Fixpoint(pg) => self.run_to_fixpoint(ir, pg)?,
+            };
+        }
+        Ok(modified)
+    }
+
+    /// Run the passes in `passes` repeatedly, until none of them modifies the IR anymore.
+    fn run_to_fixpoint(&mut self, ir: &mut Context, passes: &PassGroup) -> Result<bool, IrError> {
+        let mut modified = false;
+        for _ in 0..MAX_FIXPOINT_ITERATIONS {
+            if !self.run(ir, passes)? {
+                break;
+            }
+            modified = true;
         }
         Ok(modified)
     }
@@ -257,6 +288,10 @@ impl PassManager {
     }
 }
 
+/// The number of times a [PassOrGroup::Fixpoint] group is run at most. Passes are expected to
+/// reach a fixpoint well before it, it only guards against passes which never settle.
+const MAX_FIXPOINT_ITERATIONS: usize = 32;
+
 /// A group of passes.
 /// Can contain sub-groups.
 #[derive(Default)]
@@ -266,24 +301,11 @@ pub struct PassGroup(Vec<PassOrGroup>);
 pub enum PassOrGroup {
     Pass(&'static str),
     Group(PassGroup),
+    /// A group which is run repeatedly until none of its passes modifies the IR.
+    Fixpoint(PassGroup),
 }
 
 impl PassGroup {
-    // Flatten a group of passes into an ordered list.
-    fn flatten_pass_group(&self) -> Vec<&'static str> {
-        let mut output = Vec::<&str>::new();
-        fn inner(output: &mut Vec<&str>, input: &PassGroup) {
-            for pass_or_group in &input.0 {
-                match pass_or_group {
-                    PassOrGroup::Pass(pass) => output.push(pass),
-                    PassOrGroup::Group(pg) => inner(output, pg),
-                }
-            }
-        }
-        inner(&mut output, self);
-        output
-    }
-
     /// Append a pass to this group.
     pub fn append_pass(&mut self, pass: &'static str) {
         self.0.push(PassOrGroup::Pass(pass));
@@ -293,6 +315,11 @@ impl PassGroup {
     pub fn append_group(&mut self, group: PassGroup) {
         self.0.push(PassOrGroup::Group(group));
     }
+
+    /// Append a pass group which is run until it reaches a fixpoint.
+    pub fn append_fixpoint_group(&mut self, group: PassGroup) {
+        self.0.push(PassOrGroup::Fixpoint(group));
+    }
 }
 
 /// A convenience utility to register known passes.
@@ -308,8 +335,11 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_mem2reg_pass());
     pm.register(create_inline_in_module_pass());
     pm.register(create_inline_in_main_pass());
+    pm.register(create_effects_pass());
     pm.register(create_const_combine_pass());
+    pm.register(create_cse_pass());
     pm.register(create_simplify_cfg_pass());
+    pm.register(create_licm_pass());
     pm.register(create_func_dce_pass());
     pm.register(create_dce_pass());
     pm.register(create_arg_demotion_pass());
@@ -317,6 +347,7 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_ret_demotion_pass());
     pm.register(create_misc_demotion_pass());
     pm.register(create_memcpyopt_pass());
+    pm.register(create_storageopt_pass());
 }
 
 pub fn create_o1_pass_group() -> PassGroup {
@@ -325,10 +356,19 @@ pub fn create_o1_pass_group() -> PassGroup {
     // Configure to run our passes.
     o1.append_pass(MEM2REG_NAME);
     o1.append_pass(INLINE_MODULE_NAME);
-    o1.append_pass(CONSTCOMBINE_NAME);
-    o1.append_pass(SIMPLIFYCFG_NAME);
-    o1.append_pass(CONSTCOMBINE_NAME);
-    o1.append_pass(SIMPLIFYCFG_NAME);
+    // The effects of the calls left after inlining tell which of them may be folded or moved.
+    o1.append_pass(EFFECTS_NAME);
+    // Folding constants removes branches, and removing branches exposes more constants.
+    let mut fold = PassGroup::default();
+    fold.append_pass(CONSTCOMBINE_NAME);
+    fold.append_pass(CSE_NAME);
+    fold.append_pass(SIMPLIFYCFG_NAME);
+    fold.append_pass(DCE_NAME);
+    o1.append_fixpoint_group(fold);
+    // The storage keys are constants once folded, which tells the slots apart.
+    o1.append_pass(STORAGEOPT_NAME);
+    // Only the code left in the loops after folding is worth hoisting.
+    o1.append_pass(LICM_NAME);
     o1.append_pass(FUNC_DCE_NAME);
     o1.append_pass(DCE_NAME);
 
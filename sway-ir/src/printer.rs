@@ -18,8 +18,8 @@ use crate::{
     metadata::{MetadataIndex, Metadatum},
     module::{Kind, ModuleContent},
     value::{Value, ValueContent, ValueDatum},
-    AnalysisResult, AnalysisResultT, AnalysisResults, BinaryOpKind, BlockArgument, IrError, Module,
-    Pass, PassMutability, ScopedPass, UnaryOpKind,
+    AnalysisResult, AnalysisResultT, AnalysisResults, BinaryOpKind, BlockArgument,
+    DebugWithContext, IrError, Module, Pass, PassMutability, ScopedPass, UnaryOpKind,
 };
 
 #[derive(Debug)]
@@ -101,6 +101,38 @@ pub fn to_string(context: &Context) -> String {
         .build()
 }
 
+/// Pretty-print the instruction `ins` of the `function` to a string, naming the values the way
+/// they are named when the whole function is printed.
+///
+/// The metadata indices are local to the instruction, and don't match the ones of the module.
+pub fn instruction_to_string(context: &Context, function: Function, ins: Value) -> String {
+    let mut md_namer = MetadataNamer::default();
+    let mut namer = Namer::new(function, GlobalNamer::new());
+    for block in function.block_iter(context) {
+        for arg_val in block.arg_iter(context) {
+            namer.name(context, arg_val);
+        }
+        for block_ins in block.instruction_iter(context) {
+            if block_ins == ins {
+                // Name the constant operands first, so that only the instruction is printed.
+                for operand in ins
+                    .get_instruction(context)
+                    .map(Instruction::get_operands)
+                    .unwrap_or_default()
+                {
+                    maybe_constant_to_doc(context, &mut md_namer, &mut namer, &operand);
+                }
+                return instruction_to_doc(context, &mut md_namer, &mut namer, &block, &ins)
+                    .build()
+                    .trim()
+                    .to_owned();
+            }
+            instruction_to_doc(context, &mut md_namer, &mut namer, &block, &block_ins);
+        }
+    }
+    format!("{:?}", ins.with_context(context))
+}
+
 pub struct ModulePrinterResult;
 impl AnalysisResultT for ModulePrinterResult {}
 
@@ -969,12 +1001,10 @@ fn asm_block_to_doc(
                 .append(
                     return_name
                         .as_ref()
-                        .map(|rn| {
-                            Doc::text(format!(" -> {} {rn}", return_type.as_string(context)))
-                                .append(md_namer.md_idx_to_doc(context, metadata))
-                        })
+                        .map(|rn| Doc::text(format!(" -> {} {rn}", return_type.as_string(context))))
                         .unwrap_or(Doc::Empty),
                 )
+                .append(md_namer.md_idx_to_doc(context, metadata))
                 .append(Doc::text(" {")),
         ))
         .append(Doc::indent(
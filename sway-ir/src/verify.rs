@@ -3,12 +3,17 @@
 //! During creation, deserialization and optimization the IR should be verified to be in a
 //! consistent valid state, using the functions in this module.
 
+use rustc_hash::FxHashMap;
+
 use crate::{
-    block::BlockContent,
+    block::{Block, BlockContent},
+    compute_dom_tree, compute_post_order,
     context::Context,
+    dominates,
     error::IrError,
     function::{Function, FunctionContent},
     instruction::{FuelVmInstruction, Instruction, Predicate},
+    instruction_to_string,
     irtype::Type,
     local_var::LocalVar,
     metadata::{MetadataIndex, Metadatum},
@@ -58,10 +63,43 @@ impl<'eng> Context<'eng> {
         Ok(())
     }
 
+    /// Verify the `module` after the `pass` modified it, reporting the `pass` in the errors.
+    pub(crate) fn verify_module_after_pass(
+        &self,
+        pass: &str,
+        module: Module,
+    ) -> Result<(), IrError> {
+        self.verify_module(&self.modules[module.0])
+            .map_err(|error| IrError::VerifyFailedAfterPass(pass.to_owned(), Box::new(error)))
+    }
+
+    /// Verify the `function` of the `module` after the `pass` modified it, reporting the `pass`
+    /// in the errors.
+    pub(crate) fn verify_function_after_pass(
+        &self,
+        pass: &str,
+        module: Module,
+        function: Function,
+    ) -> Result<(), IrError> {
+        self.verify_function(&self.modules[module.0], &function)
+            .map_err(|error| IrError::VerifyFailedAfterPass(pass.to_owned(), Box::new(error)))
+    }
+
     fn verify_function(
         &self,
         cur_module: &ModuleContent,
         function: &Function,
+    ) -> Result<(), IrError> {
+        self.verify_function_content(cur_module, function)
+            .map_err(|error| {
+                IrError::VerifyFailedInFunction(function.get_name(self).to_owned(), Box::new(error))
+            })
+    }
+
+    fn verify_function_content(
+        &self,
+        cur_module: &ModuleContent,
+        function: &Function,
     ) -> Result<(), IrError> {
         let entry_block = function.get_entry_block(self);
         // Ensure that the entry block arguments are same as function arguments.
@@ -74,20 +112,26 @@ impl<'eng> Context<'eng> {
             }
         }
 
-        let function = &self.functions[function.0];
-        for block in &function.blocks {
-            self.verify_block(cur_module, function, &self.blocks[block.0])?;
+        let function_content = &self.functions[function.0];
+        for block in &function_content.blocks {
+            self.verify_block(cur_module, *function, function_content, *block)
+                .map_err(|error| {
+                    IrError::VerifyFailedInBlock(block.get_label(self), Box::new(error))
+                })?;
         }
-        self.verify_metadata(function.metadata)?;
+        self.verify_dominance(*function)?;
+        self.verify_metadata(function_content.metadata)?;
         Ok(())
     }
 
     fn verify_block(
         &self,
         cur_module: &ModuleContent,
+        function: Function,
         cur_function: &FunctionContent,
-        block: &BlockContent,
+        block: Block,
     ) -> Result<(), IrError> {
+        let block = &self.blocks[block.0];
         if block.instructions.len() <= 1 && block.preds.is_empty() {
             // Empty unreferenced blocks are a harmless artefact.
             return Ok(());
@@ -103,6 +147,7 @@ impl<'eng> Context<'eng> {
         InstructionVerifier {
             context: self,
             cur_module,
+            function,
             cur_function,
             cur_block: block,
         }
@@ -125,6 +170,88 @@ impl<'eng> Context<'eng> {
         }
     }
 
+    /// Verify that the reachable blocks of the `function` are listed as the predecessors of the
+    /// blocks they branch to, and that the values are defined in the blocks which dominate their
+    /// uses. The unreachable blocks are left alone, as the passes don't keep them consistent.
+    fn verify_dominance(&self, function: Function) -> Result<(), IrError> {
+        let po = compute_post_order(self, &function);
+        for block in &po.po_to_block {
+            for BranchToWithArgs { block: succ, .. } in block.successors(self) {
+                if !succ.pred_iter(self).any(|pred| pred == block) {
+                    return Err(IrError::VerifyFailedInBlock(
+                        block.get_label(self),
+                        Box::new(IrError::VerifyBlockPredecessorMissing(
+                            succ.get_label(self),
+                            block.get_label(self),
+                        )),
+                    ));
+                }
+            }
+        }
+
+        // The block of each instruction, and its position in it.
+        let positions = function
+            .block_iter(self)
+            .flat_map(|block| {
+                block
+                    .instruction_iter(self)
+                    .enumerate()
+                    .map(move |(idx, ins)| (ins, (block, idx)))
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let dom_tree = compute_dom_tree(self, &function, &po);
+        for block in po.po_to_block.iter().rev() {
+            for (use_idx, ins) in block.instruction_iter(self).enumerate() {
+                let operands = ins
+                    .get_instruction(self)
+                    .map(Instruction::get_operands)
+                    .unwrap_or_default();
+                for operand in operands {
+                    let is_dominated = match &self.values[operand.0].value {
+                        ValueDatum::Argument(BlockArgument {
+                            block: def_block, ..
+                        }) => {
+                            if !function.block_iter(self).any(|block| block == *def_block) {
+                                Err(IrError::VerifyUseOfValueNotInFunction)
+                            } else {
+                                Ok(dominates(&dom_tree, *def_block, *block))
+                            }
+                        }
+                        ValueDatum::Instruction(_) => match positions.get(&operand) {
+                            None => Err(IrError::VerifyUseOfValueNotInFunction),
+                            Some((def_block, def_idx)) if def_block == block => {
+                                Ok(*def_idx < use_idx)
+                            }
+                            Some((def_block, _)) => Ok(dominates(&dom_tree, *def_block, *block)),
+                        },
+                        ValueDatum::Configurable(_) | ValueDatum::Constant(_) => Ok(true),
+                    };
+                    let error = match is_dominated {
+                        Ok(true) => continue,
+                        Ok(false) => {
+                            IrError::VerifyUseNotDominated(match &self.values[operand.0].value {
+                                ValueDatum::Argument(BlockArgument { block, idx, .. }) => {
+                                    format!("argument {idx} of block '{}'", block.get_label(self))
+                                }
+                                _ => instruction_to_string(self, function, operand),
+                            })
+                        }
+                        Err(error) => error,
+                    };
+                    return Err(IrError::VerifyFailedInBlock(
+                        block.get_label(self),
+                        Box::new(IrError::VerifyFailedAtInstruction(
+                            instruction_to_string(self, function, ins),
+                            Box::new(error),
+                        )),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn verify_metadata(&self, md_idx: Option<MetadataIndex>) -> Result<(), IrError> {
         // For now we check only that struct tags are valid identiers.
         if let Some(md_idx) = md_idx {
@@ -162,6 +289,7 @@ impl<'eng> Context<'eng> {
 struct InstructionVerifier<'a, 'eng> {
     context: &'a Context<'eng>,
     cur_module: &'a ModuleContent,
+    function: Function,
     cur_function: &'a FunctionContent,
     cur_block: &'a BlockContent,
 }
@@ -169,124 +297,132 @@ struct InstructionVerifier<'a, 'eng> {
 impl<'a, 'eng> InstructionVerifier<'a, 'eng> {
     fn verify_instructions(&self) -> Result<(), IrError> {
         for ins in &self.cur_block.instructions {
-            let value_content = &self.context.values[ins.0];
-            if let ValueDatum::Instruction(instruction) = &value_content.value {
-                match instruction {
-                    Instruction::AsmBlock(..) => (),
-                    Instruction::BitCast(value, ty) => self.verify_bitcast(value, ty)?,
-                    Instruction::UnaryOp { op, arg } => self.verify_unary_op(op, arg)?,
-                    Instruction::BinaryOp { op, arg1, arg2 } => {
-                        self.verify_binary_op(op, arg1, arg2)?
-                    }
-                    Instruction::Branch(block) => self.verify_br(block)?,
-                    Instruction::Call(func, args) => self.verify_call(func, args)?,
-                    Instruction::CastPtr(val, ty) => self.verify_cast_ptr(val, ty)?,
-                    Instruction::Cmp(pred, lhs_value, rhs_value) => {
-                        self.verify_cmp(pred, lhs_value, rhs_value)?
+            self.verify_instruction(ins).map_err(|error| {
+                IrError::VerifyFailedAtInstruction(
+                    instruction_to_string(self.context, self.function, *ins),
+                    Box::new(error),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    fn verify_instruction(&self, ins: &Value) -> Result<(), IrError> {
+        let value_content = &self.context.values[ins.0];
+        if let ValueDatum::Instruction(instruction) = &value_content.value {
+            match instruction {
+                Instruction::AsmBlock(..) => (),
+                Instruction::BitCast(value, ty) => self.verify_bitcast(value, ty)?,
+                Instruction::UnaryOp { op, arg } => self.verify_unary_op(op, arg)?,
+                Instruction::BinaryOp { op, arg1, arg2 } => {
+                    self.verify_binary_op(op, arg1, arg2)?
+                }
+                Instruction::Branch(block) => self.verify_br(block)?,
+                Instruction::Call(func, args) => self.verify_call(func, args)?,
+                Instruction::CastPtr(val, ty) => self.verify_cast_ptr(val, ty)?,
+                Instruction::Cmp(pred, lhs_value, rhs_value) => {
+                    self.verify_cmp(pred, lhs_value, rhs_value)?
+                }
+                Instruction::ConditionalBranch {
+                    cond_value,
+                    true_block,
+                    false_block,
+                } => self.verify_cbr(cond_value, true_block, false_block)?,
+                Instruction::ContractCall {
+                    params,
+                    coins,
+                    asset_id,
+                    gas,
+                    ..
+                } => self.verify_contract_call(params, coins, asset_id, gas)?,
+
+                // XXX move the fuelvm verification into a module
+                Instruction::FuelVm(fuel_vm_instr) => match fuel_vm_instr {
+                    FuelVmInstruction::Gtf { index, tx_field_id } => {
+                        self.verify_gtf(index, tx_field_id)?
                     }
-                    Instruction::ConditionalBranch {
-                        cond_value,
-                        true_block,
-                        false_block,
-                    } => self.verify_cbr(cond_value, true_block, false_block)?,
-                    Instruction::ContractCall {
-                        params,
+                    FuelVmInstruction::Log {
+                        log_val,
+                        log_ty,
+                        log_id,
+                    } => self.verify_log(log_val, log_ty, log_id)?,
+                    FuelVmInstruction::ReadRegister(_) => (),
+                    FuelVmInstruction::Revert(val) => self.verify_revert(val)?,
+                    FuelVmInstruction::Smo {
+                        recipient,
+                        message,
+                        message_size,
                         coins,
-                        asset_id,
-                        gas,
-                        ..
-                    } => self.verify_contract_call(params, coins, asset_id, gas)?,
-
-                    // XXX move the fuelvm verification into a module
-                    Instruction::FuelVm(fuel_vm_instr) => match fuel_vm_instr {
-                        FuelVmInstruction::Gtf { index, tx_field_id } => {
-                            self.verify_gtf(index, tx_field_id)?
-                        }
-                        FuelVmInstruction::Log {
-                            log_val,
-                            log_ty,
-                            log_id,
-                        } => self.verify_log(log_val, log_ty, log_id)?,
-                        FuelVmInstruction::ReadRegister(_) => (),
-                        FuelVmInstruction::Revert(val) => self.verify_revert(val)?,
-                        FuelVmInstruction::Smo {
-                            recipient,
-                            message,
-                            message_size,
-                            coins,
-                        } => self.verify_smo(recipient, message, message_size, coins)?,
-                        FuelVmInstruction::StateClear {
-                            key,
-                            number_of_slots,
-                        } => self.verify_state_clear(key, number_of_slots)?,
-                        FuelVmInstruction::StateLoadWord(key) => {
-                            self.verify_state_load_word(key)?
-                        }
-                        FuelVmInstruction::StateLoadQuadWord {
-                            load_val: dst_val,
-                            key,
-                            number_of_slots,
-                        }
-                        | FuelVmInstruction::StateStoreQuadWord {
-                            stored_val: dst_val,
-                            key,
-                            number_of_slots,
-                        } => self.verify_state_access_quad(dst_val, key, number_of_slots)?,
-                        FuelVmInstruction::StateStoreWord {
-                            stored_val: dst_val,
-                            key,
-                        } => self.verify_state_store_word(dst_val, key)?,
-                        FuelVmInstruction::WideUnaryOp { op, result, arg } => {
-                            self.verify_wide_unary_op(op, result, arg)?
-                        }
-                        FuelVmInstruction::WideBinaryOp {
-                            op,
-                            result,
-                            arg1,
-                            arg2,
-                        } => self.verify_wide_binary_op(op, result, arg1, arg2)?,
-                        FuelVmInstruction::WideModularOp {
-                            op,
-                            result,
-                            arg1,
-                            arg2,
-                            arg3,
-                        } => self.verify_wide_modular_op(op, result, arg1, arg2, arg3)?,
-                        FuelVmInstruction::WideCmpOp { op, arg1, arg2 } => {
-                            self.verify_wide_cmp(op, arg1, arg2)?
-                        }
-                    },
-                    Instruction::GetElemPtr {
-                        base,
-                        elem_ptr_ty,
-                        indices,
-                    } => self.verify_get_elem_ptr(base, elem_ptr_ty, indices)?,
-                    Instruction::GetLocal(local_var) => self.verify_get_local(local_var)?,
-                    Instruction::IntToPtr(value, ty) => self.verify_int_to_ptr(value, ty)?,
-                    Instruction::Load(ptr) => self.verify_load(ptr)?,
-                    Instruction::MemCopyBytes {
-                        dst_val_ptr,
-                        src_val_ptr,
-                        byte_len,
-                    } => self.verify_mem_copy_bytes(dst_val_ptr, src_val_ptr, byte_len)?,
-                    Instruction::MemCopyVal {
-                        dst_val_ptr,
-                        src_val_ptr,
-                    } => self.verify_mem_copy_val(dst_val_ptr, src_val_ptr)?,
-                    Instruction::Nop => (),
-                    Instruction::PtrToInt(val, ty) => self.verify_ptr_to_int(val, ty)?,
-                    Instruction::Ret(val, ty) => self.verify_ret(val, ty)?,
-                    Instruction::Store {
-                        dst_val_ptr,
-                        stored_val,
-                    } => self.verify_store(dst_val_ptr, stored_val)?,
-                };
-
-                // Verify the instruction metadata too.
-                self.context.verify_metadata(value_content.metadata)?;
-            } else {
-                unreachable!("Verify instruction is not an instruction.");
-            }
+                    } => self.verify_smo(recipient, message, message_size, coins)?,
+                    FuelVmInstruction::StateClear {
+                        key,
+                        number_of_slots,
+                    } => self.verify_state_clear(key, number_of_slots)?,
+                    FuelVmInstruction::StateLoadWord(key) => self.verify_state_load_word(key)?,
+                    FuelVmInstruction::StateLoadQuadWord {
+                        load_val: dst_val,
+                        key,
+                        number_of_slots,
+                    }
+                    | FuelVmInstruction::StateStoreQuadWord {
+                        stored_val: dst_val,
+                        key,
+                        number_of_slots,
+                    } => self.verify_state_access_quad(dst_val, key, number_of_slots)?,
+                    FuelVmInstruction::StateStoreWord {
+                        stored_val: dst_val,
+                        key,
+                    } => self.verify_state_store_word(dst_val, key)?,
+                    FuelVmInstruction::WideUnaryOp { op, result, arg } => {
+                        self.verify_wide_unary_op(op, result, arg)?
+                    }
+                    FuelVmInstruction::WideBinaryOp {
+                        op,
+                        result,
+                        arg1,
+                        arg2,
+                    } => self.verify_wide_binary_op(op, result, arg1, arg2)?,
+                    FuelVmInstruction::WideModularOp {
+                        op,
+                        result,
+                        arg1,
+                        arg2,
+                        arg3,
+                    } => self.verify_wide_modular_op(op, result, arg1, arg2, arg3)?,
+                    FuelVmInstruction::WideCmpOp { op, arg1, arg2 } => {
+                        self.verify_wide_cmp(op, arg1, arg2)?
+                    }
+                },
+                Instruction::GetElemPtr {
+                    base,
+                    elem_ptr_ty,
+                    indices,
+                } => self.verify_get_elem_ptr(base, elem_ptr_ty, indices)?,
+                Instruction::GetLocal(local_var) => self.verify_get_local(local_var)?,
+                Instruction::IntToPtr(value, ty) => self.verify_int_to_ptr(value, ty)?,
+                Instruction::Load(ptr) => self.verify_load(ptr)?,
+                Instruction::MemCopyBytes {
+                    dst_val_ptr,
+                    src_val_ptr,
+                    byte_len,
+                } => self.verify_mem_copy_bytes(dst_val_ptr, src_val_ptr, byte_len)?,
+                Instruction::MemCopyVal {
+                    dst_val_ptr,
+                    src_val_ptr,
+                } => self.verify_mem_copy_val(dst_val_ptr, src_val_ptr)?,
+                Instruction::Nop => (),
+                Instruction::PtrToInt(val, ty) => self.verify_ptr_to_int(val, ty)?,
+                Instruction::Ret(val, ty) => self.verify_ret(val, ty)?,
+                Instruction::Store {
+                    dst_val_ptr,
+                    stored_val,
+                } => self.verify_store(dst_val_ptr, stored_val)?,
+            };
+
+            // Verify the instruction metadata too.
+            self.context.verify_metadata(value_content.metadata)?;
+        } else {
+            unreachable!("Verify instruction is not an instruction.");
         }
         Ok(())
     }
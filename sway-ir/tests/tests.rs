@@ -2,10 +2,13 @@ use std::path::PathBuf;
 
 use sway_ir::{
     create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, optimize as opt, Context, PassGroup,
-    PassManager,
+    create_cse_pass, create_dce_pass, create_dom_fronts_pass, create_dominators_pass,
+    create_effects_pass, create_escaped_symbols_pass, create_func_dce_pass,
+    create_inline_in_module_pass, create_licm_pass, create_mem2reg_pass, create_memcpyopt_pass,
+    create_misc_demotion_pass, create_postorder_pass, create_ret_demotion_pass,
+    create_simplify_cfg_pass, create_storageopt_pass, optimize as opt, register_known_passes,
+    AnalysisResults, Context, Function, IrError, Pass, PassGroup, PassManager, PassMutability,
+    ScopedPass,
 };
 use sway_types::SourceEngine;
 
@@ -87,6 +90,13 @@ fn inline() {
             funcs.into_iter().fold(false, |acc, func| {
                 opt::inline_all_function_calls(ir, &func).unwrap() || acc
             })
+        } else if params.iter().any(|&p| p == "const_args") {
+            // Inline only the calls passing constants to arguments which decide branches.
+            funcs.into_iter().fold(false, |acc, func| {
+                opt::inline_some_function_calls(ir, &func, opt::passes_constant_branch_args)
+                    .unwrap()
+                    || acc
+            })
         } else {
             // Get the parameters from the first line.  See the inline/README.md for details.  If
             // there aren't any found then there won't be any constraints and it'll be the
@@ -119,6 +129,27 @@ fn inline() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn inline_module() {
+    run_tests("inline_module", |first_line, ir: &mut Context| {
+        // The threshold of the cost model may be given in the first line, e.g., `// threshold 8`.
+        if let Some(threshold) = first_line
+            .strip_prefix("// threshold ")
+            .and_then(|threshold| threshold.trim().parse().ok())
+        {
+            ir.inline_threshold = threshold;
+        }
+
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_inline_in_module_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 // Clippy suggests using the map iterator below directly instead of collecting from it first, but
 // if we try that then we have borrowing issues with `ir` which is used within the closure.
 #[allow(clippy::needless_collect)]
@@ -135,6 +166,64 @@ fn constants() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn cse() {
+    run_tests("cse", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_cse_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn licm() {
+    run_tests("licm", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_licm_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn effects() {
+    run_tests("effects", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_effects_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn fold() {
+    run_tests("fold", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut fold_group = PassGroup::default();
+        fold_group.append_pass(pass_mgr.register(create_const_combine_pass()));
+        fold_group.append_pass(pass_mgr.register(create_simplify_cfg_pass()));
+        let mut pass_group = PassGroup::default();
+        pass_group.append_fixpoint_group(fold_group);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[allow(clippy::needless_collect)]
 #[test]
 fn simplify_cfg() {
@@ -164,6 +253,43 @@ fn dce() {
 
 // -------------------------------------------------------------------------------------------------
 
+// Runs the dead code eliminations the way the O1 pipeline does, after folding constant branches,
+// and checks that each program gets smaller.
+#[test]
+fn dead_code() {
+    fn num_instructions(ir: &Context) -> usize {
+        ir.module_iter()
+            .flat_map(|module| module.function_iter(ir))
+            .map(|func| func.num_instructions(ir))
+            .sum()
+    }
+
+    run_tests("dead_code", |first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        pass_mgr.register(create_escaped_symbols_pass());
+        let mut fold_group = PassGroup::default();
+        fold_group.append_pass(pass_mgr.register(create_const_combine_pass()));
+        fold_group.append_pass(pass_mgr.register(create_simplify_cfg_pass()));
+        fold_group.append_pass(pass_mgr.register(create_dce_pass()));
+        let mut pass_group = PassGroup::default();
+        pass_group.append_fixpoint_group(fold_group);
+        pass_group.append_pass(pass_mgr.register(create_func_dce_pass()));
+
+        let before = num_instructions(ir);
+        let modified = pass_mgr.run(ir, &pass_group).unwrap();
+        let after = num_instructions(ir);
+
+        let program = first_line.trim_start_matches("// report:").trim();
+        assert!(
+            after < before,
+            "No instructions removed from {program}: {before} before and {after} after."
+        );
+        modified
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[allow(clippy::needless_collect)]
 #[test]
 fn mem2reg() {
@@ -252,6 +378,19 @@ fn memcpyopt() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn storageopt() {
+    run_tests("storageopt", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_storageopt_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
@@ -260,3 +399,125 @@ fn serialize() {
 }
 
 // -------------------------------------------------------------------------------------------------
+
+#[test]
+fn print_parse_fixpoint() {
+    // Printing the IR parsed from the printed IR must give the same text back, for all of the test
+    // inputs. The first print is not compared to the input itself, which may be formatted by hand.
+    let source_engine = SourceEngine::default();
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let tests_dir: PathBuf = format!("{manifest_dir}/tests").into();
+    for sub_dir in std::fs::read_dir(tests_dir).unwrap() {
+        let sub_dir = sub_dir.unwrap().path();
+        if !sub_dir.is_dir() {
+            continue;
+        }
+        for entry in std::fs::read_dir(sub_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |ext| ext != "ir") {
+                continue;
+            }
+
+            let input = std::fs::read_to_string(&path).unwrap();
+            let parse = |text: &str| {
+                sway_ir::parser::parse(text, &source_engine).unwrap_or_else(|parse_err| {
+                    println!("{text}");
+                    panic!("{}: {parse_err}", path.display())
+                })
+            };
+            let printed = sway_ir::printer::to_string(&parse(&input));
+            let reprinted = sway_ir::printer::to_string(&parse(&printed));
+            if printed != reprinted {
+                println!("{}", prettydiff::diff_lines(&printed, &reprinted));
+                panic!(
+                    "{} is not printed the same once parsed back.",
+                    path.display()
+                );
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn passes() {
+    // The passes to run are named in the first line, e.g., `// passes: constcombine dce`, and run
+    // in that order. See `tests/README.md` for details.
+    run_tests("passes", |first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        register_known_passes(&mut pass_mgr);
+        pass_mgr.verify_after_each(true);
+
+        let mut pass_group = PassGroup::default();
+        let names = first_line
+            .strip_prefix("// passes:")
+            .expect("The first line must name the passes, e.g., `// passes: dce`.");
+        for name in names.split_whitespace() {
+            let pass = pass_mgr
+                .lookup_registered_pass(name)
+                .unwrap_or_else(|| panic!("Unknown pass '{name}'.\n\n{}", pass_mgr.help_text()));
+            pass_group.append_pass(pass.name);
+        }
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn verify_after_each() {
+    // A broken pass, which moves the last instruction before the terminator to the start of the
+    // entry block, before the instructions it uses.
+    fn misplace_instruction(
+        context: &mut Context,
+        _: &AnalysisResults,
+        function: Function,
+    ) -> Result<bool, IrError> {
+        let entry = function.get_entry_block(context);
+        let instructions = entry.instruction_iter(context).collect::<Vec<_>>();
+        let moved = instructions[instructions.len() - 2];
+        entry.remove_instruction(context, moved);
+        entry.prepend_instructions(context, vec![moved]);
+        Ok(true)
+    }
+
+    let input = r#"
+script {
+    fn main() -> u64 {
+        entry():
+        v0 = const u64 1
+        v1 = add v0, v0
+        v2 = add v1, v0
+        ret u64 v2
+    }
+}
+"#;
+    let source_engine = SourceEngine::default();
+    let mut ir = sway_ir::parser::parse(input, &source_engine).unwrap();
+
+    let mut pass_mgr = PassManager::default();
+    let mut pass_group = PassGroup::default();
+    pass_group.append_pass(pass_mgr.register(Pass {
+        name: "misplace",
+        descr: "Misplace an instruction.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(misplace_instruction)),
+    }));
+    pass_mgr.verify_after_each(true);
+
+    let err = pass_mgr.run(&mut ir, &pass_group).unwrap_err().to_string();
+    assert!(
+        err.starts_with(
+            "The IR is invalid after running pass 'misplace'. \
+            In function 'main': In block 'entry': At instruction `"
+        ),
+        "{err}"
+    );
+    assert!(
+        err.contains("where its definition does not dominate"),
+        "{err}"
+    );
+}
+
+// -------------------------------------------------------------------------------------------------
@@ -484,8 +484,8 @@ impl Not for u8 {
 }
 
 impl BitwiseAnd for b256 {
-    fn binary_and(val: self, other: Self) -> Self {
-        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(val);
+    fn binary_and(self, other: Self) -> Self {
+        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(self);
         let (other_word_1, other_word_2, other_word_3, other_word_4) = decompose(other);
         let word_1 = value_word_1.binary_and(other_word_1);
         let word_2 = value_word_2.binary_and(other_word_2);
@@ -497,8 +497,8 @@ impl BitwiseAnd for b256 {
 }
 
 impl BitwiseOr for b256 {
-    fn binary_or(val: self, other: Self) -> Self {
-        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(val);
+    fn binary_or(self, other: Self) -> Self {
+        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(self);
         let (other_word_1, other_word_2, other_word_3, other_word_4) = decompose(other);
         let word_1 = value_word_1.binary_or(other_word_1);
         let word_2 = value_word_2.binary_or(other_word_2);
@@ -510,8 +510,8 @@ impl BitwiseOr for b256 {
 }
 
 impl BitwiseXor for b256 {
-    fn binary_xor(val: self, other: Self) -> Self {
-        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(val);
+    fn binary_xor(self, other: Self) -> Self {
+        let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(self);
         let (other_word_1, other_word_2, other_word_3, other_word_4) = decompose(other);
         let word_1 = value_word_1.binary_xor(other_word_1);
         let word_2 = value_word_2.binary_xor(other_word_2);
@@ -66,13 +66,17 @@ fn create_payload(
 
     // let mut payload = Bytes::new().append(contract_id_to_bytes(target)).append(function_selector);
     let mut payload = Bytes::new();
-    payload.append(contract_id_to_bytes(target));
+    let mut target_bytes = contract_id_to_bytes(target);
+    let mut function_selector = function_selector;
+    payload.append(target_bytes);
     payload.append(function_selector);
 
     if (single_value_type_arg) {
+        let mut calldata = calldata;
         payload.append(calldata); // When calldata is copy type, just pass calldata
     } else {
-        payload.append(ptr_as_bytes(calldata.buf.ptr)); // When calldata is reference type, need to get pointer as bytes
+        let mut calldata_ptr_bytes = ptr_as_bytes(calldata.buf.ptr);
+        payload.append(calldata_ptr_bytes); // When calldata is reference type, need to get pointer as bytes
     };
 
     payload
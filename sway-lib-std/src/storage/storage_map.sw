@@ -92,7 +92,7 @@ impl<K, V> StorageKey<StorageMap<K, V>> {
     /// ```
     #[storage(write)]
     pub fn remove(self, key: K) -> bool {
-        let key = sha256((key, self.slot));
+        let key = sha256((key, self.field_id));
         clear::<V>(key)
     }
 }
@@ -1,7 +1,12 @@
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, Position, Range};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag, Location,
+    NumberOrString, Position, Range, Url,
+};
+use serde_json::json;
+use sway_error::diagnostic::ToDiagnostic;
 use sway_error::warning::CompileWarning;
 use sway_error::{error::CompileError, warning::Warning};
-use sway_types::{LineCol, Spanned};
+use sway_types::{LineCol, SourceEngine, Spanned};
 
 #[derive(Debug, Default, Clone)]
 pub struct Diagnostics {
@@ -9,15 +14,51 @@ pub struct Diagnostics {
     pub errors: Vec<Diagnostic>,
 }
 
-fn get_error_diagnostics(errors: &[CompileError]) -> Vec<Diagnostic> {
-    Vec::from_iter(errors.iter().map(|error| Diagnostic {
-        range: get_range(error.span().line_col()),
-        severity: Some(DiagnosticSeverity::ERROR),
-        message: format!("{error}"),
-        ..Default::default()
+fn get_error_diagnostics(source_engine: &SourceEngine, errors: &[CompileError]) -> Vec<Diagnostic> {
+    Vec::from_iter(errors.iter().map(|error| {
+        let parameter_error_info = error.parameter_error_info();
+        let related_information = get_related_information(source_engine, error);
+        Diagnostic {
+            range: get_range(error.span().line_col()),
+            severity: Some(DiagnosticSeverity::ERROR),
+            code: Some(NumberOrString::String(error.code().as_str().to_string())),
+            message: format!("{error}"),
+            data: parameter_error_info.map(|info| {
+                json!({
+                    "name": info.name.as_str(),
+                    "type_span": get_range(info.type_span.line_col()),
+                })
+            }),
+            related_information,
+            ..Default::default()
+        }
     }))
 }
 
+/// Returns the secondary labels of the `error`, like the previous definition of a name defined
+/// multiple times, as the related information of its diagnostic.
+fn get_related_information(
+    source_engine: &SourceEngine,
+    error: &CompileError,
+) -> Option<Vec<DiagnosticRelatedInformation>> {
+    let diagnostic = error.to_diagnostic(source_engine);
+    let related_information = diagnostic
+        .secondary_labels()
+        .into_iter()
+        .filter_map(|label| {
+            let uri = Url::from_file_path(label.source_path()?.as_path_buf()).ok()?;
+            Some(DiagnosticRelatedInformation {
+                location: Location {
+                    uri,
+                    range: get_range(label.span().line_col()),
+                },
+                message: label.text().to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+    (!related_information.is_empty()).then_some(related_information)
+}
+
 fn get_warning_diagnostics(warnings: &[CompileWarning]) -> Vec<Diagnostic> {
     Vec::from_iter(warnings.iter().map(|warning| Diagnostic {
         range: get_range(warning.span().line_col()),
@@ -28,10 +69,14 @@ fn get_warning_diagnostics(warnings: &[CompileWarning]) -> Vec<Diagnostic> {
     }))
 }
 
-pub fn get_diagnostics(warnings: &[CompileWarning], errors: &[CompileError]) -> Diagnostics {
+pub fn get_diagnostics(
+    warnings: &[CompileWarning],
+    errors: &[CompileError],
+    source_engine: &SourceEngine,
+) -> Diagnostics {
     Diagnostics {
         warnings: get_warning_diagnostics(warnings),
-        errors: get_error_diagnostics(errors),
+        errors: get_error_diagnostics(source_engine, errors),
     }
 }
 
@@ -46,6 +91,7 @@ fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>>
     match warning {
         Warning::StructFieldNeverRead
         | Warning::DeadDeclaration
+        | Warning::UnusedVariable
         | Warning::DeadEnumDeclaration
         | Warning::DeadEnumVariant { .. }
         | Warning::DeadFunctionDeclaration
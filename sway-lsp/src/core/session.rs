@@ -148,6 +148,9 @@ impl Session {
         self.token_map.clear();
         self.runnables.clear();
 
+        // Every compilation creates its own engines, so replacing the engines here drops all
+        // the types and declarations of the previous compilation, and the engines do not grow
+        // over the course of a session.
         *self.engines.write() = res.engines;
         res.token_map.deref().iter().for_each(|item| {
             let ((i, s), t) = item.pair();
@@ -436,7 +439,7 @@ pub fn parse_project(uri: &Url) -> Result<ParseResult, LanguageServerError> {
         if value.is_none() {
             // If there was an unrecoverable error in the parser
             // make sure to still return the diagnostics.
-            diagnostics = get_diagnostics(&warnings, &errors);
+            diagnostics = get_diagnostics(&warnings, &errors, engines.se());
             continue;
         }
         let Programs {
@@ -447,7 +450,7 @@ pub fn parse_project(uri: &Url) -> Result<ParseResult, LanguageServerError> {
 
         // Get a reference to the typed program AST.
         let typed_program = typed.as_ref().ok().ok_or_else(|| {
-            diagnostics = get_diagnostics(&warnings, &errors);
+            diagnostics = get_diagnostics(&warnings, &errors, engines.se());
             LanguageServerError::FailedToParse
         })?;
 
@@ -473,7 +476,7 @@ pub fn parse_project(uri: &Url) -> Result<ParseResult, LanguageServerError> {
             });
 
             programs = Some((lexed, parsed, typed_program.clone()));
-            diagnostics = get_diagnostics(&warnings, &errors);
+            diagnostics = get_diagnostics(&warnings, &errors, engines.se());
         } else {
             // Collect tokens from dependencies and the standard library prelude.
             parse_ast_to_tokens(&parsed, &ctx, |an, ctx| {
@@ -554,6 +557,22 @@ mod tests {
         assert_eq!(result, DocumentError::DocumentAlreadyStored { path });
     }
 
+    #[test]
+    fn recompiling_does_not_grow_the_type_engine() {
+        let session = Session::new();
+        let path = get_absolute_path("sway-lsp/tests/fixtures/recompile/src/main.sw");
+        let uri = get_url(&path);
+        let mut type_engine_sizes = vec![];
+        for _ in 0..500 {
+            let parse_result = parse_project(&uri).unwrap();
+            session.write_parse_result(parse_result);
+            type_engine_sizes.push(session.engines.read().te().size());
+        }
+        assert!(type_engine_sizes
+            .iter()
+            .all(|size| *size == type_engine_sizes[0]));
+    }
+
     #[test]
     fn parse_project_returns_manifest_file_not_found() {
         let dir = get_absolute_path("sway-lsp/tests/fixtures");
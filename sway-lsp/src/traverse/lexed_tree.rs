@@ -7,8 +7,8 @@ use sway_ast::{
     Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature,
     IfCondition, IfExpr, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl,
     ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse,
-    MatchBranchKind, ModuleKind, Pattern, PatternStructField, Statement, StatementLet,
-    StorageField, Ty, TypeField, UseTree,
+    MatchBranchKind, ModuleKind, Pattern, PatternArrayElement, PatternStructField, Statement,
+    StatementLet, StorageField, Ty, TypeField, UseTree,
 };
 use sway_core::language::lexed::LexedProgram;
 use sway_types::{Ident, Span, Spanned};
@@ -462,6 +462,10 @@ impl Parse for Ty {
                 insert_keyword(ctx, str_token.span());
                 length.get().parse(ctx);
             }
+            Ty::TypeOf { typeof_token, expr } => {
+                insert_keyword(ctx, typeof_token.span());
+                expr.get().parse(ctx);
+            }
             _ => {}
         }
     }
@@ -487,16 +491,20 @@ impl Parse for FnSignature {
 impl Parse for FnArgs {
     fn parse(&self, ctx: &ParseContext) {
         match self {
-            FnArgs::Static(punct) => {
-                punct.into_iter().for_each(|fn_arg| fn_arg.parse(ctx));
+            FnArgs::Static { args, .. } => {
+                args.into_iter().for_each(|fn_arg| fn_arg.parse(ctx));
             }
             FnArgs::NonStatic {
                 self_token,
                 ref_self,
                 mutable_self,
+                self_ty_opt,
                 args_opt,
             } => {
                 insert_keyword(ctx, self_token.span());
+                if let Some((_, ty)) = self_ty_opt {
+                    ty.parse(ctx);
+                }
                 if let Some(ref_token) = ref_self {
                     insert_keyword(ctx, ref_token.span());
                 }
@@ -634,6 +642,16 @@ impl Parse for Pattern {
                     field.parse(ctx);
                 }
             }
+            Pattern::Array(elements) => {
+                for element in elements.get().into_iter() {
+                    if let PatternArrayElement::Pattern(pattern) = element {
+                        pattern.parse(ctx);
+                    }
+                }
+            }
+            Pattern::At { pattern, .. } => {
+                pattern.parse(ctx);
+            }
             _ => {}
         }
     }
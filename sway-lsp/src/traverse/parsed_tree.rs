@@ -207,6 +207,12 @@ impl Parse for Expression {
                     );
                 }
             }
+            ExpressionKind::Spread(name) => {
+                ctx.tokens.insert(
+                    to_ident_key(name),
+                    Token::from_parsed(AstToken::Expression(self.clone()), SymbolKind::ValueParam),
+                );
+            }
             ExpressionKind::Tuple(fields) => {
                 fields.iter().for_each(|field| field.parse(ctx));
             }
@@ -618,7 +624,12 @@ impl Parse for StructExpressionField {
 impl Parse for ArrayExpression {
     fn parse(&self, ctx: &ParseContext) {
         self.contents.iter().for_each(|exp| exp.parse(ctx));
-        if let Some(length_span) = &self.length_span {
+        if let Some(call_path) = &self.length_constant {
+            ctx.tokens.insert(
+                to_ident_key(&call_path.suffix),
+                Token::from_parsed(AstToken::Ident(call_path.suffix.clone()), SymbolKind::Const),
+            );
+        } else if let Some(length_span) = &self.length_span {
             let ident = Ident::new(length_span.clone());
             ctx.tokens.insert(
                 to_ident_key(&ident),
@@ -939,14 +950,25 @@ impl Parse for TraitConstraint {
 
 impl Parse for FunctionParameter {
     fn parse(&self, ctx: &ParseContext) {
-        ctx.tokens.insert(
-            to_ident_key(&self.name),
-            Token::from_parsed(
-                AstToken::FunctionParameter(self.clone()),
-                SymbolKind::ValueParam,
-            ),
-        );
+        // A parameter that destructures an array has a generated name, so its tokens are the
+        // names bound by the pattern instead.
+        let names = match &self.array_pattern {
+            Some(array_pattern) => array_pattern.bound_names(),
+            None => vec![self.name.clone()],
+        };
+        for name in names {
+            ctx.tokens.insert(
+                to_ident_key(&name),
+                Token::from_parsed(
+                    AstToken::FunctionParameter(self.clone()),
+                    SymbolKind::ValueParam,
+                ),
+            );
+        }
         self.type_argument.parse(ctx);
+        if let Some(expr) = &self.type_of {
+            expr.parse(ctx);
+        }
     }
 }
 
@@ -1022,6 +1044,9 @@ impl Parse for TypeAliasDeclaration {
                 SymbolKind::TypeAlias,
             ),
         );
+        self.type_parameters.iter().for_each(|type_param| {
+            type_param.parse(ctx);
+        });
         self.ty.parse(ctx);
         self.attributes.parse(ctx);
     }
@@ -140,6 +140,14 @@ impl Parse for ty::TySideEffect {
                                     symbol_kind = decl.value().kind.clone();
                                 }
                                 type_def = Some(TypeDefinition::Ident(decl_ident));
+                            } else if let Some(span) = ctx
+                                .namespace
+                                .submodule(&[call_path.clone(), vec![item.clone()]].concat())
+                                .and_then(|tgt_submod| tgt_submod.span.clone())
+                            {
+                                // the item is a module, e.g. `use foo::bar as baz;`
+                                symbol_kind = SymbolKind::Module;
+                                type_def = Some(TypeDefinition::Ident(Ident::new(span)));
                             }
                             token.kind = symbol_kind.clone();
                             token.type_def = type_def.clone();
@@ -549,6 +557,9 @@ impl Parse for ty::TyExpression {
             ty::TyExpressionVariant::EnumTag { exp } => {
                 exp.parse(ctx);
             }
+            ty::TyExpressionVariant::ArrayToSlice { array } => {
+                array.parse(ctx);
+            }
             ty::TyExpressionVariant::UnsafeDowncast {
                 exp,
                 variant,
@@ -993,6 +1004,16 @@ impl Parse for ty::TyTypeAliasDecl {
             token.typed = Some(TypedAstToken::TypedTypeAliasDeclaration(self.clone()));
             token.type_def = Some(TypeDefinition::Ident(self.name.clone()));
         }
+        self.type_parameters.iter().for_each(|type_param| {
+            if let Some(mut token) = ctx
+                .tokens
+                .try_get_mut(&to_ident_key(&type_param.name_ident))
+                .try_unwrap()
+            {
+                token.typed = Some(TypedAstToken::TypedParameter(type_param.clone()));
+                token.type_def = Some(TypeDefinition::TypeId(type_param.type_id));
+            }
+        });
         collect_type_argument(ctx, &self.ty);
     }
 }
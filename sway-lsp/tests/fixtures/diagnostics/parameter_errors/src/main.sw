@@ -0,0 +1,5 @@
+library;
+
+pub fn mutable(mut x: u64) {}
+
+pub fn unknown(x: Foo) {}
@@ -0,0 +1,15 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn origin() -> Point {
+    Point { x: 0, y: 0 }
+}
+
+fn main() -> u64 {
+    let line = (origin(), Point { x: 1, y: 2 });
+    line.1.y
+}
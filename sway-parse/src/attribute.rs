@@ -47,7 +47,11 @@ impl<T: Parse> Parse for Annotated<T> {
                             doc_comment.span.clone(),
                         ),
                         args: Some(Parens::new(
-                            Punctuated::single(AttributeArg { name, value: None }),
+                            Punctuated::single(AttributeArg {
+                                name,
+                                value: None,
+                                args: None,
+                            }),
                             doc_comment.content_span,
                         )),
                     }),
@@ -99,9 +103,17 @@ impl Parse for AttributeArg {
                 Ok(AttributeArg {
                     name,
                     value: Some(value),
+                    args: None,
+                })
+            }
+            None => {
+                let args = Parens::try_parse(parser)?;
+                Ok(AttributeArg {
+                    name,
+                    value: None,
+                    args,
                 })
             }
-            None => Ok(AttributeArg { name, value: None }),
         }
     }
 }
@@ -169,6 +181,7 @@ mod tests {
                             span: (85, 108),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (85, 108),
@@ -199,6 +212,7 @@ mod tests {
                             span: (131, 135),
                           ),
                           value: None,
+                          args: None,
                         )),
                       ),
                       span: (130, 136),
@@ -221,10 +235,13 @@ mod tests {
               ),
               generics: None,
               arguments: Parens(
-                inner: Static(Punctuated(
-                  value_separator_pairs: [],
-                  final_value_opt: None,
-                )),
+                inner: Static(
+                  args: Punctuated(
+                    value_separator_pairs: [],
+                    final_value_opt: None,
+                  ),
+                  rest_opt: None,
+                ),
                 span: (157, 159),
               ),
               return_type_opt: None,
@@ -265,6 +282,7 @@ mod tests {
                     span: (18, 22),
                   ),
                   value: None,
+                  args: None,
                 ), CommaToken(
                   span: (22, 23),
                 )),
@@ -277,6 +295,7 @@ mod tests {
                     span: (31, 38),
                     parsed: "value",
                   ))),
+                  args: None,
                 ), CommaToken(
                   span: (38, 39),
                 )),
@@ -287,6 +306,7 @@ mod tests {
                   span: (40, 44),
                 ),
                 value: None,
+                args: None,
               )),
             ),
             span: (17, 45),
@@ -4,8 +4,8 @@ use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
     AbiToken, AddEqToken, AsmToken, CommaToken, ConfigurableToken, ConstToken, DivEqToken,
-    DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken,
-    OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
+    DoubleColonToken, DoubleDotToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken,
+    LetToken, OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
     StorageToken, StructToken, SubEqToken, Token, TraitToken, TrueToken, TypeToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
@@ -502,6 +502,16 @@ fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some((bang_token, expr)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
         return Ok(Expr::Not { bang_token, expr });
     }
+    if let Some(double_dot_token) = parser.take::<DoubleDotToken>() {
+        // Spreads are parsed only as call arguments, by `try_parse_call_args`.
+        let mut spans = vec![double_dot_token.span()];
+        spans.extend(parser.take::<Ident>().map(|name| name.span()));
+        let err = parser.emit_error_with_span(
+            ParseErrorKind::UnexpectedSpread,
+            Span::join_all(spans.clone()),
+        );
+        return Ok(Expr::Error(spans.into(), err));
+    }
     parse_projection(parser, ctx)
 }
 
@@ -527,12 +537,17 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
                             dot_token,
                             path_seg,
                             contract_args_opt: Some(contract_args),
-                            args: Parens::parse(parser)?,
+                            args: match try_parse_call_args(parser)? {
+                                Some(args) => args,
+                                None => {
+                                    return Err(parser.emit_error(ParseErrorKind::ExpectedOpenParen))
+                                }
+                            },
                         };
                         continue;
                     }
                 }
-                if let Some(args) = Parens::try_parse(parser)? {
+                if let Some(args) = try_parse_call_args(parser)? {
                     expr = Expr::MethodCall {
                         target,
                         dot_token,
@@ -609,13 +624,53 @@ fn parse_func_app(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if expr.is_control_flow() && ctx.at_start_of_statement {
         return Ok(expr);
     }
-    while let Some(args) = Parens::try_parse(parser)? {
+    while let Some(args) = try_parse_call_args(parser)? {
         let func = Box::new(expr);
         expr = Expr::FuncApp { func, args };
     }
     Ok(expr)
 }
 
+/// Parses the parenthesized arguments of a function or method call. Unlike anywhere else, the
+/// last argument can be a spread, as in `f(a, ..rest)`.
+fn try_parse_call_args(
+    parser: &mut Parser,
+) -> ParseResult<Option<Parens<Punctuated<Expr, CommaToken>>>> {
+    let Some((mut parser, span)) = parser.enter_delimited(Delimiter::Parenthesis) else {
+        return Ok(None);
+    };
+    let mut value_separator_pairs = Vec::new();
+    let mut final_value_opt = None;
+    while !parser.is_empty() {
+        let value = match parser.take() {
+            Some(double_dot_token) => Expr::Spread {
+                double_dot_token,
+                name: parser.parse()?,
+            },
+            None => parser.parse()?,
+        };
+        if parser.is_empty() {
+            final_value_opt = Some(Box::new(value));
+            break;
+        }
+        let separator = parser.parse()?;
+        let value = match value {
+            Expr::Spread { .. } if !parser.is_empty() => {
+                let err =
+                    parser.emit_error_with_span(ParseErrorKind::UnexpectedSpread, value.span());
+                Expr::Error([value.span()].into(), err)
+            }
+            value => value,
+        };
+        value_separator_pairs.push((value, separator));
+    }
+    let inner = Punctuated {
+        value_separator_pairs,
+        final_value_opt,
+    };
+    Ok(Some(Parens { inner, span }))
+}
+
 fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some(code_block_inner) = Braces::try_parse(parser)? {
         return Ok(Expr::Block(code_block_inner));
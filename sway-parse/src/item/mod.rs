@@ -1,13 +1,14 @@
 use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use sway_ast::keywords::{
-    AbiToken, ClassToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken, ModToken,
-    MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
+    AbiToken, ClassToken, CommaToken, ConfigurableToken, ConstToken, EnumToken, FnToken, HashToken,
+    ImplToken, ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken,
+    StorageToken, StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
+use sway_ast::punctuated::Punctuated;
 use sway_ast::{
-    FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
-    ItemTypeAlias, ItemUse, Submodule, TypeField,
+    FnArg, FnArgRest, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct,
+    ItemTrait, ItemTypeAlias, ItemUse, Pattern, Submodule, TypeField,
 };
 use sway_error::parser_error::ParseErrorKind;
 
@@ -96,12 +97,21 @@ impl ParseToEnd for FnArgs {
         let mut mutable_self: Option<MutToken> = None;
         if parser.peek::<(MutToken, SelfToken)>().is_some()
             || parser.peek::<(RefToken, MutToken, SelfToken)>().is_some()
+            || parser.peek::<(MutToken, RefToken, SelfToken)>().is_some()
         {
             ref_self = parser.take();
             mutable_self = parser.take();
+            // `mut ref self` is accepted as an alternative spelling of `ref mut self`.
+            if ref_self.is_none() {
+                ref_self = parser.take();
+            }
         }
         match parser.take() {
             Some(self_token) => {
+                let self_ty_opt = match parser.take() {
+                    Some(colon_token) => Some((colon_token, Box::new(parser.parse()?))),
+                    None => None,
+                };
                 match parser.take() {
                     Some(comma_token) => {
                         let (args, consumed) = parser.parse_to_end()?;
@@ -109,6 +119,7 @@ impl ParseToEnd for FnArgs {
                             self_token,
                             ref_self,
                             mutable_self,
+                            self_ty_opt,
                             args_opt: Some((comma_token, args)),
                         };
                         Ok((fn_args, consumed))
@@ -118,6 +129,7 @@ impl ParseToEnd for FnArgs {
                             self_token,
                             ref_self,
                             mutable_self,
+                            self_ty_opt,
                             args_opt: None,
                         };
                         match parser.check_empty() {
@@ -129,8 +141,50 @@ impl ParseToEnd for FnArgs {
                 }
             }
             None => {
-                let (args, consumed) = parser.parse_to_end()?;
-                let fn_args = FnArgs::Static(args);
+                let mut value_separator_pairs: Vec<(FnArg, CommaToken)> = Vec::new();
+                let mut final_value_opt = None;
+                let mut rest_opt = None;
+                let consumed = loop {
+                    if let Some(consumed) = parser.check_empty() {
+                        break consumed;
+                    }
+                    if let Some(double_dot_token) = parser.take() {
+                        rest_opt = Some(FnArgRest {
+                            double_dot_token,
+                            name: parser.parse()?,
+                        });
+                        match parser.check_empty() {
+                            Some(consumed) => break consumed,
+                            None => {
+                                return Err(
+                                    parser.emit_error(ParseErrorKind::RestParameterMustBeLast)
+                                )
+                            }
+                        }
+                    }
+                    let arg = parser.parse()?;
+                    match parser.take() {
+                        Some(comma_token) => value_separator_pairs.push((arg, comma_token)),
+                        None => {
+                            final_value_opt = Some(Box::new(arg));
+                            match parser.check_empty() {
+                                Some(consumed) => break consumed,
+                                None => {
+                                    return Err(parser.emit_error(
+                                        ParseErrorKind::ExpectedCommaOrCloseParenInFnArgs,
+                                    ))
+                                }
+                            }
+                        }
+                    }
+                };
+                let fn_args = FnArgs::Static {
+                    args: Punctuated {
+                        value_separator_pairs,
+                        final_value_opt,
+                    },
+                    rest_opt,
+                };
                 Ok((fn_args, consumed))
             }
         }
@@ -139,8 +193,28 @@ impl ParseToEnd for FnArgs {
 
 impl Parse for FnArg {
     fn parse(parser: &mut Parser) -> ParseResult<FnArg> {
+        let mut attribute_list = Vec::new();
+        while let Some(attr) = parser.guarded_parse::<HashToken, _>()? {
+            attribute_list.push(attr);
+        }
+        // `mut ref` is accepted as an alternative spelling of `ref mut`, but only for
+        // parameters. Other patterns must use `ref mut`.
+        let pattern = match parser.peek::<(MutToken, RefToken)>() {
+            Some(_) => {
+                let mutable = parser.take();
+                let reference = parser.take();
+                Pattern::Var {
+                    reference,
+                    mutable,
+                    name: parser.parse()?,
+                }
+            }
+            None => parser.parse()?,
+        };
         Ok(FnArg {
-            pattern: parser.parse()?,
+            attribute_list,
+            pattern,
+            question_mark_token: parser.take(),
             colon_token: parser.parse()?,
             ty: parser.parse()?,
         })
@@ -174,6 +248,7 @@ mod tests {
     use super::*;
     use crate::test_utils::parse;
     use sway_ast::{AttributeDecl, Item, ItemTraitItem};
+    use sway_types::Spanned;
 
     // Attribute name and its list of parameters
     type ParameterizedAttr<'a> = (&'a str, Option<Vec<&'a str>>);
@@ -615,4 +690,139 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_fn_explicit_self_type() {
+        let item = parse::<Item>(
+            r#"
+            fn f(self: Ptr<Self>, x: u64) -> bool {
+                false
+            }
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        match item_fn.fn_signature.arguments.inner {
+            FnArgs::NonStatic {
+                self_ty_opt: Some((_, ty)),
+                args_opt: Some((_, args)),
+                ..
+            } => {
+                assert_eq!(ty.span().as_str(), "Ptr<Self>");
+                assert_eq!(args.into_iter().count(), 1);
+            }
+            _ => panic!("Parsed function does not have an explicitly typed self parameter."),
+        }
+    }
+
+    #[test]
+    fn parse_fn_rest_parameter() {
+        let item = parse::<Item>(
+            r#"
+            fn f(x: u64, ..rest) {
+                g(x, ..rest)
+            }
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        match item_fn.fn_signature.arguments.inner {
+            FnArgs::Static {
+                args,
+                rest_opt: Some(rest),
+            } => {
+                assert_eq!(args.into_iter().count(), 1);
+                assert_eq!(rest.name.as_str(), "rest");
+            }
+            _ => panic!("Parsed function does not have a rest parameter."),
+        }
+    }
+
+    #[test]
+    fn parse_fn_mut_ref_parameters() {
+        let item = parse::<Item>(
+            r#"
+            fn f(mut ref self, mut ref x: u64, ref mut y: u64) {}
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        match item_fn.fn_signature.arguments.inner {
+            FnArgs::NonStatic {
+                ref_self: Some(_),
+                mutable_self: Some(_),
+                args_opt: Some((_, args)),
+                ..
+            } => {
+                for arg in args {
+                    assert!(matches!(
+                        arg.pattern,
+                        Pattern::Var {
+                            reference: Some(_),
+                            mutable: Some(_),
+                            ..
+                        }
+                    ));
+                }
+            }
+            _ => panic!("Parsed function does not have a mutable reference self parameter."),
+        }
+    }
+
+    #[test]
+    fn parse_fn_optional_parameter() {
+        let item = parse::<Item>(
+            r#"
+            fn f(x: u64, y?: u64) {}
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed item is not a function."),
+        };
+        match item_fn.fn_signature.arguments.inner {
+            FnArgs::Static { args, .. } => {
+                let optional = args
+                    .into_iter()
+                    .map(|arg| arg.question_mark_token.is_some())
+                    .collect::<Vec<_>>();
+                assert_eq!(optional, [false, true]);
+            }
+            _ => panic!("Parsed function does not have static arguments."),
+        }
+    }
+
+    #[test]
+    fn parse_generic_type_alias() {
+        let item = parse::<Item>(
+            r#"
+            type Pair<T> = (T, T);
+            "#,
+        );
+
+        let item_type_alias = match item.value {
+            ItemKind::TypeAlias(item_type_alias) => item_type_alias,
+            _ => panic!("Parsed item is not a type alias."),
+        };
+        let generics = item_type_alias
+            .generics
+            .expect("Parsed type alias has no generic parameters.");
+        let names = generics
+            .parameters
+            .inner
+            .into_iter()
+            .map(|param| param.as_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["T"]);
+    }
 }
@@ -73,7 +73,8 @@ keyword_impls! {
     ConfigurableToken,
     TypeToken,
     PtrToken,
-    SliceToken
+    SliceToken,
+    TypeofToken
 }
 
 fn peek_token<T: Token>(peeker: Peeker<'_>) -> Option<T> {
@@ -151,7 +152,9 @@ token_impls! {
     DoublePipeToken,
     UnderscoreToken,
     HashToken,
-    HashBangToken
+    HashBangToken,
+    AtToken,
+    QuestionMarkToken
 }
 
 // Keep this in sync with the list in `sway-ast/keywords.rs` defined by define_keyword!
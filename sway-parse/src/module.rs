@@ -30,7 +30,15 @@ impl ParseToEnd for Annotated<Module> {
     fn parse_to_end<'a, 'e>(mut parser: Parser<'a, '_>) -> ParseResult<(Self, ParserConsumed<'a>)> {
         // Parse the attribute list.
         let mut attribute_list = Vec::new();
-        while let Some(DocComment { .. }) = parser.peek() {
+        loop {
+            // Inner attributes, like `#![allow(dead_code)]`, apply to the whole module.
+            if let Some(attr) = parser.guarded_parse::<HashBangToken, _>()? {
+                attribute_list.push(attr);
+                continue;
+            }
+            let Some(DocComment { .. }) = parser.peek() else {
+                break;
+            };
             let doc_comment = parser.parse::<DocComment>()?;
             // TODO: Use a Literal instead of an Ident when Attribute args
             // start supporting them and remove `Ident::new_no_trim`.
@@ -47,7 +55,11 @@ impl ParseToEnd for Annotated<Module> {
                                 doc_comment.span.clone(),
                             ),
                             args: Some(Parens::new(
-                                Punctuated::single(AttributeArg { name, value: None }),
+                                Punctuated::single(AttributeArg {
+                                    name,
+                                    value: None,
+                                    args: None,
+                                }),
                                 doc_comment.content_span,
                             )),
                         }),
@@ -116,10 +128,13 @@ mod tests {
                     ),
                     generics: None,
                     arguments: Parens(
-                      inner: Static(Punctuated(
-                        value_separator_pairs: [],
-                        final_value_opt: None,
-                      )),
+                      inner: Static(
+                        args: Punctuated(
+                          value_separator_pairs: [],
+                          final_value_opt: None,
+                        ),
+                        rest_opt: None,
+                      ),
                       span: (49, 51),
                     ),
                     return_type_opt: None,
@@ -1,10 +1,10 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser, Peek};
 
-use sway_ast::brackets::{Braces, Parens};
+use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::keywords::{DoubleDotToken, FalseToken, TrueToken};
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{Literal, PathExpr, Pattern, PatternStructField};
+use sway_ast::{Literal, PathExpr, Pattern, PatternArrayElement, PatternStructField};
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::Spanned;
 
@@ -69,6 +69,22 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
         if let Some(tuple) = Parens::try_parse(parser)? {
             return Ok(Pattern::Tuple(tuple));
         }
+        if let Some(elements) = SquareBrackets::try_parse(parser)? {
+            let inner_elements: &Punctuated<_, _> = elements.get();
+            let rest_pattern = inner_elements
+                .value_separator_pairs
+                .iter()
+                .find(|(p, _)| matches!(p, PatternArrayElement::Rest { .. }));
+
+            if let Some((rest_pattern, _)) = rest_pattern {
+                return Err(parser.emit_error_with_span(
+                    ParseErrorKind::UnexpectedRestPattern,
+                    rest_pattern.span(),
+                ));
+            }
+
+            return Ok(Pattern::Array(elements));
+        }
         if let Some(underscore_token) = parser.take() {
             return Ok(Pattern::Wildcard { underscore_token });
         }
@@ -80,6 +96,22 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
                 parser.emit_error(ParseErrorKind::ExpectedPathType),
             ));
         }
+        if let Some(at_token) = parser.take() {
+            let name = match path.try_into_ident() {
+                Ok(name) => name,
+                Err(path) => {
+                    return Err(
+                        parser.emit_error_with_span(ParseErrorKind::ExpectedIdent, path.span())
+                    )
+                }
+            };
+            let pattern = Box::new(parse_atomic_pattern(parser)?);
+            return Ok(Pattern::At {
+                name,
+                at_token,
+                pattern,
+            });
+        }
         if let Some(args) = Parens::try_parse(parser)? {
             return Ok(Pattern::Constructor { path, args });
         }
@@ -123,3 +155,14 @@ impl Parse for PatternStructField {
         })
     }
 }
+
+impl Parse for PatternArrayElement {
+    fn parse(parser: &mut Parser) -> ParseResult<PatternArrayElement> {
+        if let Some(token) = parser.take::<DoubleDotToken>() {
+            let name_opt = parser.take();
+            return Ok(PatternArrayElement::Rest { token, name_opt });
+        }
+
+        Ok(PatternArrayElement::Pattern(Box::new(parser.parse()?)))
+    }
+}
@@ -54,6 +54,12 @@ impl Parse for Ty {
             })?;
             return Ok(Ty::Slice { slice_token, ty });
         }
+        if let Some(typeof_token) = parser.take() {
+            let expr = Parens::parse_all_inner(parser, |mut parser| {
+                parser.emit_error(ParseErrorKind::UnexpectedTokenAfterTypeofExpression)
+            })?;
+            return Ok(Ty::TypeOf { typeof_token, expr });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
@@ -112,4 +118,14 @@ mod tests {
         );
         assert_matches!(item, Ty::Slice { .. });
     }
+
+    #[test]
+    fn parse_typeof() {
+        let item = parse::<Ty>(
+            r#"
+            typeof(MAX_LEN)
+            "#,
+        );
+        assert_matches!(item, Ty::TypeOf { .. });
+    }
 }
@@ -39,10 +39,13 @@ fn noop_script_file() {
                 ),
                 generics: None,
                 arguments: Parens(
-                  inner: Static(Punctuated(
-                    value_separator_pairs: [],
-                    final_value_opt: None,
-                  )),
+                  inner: Static(
+                    args: Punctuated(
+                      value_separator_pairs: [],
+                      final_value_opt: None,
+                    ),
+                    rest_opt: None,
+                  ),
                   span: (35, 37),
                 ),
                 return_type_opt: None,
@@ -42,6 +42,8 @@ pub enum PunctKind {
     Pipe,
     Underscore,
     Sharp,
+    At,
+    QuestionMark,
 }
 
 impl PunctKind {
@@ -65,6 +67,8 @@ impl PunctKind {
             PunctKind::Pipe => '|',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::At => '@',
+            PunctKind::QuestionMark => '?',
         }
     }
 }
@@ -24,9 +24,16 @@ pub const TUPLE_NAME_PREFIX: &str = "__tuple_";
 // The default prefix for the compiler generated names of struct fields
 pub const DESTRUCTURE_PREFIX: &str = "__destructure_";
 
+/// The default prefix for the compiler generated names of parameters that destructure arrays
+pub const ARRAY_PARAM_NAME_PREFIX: &str = "__array_param_";
+
 /// The default prefix for the compiler generated names of match
 pub const MATCH_RETURN_VAR_NAME_PREFIX: &str = "__match_return_var_name_";
 
+/// The default prefix for the compiler generated names of keys of compound assignments to
+/// storage maps
+pub const STORAGE_MAP_KEY_VAR_NAME_PREFIX: &str = "__storage_map_key_";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
@@ -45,18 +52,70 @@ pub const DOC_COMMENT_ATTRIBUTE_NAME: &str = "doc-comment";
 
 /// The attribute used for Sway in-language unit tests.
 pub const TEST_ATTRIBUTE_NAME: &str = "test";
+pub const TEST_SHOULD_REVERT_ARG_NAME: &str = "should_revert";
 
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
+/// The attribute used to generate a builder for a function's parameters.
+pub const BUILDER_ATTRIBUTE_NAME: &str = "builder";
+
+/// The attribute used to convert a function's arguments into the types of its parameters.
+pub const COERCE_ATTRIBUTE_NAME: &str = "coerce";
+
+/// The attribute used to bind the fields of a struct parameter as locals of the function.
+pub const FIELDS_ATTRIBUTE_NAME: &str = "fields";
+
 /// The valid attribute strings related to allow.
 pub const ALLOW_ATTRIBUTE_NAME: &str = "allow";
 pub const ALLOW_DEAD_CODE_NAME: &str = "dead_code";
+pub const ALLOW_UNUSED_VARIABLES_NAME: &str = "unused_variables";
+pub const ALLOW_UNREACHABLE_CODE_NAME: &str = "unreachable_code";
+pub const ALLOW_UNUSED_RESULTS_NAME: &str = "unused_results";
+pub const ALLOW_DEPRECATED_NAME: &str = "deprecated";
+pub const ALLOW_NON_SNAKE_CASE_NAME: &str = "non_snake_case";
+pub const ALLOW_NON_CAMEL_CASE_TYPES_NAME: &str = "non_camel_case_types";
+pub const ALLOW_NON_UPPER_CASE_GLOBALS_NAME: &str = "non_upper_case_globals";
+/// The group of the lints about unused code, `dead_code`, `unused_variables` and `unused_results`.
+pub const ALLOW_UNUSED_NAME: &str = "unused";
+pub const UNUSED_LINT_GROUP: &[&str] = &[
+    ALLOW_DEAD_CODE_NAME,
+    ALLOW_UNUSED_VARIABLES_NAME,
+    ALLOW_UNUSED_RESULTS_NAME,
+];
+
+/// The attribute used to turn the warnings of a lint into errors.
+pub const DENY_ATTRIBUTE_NAME: &str = "deny";
+
+/// The lints that can be passed to the `allow` and `deny` attributes.
+pub const VALID_LINT_NAMES: &[&str] = &[
+    ALLOW_DEAD_CODE_NAME,
+    ALLOW_UNUSED_VARIABLES_NAME,
+    ALLOW_UNREACHABLE_CODE_NAME,
+    ALLOW_UNUSED_RESULTS_NAME,
+    ALLOW_DEPRECATED_NAME,
+    ALLOW_NON_SNAKE_CASE_NAME,
+    ALLOW_NON_CAMEL_CASE_TYPES_NAME,
+    ALLOW_NON_UPPER_CASE_GLOBALS_NAME,
+    ALLOW_UNUSED_NAME,
+];
+
+/// The attribute used to mark items that should no longer be used.
+pub const DEPRECATED_ATTRIBUTE_NAME: &str = "deprecated";
+pub const DEPRECATED_NOTE_ARG_NAME: &str = "note";
+
+/// The valid attribute strings related to the ABI a function's arguments are passed with.
+pub const EXTERN_ABI_ATTRIBUTE_NAME: &str = "extern_abi";
+pub const EXTERN_ABI_REGISTER_NAME: &str = "register";
+pub const EXTERN_ABI_MEMORY_NAME: &str = "memory";
 
 /// The valid attribute strings related to conditional compilation.
 pub const CFG_ATTRIBUTE_NAME: &str = "cfg";
 pub const CFG_TARGET_ARG_NAME: &str = "target";
 pub const CFG_PROGRAM_TYPE_ARG_NAME: &str = "program_type";
+pub const CFG_ANY_ARG_NAME: &str = "any";
+pub const CFG_ALL_ARG_NAME: &str = "all";
+pub const CFG_NOT_ARG_NAME: &str = "not";
 
 /// The list of valid attributes.
 pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
@@ -66,6 +125,12 @@ pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     TEST_ATTRIBUTE_NAME,
     INLINE_ATTRIBUTE_NAME,
     PAYABLE_ATTRIBUTE_NAME,
+    BUILDER_ATTRIBUTE_NAME,
+    COERCE_ATTRIBUTE_NAME,
+    FIELDS_ATTRIBUTE_NAME,
     ALLOW_ATTRIBUTE_NAME,
+    DENY_ATTRIBUTE_NAME,
     CFG_ATTRIBUTE_NAME,
+    DEPRECATED_ATTRIBUTE_NAME,
+    EXTERN_ABI_ATTRIBUTE_NAME,
 ];
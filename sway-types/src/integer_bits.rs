@@ -9,6 +9,18 @@ pub enum IntegerBits {
     V256,
 }
 
+impl IntegerBits {
+    /// Returns `true` if `v` does not fit into an integer of this width.
+    pub fn would_overflow(&self, v: u64) -> bool {
+        match self {
+            IntegerBits::Eight => v > u8::MAX as u64,
+            IntegerBits::Sixteen => v > u16::MAX as u64,
+            IntegerBits::ThirtyTwo => v > u32::MAX as u64,
+            IntegerBits::SixtyFour | IntegerBits::V256 => false,
+        }
+    }
+}
+
 impl fmt::Display for IntegerBits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use IntegerBits::*;
@@ -103,6 +103,46 @@ pub fn to_upper_camel_case(name: &str) -> String {
     ret
 }
 
+/// Find the name among `candidates` that is the most similar to `name`, if any is similar enough
+/// to be a likely misspelling of it. Used for "did you mean" suggestions.
+///
+/// Among equally similar candidates, the ones written in the same style as `name` are preferred.
+pub fn find_similar_name<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= candidate.chars().count().max(2) / 2)
+        .min_by_key(|(distance, candidate)| (*distance, !have_same_style(name, candidate)))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns `true` if `a` and `b` are written in the same style, e.g., both in snake case.
+fn have_same_style(a: &str, b: &str) -> bool {
+    is_snake_case(a) == is_snake_case(b)
+        && is_screaming_snake_case(a) == is_screaming_snake_case(b)
+        && is_upper_camel_case(a) == is_upper_camel_case(b)
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -230,4 +270,21 @@ mod test {
         assert_eq!("__MixOfThings", to_upper_camel_case("__Mix_Of_Things"));
         assert_eq!("FooBar123", to_upper_camel_case("FooBar_123"));
     }
+
+    #[test]
+    fn find_similar_names() {
+        let candidates = ["transfer", "balance", "mint_to"];
+        assert_eq!(find_similar_name("transferr", candidates), Some("transfer"));
+        assert_eq!(find_similar_name("balanse", candidates), Some("balance"));
+        assert_eq!(find_similar_name("mint", candidates), Some("mint_to"));
+        assert_eq!(find_similar_name("burn", candidates), None);
+        assert_eq!(find_similar_name("transfer", candidates), None);
+    }
+
+    #[test]
+    fn prefer_similar_names_of_the_same_style() {
+        let candidates = ["Colr", "color"];
+        assert_eq!(find_similar_name("colr", candidates), Some("color"));
+        assert_eq!(find_similar_name("Colo", candidates), Some("Colr"));
+    }
 }
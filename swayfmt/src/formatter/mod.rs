@@ -90,7 +90,17 @@ impl Formatter {
 
         self.with_comments_context(src);
 
-        let module = parse_file(&self.source_engine, Arc::from(src), path.clone())?.value;
+        let annotated_module = parse_file(&self.source_engine, Arc::from(src), path.clone())?;
+        // Module doc comments are written along with the comments before the module kind, but
+        // other inner attributes, like `#![allow(dead_code)]`, have to be written here.
+        for attr in annotated_module
+            .attribute_list
+            .iter()
+            .filter(|attr| !attr.is_doc_comment())
+        {
+            attr.format(&mut raw_formatted_code, self)?;
+        }
+        let module = annotated_module.value;
         module.format(&mut raw_formatted_code, self)?;
 
         let mut formatted_code = String::from(&raw_formatted_code);
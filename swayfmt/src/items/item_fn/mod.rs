@@ -12,10 +12,13 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    keywords::{MutToken, RefToken, SelfToken, Token},
-    FnArg, FnArgs, FnSignature, ItemFn,
+    keywords::{ColonToken, MutToken, RefToken, SelfToken, Token},
+    FnArg, FnArgs, FnSignature, ItemFn, Ty,
+};
+use sway_types::{
+    ast::{Delimiter, PunctKind},
+    Spanned,
 };
-use sway_types::{ast::Delimiter, Spanned};
 
 #[cfg(test)]
 mod tests;
@@ -202,28 +205,66 @@ fn format_fn_args(
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
     match fn_args {
-        FnArgs::Static(args) => match formatter.shape.code_line.line_style {
-            LineStyle::Multiline => {
-                if !args.value_separator_pairs.is_empty() || args.final_value_opt.is_some() {
-                    formatter.indent();
+        FnArgs::Static { args, rest_opt } => {
+            let has_args = !args.value_separator_pairs.is_empty() || args.final_value_opt.is_some();
+            match formatter.shape.code_line.line_style {
+                LineStyle::Multiline => {
+                    if has_args || rest_opt.is_some() {
+                        formatter.indent();
+                        args.format(formatted_code, formatter)?;
+                        // `..rest`
+                        if let Some(rest) = rest_opt {
+                            if !formatted_code.ends_with('\n') {
+                                writeln!(formatted_code)?;
+                            }
+                            writeln!(
+                                formatted_code,
+                                "{}{}{}",
+                                formatter.indent_str()?,
+                                rest.double_dot_token.span().as_str(),
+                                rest.name.as_str()
+                            )?;
+                        }
+                        formatter.unindent();
+                        write!(formatted_code, "{}", formatter.indent_str()?)?;
+                    }
+                }
+                _ => {
                     args.format(formatted_code, formatter)?;
-                    formatter.unindent();
-                    write!(formatted_code, "{}", formatter.indent_str()?)?;
+                    // `..rest`
+                    if let Some(rest) = rest_opt {
+                        if has_args {
+                            write!(formatted_code, "{} ", PunctKind::Comma.as_char())?;
+                        }
+                        write!(
+                            formatted_code,
+                            "{}{}",
+                            rest.double_dot_token.span().as_str(),
+                            rest.name.as_str()
+                        )?;
+                    }
                 }
             }
-            _ => args.format(formatted_code, formatter)?,
-        },
+        }
         FnArgs::NonStatic {
             self_token,
             ref_self,
             mutable_self,
+            self_ty_opt,
             args_opt,
         } => {
             match formatter.shape.code_line.line_style {
                 LineStyle::Multiline => {
                     formatter.indent();
                     write!(formatted_code, "\n{}", formatter.indent_str()?)?;
-                    format_self(self_token, ref_self, mutable_self, formatted_code)?;
+                    format_self(
+                        self_token,
+                        ref_self,
+                        mutable_self,
+                        self_ty_opt,
+                        formatted_code,
+                        formatter,
+                    )?;
                     // `args_opt`
                     if let Some((comma, args)) = args_opt {
                         // `, `
@@ -233,7 +274,14 @@ fn format_fn_args(
                     }
                 }
                 _ => {
-                    format_self(self_token, ref_self, mutable_self, formatted_code)?;
+                    format_self(
+                        self_token,
+                        ref_self,
+                        mutable_self,
+                        self_ty_opt,
+                        formatted_code,
+                        formatter,
+                    )?;
                     // `args_opt`
                     if let Some((comma, args)) = args_opt {
                         // `, `
@@ -253,7 +301,9 @@ fn format_self(
     self_token: &SelfToken,
     ref_self: &Option<RefToken>,
     mutable_self: &Option<MutToken>,
+    self_ty_opt: &Option<(ColonToken, Box<Ty>)>,
     formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
     // `ref `
     if let Some(ref_token) = ref_self {
@@ -265,6 +315,11 @@ fn format_self(
     }
     // `self`
     write!(formatted_code, "{}", self_token.span().as_str())?;
+    // `: Ty`
+    if let Some((colon_token, ty)) = self_ty_opt {
+        write!(formatted_code, "{} ", colon_token.span().as_str())?;
+        ty.format(formatted_code, formatter)?;
+    }
 
     Ok(())
 }
@@ -294,7 +349,17 @@ impl Format for FnArg {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        // `#[cfg(testnet)] `, kept on the line of the parameter.
+        for attr in &self.attribute_list {
+            let mut formatted_attr = FormattedCode::new();
+            attr.format(&mut formatted_attr, formatter)?;
+            write!(formatted_code, "{} ", formatted_attr.trim_end())?;
+        }
         self.pattern.format(formatted_code, formatter)?;
+        // `?`
+        if let Some(question_mark_token) = &self.question_mark_token {
+            write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+        }
         // `: `
         write!(formatted_code, "{} ", self.colon_token.span().as_str())?;
 
@@ -345,13 +410,17 @@ impl LeafSpans for FnArgs {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = Vec::new();
         match &self {
-            FnArgs::Static(arg_static) => {
-                collected_spans.append(&mut arg_static.leaf_spans());
+            FnArgs::Static { args, rest_opt } => {
+                collected_spans.append(&mut args.leaf_spans());
+                if let Some(rest) = rest_opt {
+                    collected_spans.push(ByteSpan::from(rest.span()));
+                }
             }
             FnArgs::NonStatic {
                 self_token,
                 ref_self,
                 mutable_self,
+                self_ty_opt,
                 args_opt,
             } => {
                 collected_spans.push(ByteSpan::from(self_token.span()));
@@ -361,6 +430,10 @@ impl LeafSpans for FnArgs {
                 if let Some(mutable) = mutable_self {
                     collected_spans.push(ByteSpan::from(mutable.span()));
                 }
+                if let Some((colon_token, ty)) = self_ty_opt {
+                    collected_spans.push(ByteSpan::from(colon_token.span()));
+                    collected_spans.append(&mut ty.leaf_spans());
+                }
                 if let Some(args) = args_opt {
                     collected_spans.append(&mut args.leaf_spans());
                 }
@@ -372,8 +445,11 @@ impl LeafSpans for FnArgs {
 
 impl LeafSpans for FnArg {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
-        let mut collected_spans = Vec::new();
+        let mut collected_spans = self.attribute_list.leaf_spans();
         collected_spans.append(&mut self.pattern.leaf_spans());
+        if let Some(question_mark_token) = &self.question_mark_token {
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+        }
         collected_spans.push(ByteSpan::from(self.colon_token.span()));
         collected_spans.push(ByteSpan::from(self.ty.span()));
         collected_spans
@@ -11,6 +11,18 @@ fmt_test_item!(  long_fn_args            "fn foo(\n    mut self,\n    this_is_a_
             intermediate_whitespace "  fn  foo( \n        mut self , \n     this_is_a_really_long_variable : Foo ,\n    hello_im_really_long: Bar , \n ) ->    String { \n }     "
 );
 
+fmt_test_item!(  explicit_self_type      "fn foo(self: Ptr<Self>, other: Foo) -> u64 {}",
+            intermediate_whitespace "fn foo(  self  :   Ptr< Self > , other : Foo ) -> u64 { }"
+);
+
+fmt_test_item!(  typeof_fn_arg           "fn foo(amount: u64, discount: typeof(amount * 2)) -> u64 {}",
+            intermediate_whitespace "fn foo( amount : u64 ,   discount :   typeof(  amount   *  2 ) ) -> u64 { }"
+);
+
+fmt_test_item!(  attributed_fn_arg       "fn foo(amount: u64, #[cfg(testnet)] discount: u64) -> u64 {}",
+            intermediate_whitespace "fn foo( amount : u64 ,   #[ cfg( testnet ) ]   discount : u64 ) -> u64 { }"
+);
+
 fmt_test_item!(  non_self_fn
 "fn test_function(
     helloitsverylong: String,
@@ -27,6 +27,11 @@ impl Format for ItemTypeAlias {
         // Add name of the type alias
         self.name.format(formatted_code, formatter)?;
 
+        // Format `GenericParams`, if any
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
+        }
+
         // Add the `=` token
         write!(formatted_code, " {} ", self.eq_token.ident().as_str())?;
 
@@ -55,6 +60,9 @@ impl LeafSpans for ItemTypeAlias {
         }
         collected_spans.push(ByteSpan::from(self.type_token.span()));
         collected_spans.push(ByteSpan::from(self.name.span()));
+        if let Some(generics) = &self.generics {
+            collected_spans.push(ByteSpan::from(generics.parameters.span()))
+        }
         collected_spans.push(ByteSpan::from(self.eq_token.span()));
         collected_spans.append(&mut self.ty.leaf_spans());
         collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
@@ -36,12 +36,20 @@ impl Format for AttributeArg {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         write!(formatted_code, "{}", self.name.span().as_str())?;
         if let Some(value) = &self.value {
             write!(formatted_code, " = {}", value.span().as_str())?;
         }
+        if let Some(args) = &self.args {
+            // `(`
+            AttributeDecl::open_parenthesis(formatted_code, formatter)?;
+            // format and add nested args e.g. `testnet`
+            args.get().format(formatted_code, formatter)?;
+            // ')'
+            AttributeDecl::close_parenthesis(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -53,6 +61,9 @@ impl LeafSpans for AttributeArg {
         if let Some(value) = &self.value {
             collected_spans.push(ByteSpan::from(value.span()));
         }
+        if let Some(args) = &self.args {
+            collected_spans.append(&mut args.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -93,12 +104,12 @@ impl Format for AttributeDecl {
         }
 
         // invariant: attribute lists cannot be empty
-        // `#`
+        // `#` or `#!`
         let hash_type_token_span = match &self.hash_kind {
-            AttributeHashKind::Inner(_) => Err(FormatterError::HashBangAttributeError),
-            AttributeHashKind::Outer(hash_token) => Ok(hash_token.span()),
+            AttributeHashKind::Inner(hash_bang_token) => hash_bang_token.span(),
+            AttributeHashKind::Outer(hash_token) => hash_token.span(),
         };
-        write!(formatted_code, "{}", hash_type_token_span?.as_str())?;
+        write!(formatted_code, "{}", hash_type_token_span.as_str())?;
         // `[`
         Self::open_square_bracket(formatted_code, formatter)?;
         let mut regular_attrs = regular_attrs.iter().peekable();
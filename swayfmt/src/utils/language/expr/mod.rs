@@ -301,6 +301,17 @@ impl Format for Expr {
                 write!(formatted_code, "{}", bang_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
             }
+            Self::Spread {
+                double_dot_token,
+                name,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    double_dot_token.span().as_str(),
+                    name.as_str()
+                )?;
+            }
             Self::Pow {
                 lhs,
                 double_star_token,
@@ -845,6 +856,13 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
+        Expr::Spread {
+            double_dot_token,
+            name,
+        } => vec![
+            ByteSpan::from(double_dot_token.span()),
+            ByteSpan::from(name.span()),
+        ],
         Expr::Pow {
             lhs,
             double_star_token,
@@ -11,7 +11,8 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    Braces, CommaToken, ExprTupleDescriptor, PathExpr, Pattern, PatternStructField, Punctuated,
+    Braces, CommaToken, ExprTupleDescriptor, PathExpr, Pattern, PatternArrayElement,
+    PatternStructField, Punctuated,
 };
 use sway_types::{ast::Delimiter, Spanned};
 
@@ -126,6 +127,26 @@ impl Format for Pattern {
                     },
                 )?;
             }
+            Self::Array(elements) => {
+                formatter.with_shape(
+                    formatter.shape.with_default_code_line(),
+                    |formatter| -> Result<(), FormatterError> {
+                        write!(formatted_code, "{}", Delimiter::Bracket.as_open_char())?;
+                        elements.get().format(formatted_code, formatter)?;
+                        write!(formatted_code, "{}", Delimiter::Bracket.as_close_char())?;
+                        Ok(())
+                    },
+                )?;
+            }
+            Self::At {
+                name,
+                at_token,
+                pattern,
+            } => {
+                name.format(formatted_code, formatter)?;
+                write!(formatted_code, " {} ", at_token.span().as_str())?;
+                pattern.format(formatted_code, formatter)?;
+            }
             Self::Error(..) => {}
         }
         Ok(())
@@ -216,6 +237,26 @@ impl Format for PatternStructField {
     }
 }
 
+impl Format for PatternArrayElement {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Rest { token, name_opt } => {
+                write!(formatted_code, "{}", token.span().as_str())?;
+                if let Some(name) = name_opt {
+                    name.format(formatted_code, formatter)?;
+                }
+            }
+            Self::Pattern(pattern) => pattern.format(formatted_code, formatter)?,
+        }
+
+        Ok(())
+    }
+}
+
 fn get_field_width(
     fields: &Punctuated<PatternStructField, CommaToken>,
     formatter: &mut Formatter,
@@ -316,6 +357,18 @@ impl LeafSpans for Pattern {
             Pattern::Tuple(tuple) => {
                 collected_spans.append(&mut tuple.leaf_spans());
             }
+            Pattern::Array(elements) => {
+                collected_spans.append(&mut elements.leaf_spans());
+            }
+            Pattern::At {
+                name,
+                at_token,
+                pattern,
+            } => {
+                collected_spans.push(ByteSpan::from(name.span()));
+                collected_spans.push(ByteSpan::from(at_token.span()));
+                collected_spans.append(&mut pattern.leaf_spans());
+            }
             Pattern::Error(spans, _) => {
                 let mut leaf_spans = spans.iter().map(|s| ByteSpan::from(s.clone())).collect();
                 collected_spans.append(&mut leaf_spans)
@@ -346,3 +399,21 @@ impl LeafSpans for PatternStructField {
         collected_spans
     }
 }
+
+impl LeafSpans for PatternArrayElement {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = Vec::new();
+        match self {
+            PatternArrayElement::Rest { token, name_opt } => {
+                collected_spans.push(ByteSpan::from(token.span()));
+                if let Some(name) = name_opt {
+                    collected_spans.push(ByteSpan::from(name.span()));
+                }
+            }
+            PatternArrayElement::Pattern(pattern) => {
+                collected_spans.append(&mut pattern.leaf_spans());
+            }
+        }
+        collected_spans
+    }
+}
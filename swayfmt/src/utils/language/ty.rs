@@ -4,9 +4,9 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    brackets::SquareBrackets,
+    brackets::{Parens, SquareBrackets},
     expr::Expr,
-    keywords::{PtrToken, SliceToken, StrToken, Token, UnderscoreToken},
+    keywords::{PtrToken, SliceToken, StrToken, Token, TypeofToken, UnderscoreToken},
     ty::{Ty, TyArrayDescriptor, TyTupleDescriptor},
 };
 use sway_types::{ast::Delimiter, Spanned};
@@ -41,6 +41,12 @@ impl Format for Ty {
             Self::Slice { slice_token, ty } => {
                 format_slice(formatted_code, slice_token.clone(), ty.clone())
             }
+            Self::TypeOf { typeof_token, expr } => format_typeof(
+                formatted_code,
+                formatter,
+                typeof_token.clone(),
+                expr.clone(),
+            ),
         }
     }
 }
@@ -111,6 +117,23 @@ fn format_slice(
     Ok(())
 }
 
+fn format_typeof(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    typeof_token: TypeofToken,
+    expr: Parens<Box<Expr>>,
+) -> Result<(), FormatterError> {
+    write!(
+        formatted_code,
+        "{}{}",
+        typeof_token.span().as_str(),
+        Delimiter::Parenthesis.as_open_char()
+    )?;
+    expr.into_inner().format(formatted_code, formatter)?;
+    write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+    Ok(())
+}
+
 impl Format for TyTupleDescriptor {
     fn format(
         &self,
@@ -167,6 +190,11 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut ty.leaf_spans());
                 collected_spans
             }
+            Ty::TypeOf { typeof_token, expr } => {
+                let mut collected_spans = vec![ByteSpan::from(typeof_token.span())];
+                collected_spans.append(&mut expr.leaf_spans());
+                collected_spans
+            }
         }
     }
 }
@@ -366,7 +366,7 @@ pub(crate) fn test_json_storage_slots(file_name: &str, built_package: &BuiltPack
 
 fn emit_json_storage_slots(file_name: &str, built_package: &BuiltPackage) -> Result<()> {
     tracing::info!("Storage slots JSON gen {} ...", file_name.bold());
-    let json_storage_slots = serde_json::json!(built_package.storage_slots);
+    let json_storage_slots = serde_json::json!(built_package.storage_slot_entries());
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
     let file = std::fs::File::create(format!(
         "{}/src/e2e_vm_tests/test_programs/{}/{}",
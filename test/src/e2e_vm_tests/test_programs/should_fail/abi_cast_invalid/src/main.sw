@@ -0,0 +1,25 @@
+script;
+
+abi Token {
+    fn transfer(amount: u64);
+}
+
+trait NotAbi {
+    fn transfer(amount: u64);
+}
+
+// Not the `ContractId` of the standard library.
+struct ContractId {
+    value: b256,
+}
+
+fn main() {
+    let _ = abi(NotAbi, 0x0000000000000000000000000000000000000000000000000000000000000000);
+
+    let _ = abi(Token, 42u64);
+
+    let _ = abi(Token, ContractId { value: 0x0000000000000000000000000000000000000000000000000000000000000000 });
+
+    let token = abi(Token, 0x0000000000000000000000000000000000000000000000000000000000000000);
+    token.transferr(1);
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi Exchange {
+    fn swap(amount_in: u64, recipient: b256, deadline: u32) -> u64;
+}
+
+impl Exchange for Contract {
+    fn swap(recipient: b256, amount_in: u64, deadline: u32) -> u64 {
+        amount_in
+    }
+}
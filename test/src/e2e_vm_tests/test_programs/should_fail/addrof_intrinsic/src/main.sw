@@ -1,10 +1,23 @@
 script;
 
+struct Wrapper {
+    value: b256,
+}
+
+fn wrap() -> Wrapper {
+    Wrapper { value: 0x0000000000000000000000000000000000000000000000000000000000000000 }
+}
+
 fn main() -> u64 {
     let number0 = 1u8;
     let xyz = __addr_of(number0);
 
     let x = ();
     let _ = __addr_of(x);
+
+    let wrapper = wrap();
+    let _ = __addr_of(wrapper.value);
+    let _ = __addr_of(wrap());
+    let _ = __addr_of(wrap().value);
     0
 }
@@ -0,0 +1,34 @@
+script;
+
+trait Display {
+    fn fmt(self) -> u64;
+}
+
+trait Debug {
+    fn dbg(self) -> u64;
+}
+
+struct Foo {}
+
+struct Bar {}
+
+impl Display for Bar {
+    fn fmt(self) -> u64 {
+        1
+    }
+}
+
+fn show<T>(x: T) -> u64 where T: Display {
+    x.fmt()
+}
+
+fn show_both<T>(value: T) -> u64 where T: Display + Debug {
+    value.fmt() + value.dbg()
+}
+
+fn main() -> u64 {
+    let a = show(Foo {});
+    let b = show_both(Bar {});
+    let c = show(Bar {});
+    a + b + c
+}
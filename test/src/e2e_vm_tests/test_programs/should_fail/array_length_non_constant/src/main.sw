@@ -0,0 +1,13 @@
+script;
+
+const FLAG = true;
+
+fn get_len() -> u64 {
+    2
+}
+
+fn main() -> u64 {
+    let a: [u64; FLAG] = [0; FLAG];
+    let b: [u64; get_len] = [0, 0];
+    0
+}
@@ -0,0 +1,13 @@
+library;
+
+fn too_few([a, b]: [u64; 3]) -> u64 {
+    a + b
+}
+
+fn too_many([a, b, ..rest]: [u64; 1]) -> u64 {
+    a + b
+}
+
+fn not_an_array([a, b]: (u64, u64)) -> u64 {
+    a + b
+}
@@ -0,0 +1,16 @@
+script;
+
+fn first(s: __slice[u64]) -> u64 {
+    0
+}
+
+fn main() {
+    let flags = [true, false, true];
+    let _ = first(flags);
+
+    let bytes = [1u8, 2u8];
+    let _ = first(bytes);
+
+    let words = [1, 2, 3];
+    let _ = first(words);
+}
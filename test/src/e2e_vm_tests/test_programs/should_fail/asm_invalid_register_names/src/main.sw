@@ -0,0 +1,17 @@
+script;
+
+fn main() -> u64 {
+    let a = asm(r1: 1, r2: 2, r1: 3, r3) {
+        add r3 r1 r2;
+        r3: u64
+    };
+    let b = asm(sp: 1, r1) {
+        move r1 sp;
+        r1: u64
+    };
+    let c = asm(r1: 1, r2) {
+        add r2 r1 r4;
+        r2: u64
+    };
+    c
+}
@@ -0,0 +1,15 @@
+script;
+
+fn main() -> u64 {
+    let a = asm(r1: 1, r2) {
+        move r2 r1;
+        r3: u64
+    };
+    let b = asm(r1: 2, r2: 3) {
+        r1: u64
+    };
+    let c = asm() {
+        zero: u64
+    };
+    c
+}
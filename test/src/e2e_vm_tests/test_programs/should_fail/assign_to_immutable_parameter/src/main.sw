@@ -0,0 +1,17 @@
+script;
+
+fn reset(value: u64) {
+    value = 0;
+}
+
+fn reset_ref(ref value: u64) {
+    value = 0;
+}
+
+fn main() {
+    reset(42);
+    reset_ref(42);
+
+    let total = 0;
+    total = 1;
+}
@@ -0,0 +1,14 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn get_x(x @ Point { x, .. }: Point) -> u64 {
+    x
+}
+
+fn main() -> u64 {
+    get_x(Point { x: 1, y: 2 })
+}
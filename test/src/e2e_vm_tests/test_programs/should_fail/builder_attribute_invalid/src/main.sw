@@ -0,0 +1,25 @@
+library;
+
+#[builder]
+fn duplicate_setter(value: u64, _value: u64) -> u64 {
+    value
+}
+
+#[builder]
+fn generic<T>(value: T) -> T {
+    value
+}
+
+#[builder]
+fn ref_mut_param(ref mut value: u64) {
+    value = 0;
+}
+
+struct S {}
+
+impl S {
+    #[builder]
+    fn method(self, value: u64) -> u64 {
+        value
+    }
+}
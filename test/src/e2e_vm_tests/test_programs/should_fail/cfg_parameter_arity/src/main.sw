@@ -0,0 +1,10 @@
+script;
+
+fn fee(amount: u64, #[cfg(testnet)] discount: u64) -> u64 {
+    amount
+}
+
+fn main() -> u64 {
+    // The `testnet` flag is not set, so `fee` takes a single argument.
+    fee(10, 2)
+}
@@ -0,0 +1,44 @@
+script;
+
+use std::convert::From;
+
+struct Meters {
+    value: u64,
+}
+
+struct Feet {
+    value: u64,
+}
+
+impl From<u32> for Meters {
+    fn from(value: u32) -> Self {
+        Meters {
+            value: value.as_u64(),
+        }
+    }
+
+    fn into(self) -> u32 {
+        0
+    }
+}
+
+impl From<u64> for Meters {
+    fn from(value: u64) -> Self {
+        Meters { value }
+    }
+
+    fn into(self) -> u64 {
+        self.value
+    }
+}
+
+#[coerce(distance)]
+fn walk(distance: Meters) -> u64 {
+    distance.value
+}
+
+fn main() -> u64 {
+    let ambiguous = walk(5);
+    let unconvertible = walk(Feet { value: 10 });
+    ambiguous + unconvertible
+}
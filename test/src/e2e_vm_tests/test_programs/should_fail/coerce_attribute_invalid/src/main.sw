@@ -0,0 +1,14 @@
+script;
+
+struct Meters {
+    value: u64,
+}
+
+#[coerce(distance)]
+fn walk(length: Meters) -> u64 {
+    length.value
+}
+
+fn main() -> u64 {
+    walk(Meters { value: 1 })
+}
@@ -0,0 +1,4 @@
+script;
+
+#[cfg(any(testnet = "yes", not(testnet, mainnet), testnet(mainnet)))]
+fn main() {}
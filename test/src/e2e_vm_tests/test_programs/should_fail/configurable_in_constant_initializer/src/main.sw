@@ -0,0 +1,11 @@
+script;
+
+configurable {
+    FEE: u64 = 25,
+}
+
+const FEE_COPY: u64 = FEE;
+
+fn main() -> u64 {
+    FEE_COPY
+}
@@ -0,0 +1,20 @@
+contract;
+
+configurable {
+    FEE: u64 = 25,
+}
+
+const DEFAULT_FEE: u64 = 25;
+
+storage {
+    fee: u64 = FEE,
+    default_fee: u64 = DEFAULT_FEE,
+}
+
+abi Fees {
+    fn noop();
+}
+
+impl Fees for Contract {
+    fn noop() {}
+}
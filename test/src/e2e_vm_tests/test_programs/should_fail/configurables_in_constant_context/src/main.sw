@@ -0,0 +1,14 @@
+script;
+
+configurable {
+    LEN: u64 = 3,
+    FEE: u64 = 25,
+}
+
+fn main() -> u64 {
+    let arr: [u64; LEN] = [1, 2, 3];
+    match arr[0] {
+        FEE => 0,
+        _ => 1,
+    }
+}
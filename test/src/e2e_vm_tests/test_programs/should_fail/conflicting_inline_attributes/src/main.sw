@@ -0,0 +1,30 @@
+script;
+
+#[inline(always)]
+#[inline(never)]
+fn double(x: u64) -> u64 {
+    x
+}
+
+struct S {}
+
+impl S {
+    #[inline(never)]
+    #[inline(never)]
+    #[inline(always)]
+    fn triple(self, x: u64) -> u64 {
+        x
+    }
+}
+
+#[inline(always)]
+#[inline(always)]
+fn quadruple(x: u64) -> u64 {
+    x
+}
+
+fn main() {
+    let _ = double(1);
+    let _ = S {}.triple(1);
+    let _ = quadruple(1);
+}
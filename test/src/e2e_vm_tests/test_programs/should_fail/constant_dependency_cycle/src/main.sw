@@ -0,0 +1,11 @@
+script;
+
+const A = B;
+const B = C;
+const C = A;
+
+const SELF_REFERENCING: u64 = SELF_REFERENCING;
+
+fn main() -> u64 {
+    A
+}
@@ -0,0 +1,26 @@
+script;
+
+abi MyContract {
+    fn test_function();
+}
+
+fn main() {
+    let caller = abi(MyContract, 0x0000000000000000000000000000000000000000000000000000000000000000);
+
+    caller.test_function {
+        gass: 1000,
+    } ();
+
+    caller.test_function {
+        gas: 1000,
+        gas: 2000,
+    } ();
+
+    caller.test_function {
+        asset_id: 0u64,
+    } ();
+
+    caller.test_function {
+        gas: true,
+    } ();
+}
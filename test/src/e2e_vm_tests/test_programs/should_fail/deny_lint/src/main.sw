@@ -0,0 +1,26 @@
+#![deny(dead_code)]
+script;
+
+#[deprecated(note = "Use `new` instead.")]
+fn old() -> u64 {
+    1
+}
+
+fn new() -> u64 {
+    2
+}
+
+#[deny(deprecated)]
+fn denied() -> u64 {
+    old()
+}
+
+fn unused() {}
+
+// The innermost lint level wins.
+#[allow(dead_code)]
+fn allowed() {}
+
+fn main() -> u64 {
+    denied() + new()
+}
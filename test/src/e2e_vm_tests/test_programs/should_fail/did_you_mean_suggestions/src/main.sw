@@ -0,0 +1,44 @@
+script;
+
+enum Option<T> {
+    None: (),
+    Some: T,
+}
+
+struct Wallet {
+    owner: u64,
+    funds: u64,
+}
+
+impl Wallet {
+    fn balance(self) -> u64 {
+        self.funds
+    }
+
+    fn balances(self, index: u64) -> u64 {
+        self.funds + index
+    }
+}
+
+fn unknown_variable() -> u64 {
+    let amount = 5;
+    amout + 1
+}
+
+fn unknown_field(wallet: Wallet) -> u64 {
+    wallet.fund
+}
+
+fn unknown_method(wallet: Wallet) -> u64 {
+    wallet.balanse(0)
+}
+
+fn unknown_variant() -> Option<u64> {
+    Option::Nome
+}
+
+fn unknown_variant_with_value() -> Option<u64> {
+    Option::Som(5)
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+script;
+
+trait Add {
+    fn add(self, other: Self) -> Self;
+}
+
+impl Add for u64 {
+    fn add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, r3) {
+            add r3 r2 r1;
+            r3: u64
+        }
+    }
+}
+
+impl Add for u32 {
+    fn add(self, other: Self) -> Self {
+        asm(r1: self, r2: other, r3) {
+            add r3 r2 r1;
+            r3: u32
+        }
+    }
+}
+
+struct Account {
+    balance: u46,
+}
+
+fn balance_of(account: Account) -> u64 {
+    account.balance
+}
+
+fn deposit(ref mut account: Account, amount: u64) {
+    account.balance = account.balance.add(amount);
+}
+
+fn main() -> u64 {
+    let mut account = Account { balance: 0 };
+    deposit(account, 10);
+    let total: u64 = balance_of(account).add(account.balance);
+    let doubled = account.balance.add(account.balance);
+    total.add(doubled)
+}
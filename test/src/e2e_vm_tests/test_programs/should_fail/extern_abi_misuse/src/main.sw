@@ -0,0 +1,33 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+#[extern_abi(c)]
+fn unknown(x: u64) -> u64 {
+    x
+}
+
+#[extern_abi(register)]
+fn too_big(p: Point, h: b256, ok: bool) -> u64 {
+    p.x
+}
+
+#[extern_abi(memory)]
+fn generic<T>(value: T) -> T {
+    value
+}
+
+#[extern_abi(register)]
+fn too_many(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64) -> u64 {
+    g
+}
+
+fn main() {
+    let _ = unknown(1);
+    let _ = too_big(Point { x: 1, y: 2 }, 0x0000000000000000000000000000000000000000000000000000000000000000, true);
+    let _ = generic(1);
+    let _ = too_many(1, 2, 3, 4, 5, 6, 7);
+}
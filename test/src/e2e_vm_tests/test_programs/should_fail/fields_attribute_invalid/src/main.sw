@@ -0,0 +1,27 @@
+library;
+
+struct Config {
+    fee: u64,
+    rate: u64,
+}
+
+struct Other {
+    fee: u64,
+}
+
+fn collides_with_parameter(#[fields] config: Config, fee: u64) -> u64 {
+    fee
+}
+
+fn collides_with_let(#[fields] config: Config) -> u64 {
+    let rate = 2;
+    rate
+}
+
+fn collides_with_other_fields(#[fields] config: Config, #[fields] other: Other) -> u64 {
+    fee
+}
+
+fn not_a_struct(#[fields] config: (u64, u64)) -> u64 {
+    config.0
+}
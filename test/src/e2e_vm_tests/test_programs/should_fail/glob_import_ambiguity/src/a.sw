@@ -0,0 +1,5 @@
+library;
+
+pub const ZERO: u64 = 0;
+
+pub const ONE: u64 = 1;
@@ -0,0 +1,5 @@
+library;
+
+pub const ZERO: u64 = 0;
+
+pub const TWO: u64 = 2;
@@ -0,0 +1,15 @@
+script;
+
+mod a;
+mod b;
+
+// Both modules export `ZERO`. This is fine as long as `ZERO` isn't used.
+use ::a::*;
+use ::b::*;
+
+fn main() -> u64 {
+    // `ONE` and `TWO` are only exported by one module each.
+    let x = ONE + TWO;
+    // This is ambiguous.
+    x + ZERO
+}
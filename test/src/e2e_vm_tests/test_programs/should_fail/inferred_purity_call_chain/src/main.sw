@@ -0,0 +1,37 @@
+contract;
+
+storage {
+    total: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read)]
+    fn total() -> u64;
+
+    fn reset();
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn total() -> u64 {
+        add_to_total(0)
+    }
+
+    fn reset() {
+        let _ = get_total();
+    }
+}
+
+fn add_to_total(amount: u64) -> u64 {
+    let total = get_total() + amount;
+    set_total(total);
+    total
+}
+
+fn get_total() -> u64 {
+    storage.total.read()
+}
+
+fn set_total(total: u64) {
+    storage.total.write(total);
+}
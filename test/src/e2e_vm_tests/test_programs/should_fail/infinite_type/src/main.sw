@@ -0,0 +1,6 @@
+script;
+
+fn main() {
+    let mut v = Vec::new();
+    v.push(v);
+}
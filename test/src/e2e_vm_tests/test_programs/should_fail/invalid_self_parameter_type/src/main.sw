@@ -0,0 +1,37 @@
+script;
+
+struct Ptr<T> {
+    inner: T,
+}
+
+enum Option<T> {
+    None: (),
+    Some: T,
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn not_self(self: u64) -> u64 {
+        self
+    }
+
+    fn wraps_other(self: Ptr<u64>) -> u64 {
+        self.inner
+    }
+
+    fn optional(self: Option<Self>) -> u64 {
+        0
+    }
+}
+
+trait Wrapped {
+    fn wrapped(self: Ptr<Self>) -> u64;
+    fn bad(self: bool) -> u64;
+}
+
+fn main() -> u64 {
+    0
+}
@@ -0,0 +1,9 @@
+script;
+
+struct Wrapper {
+    ptr: raw_ptr,
+}
+
+fn main(ptr: raw_ptr, wrapper: Wrapper, slice: raw_slice, val: u64) -> u64 {
+    val
+}
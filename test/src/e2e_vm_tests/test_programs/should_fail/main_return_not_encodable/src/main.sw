@@ -0,0 +1,11 @@
+script;
+
+struct Wrapper {
+    ptr: raw_ptr,
+}
+
+fn main() -> Wrapper {
+    Wrapper {
+        ptr: asm() { zero: raw_ptr },
+    }
+}
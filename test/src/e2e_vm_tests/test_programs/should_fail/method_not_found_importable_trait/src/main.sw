@@ -0,0 +1,8 @@
+script;
+
+mod math;
+
+fn main() -> u64 {
+    let x: u64 = 16;
+    x.square_root()
+}
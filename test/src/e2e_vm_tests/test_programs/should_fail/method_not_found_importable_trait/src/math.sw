@@ -0,0 +1,22 @@
+library;
+
+pub trait SquareRoot {
+    fn square_root(self) -> Self;
+}
+
+impl SquareRoot for u64 {
+    fn square_root(self) -> Self {
+        self
+    }
+}
+
+// Private traits are never suggested for import.
+trait ApproximateSquareRoot {
+    fn square_root(self) -> Self;
+}
+
+impl ApproximateSquareRoot for u64 {
+    fn square_root(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,7 @@
+library;
+
+use ::b::B;
+
+pub struct A {
+    b: B,
+}
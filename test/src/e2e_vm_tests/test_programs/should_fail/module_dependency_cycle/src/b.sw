@@ -0,0 +1,11 @@
+library;
+
+use ::c::get_c;
+
+pub struct B {
+    c: u64,
+}
+
+pub fn get_b() -> B {
+    B { c: get_c() }
+}
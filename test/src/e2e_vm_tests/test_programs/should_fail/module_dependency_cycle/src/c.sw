@@ -0,0 +1,9 @@
+library;
+
+use ::a::A;
+
+pub fn get_c() -> u64 {
+    0
+}
+
+pub fn take_a(a: A) {}
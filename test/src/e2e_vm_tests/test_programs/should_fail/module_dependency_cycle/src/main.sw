@@ -0,0 +1,5 @@
+library;
+
+mod a;
+mod b;
+mod c;
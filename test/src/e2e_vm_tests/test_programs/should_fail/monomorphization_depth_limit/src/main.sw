@@ -0,0 +1,25 @@
+script;
+
+struct Wrapper<T> {
+    value: T,
+}
+
+fn wrap<T>(value: T) -> Wrapper<T> {
+    Wrapper { value }
+}
+
+fn unwrap<T>(wrapper: Wrapper<T>) -> T {
+    wrapper.value
+}
+
+fn main() -> u64 {
+    // `T` is nested at most two levels deep, which is within the limit.
+    let shallow = wrap(wrap(42u64));
+    let deep = Wrapper {
+        value: Wrapper {
+            value: Wrapper { value: 42u64 },
+        },
+    };
+    let _ = wrap(deep);
+    unwrap(unwrap(shallow))
+}
@@ -0,0 +1,65 @@
+script;
+
+const LIMIT: u64 = 1;
+const LIMIT: u64 = 2;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn shift(ref mut self, by: u64) {
+        self.x = self.x + by;
+    }
+}
+
+trait Shape {
+    fn area(self, scale: u64) -> u64;
+}
+
+impl Shape for Point {
+    fn area(self) -> u64 {
+        self.x * self.y
+    }
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn duplicate_parameter(
+    value: u64,
+    value: u64,
+) -> u64 {
+    value
+}
+
+fn too_many_arguments() -> u64 {
+    add(1, 2, 3)
+}
+
+fn too_few_arguments() -> u64 {
+    add(1)
+}
+
+fn missing_field() -> Point {
+    Point { x: 1 }
+}
+
+fn unknown_field() -> Point {
+    Point { x: 1, y: 2, z: 3 }
+}
+
+fn assignment_to_immutable() -> u64 {
+    let total = 0;
+    total = 1;
+    total
+}
+
+fn method_on_immutable() {
+    let point = Point { x: 1, y: 2 };
+    point.shift(1);
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u64 {
+    let mut ref x = 5;
+    x
+}
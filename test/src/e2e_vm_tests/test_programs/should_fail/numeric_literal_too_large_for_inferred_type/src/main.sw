@@ -0,0 +1,16 @@
+script;
+
+fn takes_u16(x: u16) -> u16 {
+    x
+}
+
+fn main() {
+    let x = 300;
+    let _y: u8 = x;
+
+    let z = 70000;
+    let _ = takes_u16(z);
+
+    let w = 255;
+    let _v: u8 = w;
+}
@@ -0,0 +1,15 @@
+script;
+
+struct Counter {}
+
+impl Counter {
+    fn add(self, amount?: u64) {}
+}
+
+fn not_last(first?: u64, second: u64) {}
+
+fn before_rest(first?: u64, ..rest) {}
+
+fn ref_mut_optional(ref mut value?: u64) {}
+
+fn main() {}
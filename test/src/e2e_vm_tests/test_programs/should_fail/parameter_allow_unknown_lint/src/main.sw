@@ -0,0 +1,9 @@
+script;
+
+fn transfer(#[allow(unused_parameter)] sender: u64, amount: u64) -> u64 {
+    amount
+}
+
+fn main() -> u64 {
+    transfer(1, 2)
+}
@@ -0,0 +1,22 @@
+library;
+
+pub struct Pair<A, B> {
+    a: A,
+    b: B,
+}
+
+pub struct Wrapper<T> {
+    t: T,
+}
+
+pub fn first(p: Pair) -> u64 {
+    0
+}
+
+pub trait MyTrait {
+    fn wrap(self, w: Wrapper) -> u64;
+} {
+    fn both(self, p: Pair) -> u64 {
+        0
+    }
+}
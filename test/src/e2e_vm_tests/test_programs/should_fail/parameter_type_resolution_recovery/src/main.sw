@@ -0,0 +1,17 @@
+library;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+// The unknown types of `p` and of the array pattern are reported once. The other parameter, the
+// body and the calls are still checked, without errors about `p`, `a` or `b`.
+fn distance(p: Pont, [a, b]: Pairr, q: Point) -> u64 {
+    let first = p;
+    a + b + q.z
+}
+
+fn caller() -> u64 {
+    distance(1, true, Point { x: 1, y: 2 })
+}
@@ -0,0 +1,21 @@
+predicate;
+
+use std::{constants::ZERO_B256, logging::log, storage::storage_api::read};
+
+abi Counter {
+    fn total() -> u64;
+}
+
+fn main() -> bool {
+    let total = abi(Counter, ZERO_B256).total();
+    check_total(total)
+}
+
+fn check_total(total: u64) -> bool {
+    log(total);
+    total == stored_total()
+}
+
+fn stored_total() -> u64 {
+    read::<u64>(ZERO_B256, 0).unwrap_or(0)
+}
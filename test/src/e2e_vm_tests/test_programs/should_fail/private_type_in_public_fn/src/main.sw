@@ -0,0 +1,34 @@
+library;
+
+struct PrivateStruct {
+    value: u64,
+}
+
+enum PrivateEnum {
+    A: (),
+    B: u64,
+}
+
+pub struct PublicStruct {
+    value: u64,
+}
+
+pub fn private_struct(x: PrivateStruct) -> u64 {
+    x.value
+}
+
+pub fn private_enum_in_tuple(x: (u64, PrivateEnum)) -> u64 {
+    x.0
+}
+
+pub fn public_struct(x: PublicStruct) -> u64 {
+    x.value
+}
+
+pub fn generic<T>(x: T) -> T {
+    x
+}
+
+fn private_fn(x: PrivateStruct) -> u64 {
+    x.value
+}
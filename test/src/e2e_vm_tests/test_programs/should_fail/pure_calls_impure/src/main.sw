@@ -1,11 +1,12 @@
 contract;
 
-// this should fail because a pure function cannot call an impure function
+// this should fail because a function annotated as pure cannot call an impure function
 
 fn main() {
 }
 
 
+#[storage()]
 fn pure_function() {
   impure_function();
 }
@@ -0,0 +1,11 @@
+script;
+
+#[allow(dead_code)]
+type A = B;
+#[allow(dead_code)]
+type B = A;
+
+#[allow(dead_code)]
+type Pair<T> = (T, Pair<T>);
+
+fn main() {}
@@ -0,0 +1,39 @@
+script;
+
+struct Inner {
+    value: u64,
+}
+
+struct Outer {
+    inner: Inner,
+    pair: (u64, u64),
+    values: [u64; 2],
+}
+
+impl Outer {
+    fn set_value(self, ref mut value: u64) {
+        value = 1;
+    }
+}
+
+fn set_value(ref mut value: u64) {
+    value = 1;
+}
+
+fn set_inner(ref mut inner: Inner) {
+    inner.value = 1;
+}
+
+fn main() {
+    let outer = Outer {
+        inner: Inner { value: 0 },
+        pair: (0, 0),
+        values: [0, 0],
+    };
+
+    set_value(outer.inner.value);
+    set_inner(outer.inner);
+    set_value(outer.pair.1);
+    set_value(outer.values[1]);
+    outer.set_value(outer.values[0]);
+}
@@ -0,0 +1,33 @@
+library;
+
+fn weighted_sum(first: u64, second: u64, weight: u64) -> u64 {
+    (first + second) * weight
+}
+
+fn select(flag: bool, value: u64) -> u64 {
+    if flag { value } else { 0 }
+}
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn forward_to_mismatched(..rest) -> u64 {
+    weighted_sum(..rest) + select(..rest)
+}
+
+fn forward_too_few(..rest) -> u64 {
+    weighted_sum(..rest) + weighted_sum(1, 2, 3, ..rest)
+}
+
+fn forward_to_generic(..rest) -> u64 {
+    identity(..rest)
+}
+
+fn never_forwarded(..rest) {}
+
+struct S {}
+
+impl S {
+    fn method(..rest) {}
+}
@@ -0,0 +1,21 @@
+library;
+
+fn weighted_sum(first: u64, second: u64, weight: u64) -> u64 {
+    (first + second) * weight
+}
+
+fn spread_not_last(..rest) -> u64 {
+    weighted_sum(..rest, 1)
+}
+
+fn spread_outside_call(..rest) {
+    let _ = ..rest;
+}
+
+fn spread_in_tuple(..rest) {
+    let _ = (1, ..rest);
+}
+
+fn spread_last(..rest) -> u64 {
+    weighted_sum(..rest)
+}
@@ -0,0 +1,32 @@
+contract;
+
+struct Registry {
+    flags: StorageMap<u64, bool>,
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    registry: Registry = Registry {
+        flags: StorageMap {},
+    },
+}
+
+abi MapIndexKeyMismatch {
+    #[storage(read, write)]
+    fn set_balance(amount: u64);
+
+    #[storage(read)]
+    fn flag() -> bool;
+}
+
+impl MapIndexKeyMismatch for Contract {
+    #[storage(read, write)]
+    fn set_balance(amount: u64) {
+        storage.balances[amount] = amount;
+    }
+
+    #[storage(read)]
+    fn flag() -> bool {
+        storage.registry.flags[true].read()
+    }
+}
@@ -0,0 +1,19 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi VecIndex {
+    #[storage(read)]
+    fn first() -> u64;
+}
+
+storage {
+    values: StorageVec<u64> = StorageVec {},
+}
+
+impl VecIndex for Contract {
+    #[storage(read)]
+    fn first() -> u64 {
+        storage.values[0].read()
+    }
+}
@@ -0,0 +1,16 @@
+library;
+
+trait Counter {
+    fn increment(ref mut value: u64);
+    fn check(ref value: u64);
+}
+
+struct S {}
+
+impl Counter for S {
+    fn increment(ref value: u64) {}
+
+    fn check(ref mut value: u64) {
+        value = 0;
+    }
+}
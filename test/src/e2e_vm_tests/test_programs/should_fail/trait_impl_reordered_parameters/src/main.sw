@@ -0,0 +1,13 @@
+script;
+
+trait Transfer {
+    fn transfer(self, amount: u64, to: b256, memo: bool);
+}
+
+struct Wallet {}
+
+impl Transfer for Wallet {
+    fn transfer(self, to: b256, amount: u64, memo: bool) {}
+}
+
+fn main() {}
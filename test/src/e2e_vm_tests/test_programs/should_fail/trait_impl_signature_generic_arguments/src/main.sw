@@ -0,0 +1,15 @@
+script;
+
+trait Convert<T> {
+    fn convert(self, value: (T, bool)) -> T;
+}
+
+struct Device {}
+
+impl Convert<u64> for Device {
+    fn convert(self, value: (u32, bool)) -> u64 {
+        0
+    }
+}
+
+fn main() {}
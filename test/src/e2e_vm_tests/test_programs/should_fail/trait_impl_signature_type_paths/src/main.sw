@@ -0,0 +1,19 @@
+script;
+
+mod other;
+
+use other::{Configure, Limit};
+
+struct Config {
+    value: u64,
+}
+
+struct Device {}
+
+impl Configure for Device {
+    fn configure(self, config: Config) -> Limit {
+        Limit { value: config.value }
+    }
+}
+
+fn main() {}
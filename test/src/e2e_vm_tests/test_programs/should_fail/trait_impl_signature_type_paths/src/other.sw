@@ -0,0 +1,13 @@
+library;
+
+pub struct Config {
+    value: u64,
+}
+
+pub struct Limit {
+    value: u64,
+}
+
+pub trait Configure {
+    fn configure(self, config: Config) -> Limit;
+}
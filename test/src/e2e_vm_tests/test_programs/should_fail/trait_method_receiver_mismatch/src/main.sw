@@ -0,0 +1,28 @@
+library;
+
+struct Counter {
+    value: u64,
+}
+
+trait Count {
+    fn increment(ref mut self);
+    fn add(ref mut self, ref mut total: u64);
+    fn get(self) -> u64;
+    fn zero(counter: Self) -> Self;
+}
+
+impl Count for Counter {
+    fn increment(self) {
+    }
+
+    fn add(ref mut self, total: u64) {
+    }
+
+    fn get(counter: Self) -> u64 {
+        counter.value
+    }
+
+    fn zero(self) -> Self {
+        Counter { value: 0 }
+    }
+}
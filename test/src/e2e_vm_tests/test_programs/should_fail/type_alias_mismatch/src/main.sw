@@ -0,0 +1,11 @@
+script;
+
+type Balance = u64;
+type Amount = Balance;
+type Pair<T> = (T, T);
+
+fn main() {
+    let _balance: Balance = true;
+    let _amount: Amount = false;
+    let _pair: Pair<u8> = 7;
+}
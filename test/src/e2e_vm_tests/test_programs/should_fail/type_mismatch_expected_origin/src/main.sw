@@ -0,0 +1,53 @@
+script;
+
+fn implicit_return() -> u64 {
+    true
+}
+
+fn explicit_return(b: bool) -> u64 {
+    if b {
+        return false;
+    }
+    0
+}
+
+fn let_ascription() {
+    let _x: u64 = true;
+}
+
+fn if_branches(b: bool) {
+    let _x = if b { 1u64 } else { false };
+}
+
+fn match_arms(n: u64) {
+    let _x = match n {
+        0 => 1u64,
+        _ => false,
+    };
+}
+
+fn takes_u64(x: u64) -> u64 {
+    x
+}
+
+fn argument() {
+    let _x = takes_u64(true);
+}
+
+fn id<T>(value: T) -> T {
+    value
+}
+
+fn explicit_type_argument() {
+    let _x = id::<u64>(true);
+}
+
+fn main() {
+    let _ = implicit_return();
+    let _ = explicit_return(true);
+    let_ascription();
+    if_branches(true);
+    match_arms(0);
+    argument();
+    explicit_type_argument();
+}
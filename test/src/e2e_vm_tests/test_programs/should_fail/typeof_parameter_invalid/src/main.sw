@@ -0,0 +1,19 @@
+library;
+
+const LIMIT: u64 = 5;
+
+fn refers_to_later(a: typeof(b), b: u64) -> u64 {
+    b
+}
+
+fn refers_to_itself(a: typeof(a)) -> u64 {
+    LIMIT
+}
+
+fn does_not_type_check(a: typeof(UNKNOWN)) -> u64 {
+    LIMIT
+}
+
+trait Limited {
+    fn f(x: typeof(LIMIT));
+}
@@ -0,0 +1,18 @@
+script;
+
+struct Address {
+    value: b256,
+}
+
+enum Color {
+    Red: (),
+    Green: (),
+}
+
+fn takes_address(address: Addres) {}
+
+fn takes_color(color: Colour) {}
+
+fn takes_generic<Tx>(value: Ty) {}
+
+fn main() {}
@@ -0,0 +1,31 @@
+script;
+
+// The items that are compiled out may refer to items that do not exist.
+#[cfg(mainnet)]
+use ::mainnet_only::*;
+
+#[cfg(testnet)]
+const FEE: u64 = 1;
+#[cfg(not(testnet))]
+const FEE: u64 = 100;
+
+#[cfg(all(testnet, not(mainnet)))]
+fn bonus() -> u64 {
+    10
+}
+#[cfg(any(mainnet, not(testnet)))]
+fn bonus() -> u64 {
+    MAINNET_BONUS
+}
+
+struct Fees {
+    #[cfg(testnet)]
+    testnet: u64,
+    #[cfg(mainnet)]
+    mainnet: MainnetFee,
+}
+
+fn main() -> u64 {
+    let fees = Fees { testnet: FEE };
+    fees.testnet + bonus()
+}
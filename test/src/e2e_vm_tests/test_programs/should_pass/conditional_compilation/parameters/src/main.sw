@@ -0,0 +1,30 @@
+script;
+
+// The parameters that are compiled out are not part of the functions, and may have types that
+// do not exist.
+fn fee(
+    amount: u64,
+    #[cfg(testnet)] discount: u64,
+    #[cfg(mainnet)] surcharge: MainnetSurcharge,
+) -> u64 {
+    amount - discount
+}
+
+trait Priced {
+    fn price(self, #[cfg(not(testnet))] markup: u64) -> u64;
+}
+
+struct Item {
+    cost: u64,
+}
+
+impl Priced for Item {
+    fn price(self, #[cfg(not(testnet))] markup: u64) -> u64 {
+        self.cost
+    }
+}
+
+fn main() -> u64 {
+    let item = Item { cost: 3 };
+    fee(10, 2) + item.price()
+}
@@ -0,0 +1,26 @@
+script;
+
+trait Fee {
+    fn fee(self, amount: u64, discount: u64) -> u64;
+}
+
+struct FlatFee {
+    value: u64,
+}
+
+// The trait requires `amount` and `discount`, which a flat fee does not need.
+impl Fee for FlatFee {
+    fn fee(self, #[allow(unused_variables)] amount: u64, #[allow(unused)] discount: u64) -> u64 {
+        self.value
+    }
+}
+
+// Only `unused_a` is allowed, `unused_b` is still reported.
+fn scoped(#[allow(unused_variables)] unused_a: u64, unused_b: u64) -> u64 {
+    42
+}
+
+fn main() -> u64 {
+    let flat = FlatFee { value: 1 };
+    flat.fee(100, 10) + scoped(1, 2)
+}
@@ -1,6 +1,6 @@
 library;
 
-struct S {}
+pub struct S {}
 
 pub fn free_fn(s: S) -> S {
     s
@@ -0,0 +1,28 @@
+script;
+
+// `_` is not inserted into the namespace, so it can be declared multiple times.
+fn ignored(_: u64, _: bool) -> u64 {
+    1
+}
+
+// `_count` is inserted into the namespace and can be used, but is never reported as unused.
+fn used(_count: u64) -> u64 {
+    _count + 1
+}
+
+fn unused(_count: u64) -> u64 {
+    2
+}
+
+struct A {}
+
+impl A {
+    fn method(self, _: u64, _offset: u64) -> u64 {
+        _offset
+    }
+}
+
+fn main() -> u64 {
+    let a = A {};
+    ignored(1, true) + used(2) + unused(3) + a.method(4, 5)
+}
@@ -0,0 +1,17 @@
+script;
+
+use std::constants::ZERO_B256;
+
+abi Token {
+    fn transfer(amount: u64);
+}
+
+fn main() {
+    // The address can be given as a `ContractId`...
+    let token = abi(Token, ContractId::from(ZERO_B256));
+    token.transfer(1);
+
+    // ...or as a `b256`.
+    let token = abi(Token, ZERO_B256);
+    token.transfer(2);
+}
@@ -0,0 +1,16 @@
+library;
+
+pub mod inner;
+
+pub struct Config {
+    value: u64,
+}
+
+pub enum Color {
+    Red: (),
+    Blue: u64,
+}
+
+pub fn make(value: u64) -> Config {
+    Config { value }
+}
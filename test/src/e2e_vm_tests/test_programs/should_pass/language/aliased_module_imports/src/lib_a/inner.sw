@@ -0,0 +1,16 @@
+library;
+
+pub mod deeper;
+
+pub struct Point {
+    x: u64,
+}
+
+pub enum Shape {
+    Dot: (),
+    Line: u64,
+}
+
+pub fn forty_two() -> u64 {
+    42
+}
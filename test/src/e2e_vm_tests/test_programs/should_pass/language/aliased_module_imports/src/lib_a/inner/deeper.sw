@@ -0,0 +1,5 @@
+library;
+
+pub fn one() -> u64 {
+    1
+}
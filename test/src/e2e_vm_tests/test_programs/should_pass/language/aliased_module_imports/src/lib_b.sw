@@ -0,0 +1,5 @@
+library;
+
+pub struct Config {
+    flag: bool,
+}
@@ -0,0 +1,39 @@
+script;
+// This tests renaming types, functions, enums and modules on import.
+
+mod lib_a;
+mod lib_b;
+
+use std::{hash::sha256 as hash, vec::Vec as List};
+use lib_a::{Color as Colour, Config as ConfigA, make as make_a};
+use lib_b::Config as ConfigB;
+use lib_a::inner as deep;
+use lib_a::inner;
+use deep::forty_two as answer;
+
+fn main() -> u64 {
+    let a: ConfigA = make_a(1);
+    let b = ConfigB { flag: true };
+
+    let colour = Colour::Blue(2);
+    let blue = match colour {
+        Colour::Red => 0,
+        Colour::Blue(x) => x,
+    };
+
+    let point = deep::Point { x: 3 };
+    let shape = deep::Shape::Line(4);
+    let line = match shape {
+        deep::Shape::Dot => 0,
+        deep::Shape::Line(x) => x,
+    };
+
+    let mut list = List::new();
+    list.push(inner::forty_two());
+    list.push(deep::deeper::one());
+
+    assert(hash(42) == std::hash::sha256(42));
+    assert(answer() == list.get(0).unwrap());
+
+    a.value + blue + point.x + line + list.get(0).unwrap() + list.get(1).unwrap() + if b.flag { 1 } else { 0 }
+}
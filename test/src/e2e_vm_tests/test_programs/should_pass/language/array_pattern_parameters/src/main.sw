@@ -0,0 +1,27 @@
+script;
+
+const LEN: u64 = 4;
+
+fn sum([a, b, c]: [u64; 3]) -> u64 {
+    a + b + c
+}
+
+fn second([_, second, _]: [u64; 3]) -> u64 {
+    second
+}
+
+fn head_and_tail([head, ..tail]: [u64; LEN]) -> u64 {
+    assert(tail[0] == 2);
+    assert(tail[2] == 4);
+    head + tail[1]
+}
+
+fn first_two([first, second, ..]: [u64; 4], scale: u64) -> u64 {
+    (first + second) * scale
+}
+
+fn main() -> u64 {
+    let values = [1, 2, 3, 4];
+    // 6 + 5 + 4 + 6 + 21
+    sum([1, 2, 3]) + second([4, 5, 6]) + head_and_tail(values) + first_two(values, 2) + 21
+}
@@ -0,0 +1,52 @@
+script;
+
+fn into_parts(s: __slice[u64]) -> (raw_ptr, u64) {
+    asm(s: s) { s: (raw_ptr, u64) }
+}
+
+fn len(s: __slice[u64]) -> u64 {
+    into_parts(s).1
+}
+
+fn sum(s: __slice[u64]) -> u64 {
+    let (ptr, len) = into_parts(s);
+    let mut sum = 0;
+    let mut i = 0;
+    while i < len {
+        sum += ptr.add::<u64>(i).read::<u64>();
+        i += 1;
+    }
+    sum
+}
+
+struct Buffer {
+    values: [u64; 2],
+}
+
+impl Buffer {
+    fn total(self, extra: __slice[u64]) -> u64 {
+        sum(self.values) + sum(extra)
+    }
+}
+
+fn main() -> u64 {
+    let values = [1, 2, 3];
+    assert(len(values) == 3);
+    assert(sum(values) == 6);
+
+    // Temporaries live until the call returns.
+    assert(len([7; 5]) == 5);
+    assert(sum([10, 20]) == 30);
+
+    let buffer = Buffer { values: [4, 5] };
+    let total = buffer.total(values);
+    assert(total == 15);
+
+    total + sum([9, 9, 9])
+}
+
+fn assert(condition: bool) {
+    if !condition {
+        __revert(1)
+    }
+}
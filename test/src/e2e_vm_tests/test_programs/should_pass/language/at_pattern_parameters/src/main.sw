@@ -0,0 +1,24 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn x_plus_y(whole @ Point { x, .. }: Point) -> u64 {
+    x + whole.y
+}
+
+fn sum(pair @ (a, b): (u64, u64), scale: u64) -> u64 {
+    assert(pair.0 == a);
+    assert(pair.1 == b);
+    (a + b) * scale
+}
+
+fn main() -> u64 {
+    let p @ Point { y, .. } = Point { x: 3, y: 4 };
+    assert(p.x == 3);
+    assert(y == 4);
+
+    x_plus_y(p) + sum((1, 4), y) + 15
+}
@@ -6,7 +6,7 @@ fn f1() {}
 #[allow]
 fn f2() {}
 
-#[allow(dead_code, dead_code)]
+#[deny(dead_code, bar)]
 fn f3() {}
 
 fn main() {
@@ -0,0 +1,39 @@
+script;
+
+struct Range {
+    start: u64,
+    end: u64,
+}
+
+#[builder]
+fn weighted_sum(first: u64, second: u64, _weight: u64) -> u64 {
+    (first + second) * _weight
+}
+
+#[builder]
+fn range_len(range: Range, step: u64) -> u64 {
+    let step = if step == 0 { 1 } else { step };
+    (range.end - range.start) / step
+}
+
+#[builder]
+fn zero() -> u64 {
+    0
+}
+
+fn main() -> bool {
+    let builder = WeightedSumBuilder {};
+    let partial = builder.first(1).second(2);
+    assert(partial.weight(3).call() == 9);
+    assert(partial.weight(4).call() == 12);
+
+    let builder = RangeLenBuilder {};
+    let range = Range { start: 10, end: 20 };
+    assert(builder.range(range).step(5).call() == 2);
+    assert(builder.range(range).step(0).call() == 10);
+
+    let builder = ZeroBuilder {};
+    assert(builder.call() == 0);
+
+    true
+}
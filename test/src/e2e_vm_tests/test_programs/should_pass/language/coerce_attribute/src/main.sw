@@ -0,0 +1,58 @@
+script;
+
+use std::{constants::ZERO_B256, convert::From};
+
+struct Meters {
+    value: u64,
+}
+
+struct Feet {
+    value: u64,
+}
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Self {
+        Meters {
+            value: feet.value * 3 / 10,
+        }
+    }
+
+    fn into(self) -> Feet {
+        Feet {
+            value: self.value * 10 / 3,
+        }
+    }
+}
+
+#[coerce(distance)]
+fn walk(distance: Meters, steps: u64) -> u64 {
+    distance.value + steps
+}
+
+#[coerce(address)]
+fn is_zero(address: Address) -> bool {
+    address == Address::from(ZERO_B256)
+}
+
+struct Counter {
+    count: u64,
+}
+
+impl Counter {
+    #[coerce(by)]
+    fn advance(self, by: Meters) -> u64 {
+        self.count + by.value
+    }
+}
+
+fn main() -> bool {
+    assert(walk(Meters { value: 3 }, 1) == 4);
+    assert(walk(Feet { value: 10 }, 1) == 4);
+    assert(is_zero(ZERO_B256));
+    assert(is_zero(Address::from(ZERO_B256)));
+
+    let counter = Counter { count: 1 };
+    assert(counter.advance(Feet { value: 20 }) == 7);
+
+    true
+}
@@ -0,0 +1,4 @@
+library;
+
+pub const BASE_FEE: u64 = 10;
+pub const FEE_COUNT = 1 + 2;
@@ -0,0 +1,59 @@
+script;
+
+mod fees;
+
+use fees::{BASE_FEE, FEE_COUNT};
+
+// The type of a constant can be inferred from its value, and the value can refer to other
+// constants, including ones declared later or in other modules.
+const TOTAL_FEE = FEE + BASE_FEE;
+const FEE = 30;
+const DOUBLE_FEE = FEE * 2;
+const HALF_FEE: u64 = DOUBLE_FEE / 4;
+const DISCOUNT = TOTAL_FEE - 5;
+
+struct Fees {
+    values: [u64; fees::FEE_COUNT],
+}
+
+fn sum(values: [u64; fees::FEE_COUNT]) -> u64 {
+    values[0] + values[1] + values[2]
+}
+
+fn classify(fee: u64) -> u64 {
+    match fee {
+        FEE => 1,
+        TOTAL_FEE => 2,
+        DOUBLE_FEE => 3,
+        HALF_FEE => 4,
+        DISCOUNT => 5,
+        _ => 0,
+    }
+}
+
+fn main() -> u64 {
+    let fees = Fees {
+        values: [FEE, BASE_FEE, TOTAL_FEE],
+    };
+    let repeated: [u64; FEE_COUNT] = [HALF_FEE; FEE_COUNT];
+    let counts = [1u8; fees::FEE_COUNT];
+
+    assert(sum(fees.values) == 80);
+    assert(repeated[2] == 15);
+    assert(counts[2] == 1);
+
+    assert(classify(30) == 1);
+    assert(classify(40) == 2);
+    assert(classify(60) == 3);
+    assert(classify(15) == 4);
+    assert(classify(35) == 5);
+    assert(classify(0) == 0);
+
+    classify(TOTAL_FEE)
+}
+
+fn assert(condition: bool) {
+    if !condition {
+        __revert(0)
+    }
+}
@@ -0,0 +1,28 @@
+script;
+
+use std::constants::{BASE_ASSET_ID, ZERO_B256};
+
+abi MyContract {
+    #[payable]
+    fn deposit();
+}
+
+fn main() {
+    let caller = abi(MyContract, ZERO_B256);
+
+    // The asset ID can be given as an `AssetId`...
+    caller.deposit {
+        coins: 1,
+        asset_id: BASE_ASSET_ID,
+    } ();
+
+    // ...or as a `b256`.
+    caller.deposit {
+        coins: 1,
+        asset_id: ZERO_B256,
+        gas: 10000,
+    } ();
+
+    // All the parameters are optional.
+    caller.deposit();
+}
@@ -0,0 +1,55 @@
+script;
+
+#[deprecated(note = "Use `transfer_to` instead.")]
+fn transfer(amount: u64) -> u64 {
+    transfer_to(amount)
+}
+
+fn transfer_to(amount: u64) -> u64 {
+    amount
+}
+
+#[deprecated]
+struct OldPoint {
+    x: u64,
+}
+
+#[deprecated]
+const OLD_LIMIT: u64 = 10;
+
+// Uses of deprecated items inside deprecated items are not reported.
+#[deprecated(note = "Use `OldPoint` directly.")]
+fn make_old_point(x: u64) -> OldPoint {
+    OldPoint { x: x + OLD_LIMIT }
+}
+
+enum Kind {
+    #[deprecated(note = "Use `Kind::New` instead.")]
+    Old: (),
+    New: (),
+}
+
+fn kind_value(kind: Kind) -> u64 {
+    match kind {
+        Kind::Old => 1,
+        Kind::New => 2,
+    }
+}
+
+struct S {}
+
+impl S {
+    #[deprecated]
+    fn old_method(self) -> u64 {
+        OLD_LIMIT
+    }
+}
+
+fn main() -> u64 {
+    let a = transfer(1);
+    let p = make_old_point(2);
+    let q = OldPoint { x: 3 };
+    let k = kind_value(Kind::Old) + kind_value(Kind::New);
+    let b = S {}.old_method();
+    a + p.x + q.x + k + b + OLD_LIMIT
+}
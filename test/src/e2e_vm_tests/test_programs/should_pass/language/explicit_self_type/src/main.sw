@@ -0,0 +1,39 @@
+script;
+
+struct Ptr<T> {
+    inner: T,
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn get(self) -> u64 {
+        self.value
+    }
+
+    fn get_wrapped(self: Ptr<Self>) -> u64 {
+        self.inner.value + 1
+    }
+
+    fn add_wrapped(self: Ptr<Self>, other: u64) -> u64 {
+        self.inner.value + other
+    }
+}
+
+struct Pair<T> {
+    a: T,
+}
+
+impl<T> Pair<T> {
+    fn first(self: Ptr<Self>) -> T {
+        self.inner.a
+    }
+}
+
+fn main() -> u64 {
+    let p = Ptr { inner: Counter { value: 41 } };
+    let q = Ptr { inner: Pair { a: 7 } };
+    p.get_wrapped() + p.add_wrapped(1) + q.first() + p.inner.get()
+}
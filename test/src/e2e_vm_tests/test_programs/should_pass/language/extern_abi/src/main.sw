@@ -0,0 +1,28 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+#[extern_abi(register)]
+#[inline(never)]
+fn add(x: u64, y: u64, double: bool) -> u64 {
+    let sum = x + y;
+    if double {
+        sum * 2
+    } else {
+        sum
+    }
+}
+
+#[extern_abi(memory)]
+#[inline(never)]
+fn sum(p: Point, offset: u64) -> u64 {
+    p.x + p.y + offset
+}
+
+fn main() -> u64 {
+    // 2 * (3 + 4) + (10 + 11 + 7)
+    add(3, 4, true) + sum(Point { x: 10, y: 11 }, 7)
+}
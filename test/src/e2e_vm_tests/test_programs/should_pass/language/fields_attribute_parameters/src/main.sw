@@ -0,0 +1,51 @@
+script;
+
+struct Config {
+    fee: u64,
+    rate: u64,
+    enabled: bool,
+}
+
+struct Limits {
+    min: u64,
+    max: u64,
+}
+
+// Only the fields the body refers to are bound, so the unused `enabled` is not reported.
+fn cost(#[fields] config: Config, amount: u64) -> u64 {
+    fee + amount * rate
+}
+
+// The fields of several parameters can be bound, and the parameter itself remains usable.
+fn clamp(#[fields] config: Config, #[fields] limits: Limits, amount: u64) -> u64 {
+    let total = if config.enabled { fee + amount } else { amount };
+    if total < min {
+        min
+    } else if total > max {
+        max
+    } else {
+        total
+    }
+}
+
+struct Account {
+    balance: u64,
+}
+
+impl Account {
+    fn charge(self, #[fields] config: Config) -> u64 {
+        self.balance - fee
+    }
+}
+
+fn main() -> u64 {
+    let config = Config {
+        fee: 2,
+        rate: 3,
+        enabled: true,
+    };
+    let limits = Limits { min: 5, max: 10 };
+    let account = Account { balance: 20 };
+    // 2 + 4 * 3 + 10 + 18
+    cost(config, 4) + clamp(config, limits, 30) + account.charge(config)
+}
@@ -0,0 +1,29 @@
+script;
+
+struct Wrapper<T> {
+    value: T,
+}
+
+type Balance = u64;
+type Pair<T> = (T, T);
+type Wrapped<T> = Wrapper<T>;
+type BalancePair = Pair<Balance>;
+
+fn sum(pair: Pair<u64>) -> u64 {
+    pair.0 + pair.1
+}
+
+fn unwrap<T>(wrapped: Wrapped<T>) -> T {
+    wrapped.value
+}
+
+fn main() -> u64 {
+    let balances: BalancePair = (10, 20);
+    let total: Balance = sum(balances);
+
+    let flags: Pair<bool> = (true, false);
+    assert(flags.0 && !flags.1);
+
+    let wrapped: Wrapped<Balance> = Wrapper { value: 12 };
+    total + unwrap(wrapped)
+}
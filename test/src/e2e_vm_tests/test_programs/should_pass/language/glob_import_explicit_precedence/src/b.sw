@@ -0,0 +1,5 @@
+library;
+
+pub const ZERO: u64 = 10;
+
+pub const ONE: u64 = 11;
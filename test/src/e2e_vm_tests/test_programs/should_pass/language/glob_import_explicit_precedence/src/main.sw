@@ -0,0 +1,15 @@
+script;
+
+mod a;
+mod b;
+
+// The explicit import takes precedence over both glob imports,
+// regardless of the order of the `use` statements.
+use ::a::ZERO;
+use ::a::*;
+use ::b::*;
+use ::b::ONE;
+
+fn main() -> u64 {
+    ZERO + ONE
+}
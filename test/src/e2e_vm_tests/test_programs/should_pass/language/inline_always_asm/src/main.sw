@@ -0,0 +1,13 @@
+script;
+
+#[inline(always)]
+fn add(a: u64, b: u64) -> u64 {
+    asm(a: a, b: b, res) {
+        add res a b;
+        res: u64
+    }
+}
+
+fn main() -> u64 {
+    add(1, 2)
+}
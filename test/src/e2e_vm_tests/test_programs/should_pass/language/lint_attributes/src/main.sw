@@ -0,0 +1,33 @@
+#![allow(non_upper_case_globals)]
+script;
+
+const lowercase_const: u64 = 1;
+
+struct S {}
+
+#[allow(dead_code)]
+impl S {
+    fn unused_method(self) {}
+}
+
+#[allow(unused_variables)]
+fn with_unused_variable() {
+    let allowed = 5;
+}
+
+fn without_allow() {
+    let not_allowed = 5;
+}
+
+#[allow(dead_code)]
+fn unused() {}
+
+#[allow(unused_variables)]
+fn also_unused() {}
+
+fn main() -> u64 {
+    let _ = S {};
+    with_unused_variable();
+    without_allow();
+    lowercase_const
+}
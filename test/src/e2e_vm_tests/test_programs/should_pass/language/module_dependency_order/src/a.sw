@@ -0,0 +1,8 @@
+library;
+
+use ::b::{get_value, Wrapper};
+
+pub fn get_answer() -> u64 {
+    let wrapper: Wrapper = get_value();
+    wrapper.value
+}
@@ -0,0 +1,11 @@
+library;
+
+use ::c::ANSWER;
+
+pub struct Wrapper {
+    value: u64,
+}
+
+pub fn get_value() -> Wrapper {
+    Wrapper { value: ANSWER }
+}
@@ -0,0 +1,13 @@
+script;
+// This tests that submodules are type-checked after the submodules they import from,
+// regardless of the order in which they are declared.
+
+mod a;
+mod b;
+mod c;
+
+use a::get_answer;
+
+fn main() -> u64 {
+    get_answer()
+}
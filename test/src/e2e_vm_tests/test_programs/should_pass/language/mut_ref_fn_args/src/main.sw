@@ -0,0 +1,29 @@
+script;
+
+struct Counter {
+    value: u32,
+}
+
+impl Counter {
+    fn increment(mut ref self) {
+        self.value += 1;
+    }
+}
+
+fn set_to_twenty(mut ref b: u32) {
+    b = 20;
+}
+
+fn add_ten(ref mut b: u32) {
+    b += 10;
+}
+
+fn main() -> u32 {
+    let mut b = 0u32;
+    set_to_twenty(b);
+    add_ten(b);
+
+    let mut counter = Counter { value: b };
+    counter.increment();
+    counter.value
+}
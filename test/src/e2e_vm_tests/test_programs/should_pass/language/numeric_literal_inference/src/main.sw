@@ -0,0 +1,18 @@
+script;
+
+fn add_u8(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn main() -> u64 {
+    // The literal gets its type from its first constrained use.
+    let x = 1;
+    let y: u8 = x;
+    let z = add_u8(y, 2);
+
+    // Unconstrained literals default to `u64`.
+    let n = 40;
+    let m = n + 2;
+
+    if z == 3 { m } else { 0 }
+}
@@ -0,0 +1,38 @@
+script;
+
+fn add_bonus(value: u64, bonus?: u64) -> u64 {
+    match bonus {
+        Some(bonus) => value + bonus,
+        None => value,
+    }
+}
+
+fn count_given(first?: u64, second?: bool) -> u64 {
+    let mut count = 0;
+    if first.is_some() {
+        count += 1;
+    }
+    if second.is_some() {
+        count += 1;
+    }
+    count
+}
+
+#[builder]
+fn scaled(value: u64, factor?: u64) -> u64 {
+    value * factor.unwrap_or(1)
+}
+
+fn main() -> bool {
+    assert(add_bonus(1) == 1);
+    assert(add_bonus(1, 2) == 3);
+
+    assert(count_given() == 0);
+    assert(count_given(1) == 1);
+    assert(count_given(1, true) == 2);
+
+    let builder = ScaledBuilder {};
+    assert(builder.value(2).factor(3).call() == 6);
+
+    true
+}
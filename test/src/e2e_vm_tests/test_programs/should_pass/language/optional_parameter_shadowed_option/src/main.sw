@@ -0,0 +1,22 @@
+script;
+
+// Shadows `Option` of the std prelude. Optional parameters still use the std `Option`.
+struct Option {
+    value: u64,
+}
+
+fn add_bonus(value: u64, bonus?: u64) -> u64 {
+    match bonus {
+        Some(bonus) => value + bonus,
+        None => value,
+    }
+}
+
+fn main() -> bool {
+    let option = Option { value: 1 };
+
+    assert(add_bonus(option.value) == 1);
+    assert(add_bonus(option.value, 2) == 3);
+
+    true
+}
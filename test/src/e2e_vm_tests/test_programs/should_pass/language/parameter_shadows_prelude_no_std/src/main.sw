@@ -0,0 +1,15 @@
+script;
+
+// Without `std` there is no std prelude, so these parameters do not shadow anything.
+fn unwrap_or(Some: u64, default: u64) -> u64 {
+    if Some == 0 { default } else { Some }
+}
+
+fn checked(require: bool) -> bool {
+    require
+}
+
+fn main() {
+    let _ = unwrap_or(0, 1);
+    let _ = checked(true);
+}
@@ -0,0 +1,36 @@
+script;
+
+fn unwrap_or(Some: u64, default: u64) -> u64 {
+    if Some == 0 { default } else { Some }
+}
+
+struct S {}
+
+impl S {
+    fn method(self, Vec: u64) -> u64 {
+        Vec
+    }
+}
+
+fn checked(require: bool) -> bool {
+    require
+}
+
+fn not_shadowing(some: u64, vec: u64) -> u64 {
+    some + vec
+}
+
+// Shadows `Identity` of the prelude, so the parameter below does not shadow a prelude item.
+struct Identity {}
+
+fn local_item(Identity: u64) -> u64 {
+    Identity
+}
+
+fn main() {
+    let _ = unwrap_or(0, 1);
+    let _ = S {}.method(2);
+    let _ = checked(true);
+    let _ = not_shadowing(3, 4);
+    let _ = local_item(5);
+}
@@ -0,0 +1,46 @@
+script;
+
+struct Inner {
+    value: u64,
+}
+
+struct Outer {
+    inner: Inner,
+    pair: (u64, u64),
+    values: [u64; 2],
+}
+
+impl Outer {
+    fn set_to(self, ref mut value: u64, new_value: u64) {
+        value = new_value;
+    }
+}
+
+fn set_to(ref mut value: u64, new_value: u64) {
+    value = new_value;
+}
+
+fn set_inner(ref mut inner: Inner) {
+    inner.value = inner.value + 1;
+}
+
+fn main() -> u64 {
+    let mut outer = Outer {
+        inner: Inner { value: 0 },
+        pair: (0, 0),
+        values: [0, 0],
+    };
+    let helper = Outer {
+        inner: Inner { value: 0 },
+        pair: (0, 0),
+        values: [0, 0],
+    };
+
+    set_to(outer.inner.value, 1);
+    set_inner(outer.inner);
+    set_to(outer.pair.1, 10);
+    set_to(outer.values[1], 100);
+    helper.set_to(outer.values[0], 1000);
+
+    outer.inner.value + outer.pair.1 + outer.values[1] + outer.values[0]
+}
@@ -0,0 +1,46 @@
+script;
+
+fn weighted_sum(first: u64, second: u64, weight: u64) -> u64 {
+    (first + second) * weight
+}
+
+fn select(flag: bool, value: u64) -> u64 {
+    if flag { value } else { 0 }
+}
+
+fn zero() -> u64 {
+    0
+}
+
+fn offset_weighted_sum(offset: u64, ..rest) -> u64 {
+    offset + weighted_sum(..rest)
+}
+
+fn sum_with_first(first: u64, ..rest) -> u64 {
+    weighted_sum(first, ..rest)
+}
+
+// Forwards to a function which has a rest parameter itself.
+fn forward_all(..rest) -> u64 {
+    offset_weighted_sum(..rest)
+}
+
+// Forwards the same rest parameter twice.
+fn select_twice(..rest) -> u64 {
+    select(..rest) + select(..rest)
+}
+
+fn forward_nothing(..rest) -> u64 {
+    zero(..rest)
+}
+
+fn main() -> bool {
+    assert(offset_weighted_sum(1, 2, 3, 4) == 21);
+    assert(sum_with_first(1, 2, 3) == 9);
+    assert(forward_all(1, 2, 3, 4) == 21);
+    assert(select_twice(true, 5) == 10);
+    assert(select_twice(false, 5) == 0);
+    assert(forward_nothing() == 0);
+
+    true
+}
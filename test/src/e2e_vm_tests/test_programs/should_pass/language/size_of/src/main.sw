@@ -21,6 +21,14 @@ fn return_the_same<T>(elem: T) -> T {
   x
 }
 
+fn size_of_generic<T>() -> u64 {
+  __size_of::<T>()
+}
+
+fn size_of_val_generic<T>(elem: T) -> u64 {
+  __size_of_val(elem)
+}
+
 fn main() -> u64 {
     let x = Data {
         one: 1,
@@ -44,5 +52,9 @@ fn main() -> u64 {
     assert(__size_of_val(p) == 24);
     assert(__size_of_val(foo) == 8);
     assert(__size_of::<Point>() == 24);
+    assert(size_of_generic::<Data>() == 24);
+    assert(size_of_generic::<u64>() == 8);
+    assert(size_of_val_generic(p) == 24);
+    assert(size_of_val_generic(true) == 8);
     1
 }
@@ -0,0 +1,14 @@
+script;
+
+fn first(a: b256, b: b256, c: b256, count: u64) -> b256 {
+    a
+}
+
+fn main() -> b256 {
+    first(
+        0x1111111111111111111111111111111111111111111111111111111111111111,
+        0x2222222222222222222222222222222222222222222222222222222222222222,
+        0x3333333333333333333333333333333333333333333333333333333333333333,
+        3,
+    )
+}
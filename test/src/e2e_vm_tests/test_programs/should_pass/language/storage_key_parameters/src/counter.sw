@@ -0,0 +1,13 @@
+library;
+
+#[storage(read, write)]
+pub fn increment(ref mut counter: StorageKey<u64>, amount: u64) -> u64 {
+    let new_value = counter.read() + amount;
+    counter.write(new_value);
+    new_value
+}
+
+#[storage(read)]
+pub fn get(counter: StorageKey<u64>) -> u64 {
+    counter.read()
+}
@@ -0,0 +1,53 @@
+contract;
+
+mod counter;
+
+use counter::{get, increment};
+
+storage {
+    calls: u64 = 0,
+    total: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read, write)]
+    fn add(amount: u64) -> u64;
+
+    #[storage(read)]
+    fn calls() -> u64;
+
+    #[storage(read)]
+    fn total() -> u64;
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn add(amount: u64) -> u64 {
+        let _ = increment(storage.calls, 1);
+        increment(storage.total, amount)
+    }
+
+    #[storage(read)]
+    fn calls() -> u64 {
+        get(storage.calls)
+    }
+
+    #[storage(read)]
+    fn total() -> u64 {
+        get(storage.total)
+    }
+}
+
+#[test]
+fn test_writes_through_storage_key_parameters() {
+    let caller = abi(Counter, CONTRACT_ID);
+
+    assert(caller.calls() == 0);
+    assert(caller.total() == 0);
+
+    assert(caller.add(5) == 5);
+    assert(caller.add(7) == 12);
+
+    assert(caller.calls() == 2);
+    assert(caller.total() == 12);
+}
@@ -0,0 +1,34 @@
+script;
+
+const LEN: u64 = 4;
+
+// Interface parameter types are resolved like the parameter types of function declarations,
+// so array lengths in trait methods may refer to constants.
+trait Summable {
+    fn sum(self, values: [u64; LEN]) -> u64;
+} {
+    fn sum_twice(self, values: [u64; LEN]) -> u64 {
+        self.sum(values) + self.sum(values)
+    }
+}
+
+struct Adder {}
+
+impl Summable for Adder {
+    fn sum(self, values: [u64; LEN]) -> u64 {
+        let mut total = 0;
+        let mut i = 0;
+        while i < LEN {
+            total += values[i];
+            i += 1;
+        }
+        total
+    }
+}
+
+fn main() -> u64 {
+    let adder = Adder {};
+    let values: [u64; LEN] = [1, 2, 3, 4];
+    assert(adder.sum_twice(values) == 20);
+    adder.sum(values)
+}
@@ -0,0 +1,16 @@
+script;
+
+fn len<T>(_array: [T; 0]) -> u64 {
+    0
+}
+
+fn first<T>(array: [T; 2]) -> T {
+    array[0]
+}
+
+fn main() -> u64 {
+    // The element type of the empty array is only known from the explicit type argument.
+    let empty = len::<bool>([]);
+    let value = first::<u64>([40, 2]);
+    empty + value + 2
+}
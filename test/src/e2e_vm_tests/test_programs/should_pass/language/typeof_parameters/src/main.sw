@@ -0,0 +1,26 @@
+script;
+
+const MAX: u32 = 10;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+const ORIGIN: Point = Point { x: 1, y: 2 };
+
+// The type of `amount` is `u32`, the type of `MAX`.
+fn clamp(amount: typeof(MAX)) -> u32 {
+    if amount > MAX { MAX } else { amount }
+}
+
+// A parameter can be typed by an expression referring to the parameters declared before it.
+fn sum(point: typeof(ORIGIN), offset: typeof(point.x)) -> u64 {
+    point.x + point.y + offset
+}
+
+fn main() -> u64 {
+    let clamped: u32 = clamp(25);
+    // 10 + (1 + 2 + 29)
+    clamped.as_u64() + sum(ORIGIN, 29)
+}
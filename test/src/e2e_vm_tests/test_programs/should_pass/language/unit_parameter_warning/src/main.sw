@@ -0,0 +1,21 @@
+script;
+
+fn takes_unit(a: u64, unit: ()) -> u64 {
+    a
+}
+
+fn generic<T>(value: T) -> T {
+    value
+}
+
+struct S {}
+
+impl S {
+    fn method(self, unit: ()) {}
+}
+
+fn main() {
+    let _ = takes_unit(1, ());
+    generic(());
+    S {}.method(());
+}
@@ -0,0 +1,46 @@
+script;
+
+struct Config {
+    base: u64,
+    step: u64,
+}
+
+// The address of `config.step`, and `config.base * 2`, are the same in every iteration, and get
+// hoisted out of the loop.
+fn sum(config: Config, len: u64) -> u64 {
+    let mut i = 0;
+    let mut total = 0;
+    while i < len {
+        total += config.base * 2 + i * config.step;
+        i += 1;
+    }
+    total
+}
+
+// The loop which never runs must not revert on the overflowing invariant multiplication. The
+// trip count is read from the `zero` register, so that the loop is not folded away.
+fn never_runs(config: Config) -> u64 {
+    let len = asm() { zero };
+    let mut i = 0;
+    while i < len {
+        i += config.base * 0xFFFF_FFFF_FFFF_FFFF;
+    }
+    i
+}
+
+// Once `sum` is inlined, its loop is nested in this one.
+fn sum_of_sums(config: Config, len: u64) -> u64 {
+    let mut len_i = 0;
+    let mut total = 0;
+    while len_i < len {
+        total += sum(config, len_i);
+        len_i += 1;
+    }
+    total
+}
+
+fn main() -> u64 {
+    let config = Config { base: 10, step: 3 };
+    // 5 * 20 + 3 * (0 + 1 + 2 + 3 + 4) = 130, and 0 + 20 + (40 + 3) = 63.
+    sum(config, 5) + sum_of_sums(config, 3) + never_runs(config)
+}
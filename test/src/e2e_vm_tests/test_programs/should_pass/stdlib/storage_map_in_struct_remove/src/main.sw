@@ -0,0 +1,54 @@
+contract;
+
+abi RegistryFlags {
+    #[storage(read, write)]
+    fn set_flag(key: u64, value: bool);
+
+    #[storage(read)]
+    fn flag(key: u64) -> Option<bool>;
+
+    #[storage(write)]
+    fn remove_flag(key: u64) -> bool;
+}
+
+struct Registry {
+    version: u64,
+    flags: StorageMap<u64, bool>,
+}
+
+storage {
+    registry: Registry = Registry {
+        version: 1,
+        flags: StorageMap {},
+    },
+}
+
+impl RegistryFlags for Contract {
+    #[storage(read, write)]
+    fn set_flag(key: u64, value: bool) {
+        storage.registry.flags.insert(key, value);
+    }
+
+    #[storage(read)]
+    fn flag(key: u64) -> Option<bool> {
+        storage.registry.flags.get(key).try_read()
+    }
+
+    #[storage(write)]
+    fn remove_flag(key: u64) -> bool {
+        storage.registry.flags.remove(key)
+    }
+}
+
+#[test]
+fn test_remove_from_map_in_struct() {
+    let caller = abi(RegistryFlags, CONTRACT_ID);
+
+    caller.set_flag(7, true);
+    caller.set_flag(8, true);
+    assert(caller.flag(7).unwrap());
+
+    let _ = caller.remove_flag(7);
+    assert(caller.flag(7).is_none());
+    assert(caller.flag(8).unwrap());
+}
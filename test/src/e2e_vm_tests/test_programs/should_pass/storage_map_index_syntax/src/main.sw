@@ -0,0 +1,157 @@
+contract;
+
+abi MapIndexSyntax {
+    #[storage(read, write)]
+    fn set_balance(owner: Identity, amount: u64);
+
+    #[storage(read, write)]
+    fn add_to_balance(owner: Identity, amount: u64);
+
+    #[storage(read)]
+    fn balance(owner: Identity) -> Option<u64>;
+
+    #[storage(read, write)]
+    fn set_counter(key: u64, value: u64);
+
+    #[storage(read, write)]
+    fn add_to_next_counter(amount: u64);
+
+    #[storage(read)]
+    fn counter(key: u64) -> Option<u64>;
+
+    #[storage(read, write)]
+    fn set_allowance(owner: Identity, spender: u64, amount: u64);
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: u64) -> Option<u64>;
+
+    #[storage(read, write)]
+    fn set_nested_value(key: u64, value: bool);
+
+    #[storage(read)]
+    fn nested_value(key: u64) -> Option<bool>;
+}
+
+struct Registry {
+    flags: StorageMap<u64, bool>,
+}
+
+storage {
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    counters: StorageMap<u64, u64> = StorageMap {},
+    next_counter_key: u64 = 0,
+    allowances: StorageMap<Identity, StorageMap<u64, u64>> = StorageMap {},
+    registry: Registry = Registry {
+        flags: StorageMap {},
+    },
+}
+
+#[storage(read, write)]
+fn next_counter_key() -> u64 {
+    let key = storage.next_counter_key.try_read().unwrap_or(0);
+    storage.next_counter_key.write(key + 1);
+    key
+}
+
+impl MapIndexSyntax for Contract {
+    #[storage(read, write)]
+    fn set_balance(owner: Identity, amount: u64) {
+        storage.balances[owner] = amount;
+    }
+
+    #[storage(read, write)]
+    fn add_to_balance(owner: Identity, amount: u64) {
+        storage.balances[owner] += amount;
+    }
+
+    #[storage(read)]
+    fn balance(owner: Identity) -> Option<u64> {
+        storage.balances[owner].try_read()
+    }
+
+    #[storage(read, write)]
+    fn set_counter(key: u64, value: u64) {
+        storage.counters[key] = value;
+    }
+
+    #[storage(read, write)]
+    fn add_to_next_counter(amount: u64) {
+        storage.counters[next_counter_key()] += amount;
+    }
+
+    #[storage(read)]
+    fn counter(key: u64) -> Option<u64> {
+        storage.counters[key].try_read()
+    }
+
+    #[storage(read, write)]
+    fn set_allowance(owner: Identity, spender: u64, amount: u64) {
+        storage.allowances[owner][spender] = amount;
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: u64) -> Option<u64> {
+        storage.allowances[owner][spender].try_read()
+    }
+
+    #[storage(read, write)]
+    fn set_nested_value(key: u64, value: bool) {
+        storage.registry.flags[key] = value;
+    }
+
+    #[storage(read)]
+    fn nested_value(key: u64) -> Option<bool> {
+        storage.registry.flags[key].try_read()
+    }
+}
+
+#[test()]
+fn test_map_index() {
+    let caller = abi(MapIndexSyntax, CONTRACT_ID);
+    let owner = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000001));
+
+    assert(caller.balance(owner).is_none());
+
+    caller.set_balance(owner, 40);
+    assert(caller.balance(owner).unwrap() == 40);
+
+    caller.add_to_balance(owner, 2);
+    assert(caller.balance(owner).unwrap() == 42);
+}
+
+#[test()]
+fn test_map_compound_assignment_evaluates_key_once() {
+    let caller = abi(MapIndexSyntax, CONTRACT_ID);
+
+    caller.set_counter(0, 1);
+    caller.set_counter(1, 10);
+
+    caller.add_to_next_counter(5);
+    assert(caller.counter(0).unwrap() == 6);
+    assert(caller.counter(1).unwrap() == 10);
+}
+
+#[test()]
+fn test_map_of_maps_index() {
+    let caller = abi(MapIndexSyntax, CONTRACT_ID);
+    let owner = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000001));
+    let other = Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000002));
+
+    caller.set_allowance(owner, 1, 10);
+    caller.set_allowance(owner, 2, 20);
+
+    assert(caller.allowance(owner, 1).unwrap() == 10);
+    assert(caller.allowance(owner, 2).unwrap() == 20);
+    assert(caller.allowance(other, 1).is_none());
+}
+
+#[test()]
+fn test_map_in_struct_index() {
+    let caller = abi(MapIndexSyntax, CONTRACT_ID);
+
+    assert(caller.nested_value(7).is_none());
+
+    caller.set_nested_value(7, true);
+    assert(caller.nested_value(7).unwrap());
+    assert(caller.nested_value(8).is_none());
+}
@@ -0,0 +1,79 @@
+contract;
+
+struct Limits {
+    min: u64,
+    max: u64,
+}
+
+enum Color {
+    Red: (),
+    Green: (),
+}
+
+enum Mode {
+    Off: (),
+    Fixed: u64,
+    Range: Limits,
+}
+
+struct Tags {
+    color: Color,
+    values: [u64; 3],
+    name: str[5],
+}
+
+struct SystemConfig {
+    enabled: bool,
+    limits: Limits,
+    mode: Mode,
+    tags: Tags,
+    flags: [bool; 2],
+    owner: b256,
+}
+
+storage {
+    config: SystemConfig = SystemConfig {
+        enabled: true,
+        limits: Limits { min: 1, max: 2 },
+        mode: Mode::Range(Limits { min: 3, max: 4 }),
+        tags: Tags {
+            color: Color::Green,
+            values: [5, 6, 7],
+            name: "fuels",
+        },
+        flags: [true, false],
+        owner: 0x0000000000000000000000000000000000000000000000000000000000000008,
+    },
+    values: [u64; 2] = [9, 10],
+}
+
+abi StorageNestedTypes {
+    #[storage(read, write)]
+    fn set_max(max: u64) -> u64;
+
+    #[storage(read, write)]
+    fn set_mode_fixed(value: u64) -> Mode;
+
+    #[storage(read, write)]
+    fn set_values(values: [u64; 3]) -> u64;
+}
+
+impl StorageNestedTypes for Contract {
+    #[storage(read, write)]
+    fn set_max(max: u64) -> u64 {
+        storage.config.limits.max.write(max);
+        storage.config.limits.max.read()
+    }
+
+    #[storage(read, write)]
+    fn set_mode_fixed(value: u64) -> Mode {
+        storage.config.mode.write(Mode::Fixed(value));
+        storage.config.mode.read()
+    }
+
+    #[storage(read, write)]
+    fn set_values(values: [u64; 3]) -> u64 {
+        storage.config.tags.values.write(values);
+        storage.config.tags.values.read()[1] + storage.values.read()[0]
+    }
+}
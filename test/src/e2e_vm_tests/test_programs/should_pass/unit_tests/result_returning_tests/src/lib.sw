@@ -0,0 +1,33 @@
+library;
+
+fn checked_sub(a: u64, b: u64) -> Result<u64, str[9]> {
+  if b > a {
+    Result::Err("underflow")
+  } else {
+    Result::Ok(a - b)
+  }
+}
+
+#[test]
+fn result_ok_passes() -> Result<(), str[9]> {
+  match checked_sub(5, 3) {
+    Result::Ok(diff) => {
+      assert(diff == 2);
+      Result::Ok(())
+    },
+    Result::Err(e) => Result::Err(e),
+  }
+}
+
+#[test]
+fn result_err_is_handled() -> Result<u64, u64> {
+  match checked_sub(3, 5) {
+    Result::Ok(_) => Result::Err(0),
+    Result::Err(_) => Result::Ok(1),
+  }
+}
+
+#[test(should_revert)]
+fn result_err_counts_as_revert() -> Result<u64, str[9]> {
+  checked_sub(3, 5)
+}
@@ -37,9 +37,8 @@ fn main() -> [M2; 6] {
 }
 
 #[test]
-fn test() -> [M2; 6] {
+fn test() {
    let mut b = bar();
    assert(b.0[0].a.a == U256::new());
    assert(b.0[0].a.b == U256::max());
-   b.0
 }
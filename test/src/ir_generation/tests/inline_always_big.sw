@@ -0,0 +1,23 @@
+// optimisation-inline
+
+script;
+
+// Too big to be inlined by the heuristic, and called more than once.
+#[inline(always)]
+fn always_inline(a: u64, b: u64) -> u64 {
+    let c = a * b;
+    let d = c + a;
+    let e = d - b;
+    e * c + d
+}
+
+fn main() -> u64 {
+    always_inline(1, 2) + always_inline(3, 4)
+}
+
+// ::check-ir::
+
+// check: fn always_inline
+
+// ::check-asm::
+// not: call always_inline
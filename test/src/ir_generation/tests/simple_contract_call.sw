@@ -130,6 +130,6 @@ fn main() -> u64 {
 // check: store $user_arg to $args_user_ptr
 
 // check: $(asset_id_ptr=$VAL) = get_local ptr b256, $asset_id_2_const
-// check: $(gas=$VAL) = read_register cgas
+// check: $(gas=$VAL) = asm() -> u64 cgas
 // check: $(coins=$VAL) = const u64 0
 // check: $(call_res=$VAL) = contract_call ptr { u64, b256 } get_s $args_ptr, $coins, $asset_id_ptr, $gas